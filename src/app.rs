@@ -1,24 +1,50 @@
 use anyhow::Result;
 use crossterm::{
+    cursor::Show,
     event::{self, DisableMouseCapture, EnableMouseCapture, Event},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
     Terminal,
 };
 use std::io::{self, Stdout};
-use std::time::Duration;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
 use crate::config::Config;
+use crate::data::models::{HistoryEntry, Task};
 use crate::handlers::input::InputHandler;
-use crate::taskwarrior::TaskwarriorIntegration;
+use crate::taskwarrior::{TaskBinaryNotFound, TaskwarriorIntegration};
 use crate::ui::app_ui::AppUI;
+use crate::utils::editor;
 
 pub type AppTerminal = Terminal<CrosstermBackend<Stdout>>;
 
+// How often the loop looks for finished loads, and the spinner's frame rate
+pub const BUSY_WAKE_UP: Duration = Duration::from_millis(100);
+// An idle screen is still redrawn this often, so "in 5m", "2h ago" and overdue colors keep up
+pub const IDLE_REDRAW: Duration = Duration::from_secs(60);
+
+/// How long to wait for input before the loop has something else to do: queued
+/// bulk work runs right away, running loads are checked on every `BUSY_WAKE_UP`,
+/// and otherwise nothing happens until the next idle redraw
+pub fn next_wake_up(has_pending_work: bool, waiting_on_loads: bool, since_draw: Duration) -> Duration {
+    if has_pending_work {
+        Duration::ZERO
+    } else if waiting_on_loads {
+        BUSY_WAKE_UP
+    } else {
+        IDLE_REDRAW.saturating_sub(since_draw)
+    }
+}
+
 pub struct App {
     pub config: Config,
     pub terminal: AppTerminal,
@@ -29,25 +55,50 @@ pub struct App {
 }
 
 impl App {
-    pub fn new(config_path: Option<&str>, _verbose: bool) -> Result<Self> {
+    pub fn new(
+        config_path: Option<&str>,
+        taskrc_path: Option<PathBuf>,
+        data_location: Option<PathBuf>,
+        startup_filter: Option<String>,
+    ) -> Result<Self> {
+        Self::install_panic_hook();
+
         // Initialize terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
         execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
         let backend = CrosstermBackend::new(stdout);
-        let terminal = Terminal::new(backend)?;
+        let mut terminal = Terminal::new(backend)?;
 
-        // Load configuration
-        let config = Config::load(config_path)?;
+        // Load configuration; --taskrc/--data/--filter win over the config file
+        let config = match Config::load(config_path)
+            .and_then(|config| config.with_taskwarrior_overrides(taskrc_path, data_location))
+            .map(|config| config.with_startup_filter(startup_filter))
+        {
+            Ok(config) => config,
+            Err(e) => {
+                Self::restore_terminal(&mut terminal);
+                return Err(e);
+            }
+        };
         
         // Initialize Taskwarrior integration
-        let taskwarrior = TaskwarriorIntegration::new(
-            config.taskwarrior.taskrc_path.clone(),
-            config.taskwarrior.data_location.clone(),
-        )?;
+        let taskwarrior = match TaskwarriorIntegration::from_config(&config.taskwarrior) {
+            Ok(taskwarrior) => taskwarrior,
+            Err(e) => {
+                if e.downcast_ref::<TaskBinaryNotFound>().is_some() {
+                    let _ = Self::show_missing_taskwarrior(&mut terminal, &e.to_string());
+                }
+                Self::restore_terminal(&mut terminal);
+                return Err(e);
+            }
+        };
         
         // Initialize components
-        let ui = AppUI::new(&config)?;
+        let mut ui = AppUI::new(&config)?;
+        if config.ui.startup_briefing {
+            ui.request_startup_briefing();
+        }
         let input_handler = InputHandler::new(&config);
 
         Ok(App {
@@ -61,45 +112,100 @@ impl App {
     }
 
     pub async fn run(&mut self) -> Result<()> {
-        // Create channels for async communication
-        let (_tx, mut _rx) = mpsc::channel::<String>(32);
+        // Task exports run on a blocking thread and report back here
+        let (tx, mut rx) = mpsc::channel::<Result<Vec<Task>>>(32);
+        // So does `task info` for the selected task's history
+        let (history_tx, mut history_rx) = mpsc::channel::<(String, Result<Vec<HistoryEntry>>)>(32);
 
         // Initialize with tasks
-        self.ui.load_tasks(&self.taskwarrior).await?;
+        self.start_task_load(&tx);
 
-        // Flag to track when we need to redraw
+        // Drawing only happens when something changed; an idle terminal just waits for input
         let mut needs_redraw = true;
+        let mut last_draw = Instant::now();
+        // `task info` calls still running; their results can't wake the loop by themselves
+        let mut history_loads = 0usize;
 
         loop {
+            // Apply any finished loads and kick off requested ones
+            while let Ok(result) = rx.try_recv() {
+                self.ui.finish_loading(result);
+                self.ui.report_skipped_tasks(self.taskwarrior.skipped_tasks());
+                needs_redraw = true;
+            }
+            if self.ui.take_reload_request() {
+                self.start_task_load(&tx);
+                needs_redraw = true;
+            }
+            while let Ok((uuid, result)) = history_rx.try_recv() {
+                self.ui.finish_history(&uuid, result);
+                history_loads = history_loads.saturating_sub(1);
+                needs_redraw = true;
+            }
+            if let Some(uuid) = self.ui.take_history_request() {
+                self.start_history_load(uuid, &history_tx);
+                history_loads += 1;
+            }
+
+            // Keep the loading spinner moving
+            if self.ui.is_loading() {
+                needs_redraw = true;
+            }
+
+            // Step queued bulk operations, redrawing progress as they go
+            if self.ui.has_pending_work() {
+                self.ui.process_pending_work(&self.taskwarrior).await;
+                needs_redraw = true;
+            }
+
+            if last_draw.elapsed() >= IDLE_REDRAW {
+                needs_redraw = true;
+            }
+
             // Only draw if needed
             if needs_redraw {
                 self.terminal.draw(|f| self.ui.draw(f))?;
                 needs_redraw = false;
+                last_draw = Instant::now();
             }
 
-            // Handle input and resize events - block for a bit longer to reduce CPU usage
-            if event::poll(Duration::from_millis(250))? {
+            // Block until input arrives or the loop has something else to do
+            let wait = next_wake_up(
+                self.ui.has_pending_work(),
+                self.ui.is_loading() || history_loads > 0,
+                last_draw.elapsed(),
+            );
+            if event::poll(wait)? {
                 match event::read()? {
                     Event::Key(key) => {
                         let in_form = self.ui.has_active_form();
-                        let action = self.input_handler.handle_key_event_with_context(key, in_form);
-                        match action {
-                            crate::handlers::input::Action::Quit => {
-                                self.should_quit = true;
-                            }
-                            _ => {
-                                // Handle other actions and trigger redraw
-                                self.ui.handle_action(action, &self.taskwarrior).await?;
-                                needs_redraw = true;
-                            }
+                        let action = self.input_handler.handle_key_event_in_view(key, in_form, self.ui.key_context());
+                        // Quit goes through the UI too, which may ask about unsaved changes first.
+                        // A failed or timed-out `task` shows in the footer rather than ending the app.
+                        if let Err(e) = self.ui.handle_action(action, &self.taskwarrior).await {
+                            self.ui.report_error(&e);
+                        }
+                        needs_redraw = true;
+                        if let Some(config) = self.ui.take_reloaded_config() {
+                            self.input_handler = InputHandler::new(&config);
+                            self.config = config;
+                        }
+                        if self.ui.take_quit_request() {
+                            self.should_quit = true;
+                        }
+                        if let Some(text) = self.ui.take_editor_request() {
+                            let result = self.run_external_editor(&text);
+                            self.ui.finish_external_edit(result);
                         }
                     }
-                    Event::Resize(_, _) => {
-                        // Terminal was resized - trigger immediate redraw
+                    Event::Resize(width, height) => {
+                        // Take the new size (clearing the screen) before the next frame, so it
+                        // isn't diffed against what was on screen at the old size
+                        self.terminal.resize(Rect::new(0, 0, width, height))?;
                         needs_redraw = true;
                     }
                     _ => {
-                        // Ignore other events (mouse, focus, etc.)
+                        // Ignore other events (mouse, focus, etc.); nothing to redraw
                     }
                 }
             }
@@ -109,19 +215,119 @@ impl App {
             }
         }
 
+        // A theme picked with the theme key sticks for the next start
+        if let Some(theme) = self.ui.switched_theme() {
+            if let Err(e) = self.config.save_theme_name(theme) {
+                log::warn!("Couldn't save theme \"{}\": {:#}", theme, e);
+            }
+        }
+
         Ok(())
     }
-}
 
-impl Drop for App {
-    fn drop(&mut self) {
-        // Restore terminal
+    // Full-screen explanation shown before exiting when `task` isn't installed
+    fn show_missing_taskwarrior(terminal: &mut AppTerminal, message: &str) -> Result<()> {
+        let text = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                message.to_string(),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from("LazyTask is a frontend for Taskwarrior and needs the `task` command."),
+            Line::from("Install it from https://taskwarrior.org/download/ or your package manager,"),
+            Line::from("then make sure `task --version` works in your shell."),
+            Line::from(""),
+            Line::from(Span::styled("Press any key to exit", Style::default().fg(Color::Gray))),
+        ];
+
+        terminal.draw(|f| {
+            let message = Paragraph::new(text)
+                .block(Block::default()
+                    .title("Taskwarrior not found")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Red))
+                )
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true });
+            f.render_widget(message, f.area());
+        })?;
+
+        loop {
+            if let Event::Key(_) = event::read()? {
+                return Ok(());
+            }
+        }
+    }
+
+    // Hand the terminal to $EDITOR and take it back afterwards, whatever the editor did
+    fn run_external_editor(&mut self, text: &str) -> Result<Option<String>> {
+        Self::restore_terminal(&mut self.terminal);
+        let result = editor::edit_text(text);
+
+        enable_raw_mode()?;
+        execute!(self.terminal.backend_mut(), EnterAlternateScreen, EnableMouseCapture)?;
+        self.terminal.clear()?;
+        result
+    }
+
+    // Leave raw mode and the alternate screen before the panic message prints,
+    // otherwise it lands on the alternate screen and the shell is left unusable
+    fn install_panic_hook() {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = disable_raw_mode();
+            let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, Show);
+            log::error!("panic: {}", info);
+            default_hook(info);
+        }));
+    }
+
+    fn restore_terminal(terminal: &mut AppTerminal) {
         let _ = disable_raw_mode();
         let _ = execute!(
-            self.terminal.backend_mut(),
+            terminal.backend_mut(),
             LeaveAlternateScreen,
             DisableMouseCapture
         );
-        let _ = self.terminal.show_cursor();
+        let _ = terminal.show_cursor();
+    }
+
+    fn start_history_load(&self, uuid: String, tx: &mpsc::Sender<(String, Result<Vec<HistoryEntry>>)>) {
+        let load = self.taskwarrior.task_info(&uuid);
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let _ = tx.send((uuid, load.await)).await;
+        });
+    }
+
+    fn start_task_load(&mut self, tx: &mpsc::Sender<Result<Vec<Task>>>) {
+        let filter = self.ui.export_filter();
+        self.ui.start_loading(filter.clone());
+
+        // Usually all tasks; the UI sorts and filters them either way. Recent
+        // exports come from the cache, and `task export` itself runs on the blocking pool.
+        let startup_filter = self.ui.startup_filter().map(str::to_string);
+        let load = self.taskwarrior.list_tasks(filter.as_deref());
+        let load_all = self.taskwarrior.list_tasks(startup_filter.as_deref());
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let result = match load.await {
+                Err(e) if filter != startup_filter => {
+                    // Older Taskwarrior versions may reject virtual tags; filter client-side instead
+                    log::warn!("Filtered export ({}) failed, loading all tasks: {}", filter.unwrap_or_default(), e);
+                    load_all.await
+                }
+                result => result,
+            };
+            let _ = tx.send(result).await;
+        });
+    }
+}
+
+impl Drop for App {
+    fn drop(&mut self) {
+        // Restore terminal
+        Self::restore_terminal(&mut self.terminal);
     }
 }
@@ -0,0 +1,27 @@
+// Test program for parsing the new task ID out of `task add` output
+
+use lazytask::taskwarrior::TaskwarriorCLI;
+
+fn main() {
+    println!("🆔 LazyTask Add ID Parsing Test");
+    println!("===============================");
+    println!();
+
+    let cases: [(&str, Option<u32>); 6] = [
+        ("Created task 42.", Some(42)),
+        ("Created task 7 (recurrence template).", Some(7)),
+        ("Using alternate .taskrc file /tmp/rc 2\nCreated task 128.", Some(128)),
+        ("Created task 3.\nThe project 'home' has changed.  Project 'home' is 0% complete (1 of 1 tasks remaining).", Some(3)),
+        ("", None),
+        ("Project 'work' is 50% complete (2 of 4 tasks remaining).", None),
+    ];
+
+    for (output, expected) in cases {
+        let parsed = TaskwarriorCLI::parse_created_task_id(output);
+        assert_eq!(parsed, expected, "output: {:?}", output);
+        println!("✅ {:?} → {:?}", output.lines().last().unwrap_or(""), parsed);
+    }
+
+    println!();
+    println!("🎉 Add ID parsing checks passed");
+}
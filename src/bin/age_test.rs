@@ -0,0 +1,81 @@
+// Test program for relative duration boundaries, the Age and Modified columns and the Age sort order
+
+use chrono::{Duration, Utc};
+use lazytask::data::models::Task;
+use lazytask::data::sort::SortKey;
+use lazytask::ui::components::task_list::{TaskColumn, TaskListWidget};
+use lazytask::utils::formatting::format_relative_duration;
+use ratatui::{backend::TestBackend, Terminal};
+
+// The task list's row for a single task
+fn task_row(columns: &[&str], task: Task) -> String {
+    let columns: Vec<String> = columns.iter().map(|c| c.to_string()).collect();
+    let mut list = TaskListWidget::new(&columns);
+    list.set_tasks(vec![task]);
+    let mut terminal = Terminal::new(TestBackend::new(60, 4)).expect("test terminal");
+    terminal.draw(|f| list.render(f, f.area())).expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..60).map(|x| buffer[(x, 2)].symbol().to_string()).collect()
+}
+
+fn main() {
+    println!("⏳ LazyTask Age Test");
+    println!("====================");
+    println!();
+
+    let cases = [
+        (Duration::seconds(-30), "1m"),
+        (Duration::seconds(20), "1m"),
+        (Duration::minutes(59), "59m"),
+        (Duration::minutes(60), "1h"),
+        (Duration::hours(23), "23h"),
+        (Duration::hours(24), "1d"),
+        (Duration::days(13), "13d"),
+        (Duration::days(14), "2w"),
+        (Duration::days(29), "4w"),
+        (Duration::days(55), "7w"),
+        (Duration::days(56), "2mo"),
+        (Duration::days(74), "2mo"),
+        (Duration::days(75), "3mo"),
+        (Duration::days(359), "11mo"),
+        (Duration::days(364), "11mo"),
+        (Duration::days(365), "1y"),
+        (Duration::days(800), "2y"),
+    ];
+
+    for (age, expected) in cases {
+        let result = format_relative_duration(age);
+        assert_eq!(result, expected, "format_relative_duration({:?})", age);
+        println!("✅ {:>6} min -> {}", age.num_minutes(), result);
+    }
+
+    // The list's Age and Modified columns and the detail panel share the formatter
+    assert_eq!(TaskColumn::from_name("Modified"), Some(TaskColumn::Modified));
+    let mut task = Task::new("Migrate the wiki".to_string());
+    task.entry = Utc::now() - Duration::weeks(8) - Duration::hours(1);
+    task.modified = Some(Utc::now() - Duration::days(3) - Duration::hours(1));
+    let row = task_row(&["description", "age", "modified"], task.clone());
+    assert!(row.contains("2mo") && row.contains("3d") && !row.contains("8w"), "{:?}", row);
+    task.modified = None;
+    let row = task_row(&["description", "modified"], task);
+    assert_eq!(row.trim_matches(|c| c == '│' || c == ' '), "Migrate the wiki", "{:?}", row);
+    println!("✅ age and modified columns: 8 weeks old reads 2mo, a task never modified is blank");
+
+    // Age sort puts the most recently entered task first
+    let now = Utc::now();
+    let mut tasks: Vec<Task> = [3, 1, 2]
+        .iter()
+        .map(|days| {
+            let mut task = Task::new(format!("{} days old", days));
+            task.entry = now - Duration::days(*days);
+            task
+        })
+        .collect();
+    SortKey::Age.sort(&mut tasks);
+    let order: Vec<&str> = tasks.iter().map(|t| t.description.as_str()).collect();
+    assert_eq!(order, ["1 days old", "2 days old", "3 days old"]);
+    println!("✅ Age sort: {:?}", order);
+
+    println!();
+    println!("🎉 All age checks passed");
+}
@@ -0,0 +1,61 @@
+// Test program for the task list's `annotations` column, which counts each task's annotations
+
+use lazytask::data::models::Task;
+use lazytask::ui::components::task_list::{TaskColumn, TaskListWidget};
+use ratatui::{backend::TestBackend, Terminal};
+use serde_json::json;
+
+// The task list's rows as text, one string per line
+fn rows(columns: &[&str], tasks: &[Task]) -> Vec<String> {
+    let columns: Vec<String> = columns.iter().map(|c| c.to_string()).collect();
+    let mut list = TaskListWidget::new(&columns);
+    list.set_tasks(tasks.to_vec());
+
+    let height = tasks.len() as u16 + 3;
+    let mut terminal = Terminal::new(TestBackend::new(60, height)).expect("test terminal");
+    terminal.draw(|f| list.render(f, f.area())).expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| (0..60).map(|x| buffer[(x, y)].symbol().to_string()).collect::<String>())
+        .collect()
+}
+
+fn main() {
+    println!("📝 LazyTask Annotation Column Test");
+    println!("=================================");
+    println!();
+
+    assert_eq!(TaskColumn::from_name("annotations"), Some(TaskColumn::Annotations));
+    assert_eq!(TaskColumn::from_name("Notes"), Some(TaskColumn::Annotations));
+    println!("✅ `annotations` (or `notes`) in ui.task_list_columns turns the column on");
+
+    let annotated = Task::from_json(&json!({
+        "uuid": "6f1c3a2e-0b5d-4e8f-9a7c-1d2e3f4a5b6c",
+        "id": 1,
+        "description": "Plan the offsite",
+        "status": "pending",
+        "entry": "20250301T090000Z",
+        "annotations": [
+            {"entry": "20250302T100000Z", "description": "Venue shortlist in the shared drive"},
+            {"entry": "20250303T100000Z", "description": "Budget approved"}
+        ]
+    }))
+    .expect("parse annotated task");
+    assert_eq!(annotated.annotations.len(), 2);
+    let plain = Task::new("Order supplies".to_string());
+
+    let text = rows(&["description", "annotations"], &[annotated.clone(), plain.clone()]);
+    assert!(text[1].contains("Ann"), "{:?}", text);
+    let annotated_row = text.iter().find(|row| row.contains("Plan the offsite")).expect("annotated row");
+    assert!(annotated_row.contains("(2)"), "{:?}", text);
+    let plain_row = text.iter().find(|row| row.contains("Order supplies")).expect("plain row");
+    assert!(!plain_row.contains('('), "{:?}", text);
+    println!("✅ Annotated tasks show a count; others leave the cell blank");
+
+    let text = rows(&["id", "project", "priority", "due", "description"], &[annotated]);
+    assert!(!text.iter().any(|row| row.contains("(2)") || row.contains("Ann")), "{:?}", text);
+    println!("✅ The default layout doesn't spend a column on it");
+
+    println!();
+    println!("🎉 Annotation column test passed!");
+}
@@ -0,0 +1,125 @@
+// Test program for the annotation manager: add, edit, delete and identical annotations
+
+use chrono::{Duration, Utc};
+use lazytask::data::models::{Annotation, Task};
+use lazytask::handlers::input::Action;
+use lazytask::ui::components::annotation_dialog::{AnnotationDialog, AnnotationDialogResult};
+use lazytask::utils::formatting::DateFormat;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::render;
+
+fn annotation(days_ago: i64, text: &str) -> Annotation {
+    Annotation {
+        entry: Utc::now() - Duration::days(days_ago),
+        description: text.to_string(),
+    }
+}
+
+fn type_text(dialog: &mut AnnotationDialog, text: &str) {
+    for c in text.chars() {
+        let action = if c == ' ' { Action::Space } else { Action::Character(c) };
+        assert_eq!(dialog.handle_input(action).unwrap(), None);
+    }
+}
+
+fn texts(dialog: &AnnotationDialog) -> Vec<&str> {
+    dialog.annotations.iter().map(|a| a.description.as_str()).collect()
+}
+
+fn screen(dialog: &AnnotationDialog) -> String {
+    render(80, 20, |f| dialog.render(f, f.area()))
+}
+
+fn main() {
+    println!("📝 LazyTask Annotation Test");
+    println!("==========================");
+    println!();
+
+    let mut task = Task::new("Write report".to_string());
+    task.annotations = vec![
+        annotation(3, "called Bob"),
+        annotation(2, "see ticket 42"),
+        annotation(1, "called Bob"),
+    ];
+    let mut dialog = AnnotationDialog::new(&task, DateFormat::default());
+    let rendered = screen(&dialog);
+    assert!(rendered.contains("Annotations (3)") && rendered.contains("see ticket 42"));
+    println!("✅ The manager lists the task's annotations");
+
+    // Add
+    dialog.handle_input(Action::Character('a')).unwrap();
+    assert!(dialog.is_editing());
+    type_text(&mut dialog, "due:friday +home");
+    let result = dialog.handle_input(Action::Select).unwrap();
+    assert_eq!(result, Some(AnnotationDialogResult::Add("due:friday +home".to_string())));
+    dialog.apply(&result.unwrap());
+    assert_eq!(texts(&dialog), ["called Bob", "see ticket 42", "called Bob", "due:friday +home"]);
+    assert_eq!(dialog.selected_annotation().unwrap().description, "due:friday +home");
+    println!("✅ a adds an annotation (text with ':' and '+' is kept as typed)");
+
+    // Empty text is refused
+    dialog.handle_input(Action::Character('a')).unwrap();
+    assert_eq!(dialog.handle_input(Action::Select).unwrap(), None);
+    assert!(dialog.error.is_some());
+    dialog.handle_input(Action::Back).unwrap();
+    assert!(!dialog.is_editing());
+    println!("✅ Empty annotations are refused, Esc leaves the text input");
+
+    // Edit the unique one
+    dialog.handle_input(Action::MoveUp).unwrap();
+    dialog.handle_input(Action::MoveUp).unwrap();
+    assert_eq!(dialog.selected_annotation().unwrap().description, "see ticket 42");
+    dialog.handle_input(Action::Character('e')).unwrap();
+    type_text(&mut dialog, "3");
+    let result = dialog.handle_input(Action::Select).unwrap();
+    assert_eq!(
+        result,
+        Some(AnnotationDialogResult::Edit { old: "see ticket 42".to_string(), new: "see ticket 423".to_string() })
+    );
+    dialog.apply(&result.unwrap());
+    assert_eq!(texts(&dialog), ["called Bob", "called Bob", "due:friday +home", "see ticket 423"]);
+    println!("✅ e edits by removing the old text and adding the new one");
+
+    // Unchanged edit does nothing
+    dialog.handle_input(Action::Select).unwrap();
+    assert_eq!(dialog.handle_input(Action::Select).unwrap(), None);
+    assert!(!dialog.is_editing());
+    println!("✅ Saving an unchanged annotation doesn't run a command");
+
+    // Delete a unique annotation straight away
+    let result = dialog.handle_input(Action::Character('d')).unwrap();
+    assert_eq!(result, Some(AnnotationDialogResult::Delete("see ticket 423".to_string())));
+    dialog.apply(&result.unwrap());
+    assert_eq!(texts(&dialog), ["called Bob", "called Bob", "due:friday +home"]);
+    println!("✅ d deletes a unique annotation by its text");
+
+    // Identical annotations warn and need a second d
+    dialog.handle_input(Action::MoveUp).unwrap();
+    assert_eq!(dialog.selected_duplicates(), 2);
+    assert!(screen(&dialog).contains("2 annotations have this text"));
+    assert_eq!(dialog.handle_input(Action::Character('d')).unwrap(), None);
+    assert!(dialog.error.as_deref().unwrap().contains("Press d again"));
+    println!("✅ Identical annotations: {}", dialog.error.as_deref().unwrap());
+
+    // Anything else cancels the pending delete
+    dialog.handle_input(Action::MoveDown).unwrap();
+    dialog.handle_input(Action::MoveUp).unwrap();
+    assert_eq!(dialog.handle_input(Action::Character('d')).unwrap(), None);
+    let result = dialog.handle_input(Action::Character('d')).unwrap();
+    assert_eq!(result, Some(AnnotationDialogResult::Delete("called Bob".to_string())));
+    let oldest = dialog.annotations[0].entry;
+    dialog.apply(&result.unwrap());
+    assert_eq!(texts(&dialog), ["called Bob", "due:friday +home"]);
+    assert!(dialog.annotations[0].entry > oldest);
+    println!("✅ A second d deletes, and like Taskwarrior the oldest copy goes");
+
+    assert_eq!(dialog.handle_input(Action::Back).unwrap(), Some(AnnotationDialogResult::Close));
+    let empty = AnnotationDialog::new(&Task::new("Nothing".to_string()), DateFormat::default());
+    assert!(screen(&empty).contains("No annotations"));
+    println!("✅ Esc closes; a task without annotations says how to add one");
+
+    println!();
+    println!("🎉 Annotation test passed!");
+}
@@ -0,0 +1,76 @@
+// Test program showing Taskwarrior commands no longer block the async runtime:
+// while a `task` that sleeps runs, a ticker on the same single-threaded runtime
+// has to keep running.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use lazytask::taskwarrior::{TaskBinaryNotFound, TaskwarriorCLI};
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::FakeTask;
+
+const FAKE_TASK: &str = "#!/bin/sh
+sleep 1
+case \"$*\" in
+  *add*) echo 'Created task 7.' ;;
+  *fail*) echo 'No such command.' >&2; exit 1 ;;
+esac
+";
+
+fn main() {
+    println!("⏱️  LazyTask Async CLI Test");
+    println!("==========================");
+    println!();
+
+    let fake = FakeTask::with_script("async-cli", FAKE_TASK);
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("runtime");
+
+    runtime.block_on(async {
+        let cli = TaskwarriorCLI::new(None, None);
+
+        // Ticks every 50ms for as long as the runtime gets to run other work
+        let ticks = Arc::new(AtomicUsize::new(0));
+        let ticker = {
+            let ticks = ticks.clone();
+            tokio::spawn(async move {
+                loop {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    ticks.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+        };
+
+        let started = Instant::now();
+        let id = cli.add_task("Slow add", &[]).await.expect("fake add succeeds");
+        let elapsed = started.elapsed();
+        assert_eq!(id, 7);
+        assert!(elapsed >= Duration::from_millis(900));
+        let during = ticks.load(Ordering::SeqCst);
+        assert!(during >= 10, "only {} ticks during a 1s command", during);
+        println!("✅ A 1s `task add` returned ID {} while the runtime ticked {} times", id, during);
+
+        let error = cli.modify_task(1, &[("fail", "")]).await.unwrap_err();
+        assert!(error.to_string().contains("No such command."), "{}", error);
+        println!("✅ Failures still come back as errors: {}", error);
+
+        ticker.abort();
+    });
+
+    std::env::set_var("PATH", fake.dir());
+    std::fs::remove_file(fake.path("task")).ok();
+    runtime.block_on(async {
+        let error = TaskwarriorCLI::new(None, None).sync().await.unwrap_err();
+        assert!(error.downcast_ref::<TaskBinaryNotFound>().is_some(), "{}", error);
+        println!("✅ A missing `task` binary is still reported as such: {}", error);
+    });
+
+    println!();
+    println!("🎉 Async CLI test passed!");
+}
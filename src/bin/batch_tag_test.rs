@@ -0,0 +1,160 @@
+// Test program for B: adding or removing a tag on every task in the filtered list, after a
+// y/n confirmation with the count, with one task the tag can't be changed on.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::{Task, TaskStatus};
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 40;
+
+fn key(handler: &InputHandler, ui: &AppUI, code: KeyCode) -> Action {
+    handler.handle_key_event_in_view(KeyEvent::new(code, KeyModifiers::NONE), ui.has_active_form(), ui.key_context())
+}
+
+fn task(id: u32, uuid: &str, description: &str, tags: &[&str]) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.id = Some(id);
+    task.uuid = uuid.to_string();
+    task.tags = tags.iter().map(|tag| tag.to_string()).collect();
+    task
+}
+
+async fn type_text(ui: &mut AppUI, taskwarrior: &TaskwarriorIntegration, text: &str) {
+    for c in text.chars() {
+        ui.handle_action(Action::Character(c), taskwarrior).await.expect("type");
+    }
+}
+
+async fn run_pending(ui: &mut AppUI, taskwarrior: &TaskwarriorIntegration) {
+    while ui.has_pending_work() {
+        ui.process_pending_work(taskwarrior).await;
+    }
+}
+
+fn main() {
+    println!("🏷️  LazyTask Batch Tag Test");
+    println!("==========================");
+    println!();
+
+    let fake = FakeTask::new("batch-tag");
+    let log = fake.path("commands");
+    let fake_task = format!(
+        "#!/bin/sh\ncase \"$*\" in\n  *--version*) echo '3.1.0' ;;\n  *broken*modify*) echo 'Could not modify' >&2; exit 1 ;;\n  *modify*) echo \"$*\" >> '{}' ;;\nesac\n",
+        log.display()
+    );
+    fake.set_script(&fake_task);
+
+    let tasks = vec![
+        task(1, "uuid-write", "Write the agenda", &[]),
+        task(2, "uuid-book", "Book the room", &["review"]),
+        task(3, "uuid-order", "Order lunch", &[]),
+    ];
+    let mut done = task(4, "uuid-done", "Send the invites", &[]);
+    done.status = TaskStatus::Completed;
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let config = Config::default();
+        let handler = InputHandler::new(&config);
+        let mut ui = AppUI::new(&config).expect("app ui");
+        ui.start_loading(None);
+        let mut loaded = tasks.clone();
+        loaded.push(done.clone());
+        ui.finish_loading(Ok(loaded));
+        ui.handle_action(Action::MoveDown, &taskwarrior).await.expect("down");
+
+        let action = key(&handler, &ui, KeyCode::Char('B'));
+        assert!(matches!(action, Action::BatchTag), "{:?}", action);
+        ui.handle_action(action, &taskwarrior).await.expect("batch tag");
+        assert!(ui.has_active_form());
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Tag Listed Tasks") && text.contains("Every listed task (3 tasks)"), "{}", text);
+        println!("✅ B asks for a tag to put on the 3 listed tasks (not the completed one)");
+
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("enter");
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("Type a tag name"));
+        type_text(&mut ui, &taskwarrior, "+review").await;
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Tag: +review") && text.contains("(•) Add"), "{}", text);
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("enter");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Add +review to 2 listed tasks?"), "{}", text);
+        println!("✅ The confirmation counts only the tasks that don't have the tag yet");
+
+        ui.handle_action(Action::Character('n'), &taskwarrior).await.expect("cancel");
+        assert!(!ui.has_active_form() && !ui.has_pending_work());
+        assert!(!log.exists());
+        println!("✅ n cancels without running anything");
+
+        ui.handle_action(Action::BatchTag, &taskwarrior).await.expect("batch tag");
+        type_text(&mut ui, &taskwarrior, "review").await;
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("enter");
+        ui.handle_action(Action::Character('y'), &taskwarrior).await.expect("confirm");
+        assert!(!ui.has_active_form() && ui.has_pending_work());
+        run_pending(&mut ui, &taskwarrior).await;
+        let commands = std::fs::read_to_string(&log).expect("task was run");
+        // Past the rc. overrides LazyTask passes first
+        let mut lines: Vec<&str> = commands.lines().filter_map(|line| line.find("uuid-").map(|i| &line[i..])).collect();
+        lines.sort();
+        assert_eq!(lines, ["uuid-order modify +review", "uuid-write modify +review"], "{}", commands);
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Added +review to 2 tasks"), "{}", text);
+        // The reload that follows lists the tasks in another order; Book the room stays selected
+        let mut reloaded = tasks.clone();
+        reloaded.reverse();
+        ui.start_loading(None);
+        ui.finish_loading(Ok(reloaded));
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("UUID          uuid-book"), "{}", text);
+        println!("✅ y adds it task by task, reports the count and keeps the selection");
+
+        // Tab switches to removing; so does typing - first
+        std::fs::remove_file(&log).ok();
+        let mut tagged = tasks.clone();
+        for task in tagged.iter_mut() {
+            task.tags = vec!["review".to_string()];
+        }
+        tagged[1].uuid = "uuid-broken".to_string();
+        ui.start_loading(None);
+        ui.finish_loading(Ok(tagged));
+        ui.handle_action(Action::BatchTag, &taskwarrior).await.expect("batch tag");
+        type_text(&mut ui, &taskwarrior, "-review").await;
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("(•) Remove"));
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("enter");
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("Remove -review from 3 listed tasks?"));
+        ui.handle_action(Action::Character('y'), &taskwarrior).await.expect("confirm");
+        run_pending(&mut ui, &taskwarrior).await;
+        let commands = std::fs::read_to_string(&log).expect("task was run");
+        assert_eq!(commands.lines().count(), 2, "{}", commands);
+        assert!(commands.contains("uuid-write modify -review"), "{}", commands);
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Removed -review from 2 of 3 tasks; 1 failed"), "{}", text);
+        println!("✅ Removing works the same way, and failures are counted");
+
+        ui.handle_action(Action::BatchTag, &taskwarrior).await.expect("batch tag");
+        type_text(&mut ui, &taskwarrior, "-urgent").await;
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("enter");
+        assert!(!ui.has_active_form());
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("No listed task has -urgent"));
+        println!("✅ Nothing to change means no confirmation");
+
+        ui.start_loading(None);
+        ui.finish_loading(Ok(Vec::new()));
+        ui.handle_action(Action::BatchTag, &taskwarrior).await.expect("batch tag");
+        assert!(!ui.has_active_form());
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("No tasks listed to tag"));
+        println!("✅ An empty list has nothing to tag");
+    });
+
+    println!();
+    println!("🎉 Batch tag test passed!");
+}
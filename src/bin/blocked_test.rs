@@ -0,0 +1,95 @@
+// Test program for dependency-blocked tasks: resolution, row color and the unblocked-only filter
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::{Task, TaskStatus};
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::ui::views::main_view::MainView;
+use ratatui::{backend::TestBackend, style::Color, Terminal};
+
+const WIDTH: u16 = 120;
+const HEIGHT: u16 = 40;
+
+fn task(description: &str, id: u32) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.id = Some(id);
+    task
+}
+
+// Foreground color of the row whose text contains `description`, or None if it isn't shown
+fn row_color(view: &mut MainView, description: &str) -> Option<Color> {
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).expect("test terminal");
+    terminal.draw(|f| view.render(f, f.area(), WIDTH)).expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..HEIGHT).find_map(|y| {
+        let line: String = (0..WIDTH).map(|x| buffer[(x, y)].symbol().to_string()).collect();
+        let x = line.find(description)?;
+        Some(buffer[(line[..x].chars().count() as u16, y)].fg)
+    })
+}
+
+fn title(view: &mut MainView) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).expect("test terminal");
+    terminal.draw(|f| view.render(f, f.area(), WIDTH)).expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..HEIGHT)
+        .map(|y| (0..WIDTH).map(|x| buffer[(x, y)].symbol().to_string()).collect::<String>())
+        .find(|line| line.contains(" Tasks ("))
+        .unwrap_or_default()
+}
+
+fn main() {
+    println!("⛓️  LazyTask Blocked Tasks Test");
+    println!("==============================");
+    println!();
+
+    let handler = InputHandler::new(&Config::default());
+    let key = handler.handle_key_event_with_context(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::NONE), false);
+    assert!(matches!(key, Action::ToggleBlocked));
+    println!("✅ b toggles the unblocked-only filter");
+
+    let open_dependency = task("Order the parts", 1);
+    let mut done_dependency = task("Measure the shelf", 2);
+    done_dependency.status = TaskStatus::Completed;
+    let mut blocked = task("Assemble the shelf", 3);
+    blocked.depends = vec![open_dependency.uuid.clone(), done_dependency.uuid.clone()];
+    let mut unblocked = task("Paint the shelf", 4);
+    unblocked.depends = vec![done_dependency.uuid.clone()];
+    let mut unknown = task("Hang the shelf", 5);
+    unknown.depends = vec!["00000000-0000-0000-0000-000000000000".to_string()];
+
+    let all = vec![open_dependency, done_dependency, blocked, unblocked, unknown];
+    let open = Task::open_uuids(&all);
+    assert!(all[2].is_blocked_by(&open));
+    assert!(!all[3].is_blocked_by(&open));
+    assert!(!all[4].is_blocked_by(&open));
+    println!("✅ Only dependencies that are still pending or waiting block a task");
+
+    let mut view = MainView::new(&Config::default());
+    view.update_available_filters(&all);
+    let pending: Vec<Task> = all.iter().filter(|t| view.matches_filters(t)).cloned().collect();
+    assert_eq!(pending.len(), 4);
+    view.set_tasks_with_preserved_selection(pending, None);
+
+    assert_eq!(row_color(&mut view, "Assemble the shelf"), Some(Color::DarkGray));
+    assert_eq!(row_color(&mut view, "Paint the shelf"), Some(Color::White));
+    println!("✅ Blocked rows are dimmed; a task whose dependencies are done is not");
+
+    view.set_unblocked_only(true);
+    let shown: Vec<&str> = all
+        .iter()
+        .filter(|t| view.matches_filters(t))
+        .map(|t| t.description.as_str())
+        .collect();
+    assert_eq!(shown, vec!["Order the parts", "Paint the shelf", "Hang the shelf"]);
+    assert!(title(&mut view).contains("Unblocked"));
+    println!("✅ Unblocked only hides blocked tasks and says so in the title");
+
+    view.clear_all_filters();
+    assert!(!view.is_unblocked_only());
+    assert!(!title(&mut view).contains("Unblocked"));
+    println!("✅ Clearing all filters shows blocked tasks again");
+
+    println!();
+    println!("🎉 Blocked task checks passed");
+}
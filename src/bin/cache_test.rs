@@ -0,0 +1,79 @@
+// Test program for the export cache: repeated loads reuse `task export`,
+// and any change made through LazyTask forces the next load to export again.
+
+use lazytask::data::cache::TaskCache;
+use lazytask::data::models::Task;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::FakeTask;
+
+const FAKE_TASK: &str = "#!/bin/sh
+dir=$(dirname \"$0\")
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+  *_get*) ;;
+  *export*) echo x >> \"$dir/exports\"; echo '[{\"uuid\": \"11111111-1111-1111-1111-111111111111\", \"description\": \"cached\", \"status\": \"pending\"}]' ;;
+  *add*) echo 'Created task 2.' ;;
+esac
+";
+
+fn main() {
+    println!("🗄️  LazyTask Export Cache Test");
+    println!("=============================");
+    println!();
+
+    let mut cache = TaskCache::new(60);
+    assert!(cache.is_stale(None));
+    cache.insert(None, vec![Task::new("one".to_string())]);
+    assert_eq!(cache.get(None).map(|tasks| tasks.len()), Some(1));
+    assert!(cache.is_stale(Some("status:pending")));
+    cache.insert(Some("status:pending  +OVERDUE"), Vec::new());
+    assert!(!cache.is_stale(Some("status:pending +OVERDUE")));
+    cache.invalidate_all();
+    assert!(cache.is_stale(None) && cache.is_stale(Some("status:pending +OVERDUE")));
+    println!("✅ Exports are cached per filter and invalidate_all drops them all");
+
+    let mut disabled = TaskCache::new(0);
+    disabled.insert(None, Vec::new());
+    assert!(disabled.is_stale(None));
+    println!("✅ A max age of 0 turns caching off");
+
+    let fake = FakeTask::with_script("cache", FAKE_TASK);
+    let exports = || std::fs::read_to_string(fake.path("exports")).map(|s| s.lines().count()).unwrap_or(0);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task").with_cache_max_age(60);
+
+        let tasks = taskwarrior.list_tasks(None).await.expect("export");
+        assert_eq!(tasks[0].description, "cached");
+        taskwarrior.list_tasks(None).await.expect("export");
+        assert_eq!(exports(), 1);
+        println!("✅ A second load within the cache window doesn't run `task export`");
+
+        taskwarrior.list_tasks(Some("status:pending")).await.expect("export");
+        assert_eq!(exports(), 2);
+        println!("✅ A different filter runs its own export");
+
+        taskwarrior.add_task("New", &[]).await.expect("fake add");
+        taskwarrior.list_tasks(None).await.expect("export");
+        assert_eq!(exports(), 3);
+        println!("✅ Adding a task makes the next load export again");
+
+        taskwarrior.invalidate_cache();
+        taskwarrior.list_tasks(None).await.expect("export");
+        assert_eq!(exports(), 4);
+        println!("✅ invalidate_cache (used by F5) does the same");
+
+        let uncached = TaskwarriorIntegration::new(None, None).expect("fake task").with_cache_max_age(0);
+        uncached.list_tasks(None).await.expect("export");
+        uncached.list_tasks(None).await.expect("export");
+        assert_eq!(exports(), 6);
+        println!("✅ cache_max_age = 0 exports every time");
+    });
+
+    println!();
+    println!("🎉 Export cache test passed!");
+}
@@ -0,0 +1,65 @@
+// Test program for jumping from the task list to a task's due date in the calendar
+
+use chrono::{TimeZone, Utc};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::ui::views::reports_view::ReportsView;
+use lazytask::utils::formatting::DateFormat;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::render;
+
+fn screen(view: &mut ReportsView) -> String {
+    render(120, 40, |f| view.render(f, f.area()))
+}
+
+// Heading of the calendar's day panel, e.g. "Friday, 2025-03-14"
+fn day_heading(date: chrono::DateTime<Utc>) -> String {
+    let day = date.date_naive();
+    format!("{}, {}", day.format("%A"), DateFormat::default().naive_date(day))
+}
+
+fn main() {
+    println!("📅 LazyTask Calendar Jump Test");
+    println!("=============================");
+    println!();
+
+    let config = Config::default();
+    let handler = InputHandler::new(&config);
+    let c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE);
+    assert!(matches!(handler.handle_key_event_with_context(c, false), Action::Context));
+    assert!(matches!(handler.handle_key_event_with_context(c, true), Action::Character('c')));
+    println!("✅ c maps to the calendar action outside text input (and still types in forms)");
+
+    let due = Utc.with_ymd_and_hms(2025, 3, 14, 17, 0, 0).unwrap();
+    let mut task = Task::new("File taxes".to_string());
+    task.due = Some(due);
+
+    let mut view = ReportsView::new(&config);
+    view.update_tasks(vec![task.clone()]);
+    assert!(!view.is_calendar_mode());
+    view.show_in_calendar(task.due);
+    assert!(view.is_calendar_mode());
+    let rendered = screen(&mut view);
+    assert!(rendered.contains(&day_heading(due)), "{}", rendered);
+    assert!(rendered.contains("File taxes"), "{}", rendered);
+    println!("✅ A task with a due date opens the calendar on that day, listing the task");
+
+    view.show_in_calendar(None);
+    assert!(view.is_calendar_mode());
+    assert!(screen(&mut view).contains(&day_heading(Utc::now())));
+    println!("✅ Without a due date the calendar opens on today");
+
+    // The calendar keeps its day and mode until something changes them
+    view.show_in_calendar(task.due);
+    view.update_tasks(vec![task]);
+    assert!(view.is_calendar_mode());
+    assert!(screen(&mut view).contains(&day_heading(due)));
+    println!("✅ Reloading tasks keeps the calendar's mode and day");
+
+    println!();
+    println!("🎉 Calendar jump test passed!");
+}
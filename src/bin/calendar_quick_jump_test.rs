@@ -0,0 +1,127 @@
+// Test program for the calendar's quick jumps: 1-7 for the next Monday..Sunday and
+// g for a go-to-date prompt.
+
+use chrono::{Datelike, Duration, TimeZone, Utc, Weekday};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::handlers::input::{Action, InputHandler, KeyContext};
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+use lazytask::ui::components::calendar_view::CalendarWidget;
+use lazytask::ui::components::task_form::TaskForm;
+use lazytask::ui::views::reports_view::ReportsView;
+use lazytask::utils::formatting::DateFormat;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{render, screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 40;
+
+fn view_screen(view: &mut ReportsView) -> String {
+    render(WIDTH, HEIGHT, |f| view.render(f, f.area()))
+}
+
+// Heading of the calendar's day panel, e.g. "Friday, 2025-03-14"
+fn day_heading(day: chrono::NaiveDate) -> String {
+    format!("{}, {}", day.format("%A"), DateFormat::default().naive_date(day))
+}
+
+fn main() {
+    println!("🗓️  LazyTask Calendar Quick Jump Test");
+    println!("===================================");
+    println!();
+
+    let today = Utc::now().date_naive();
+    let monday = TaskForm::parse_taskwarrior_date("monday").expect("monday").date_naive();
+    assert_eq!(monday.weekday(), Weekday::Mon);
+    assert!(monday > today && monday <= today + Duration::days(7));
+    assert_eq!(TaskForm::parse_taskwarrior_date("Fri").expect("fri").weekday(), Weekday::Fri);
+    let in_two_weeks = TaskForm::parse_taskwarrior_date("+2w").expect("+2w").date_naive();
+    assert_eq!(in_two_weeks, today + Duration::weeks(2));
+    println!("✅ The date parser reads weekdays (next one after today) and +2w");
+
+    let mut config = Config::default();
+    config.ui.vim_keys = true;
+    let handler = InputHandler::new(&config);
+    let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+    assert!(matches!(handler.handle_key_event_in_view(key('5'), false, KeyContext::Reports), Action::Character('5')));
+    assert!(matches!(handler.handle_key_event_in_view(key('5'), false, KeyContext::TaskList), Action::SortBy(_)));
+    assert!(matches!(handler.handle_key_event_in_view(key('g'), false, KeyContext::Reports), Action::Character('g')));
+    assert!(matches!(handler.handle_key_event_in_view(key('g'), false, KeyContext::TaskList), Action::FirstTask));
+    assert!(matches!(handler.handle_key_event_in_view(key('c'), false, KeyContext::Reports), Action::Context));
+    println!("✅ 1-7 and g belong to the calendar there and keep their meaning in the task list");
+
+    // Wednesday 2025-03-12
+    let mut view = ReportsView::new(&config);
+    view.show_in_calendar(Some(Utc.with_ymd_and_hms(2025, 3, 12, 0, 0, 0).unwrap()));
+    for (weekday, expected) in [(Weekday::Fri, (2025, 3, 14)), (Weekday::Fri, (2025, 3, 21)), (Weekday::Mon, (2025, 3, 24))] {
+        view.jump_to_weekday(weekday);
+        let day = chrono::NaiveDate::from_ymd_opt(expected.0, expected.1, expected.2).unwrap();
+        assert!(view_screen(&mut view).contains(&day_heading(day)), "{:?}", expected);
+    }
+    println!("✅ A weekday key goes to the next such day after the selected one");
+
+    let _fake = FakeTask::with_script("quick-jump", FAKE_TASK);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(Vec::new()));
+        assert_eq!(ui.key_context(), KeyContext::TaskList);
+        ui.handle_action(Action::Reports, &taskwarrior).await.expect("reports");
+        assert_eq!(ui.key_context(), KeyContext::TaskList);
+        ui.handle_action(Action::Context, &taskwarrior).await.expect("calendar");
+        assert_eq!(ui.key_context(), KeyContext::Reports);
+
+        ui.handle_action(Action::Character('5'), &taskwarrior).await.expect("friday");
+        let friday = TaskForm::next_weekday(today, Weekday::Fri);
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains(&day_heading(friday)));
+        println!("✅ 5 in the calendar selects this coming Friday");
+
+        ui.handle_action(Action::Character('g'), &taskwarrior).await.expect("prompt");
+        assert!(ui.has_active_form());
+        for c in "someday".chars() {
+            ui.handle_action(Action::Character(c), &taskwarrior).await.expect("type");
+        }
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("bad date");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Go to Date") && text.contains("Unknown date \"someday\""), "{}", text);
+        println!("✅ g opens a prompt that keeps a date it can't read");
+
+        for _ in 0.."someday".len() {
+            ui.handle_action(Action::Backspace, &taskwarrior).await.expect("erase");
+        }
+        for c in "+10w".chars() {
+            ui.handle_action(Action::Character(c), &taskwarrior).await.expect("type");
+        }
+        let target = today + Duration::weeks(10);
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains(&day_heading(target)));
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("jump");
+        assert!(!ui.has_active_form());
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains(&day_heading(target)), "{}", text);
+        let (year, month) = CalendarWidget::offset_month(target.year(), target.month(), 1);
+        assert!(text.contains(CalendarWidget::month_name(month)) && text.contains(&year.to_string()), "{}", text);
+        println!("✅ Enter selects the day and the three months center on it: {}", target);
+
+        ui.handle_action(Action::Character('g'), &taskwarrior).await.expect("prompt");
+        ui.handle_action(Action::Character('t'), &taskwarrior).await.expect("type");
+        ui.handle_action(Action::Back, &taskwarrior).await.expect("cancel");
+        assert!(!ui.has_active_form() && ui.key_context() == KeyContext::Reports);
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains(&day_heading(target)));
+        println!("✅ Esc closes the prompt and stays on the same day");
+    });
+
+    println!();
+    println!("🎉 Calendar quick jump test passed!");
+}
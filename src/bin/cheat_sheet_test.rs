@@ -0,0 +1,91 @@
+// Test program for the ? cheat sheet: a popup of the current view's keys over the view,
+// built from the key bindings and closed by the next key.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+fn key(handler: &InputHandler, ui: &AppUI, code: KeyCode) -> Action {
+    handler.handle_key_event_in_view(KeyEvent::new(code, KeyModifiers::NONE), ui.has_active_form(), ui.key_context())
+}
+
+fn main() {
+    println!("⌨️  LazyTask Cheat Sheet Test");
+    println!("============================");
+    println!();
+
+    let _fake = FakeTask::with_script("cheat-sheet", FAKE_TASK);
+
+    let mut task = Task::new("Plan the offsite".to_string());
+    task.id = Some(1);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut config = Config::default();
+        config.ui.vim_keys = true;
+        let handler = InputHandler::new(&config);
+        let mut ui = AppUI::new(&config).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![task.clone()]));
+
+        let action = key(&handler, &ui, KeyCode::Char('?'));
+        assert!(matches!(action, Action::CheatSheet), "{:?}", action);
+        ui.handle_action(action, &taskwarrior).await.expect("cheat sheet");
+        let text = screen(&mut ui, 160, 50);
+        assert!(text.contains("Keys · Task List") && text.contains("Any key closes"), "{}", text);
+        assert!(text.contains("Add new task") && text.contains("Global") && text.contains("Reload tasks"), "{}", text);
+        // Generated from the bindings: vim_keys adds j/k next to the arrows
+        assert!(text.contains("↓, j") && text.contains("Next task"), "{}", text);
+        assert!(!text.contains("Toggle calendar / dashboard"), "{}", text);
+        assert!(text.contains("LazyTask v0.1") && text.contains("[?] Keys"), "{}", text);
+        println!("✅ ? shows the task list's keys and the global ones over the list");
+
+        // The key that closes it does nothing else: d would otherwise ask to finish the task
+        ui.handle_action(key(&handler, &ui, KeyCode::Char('d')), &taskwarrior).await.expect("close");
+        assert!(!ui.has_active_form());
+        let text = screen(&mut ui, 160, 50);
+        assert!(!text.contains("Keys · Task List") && text.contains("Plan the offsite"), "{}", text);
+        println!("✅ Any key closes it without acting");
+
+        ui.handle_action(Action::Reports, &taskwarrior).await.expect("reports");
+        ui.handle_action(key(&handler, &ui, KeyCode::Char('?')), &taskwarrior).await.expect("cheat sheet");
+        let text = screen(&mut ui, 160, 50);
+        assert!(text.contains("Keys · Reports") && text.contains("Toggle calendar / dashboard"), "{}", text);
+        assert!(!text.contains("Add new task") && text.contains("By Project"), "{}", text);
+        ui.handle_action(key(&handler, &ui, KeyCode::Esc), &taskwarrior).await.expect("close");
+        let text = screen(&mut ui, 160, 50);
+        assert!(!text.contains("Keys · Reports") && text.contains("Time Tracked"), "{}", text);
+        println!("✅ In Reports it lists the Reports keys, and Esc only closes it");
+
+        ui.handle_action(key(&handler, &ui, KeyCode::Char('?')), &taskwarrior).await.expect("cheat sheet");
+        ui.handle_action(Action::Back, &taskwarrior).await.expect("close");
+        ui.handle_action(Action::Back, &taskwarrior).await.expect("task list");
+        ui.handle_action(key(&handler, &ui, KeyCode::Char('?')), &taskwarrior).await.expect("cheat sheet");
+        let text = screen(&mut ui, 80, 24);
+        assert!(text.contains("Keys · Task List") && text.contains("… more with F1"), "{}", text);
+        println!("✅ On a small terminal it fits and points to F1 for the rest");
+        ui.handle_action(Action::None, &taskwarrior).await.expect("close");
+
+        ui.handle_action(Action::Filter, &taskwarrior).await.expect("filter");
+        assert!(ui.has_active_form());
+        assert!(matches!(key(&handler, &ui, KeyCode::Char('?')), Action::Character('?')));
+        println!("✅ While typing in the filter panel ? is just a character");
+    });
+
+    println!();
+    println!("🎉 Cheat sheet test passed!");
+}
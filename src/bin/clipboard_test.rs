@@ -0,0 +1,97 @@
+// Test program for copying a task's UUID, description or `task add` command. The sandbox
+// has no display, so this checks the keys and the no-clipboard fallback.
+
+use chrono::TimeZone;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::{Priority, Task};
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+use lazytask::utils::clipboard::SystemClipboard;
+use lazytask::utils::formatting::shell_quote;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 30;
+
+fn main() {
+    println!("📋 LazyTask Clipboard Test");
+    println!("=========================");
+    println!();
+
+    let handler = InputHandler::new(&Config::default());
+    let key = |code, modifiers| handler.handle_key_event_with_context(KeyEvent::new(code, modifiers), false);
+    assert!(matches!(key(KeyCode::Char('Y'), KeyModifiers::SHIFT), Action::CopyUuid));
+    assert!(matches!(key(KeyCode::Char('y'), KeyModifiers::CONTROL), Action::CopyDescription));
+    assert!(matches!(key(KeyCode::Char('y'), KeyModifiers::NONE), Action::Character('y')));
+    assert!(matches!(key(KeyCode::Char('X'), KeyModifiers::SHIFT), Action::CopyAddCommand));
+    println!("✅ Y copies the UUID, Ctrl+y the description and X a task add command");
+
+    assert_eq!(shell_quote("project:work"), "project:work");
+    assert_eq!(shell_quote("+home"), "+home");
+    assert_eq!(shell_quote("project:Home Office"), "'project:Home Office'");
+    assert_eq!(shell_quote("Don't forget"), "'Don'\\''t forget'");
+    assert_eq!(shell_quote(""), "''");
+    println!("✅ Shell quoting leaves plain words alone");
+
+    let mut task = Task::new("Call Sam about the due:friday deadline".to_string());
+    task.project = Some("work.clients".to_string());
+    task.priority = Some(Priority::High);
+    task.tags = vec!["phone".to_string(), "follow up".to_string()];
+    task.due = Some(chrono::Utc.with_ymd_and_hms(2025, 3, 14, 0, 0, 0).unwrap());
+    task.depends = vec![Task::new("Other".to_string()).uuid];
+    assert_eq!(
+        AppUI::add_command(&task),
+        "task add project:work.clients priority:H +phone '+follow up' due:2025-03-14 -- 'Call Sam about the due:friday deadline'"
+    );
+    task.due = Some(chrono::Utc.with_ymd_and_hms(2025, 3, 14, 16, 30, 0).unwrap());
+    assert!(AppUI::add_command(&task).contains(" due:2025-03-14T16:30:00Z "));
+    assert_eq!(AppUI::add_command(&Task::new("It's done".to_string())), "task add -- 'It'\\''s done'");
+    println!("✅ task add commands carry project, priority, tags and due; the description follows --");
+
+    std::env::remove_var("DISPLAY");
+    std::env::remove_var("WAYLAND_DISPLAY");
+    assert!(SystemClipboard::default().copy("text").is_err());
+    println!("✅ Without a display there is no clipboard, and copying says so");
+
+    let _fake = FakeTask::with_script("clipboard", FAKE_TASK);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        let mut task = Task::new("Send the quarterly report".to_string());
+        task.id = Some(1);
+        let uuid = task.uuid.clone();
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![task]));
+
+        ui.handle_action(Action::CopyUuid, &taskwarrior).await.expect("copy uuid");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains(&format!("No clipboard available; UUID: {}", uuid)), "{}", text);
+        println!("✅ The UUID is shown in the footer when it can't be copied");
+
+        ui.handle_action(Action::CopyDescription, &taskwarrior).await.expect("copy description");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("No clipboard available; description: Send the quarterly report"), "{}", text);
+        println!("✅ So is the description");
+
+        ui.handle_action(Action::CopyAddCommand, &taskwarrior).await.expect("copy command");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("No clipboard available; task add command: task add -- 'Send the quarterly report'"), "{}", text);
+        println!("✅ And the task add command");
+    });
+
+    println!();
+    println!("🎉 Clipboard test passed!");
+}
@@ -0,0 +1,67 @@
+// Test program for the compact single-column layout on narrow terminals
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::ui::views::main_view::{MainView, PanelLayout, COMPACT_WIDTH};
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::render;
+
+const HEIGHT: u16 = 40;
+
+fn screen(view: &mut MainView, width: u16) -> String {
+    render(width, HEIGHT, |f| view.render(f, f.area(), width))
+}
+
+fn key(c: char) -> Action {
+    let handler = InputHandler::new(&Config::default());
+    handler.handle_key_event_with_context(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE), false)
+}
+
+fn main() {
+    println!("📱 LazyTask Compact Layout Test");
+    println!("==============================");
+    println!();
+
+    assert!(matches!(key('i'), Action::ToggleDetail));
+    assert!(matches!(key('L'), Action::CycleLayout));
+    println!("✅ i toggles the detail overlay, L switches layout");
+
+    let mut view = MainView::new(&Config::default());
+    let mut task = Task::new("Renew passport before the trip".to_string());
+    task.id = Some(1);
+    view.set_tasks_with_preserved_selection(vec![task], None);
+
+    assert_eq!(view.layout(), PanelLayout::Auto);
+    assert!(screen(&mut view, 120).contains("Task Details"));
+    assert!(!screen(&mut view, COMPACT_WIDTH - 1).contains("Task Details"));
+    assert!(screen(&mut view, COMPACT_WIDTH - 1).contains("Renew passport before the trip"));
+    println!("✅ Auto layout: split at 120 columns, full-width list below {}", COMPACT_WIDTH);
+
+    assert!(view.toggle_detail_overlay());
+    let overlay = screen(&mut view, 60);
+    assert!(overlay.contains("Task Details") && overlay.contains("Urgency"), "{}", overlay);
+    view.close_detail_overlay();
+    assert!(!screen(&mut view, 60).contains("Task Details"));
+    println!("✅ In the compact layout the details open as an overlay and close again");
+
+    screen(&mut view, 120);
+    assert!(!view.toggle_detail_overlay());
+    println!("✅ The overlay toggle does nothing when details are already beside the list");
+
+    view.cycle_layout();
+    assert_eq!(view.layout(), PanelLayout::Split);
+    assert!(screen(&mut view, 60).contains("Task Details"));
+    view.cycle_layout();
+    assert_eq!(view.layout(), PanelLayout::Compact);
+    assert!(!screen(&mut view, 200).contains("Task Details"));
+    view.cycle_layout();
+    assert_eq!(view.layout(), PanelLayout::Auto);
+    println!("✅ L forces split or compact regardless of width, then goes back to auto");
+
+    println!();
+    println!("🎉 Compact layout test passed!");
+}
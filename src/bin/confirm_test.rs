@@ -0,0 +1,60 @@
+// Test program for the done / delete confirmation settings and dialog
+
+use lazytask::config::Config;
+use lazytask::handlers::input::Action;
+use lazytask::ui::components::modal_dialog::{ConfirmDialog, ConfirmResult};
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::render;
+
+fn screen(dialog: &ConfirmDialog) -> String {
+    render(80, 20, |f| dialog.render(f, f.area()))
+}
+
+fn main() {
+    println!("❓ LazyTask Confirmation Test");
+    println!("============================");
+    println!();
+
+    let config = Config::default();
+    assert!(config.ui.confirm_delete);
+    assert!(!config.ui.confirm_done);
+    println!("✅ Deleting asks first by default, completing doesn't");
+
+    // Config files written before the settings existed get the same defaults
+    let contents = toml::to_string(&config).expect("serialize config");
+    let old_contents: String = contents
+        .lines()
+        .filter(|line| !line.starts_with("confirm_"))
+        .map(|line| format!("{}\n", line))
+        .collect();
+    let path = std::env::temp_dir().join(format!("lazytask_confirm_test_{}.toml", std::process::id()));
+    std::fs::write(&path, old_contents).expect("write config");
+    let loaded = Config::load(path.to_str()).expect("load config");
+    assert!(loaded.ui.confirm_delete);
+    assert!(!loaded.ui.confirm_done);
+
+    std::fs::write(&path, contents.replace("confirm_done = false", "confirm_done = true")).expect("write config");
+    let loaded = Config::load(path.to_str()).expect("load config");
+    assert!(loaded.ui.confirm_done);
+    std::fs::remove_file(&path).ok();
+    println!("✅ Missing settings fall back to the defaults; confirm_done can be switched on");
+
+    let mut dialog = ConfirmDialog::new("Complete Task", "Mark as done \"Water the plants\"?", "done", "keep");
+    let text = screen(&dialog);
+    assert!(text.contains("Complete Task"), "{}", text);
+    assert!(text.contains("Water the plants"), "{}", text);
+    assert!(text.contains("y done"), "{}", text);
+    assert!(text.contains("n/Esc keep"), "{}", text);
+    println!("✅ The dialog shows the task description and both answers");
+
+    assert_eq!(dialog.handle_input(Action::Character('x')).expect("input"), None);
+    assert_eq!(dialog.handle_input(Action::Character('y')).expect("input"), Some(ConfirmResult::Confirm));
+    assert_eq!(dialog.handle_input(Action::Character('n')).expect("input"), Some(ConfirmResult::Cancel));
+    assert_eq!(dialog.handle_input(Action::Select).expect("input"), Some(ConfirmResult::Cancel));
+    println!("✅ y confirms; n, Esc and Enter keep the task");
+
+    println!();
+    println!("🎉 Confirmation test passed!");
+}
@@ -0,0 +1,112 @@
+// Test program for scrolling the reports dashboard's project table when not every project fits.
+
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+use lazytask::ui::views::reports_view::ReportsView;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+const PROJECTS: usize = 30;
+
+// Project pN gets 31 - N tasks, so the dashboard lists them p0, p1, ...
+fn tasks() -> Vec<Task> {
+    let mut tasks = Vec::new();
+    for n in 0..PROJECTS {
+        for i in 0..(PROJECTS + 1 - n) {
+            let mut task = Task::new(format!("Task {} of p{}", i, n));
+            task.project = Some(format!("p{}", n));
+            tasks.push(task);
+        }
+    }
+    tasks
+}
+
+fn render(view: &mut ReportsView, width: u16, height: u16) -> String {
+    fake_task::render(width, height, |f| view.render(f, f.area()))
+}
+
+// A row of the project table, rather than the project's name in recent activity
+fn shows_row(text: &str, project: &str) -> bool {
+    text.contains(&format!("│{} ", project))
+}
+
+fn main() {
+    println!("📜 LazyTask Dashboard Scroll Test");
+    println!("================================");
+    println!();
+
+    let config = Config::default();
+    let mut view = ReportsView::new(&config);
+    view.update_tasks(tasks());
+    assert_eq!(view.selected_project(), Some("p0"));
+
+    let text = render(&mut view, 160, 40);
+    assert!(text.contains(&format!("By Project (1/{})", PROJECTS)), "{}", text);
+    assert!(shows_row(&text, "p0") && !shows_row(&text, "p29"), "{}", text);
+    println!("✅ Projects are listed busiest first and the title shows the position");
+
+    view.last_row();
+    let text = render(&mut view, 160, 40);
+    assert_eq!(view.selected_project(), Some("p29"));
+    assert!(text.contains(&format!("By Project ({}/{})", PROJECTS, PROJECTS)), "{}", text);
+    assert!(shows_row(&text, "p29") && !shows_row(&text, "p0"), "{}", text);
+    println!("✅ Selecting the last project scrolls it into view");
+
+    view.first_row();
+    view.page_down();
+    let paged = view.selected_project().map(str::to_string);
+    assert!(paged.as_deref() != Some("p0") && paged.as_deref() != Some("p1"), "{:?}", paged);
+    view.page_up();
+    assert_eq!(view.selected_project(), Some("p0"));
+    view.previous_row();
+    assert_eq!(view.selected_project(), Some("p0"));
+    view.next_row();
+    assert_eq!(view.selected_project(), Some("p1"));
+    println!("✅ Page down moves by the rows that fit; moving stops at the ends");
+
+    let mut more = tasks();
+    let mut extra = Task::new("One more".to_string());
+    extra.project = Some("p1".to_string());
+    more.push(extra);
+    view.update_tasks(more);
+    assert_eq!(view.selected_project(), Some("p1"));
+    println!("✅ Reloading tasks keeps the selected project");
+
+    let text = render(&mut view, 60, 80);
+    assert!(text.contains("By Project") && shows_row(&text, "p1"), "{}", text);
+    println!("✅ The narrow layout scrolls the same table");
+
+    let _fake = FakeTask::with_script("dashboard", FAKE_TASK);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&config).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(tasks()));
+
+        ui.handle_action(Action::Reports, &taskwarrior).await.expect("reports");
+        ui.handle_action(Action::MoveDown, &taskwarrior).await.expect("down");
+        ui.handle_action(Action::MoveDown, &taskwarrior).await.expect("down");
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("enter");
+
+        let text = screen(&mut ui, 160, 30);
+        assert!(text.contains("Showing project p2"), "{}", text);
+        assert!(text.contains("Tasks (1–9 of 29)") && text.contains("Selected: p2"), "{}", text);
+        println!("✅ Enter shows the selected project's tasks");
+    });
+
+    println!();
+    println!("🎉 Dashboard scroll test passed!");
+}
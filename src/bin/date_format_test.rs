@@ -0,0 +1,71 @@
+// Test program for the configurable ui.date_format / ui.datetime_format patterns
+
+use chrono::{Local, TimeZone, Utc};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::ui::components::task_detail::TaskDetailWidget;
+use lazytask::utils::formatting::DateFormat;
+use ratatui::{backend::TestBackend, Terminal};
+
+fn main() {
+    println!("📅 LazyTask Date Format Test");
+    println!("============================");
+    println!();
+
+    for pattern in ["%Y-%m-%d", "%d.%m.%Y %H:%M", "%A, %e %B", "plain text"] {
+        assert!(DateFormat::is_valid_pattern(pattern), "{}", pattern);
+    }
+    for pattern in ["%Q", "%Y-%m-%", "%d/%m/%"] {
+        assert!(!DateFormat::is_valid_pattern(pattern), "{}", pattern);
+    }
+    println!("✅ Unknown or truncated specifiers are rejected");
+
+    let mut config = Config::default();
+    assert!(config.ui.check_date_formats().is_empty());
+    config.ui.date_format = "%d/%m/%Y".to_string();
+    config.ui.datetime_format = "%d.%m.%Y %Q".to_string();
+    let warnings = config.ui.check_date_formats();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("ui.datetime_format"), "{}", warnings[0]);
+    assert_eq!(config.ui.date_format, "%d/%m/%Y");
+    assert_eq!(config.ui.datetime_format, Config::default().ui.datetime_format);
+    println!("✅ Invalid pattern falls back to the default with a warning: {}", warnings[0]);
+
+    // Loading a config file warns instead of failing
+    let path = std::env::temp_dir().join(format!("lazytask-date-format-{}.toml", std::process::id()));
+    let mut bad = Config::default();
+    bad.ui.short_date_format = "%m/%".to_string();
+    bad.save(&path).expect("write config");
+    let loaded = Config::load(path.to_str()).expect("config with bad pattern still loads");
+    std::fs::remove_file(&path).ok();
+    assert_eq!(loaded.ui.short_date_format, "%m/%d");
+    assert_eq!(loaded.warnings.len(), 1);
+    println!("✅ Config::load keeps going and records the warning");
+
+    // Day-first formats
+    let dates = DateFormat::new(&config.ui);
+    let moment = Local.with_ymd_and_hms(2026, 3, 14, 9, 30, 0).unwrap().with_timezone(&Utc);
+    assert_eq!(dates.date(&moment), "14/03/2026");
+    assert_eq!(dates.naive_date(moment.with_timezone(&Local).date_naive()), "14/03/2026");
+    println!("✅ Dates use the configured pattern in local time");
+
+    config.ui.datetime_format = "%d.%m.%Y %H:%M".to_string();
+    let mut task = Task::new("Check date formats".to_string());
+    task.entry = moment;
+    task.due = Some(moment);
+
+    let mut terminal = Terminal::new(TestBackend::new(60, 40)).expect("test terminal");
+    let widget = TaskDetailWidget::new().with_date_format(DateFormat::new(&config.ui));
+    terminal.draw(|f| widget.render(f, f.area(), Some(&task))).expect("draw");
+    let buffer = terminal.backend().buffer();
+    let rendered: String = (0..buffer.area.height)
+        .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol().to_string()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(rendered.contains("14.03.2026 09:30"), "{}", rendered);
+    assert!(!rendered.contains("2026-03-14"));
+    println!("✅ Detail panel renders the configured datetime format");
+
+    println!();
+    println!("🎉 Date format checks passed");
+}
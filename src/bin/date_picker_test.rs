@@ -0,0 +1,92 @@
+// Test program for the due date picker in the task form
+
+use chrono::{Duration, NaiveDate, Utc};
+use lazytask::handlers::input::Action;
+use lazytask::ui::components::calendar_view::CalendarWidget;
+use lazytask::ui::components::date_picker::{DatePicker, DatePickerResult};
+use lazytask::ui::components::task_form::TaskForm;
+use ratatui::{backend::TestBackend, Terminal};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn press(form: &mut TaskForm, actions: &[Action]) {
+    for action in actions {
+        form.handle_input(action.clone()).unwrap();
+    }
+}
+
+fn main() {
+    println!("🗓️  LazyTask Date Picker Test");
+    println!("============================");
+    println!();
+
+    // October 2026 starts on a Thursday and spans five weeks
+    let weeks = CalendarWidget::month_weeks(2026, 10);
+    assert_eq!(weeks.len(), 5);
+    assert_eq!(weeks[0], [None, None, None, Some(1), Some(2), Some(3), Some(4)]);
+    assert_eq!(weeks[4], [Some(26), Some(27), Some(28), Some(29), Some(30), Some(31), None]);
+    // February 2027 starts on a Monday and fits in exactly four rows
+    assert_eq!(CalendarWidget::month_weeks(2027, 2).len(), 4);
+    println!("✅ Month grid is Monday-first with blanks outside the month");
+
+    let mut picker = DatePicker::new(date(2026, 1, 31));
+    assert_eq!(picker.handle_input(Action::Character('>')), None);
+    assert_eq!(picker.selected, date(2026, 2, 28));
+    picker.handle_input(Action::Character('<'));
+    assert_eq!(picker.selected, date(2026, 1, 28));
+    picker.handle_input(Action::MoveDown);
+    picker.handle_input(Action::MoveRight);
+    assert_eq!(picker.selected, date(2026, 2, 5));
+    picker.handle_input(Action::MoveUp);
+    picker.handle_input(Action::MoveLeft);
+    assert_eq!(picker.selected, date(2026, 1, 28));
+    picker.handle_input(Action::Character('t'));
+    assert_eq!(picker.selected, Utc::now().date_naive());
+    assert_eq!(picker.handle_input(Action::Back), Some(DatePickerResult::Cancel));
+    println!("✅ Arrows move by day/week, < > by month (clamped), t jumps to today");
+
+    let mut terminal = Terminal::new(TestBackend::new(40, 14)).expect("test terminal");
+    let picker = DatePicker::new(date(2026, 10, 16));
+    terminal.draw(|f| picker.render(f, f.area())).expect("draw");
+    let buffer = terminal.backend().buffer();
+    let rendered: String = (0..14)
+        .map(|y| (0..40).map(|x| buffer[(x, y)].symbol().to_string()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(rendered.contains("October 2026"), "{}", rendered);
+    assert!(rendered.contains("Mo  Tu  We  Th  Fr  Sa  Su"), "{}", rendered);
+    assert!(rendered.contains("12  13  14  15  16  17  18"), "{}", rendered);
+    println!("✅ Picker renders the selected month");
+
+    // Ctrl+D only opens the picker on the Due field
+    let mut form = TaskForm::new_task();
+    press(&mut form, &[Action::DatePicker]);
+    assert!(form.date_picker.is_none());
+    press(&mut form, &[Action::Tab, Action::Tab, Action::Tab, Action::DatePicker]);
+    let picked = form.date_picker.as_ref().map(|p| p.selected);
+    assert_eq!(picked, Some(Utc::now().date_naive()));
+    press(&mut form, &[Action::MoveRight, Action::Select]);
+    assert!(form.date_picker.is_none());
+    let tomorrow = Utc::now().date_naive() + Duration::days(1);
+    assert_eq!(form.due_input, tomorrow.format("%Y-%m-%d").to_string());
+    println!("✅ Empty Due starts on today; Enter writes the picked date back");
+
+    // Picker starts on the typed date and keeps its time of day
+    form.due_input = "2026-03-14 17:30".to_string();
+    press(&mut form, &[Action::DatePicker]);
+    assert_eq!(form.date_picker.as_ref().map(|p| p.selected), Some(date(2026, 3, 14)));
+    press(&mut form, &[Action::Character('>'), Action::Select]);
+    assert_eq!(form.due_input, "2026-04-14 17:30");
+
+    // Esc closes only the picker
+    press(&mut form, &[Action::DatePicker, Action::MoveDown]);
+    let result = form.handle_input(Action::Back).unwrap();
+    assert!(result.is_none() && form.date_picker.is_none());
+    assert_eq!(form.due_input, "2026-04-14 17:30");
+    println!("✅ Starts on the current due date, keeps the time, Esc leaves the form open");
+
+    println!();
+    println!("🎉 Date picker checks passed");
+}
@@ -0,0 +1,54 @@
+// Test program for the ui.default_view startup setting
+
+use lazytask::config::Config;
+use lazytask::ui::app_ui::{AppUI, AppView};
+use ratatui::{backend::TestBackend, Terminal};
+
+const WIDTH: u16 = 120;
+const HEIGHT: u16 = 30;
+
+// Draw the app as it looks on startup with the given default_view
+fn startup_screen(name: &str) -> String {
+    let mut config = Config::default();
+    config.ui.default_view = name.to_string();
+    let mut ui = AppUI::new(&config).expect("app ui");
+
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).expect("test terminal");
+    terminal.draw(|f| ui.draw(f)).expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..HEIGHT)
+        .map(|y| (0..WIDTH).map(|x| buffer[(x, y)].symbol().to_string()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn main() {
+    println!("🪟 LazyTask Default View Test");
+    println!("============================");
+    println!();
+
+    assert_eq!(AppView::from_name("task_list"), Some(AppView::TaskList));
+    assert_eq!(AppView::from_name("reports"), Some(AppView::Reports));
+    assert_eq!(AppView::from_name("projects"), Some(AppView::Projects));
+    assert_eq!(AppView::from_name(" Reports "), Some(AppView::Reports));
+    assert_eq!(AppView::from_name("calendar"), None);
+    println!("✅ task_list, reports and projects are recognised, ignoring case and spaces");
+
+    let screen = startup_screen("task_list");
+    assert!(!screen.contains("Task Summary") && !screen.contains("Unknown ui.default_view"));
+    println!("✅ \"task_list\" starts in the task list");
+
+    assert!(startup_screen("reports").contains("Task Summary"));
+    println!("✅ \"reports\" starts on the reports dashboard");
+
+    assert!(startup_screen("projects").contains("Projects (0)"));
+    println!("✅ \"projects\" starts in the Projects view");
+
+    let screen = startup_screen("calendar");
+    assert!(!screen.contains("Task Summary"));
+    assert!(screen.contains("Unknown ui.default_view \"calendar\""), "{}", screen);
+    println!("✅ Unknown names fall back to the task list with a warning in the status bar");
+
+    println!();
+    println!("🎉 Default view test passed!");
+}
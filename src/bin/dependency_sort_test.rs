@@ -0,0 +1,81 @@
+// Test program for the dependency sort (`6`): prerequisites come before the tasks that
+// depend on them, urgency orders everything else, and cycles can't hang it
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::data::sort::SortKey;
+use lazytask::handlers::input::{Action, InputHandler};
+
+fn task(description: &str, urgency: f64) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.urgency = urgency;
+    task
+}
+
+fn depends_on(task: &mut Task, prerequisites: &[&Task]) {
+    task.depends = prerequisites.iter().map(|p| p.uuid.clone()).collect();
+}
+
+fn order(tasks: &[Task]) -> Vec<&str> {
+    tasks.iter().map(|task| task.description.as_str()).collect()
+}
+
+fn main() {
+    println!("🔗 LazyTask Dependency Sort Test");
+    println!("===============================");
+    println!();
+
+    let handler = InputHandler::new(&Config::default());
+    let action = handler.handle_key_event_with_context(KeyEvent::new(KeyCode::Char('6'), KeyModifiers::NONE), false);
+    assert!(matches!(action, Action::SortBy(SortKey::Dependencies)), "{:?}", action);
+    assert_eq!(SortKey::Dependencies.label(), "Dependencies");
+    println!("✅ 6 sorts by dependencies");
+
+    // Book venue -> Send invites -> Hold the party; the party is the most urgent
+    let venue = task("Book venue", 2.0);
+    let mut invites = task("Send invites", 5.0);
+    depends_on(&mut invites, &[&venue]);
+    let mut party = task("Hold the party", 12.0);
+    depends_on(&mut party, &[&invites, &venue]);
+    let groceries = task("Buy groceries", 8.0);
+    let taxes = task("File taxes", 3.0);
+    let mut tasks = vec![party.clone(), taxes.clone(), invites.clone(), groceries.clone(), venue.clone()];
+    SortKey::Dependencies.sort(&mut tasks);
+    assert_eq!(order(&tasks), ["Buy groceries", "File taxes", "Book venue", "Send invites", "Hold the party"]);
+    println!("✅ Prerequisites first, urgency otherwise: {:?}", order(&tasks));
+
+    // Depending on a task that isn't loaded, or on itself, doesn't hold a task back
+    let mut orphan = task("Review the contract", 9.0);
+    orphan.depends = vec!["00000000-0000-0000-0000-000000000000".to_string(), orphan.uuid.clone()];
+    let mut tasks = vec![taxes.clone(), orphan];
+    SortKey::Dependencies.sort(&mut tasks);
+    assert_eq!(order(&tasks), ["Review the contract", "File taxes"]);
+    println!("✅ Unknown and self dependencies are ignored");
+
+    // A -> B -> C -> A, plus D waiting on the cycle
+    let mut a = task("Cycle A", 1.0);
+    let mut b = task("Cycle B", 6.0);
+    let mut c = task("Cycle C", 4.0);
+    let mut d = task("After the cycle", 20.0);
+    let (a_uuid, b_uuid, c_uuid) = (a.uuid.clone(), b.uuid.clone(), c.uuid.clone());
+    a.depends = vec![b_uuid.clone()];
+    b.depends = vec![c_uuid.clone()];
+    c.depends = vec![a_uuid];
+    d.depends = vec![c_uuid];
+    let mut tasks = vec![d, a, b, c, taxes.clone()];
+    SortKey::Dependencies.sort(&mut tasks);
+    assert_eq!(tasks.len(), 5);
+    assert_eq!(order(&tasks), ["File taxes", "Cycle B", "Cycle A", "Cycle C", "After the cycle"]);
+    println!("✅ A cycle is broken at its most urgent task: {:?}", order(&tasks));
+
+    let mut tasks = vec![task("Same", 1.0), task("Same", 1.0)];
+    let first = tasks[1].uuid.clone();
+    tasks[1].entry = tasks[0].entry + chrono::Duration::seconds(1);
+    SortKey::Dependencies.sort(&mut tasks);
+    assert_eq!(tasks[0].uuid, first);
+    println!("✅ Equal urgency falls back to newest first, like the other sorts");
+
+    println!();
+    println!("🎉 Dependency sort test passed!");
+}
@@ -0,0 +1,79 @@
+// Test program for parsing, editing and preserving task dependencies
+
+use lazytask::data::models::Task;
+use lazytask::handlers::input::Action;
+use lazytask::ui::components::task_form::{TaskForm, TaskFormResult};
+use serde_json::json;
+
+fn save(form: &mut TaskForm) -> Task {
+    form.handle_input(Action::Select).expect("input"); // leave editing
+    match form.handle_input(Action::Select).expect("input") {
+        Some(TaskFormResult::Save(task)) => task,
+        _ => panic!("form did not save"),
+    }
+}
+
+fn main() {
+    println!("🔗 LazyTask Depends Test");
+    println!("========================");
+    println!();
+
+    let blocker_uuid = "11111111-2222-3333-4444-555555555555";
+    let other_uuid = "66666666-7777-8888-9999-000000000000";
+
+    // Both export formats parse
+    let modern = Task::from_json(&json!({
+        "id": 3, "uuid": "aaaaaaaa-0000-0000-0000-000000000000", "description": "blocked",
+        "status": "pending", "depends": [blocker_uuid],
+    })).expect("parse");
+    let legacy = Task::from_json(&json!({
+        "uuid": "bbbbbbbb-0000-0000-0000-000000000000", "description": "legacy",
+        "status": "pending", "depends": format!("{},{}", blocker_uuid, other_uuid),
+    })).expect("parse");
+    assert_eq!(modern.depends, vec![blocker_uuid]);
+    assert_eq!(legacy.depends, vec![blocker_uuid, other_uuid]);
+    assert!(modern.is_blocked());
+    println!("✅ depends parses from arrays and comma-separated strings");
+
+    let mut blocker = Task::new("blocker".to_string());
+    blocker.uuid = blocker_uuid.to_string();
+    blocker.id = Some(1);
+    let mut other = Task::new("other".to_string());
+    other.uuid = other_uuid.to_string();
+    other.id = Some(2);
+    let all = vec![blocker, other, modern.clone()];
+
+    // Editing only the description keeps the task blocked
+    let mut form = TaskForm::edit_task(modern.clone()).with_dependency_ids(&all);
+    assert_eq!(form.depends_input, "1");
+    form.handle_input(Action::Character('!')).expect("input");
+    let edited = save(&mut form);
+    assert_eq!(edited.description, "blocked!");
+    assert_eq!(edited.depends, vec![blocker_uuid]);
+    assert!(edited.is_blocked());
+    assert_eq!(edited.depends_modification(&modern.depends).as_deref(), Some(blocker_uuid));
+    println!("✅ Editing the description keeps dependencies (depends:{})", blocker_uuid);
+
+    // Swap the dependency by ID: task 2 added, task 1 removed
+    let mut form = TaskForm::edit_task(modern.clone()).with_dependency_ids(&all);
+    form.depends_input = "2".to_string();
+    let swapped = save(&mut form);
+    assert_eq!(swapped.depends, vec![other_uuid]);
+    assert_eq!(
+        swapped.depends_modification(&modern.depends),
+        Some(format!("{},-{}", other_uuid, blocker_uuid))
+    );
+    println!("✅ Dependencies can be added and removed by task ID");
+
+    // Clearing the field removes every dependency; nothing to send when none existed
+    let mut form = TaskForm::edit_task(modern.clone()).with_dependency_ids(&all);
+    form.depends_input.clear();
+    let cleared = save(&mut form);
+    assert!(!cleared.is_blocked());
+    assert_eq!(cleared.depends_modification(&modern.depends), Some(format!("-{}", blocker_uuid)));
+    assert_eq!(Task::new("fresh".to_string()).depends_modification(&[]), None);
+    println!("✅ Clearing dependencies unblocks the task");
+
+    println!();
+    println!("🎉 Depends checks passed");
+}
@@ -0,0 +1,57 @@
+// Test program for the description column, which is cut to the width it is rendered at
+// instead of a fixed number of characters
+
+use lazytask::data::models::Task;
+use lazytask::ui::components::task_list::TaskListWidget;
+use ratatui::{backend::TestBackend, Terminal};
+
+const DESCRIPTION: &str = "Draft the quarterly planning document for the infrastructure team, \
+including hiring, budget, on-call changes and the datacenter migration timeline";
+
+// The row showing the task, at a given terminal width
+fn task_row(width: u16) -> String {
+    let columns: Vec<String> = ["id", "project", "priority", "due", "description"].iter().map(|c| c.to_string()).collect();
+    let mut list = TaskListWidget::new(&columns);
+    list.set_tasks(vec![Task::new(DESCRIPTION.to_string())]);
+
+    let mut terminal = Terminal::new(TestBackend::new(width, 4)).expect("test terminal");
+    terminal.draw(|f| list.render(f, f.area())).expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..width).map(|x| buffer[(x, 2)].symbol().to_string()).collect()
+}
+
+// Description text shown in a row, up to the right border
+fn shown_description(row: &str) -> String {
+    let start = row.find("Draft").expect("description in row");
+    row[start..].trim_end_matches('│').trim_end().to_string()
+}
+
+fn main() {
+    println!("📏 LazyTask Description Width Test");
+    println!("=================================");
+    println!();
+
+    let mut previous = 0;
+    for width in [70, 100, 140, 200] {
+        let row = task_row(width);
+        let shown = shown_description(&row);
+        assert!(shown.ends_with("..."), "{}: {:?}", width, row);
+        assert!(DESCRIPTION.starts_with(shown.trim_end_matches("...")), "{}: {:?}", width, row);
+        assert!(shown.chars().count() > previous, "{}: {:?}", width, row);
+        previous = shown.chars().count();
+        println!("✅ {} columns wide: {} characters of description, ending in ...", width, previous);
+    }
+    assert!(previous > 45, "wide terminals show more than the old 45: {}", previous);
+
+    let row = task_row(200);
+    let free = row.trim_end_matches('│').len() - row.trim_end_matches('│').trim_end().len();
+    assert!(free <= 1, "the cut fills the column: {:?}", row);
+    println!("✅ The cut fills the column right up to the border");
+
+    let full = task_row(260);
+    assert_eq!(shown_description(&full), DESCRIPTION, "{:?}", full);
+    println!("✅ A description that fits is shown whole, without ...");
+
+    println!();
+    println!("🎉 Description width test passed!");
+}
@@ -0,0 +1,180 @@
+// Test program for `taskwarrior.direct_db_writes`: modifications written straight to a
+// throwaway taskchampion.sqlite3 with Taskwarrior 3's schema, and the CLI fallback.
+
+use std::path::Path;
+
+use chrono::{TimeZone, Utc};
+use lazytask::data::database::TaskChampionDB;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use rusqlite::Connection;
+use serde_json::{json, Value};
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::FakeTask;
+
+const UUID: &str = "5d2b1c7e-8f3a-4b6d-9e0f-1a2b3c4d5e6f";
+const OLD_MODIFIED: &str = "1700000000";
+
+// The tables Taskwarrior 3 creates, including the generated `uuid` and the `synced` flag
+// later versions add to `operations`
+fn create_db(path: &Path, data: Value) {
+    let conn = Connection::open(path).expect("create db");
+    conn.execute_batch(
+        "CREATE TABLE operations (id INTEGER PRIMARY KEY AUTOINCREMENT, data STRING);
+         ALTER TABLE operations ADD COLUMN uuid GENERATED ALWAYS AS (
+             coalesce(json_extract(data, '$.Update.uuid'), json_extract(data, '$.Create.uuid'),
+                      json_extract(data, '$.Delete.uuid'))) VIRTUAL;
+         ALTER TABLE operations ADD COLUMN synced bool DEFAULT false;
+         CREATE TABLE sync_meta (key STRING PRIMARY KEY, value STRING);
+         CREATE TABLE tasks (uuid STRING PRIMARY KEY, data STRING);
+         CREATE TABLE working_set (id INTEGER PRIMARY KEY, uuid STRING);",
+    )
+    .expect("schema");
+    conn.execute("INSERT INTO tasks (uuid, data) VALUES (?1, ?2)", (UUID, data.to_string())).expect("task");
+    conn.execute("INSERT INTO working_set (id, uuid) VALUES (1, ?1)", [UUID]).expect("working set");
+}
+
+fn sample_task() -> Value {
+    json!({
+        "description": "Buy stamps",
+        "status": "pending",
+        "entry": "1699990000",
+        "modified": OLD_MODIFIED,
+        "project": "home",
+        "priority": "M",
+        "tag_errand": "",
+        "due": "1700500000",
+    })
+}
+
+// (data, uuid column, synced) for each logged operation, oldest first
+fn operations(path: &Path) -> Vec<(Value, Option<String>, bool)> {
+    let conn = Connection::open(path).expect("open db");
+    let mut statement = conn.prepare("SELECT data, uuid, synced FROM operations ORDER BY id").expect("query");
+    statement
+        .query_map([], |row| {
+            let data: String = row.get(0)?;
+            Ok((serde_json::from_str(&data).expect("operation json"), row.get(1)?, row.get(2)?))
+        })
+        .expect("rows")
+        .map(|row| row.expect("row"))
+        .collect()
+}
+
+fn property(db: &TaskChampionDB, name: &str) -> Option<String> {
+    db.task_data(UUID).expect("read").expect("task exists").get(name).cloned()
+}
+
+fn main() {
+    println!("🗄️  LazyTask Direct DB Write Test");
+    println!("================================");
+    println!();
+
+    let fake = FakeTask::new("direct-db");
+    let path = fake.path("taskchampion.sqlite3");
+    create_db(&path, sample_task());
+    let mut db = TaskChampionDB::new(path.clone()).expect("open db");
+
+    assert_eq!(db.working_set_uuid(1).expect("lookup").as_deref(), Some(UUID));
+    assert_eq!(db.working_set_uuid(9).expect("lookup"), None);
+    println!("✅ Task IDs are looked up in the working set");
+
+    // What the task form sends: every field, with tags cleared and added back
+    let written = db
+        .modify_task(UUID, &[
+            ("description", "Buy stamps"),
+            ("project", "work"),
+            ("priority", ""),
+            ("tags", ""),
+            ("+errand", ""),
+            ("+post", ""),
+            ("due", "2025-03-14T09:00:00Z"),
+        ])
+        .expect("modify");
+    assert!(written);
+    let due = Utc.with_ymd_and_hms(2025, 3, 14, 9, 0, 0).unwrap().timestamp().to_string();
+    assert_eq!(property(&db, "project").as_deref(), Some("work"));
+    assert_eq!(property(&db, "priority"), None);
+    assert_eq!(property(&db, "tag_errand").as_deref(), Some(""));
+    assert_eq!(property(&db, "tag_post").as_deref(), Some(""));
+    assert_eq!(property(&db, "due"), Some(due.clone()));
+    let modified = property(&db, "modified").expect("modified");
+    assert!(modified.parse::<i64>().expect("timestamp") >= Utc::now().timestamp() - 60);
+    println!("✅ Changed properties are stored the way TaskChampion stores them");
+
+    let logged = operations(&path);
+    assert_eq!(logged[0].0, json!("UndoPoint"));
+    let updates: Vec<&Value> = logged[1..].iter().map(|(data, _, _)| &data["Update"]).collect();
+    let changed: Vec<&str> = updates.iter().map(|update| update["property"].as_str().expect("property")).collect();
+    assert_eq!(changed, ["due", "modified", "priority", "project", "tag_post"]);
+    let priority = updates.iter().find(|update| update["property"] == "priority").expect("priority update");
+    assert_eq!((&priority["old_value"], &priority["value"]), (&json!("M"), &Value::Null));
+    let post = updates.iter().find(|update| update["property"] == "tag_post").expect("tag update");
+    assert_eq!((&post["old_value"], &post["value"]), (&Value::Null, &json!("")));
+    assert!(updates.iter().all(|update| update["uuid"] == UUID && update["timestamp"].is_string()));
+    assert!(logged[1..].iter().all(|(_, uuid, synced)| uuid.as_deref() == Some(UUID) && !synced));
+    println!("✅ An undo point and one update per changed property are logged for sync: {:?}", changed);
+
+    assert!(db.modify_task(UUID, &[("project", "work")]).expect("no-op"));
+    assert_eq!(operations(&path).len(), logged.len());
+    println!("✅ A change to what's already there logs nothing");
+
+    for attributes in [
+        &[("project", "garden"), ("due", "tomorrow")][..],
+        &[("depends", "a1b2c3d4")][..],
+        &[("description", "")][..],
+        &[("status", "completed")][..],
+    ] {
+        assert!(!db.modify_task(UUID, attributes).expect("unsupported"), "{:?}", attributes);
+        assert_eq!(property(&db, "project").as_deref(), Some("work"));
+        assert_eq!(operations(&path).len(), logged.len());
+    }
+    println!("✅ Changes that need Taskwarrior write nothing, not even part of the change");
+
+    assert!(db.modify_task("00000000-0000-0000-0000-000000000000", &[("project", "x")]).is_err());
+    println!("✅ A task missing from the database is an error");
+
+    let legacy = fake.path("legacy.sqlite3");
+    let mut data = sample_task();
+    data["tags"] = json!("errand");
+    create_db(&legacy, data);
+    let mut legacy_db = TaskChampionDB::new(legacy.clone()).expect("open legacy");
+    assert!(!legacy_db.modify_task(UUID, &[("project", "work")]).expect("legacy"));
+    assert!(operations(&legacy).is_empty());
+    println!("✅ Tasks with an old combined `tags` property are left to the CLI");
+
+    let log = fake.path("commands");
+    let fake_task = format!(
+        "#!/bin/sh\ncase \"$*\" in\n  *--version*) echo '3.1.0' ;;\n  *modify*) echo \"$*\" >> '{}' ;;\nesac\n",
+        log.display()
+    );
+    fake.set_script(&fake_task);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let cli_only = TaskwarriorIntegration::new(None, Some(fake.dir().to_path_buf())).expect("fake task");
+        cli_only.modify_task(1, &[("project", "garden")]).await.expect("modify");
+        assert_eq!(property(&db, "project").as_deref(), Some("work"));
+        assert_eq!(std::fs::read_to_string(&log).expect("task ran").lines().count(), 1);
+        println!("✅ Off by default: changes run `task`");
+
+        let direct = TaskwarriorIntegration::new(None, Some(fake.dir().to_path_buf()))
+            .expect("fake task")
+            .with_direct_db_writes(true);
+        direct.modify_task(1, &[("project", "garden")]).await.expect("modify by id");
+        direct.modify_task_by_uuid(UUID, &[("wait", "2030-01-01T00:00:00Z")]).await.expect("modify by uuid");
+        assert_eq!(property(&db, "project").as_deref(), Some("garden"));
+        assert!(property(&db, "wait").is_some());
+        assert_eq!(std::fs::read_to_string(&log).expect("log").lines().count(), 1);
+        println!("✅ With direct_db_writes, edits by ID and UUID skip `task`");
+
+        direct.modify_task_by_uuid(UUID, &[("due", "eow")]).await.expect("fallback");
+        let commands = std::fs::read_to_string(&log).expect("log");
+        assert!(commands.lines().last().unwrap_or_default().contains("modify due:eow"), "{}", commands);
+        println!("✅ What it can't write still goes through `task`");
+    });
+
+    println!();
+    println!("🎉 Direct DB write test passed!");
+}
@@ -0,0 +1,86 @@
+// Test program for `d` as a status toggle: it completes a pending task and reopens a
+// completed one.
+
+use chrono::{Duration, Utc};
+use lazytask::config::Config;
+use lazytask::data::models::{Task, TaskStatus};
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 30;
+
+// Completed `hours_ago`, without an ID like Taskwarrior's completed tasks
+fn completed(description: &str, hours_ago: i64) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.status = TaskStatus::Completed;
+    task.end = Some(Utc::now() - Duration::hours(hours_ago));
+    task
+}
+
+fn main() {
+    println!("🔁 LazyTask Done Toggle Test");
+    println!("===========================");
+    println!();
+
+    let fake = FakeTask::new("done-toggle");
+    let log = fake.path("commands");
+    let fake_task = format!(
+        "#!/bin/sh\ncase \"$*\" in\n  *--version*) echo '3.1.0' ;;\n  *modify*|*done*) echo \"$*\" >> '{}' ;;\nesac\n",
+        log.display()
+    );
+    fake.set_script(&fake_task);
+
+    let mut pending = Task::new("Plan the garden".to_string());
+    pending.id = Some(1);
+    let library = completed("Return library books", 3);
+    let bill = completed("Pay the water bill", 30);
+    let tasks = vec![pending.clone(), library.clone(), bill.clone()];
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(tasks.clone()));
+
+        ui.handle_action(Action::RecentlyCompleted, &taskwarrior).await.expect("recent");
+        ui.handle_action(Action::DoneTask, &taskwarrior).await.expect("toggle");
+        let commands = std::fs::read_to_string(&log).expect("task was run");
+        assert!(commands.contains(&format!("{} modify status:pending end:", library.uuid)), "{}", commands);
+        assert!(!commands.contains("done"), "{}", commands);
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("Reopened 'Return library books'"));
+        assert!(ui.take_reload_request());
+        println!("✅ d on a completed task reopens it: task {}", commands.trim());
+
+        // The reload brings the task back as pending, so it leaves the completed view
+        let mut reopened = library.clone();
+        reopened.status = TaskStatus::Pending;
+        reopened.end = None;
+        reopened.id = Some(2);
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![pending.clone(), reopened, bill.clone()]));
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Completed in the last 7d (1)") && text.contains("Pay the water bill"), "{}", text);
+        std::fs::remove_file(&log).expect("clear log");
+        ui.handle_action(Action::DoneTask, &taskwarrior).await.expect("toggle again");
+        let commands = std::fs::read_to_string(&log).expect("task was run");
+        assert!(commands.contains(&format!("{} modify status:pending", bill.uuid)), "{}", commands);
+        println!("✅ The selection stays in the completed view, on the next completed task");
+
+        ui.handle_action(Action::RecentlyCompleted, &taskwarrior).await.expect("back");
+        std::fs::remove_file(&log).expect("clear log");
+        ui.handle_action(Action::DoneTask, &taskwarrior).await.expect("done");
+        let commands = std::fs::read_to_string(&log).expect("task was run");
+        assert!(commands.trim_end().ends_with(" done") && !commands.contains("modify"), "{}", commands);
+        println!("✅ d on a pending task still completes it");
+    });
+
+    println!();
+    println!("🎉 Done toggle test passed!");
+}
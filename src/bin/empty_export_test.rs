@@ -0,0 +1,103 @@
+// Test program for empty exports, broken exports (including a task that can't be read)
+// and the empty task list placeholder.
+
+use lazytask::config::Config;
+use lazytask::taskwarrior::{TaskwarriorCLI, TaskwarriorIntegration};
+use lazytask::ui::app_ui::AppUI;
+use lazytask::ui::components::task_list::TaskListWidget;
+use lazytask::utils::helpers::UrgencyCoefficients;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{render, FakeTask};
+
+const FAKE_TASK: &str = r#"#!/bin/sh
+case "$*" in
+  *--version*) echo '3.1.0' ;;
+  *export*) echo '[{"uuid": "33333333-3333-3333-3333-333333333333", "description": "fine"}, {"status": "pending"}]' ;;
+esac
+"#;
+
+fn footer(ui: &mut AppUI) -> String {
+    fake_task::screen(ui, 140, 30).lines().skip(27).collect()
+}
+
+fn screen(list: &mut TaskListWidget) -> String {
+    render(60, 8, |f| list.render(f, f.area()))
+}
+
+fn main() {
+    println!("📭 LazyTask Empty Export Test");
+    println!("============================");
+    println!();
+
+    for output in ["[]", "[\n]\n", "", "  \n"] {
+        let tasks = TaskwarriorCLI::parse_export(output).expect("empty export is not an error");
+        assert!(tasks.is_empty());
+    }
+    println!("✅ A new database (\"[]\" or no output) gives an empty list");
+
+    for output in ["Configuration error: .taskrc not found", "[{\"uuid\": \"a\""] {
+        let error = TaskwarriorCLI::parse_export(output).unwrap_err().to_string();
+        assert!(error.starts_with("task export returned invalid JSON"), "{}", error);
+    }
+    println!(
+        "✅ Invalid JSON is an error: {}",
+        TaskwarriorCLI::parse_export("Configuration error").unwrap_err()
+    );
+
+    let output = r#"[
+        {"uuid": "11111111-1111-1111-1111-111111111111", "description": "good", "status": "pending"},
+        {"uuid": "22222222-2222-2222-2222-222222222222", "status": "pending"}
+    ]"#;
+    let tasks = TaskwarriorCLI::parse_export(output).unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].description, "good");
+    let (_, skipped) = TaskwarriorCLI::parse_export_counting(output, &UrgencyCoefficients::default()).unwrap();
+    assert_eq!(skipped, 1);
+    println!("✅ A single unreadable task is skipped and counted, the rest still load");
+
+    let output = r#"[
+        {"description": "no uuid", "status": "pending"},
+        {"uuid": "not-a-uuid", "description": "bad uuid", "status": "pending"}
+    ]"#;
+    let (tasks, skipped) = TaskwarriorCLI::parse_export_counting(output, &UrgencyCoefficients::default()).unwrap();
+    assert_eq!((tasks.len(), skipped), (2, 0));
+    assert!(tasks.iter().all(|task| uuid::Uuid::parse_str(&task.uuid).is_ok()));
+    assert_ne!(tasks[1].uuid, "not-a-uuid");
+    assert_ne!(tasks[0].uuid, tasks[1].uuid);
+    println!("✅ Tasks with a missing or invalid UUID are kept under a made-up one");
+
+    let mut list = TaskListWidget::new(&["description".to_string()]);
+    assert!(!screen(&mut list).contains("No tasks"));
+    list.set_empty_message(Some("No tasks yet — press 'a' to add one".to_string()));
+    assert!(screen(&mut list).contains("No tasks yet — press 'a' to add one"));
+    println!("✅ Placeholder shows only once a message is set (not before the first load)");
+
+    list.set_tasks(tasks);
+    assert!(!screen(&mut list).contains("No tasks yet"));
+    println!("✅ Placeholder disappears when there are tasks");
+
+    let _fake = FakeTask::with_script("skipped", FAKE_TASK);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        assert_eq!(taskwarrior.skipped_tasks(), 0);
+        let loaded = taskwarrior.list_tasks(None).await.expect("export");
+        assert_eq!((loaded.len(), taskwarrior.skipped_tasks()), (1, 1));
+
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(loaded));
+        ui.report_skipped_tasks(taskwarrior.skipped_tasks());
+        let text = footer(&mut ui);
+        assert!(text.contains("1 task couldn't be read and is not shown"), "{}", text);
+        ui.report_skipped_tasks(3);
+        assert!(footer(&mut ui).contains("3 tasks couldn't be read and are not shown"));
+        println!("✅ Unreadable tasks are counted and reported in the footer");
+    });
+
+    println!();
+    println!("🎉 Empty export checks passed");
+}
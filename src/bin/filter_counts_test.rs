@@ -0,0 +1,68 @@
+// Test program for the match counts next to each status, project and tag filter option
+
+use lazytask::config::Config;
+use lazytask::data::models::{Task, TaskStatus};
+use lazytask::ui::views::main_view::MainView;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::render;
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 50;
+
+fn task(description: &str, project: &str, tags: &[&str]) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.project = Some(project.to_string());
+    task.tags = tags.iter().map(|tag| tag.to_string()).collect();
+    task
+}
+
+fn screen(view: &mut MainView) -> String {
+    render(WIDTH, HEIGHT, |f| view.render(f, f.area(), WIDTH))
+}
+
+fn main() {
+    println!("🔢 LazyTask Filter Counts Test");
+    println!("=============================");
+    println!();
+
+    let mut done = task("file expenses", "work", &["admin"]);
+    done.status = TaskStatus::Completed;
+    let tasks = vec![
+        task("standup", "work", &["meeting"]),
+        task("invoice", "work.clientA", &["admin"]),
+        task("deploy site", "work.clientA.web", &[]),
+        task("weed beds", "home.garden", &["outside"]),
+        done,
+    ];
+
+    // Pending is selected by default
+    let mut view = MainView::new(&Config::default());
+    view.update_available_filters(&tasks);
+    view.refresh_option_counts();
+    let panel = screen(&mut view);
+    assert!(panel.contains("Pending (4)") && panel.contains("Completed (1)"), "{}", panel);
+    println!("✅ Status options count tasks regardless of the status selection");
+
+    assert!(panel.contains("work (3)") && panel.contains("clientA (2)") && panel.contains("home (1)"), "{}", panel);
+    assert!(panel.contains("admin (1)") && panel.contains("meeting (1)"), "{}", panel);
+    println!("✅ Project and tag counts follow the status selection; parents include subprojects");
+
+    view.show_only_project("work");
+    view.refresh_option_counts();
+    let panel = screen(&mut view);
+    assert!(panel.contains("Pending (3)") && panel.contains("Completed (1)"), "{}", panel);
+    assert!(panel.contains("outside (0)"), "{}", panel);
+    assert!(panel.contains("home (1)"), "{}", panel);
+    println!("✅ Selecting a project updates the other sections but not the project counts");
+
+    view.toggle_project_exact();
+    view.refresh_option_counts();
+    let panel = screen(&mut view);
+    assert!(panel.contains("work (1)"), "{}", panel);
+    println!("✅ With exact matching a parent only counts its own tasks");
+
+    println!();
+    println!("🎉 Filter counts test passed!");
+}
@@ -0,0 +1,129 @@
+// Test program for type-ahead find in the task list: `f`, then type to jump, n/N to step.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::{Action, InputHandler, KeyContext};
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+use lazytask::ui::views::main_view::MainView;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 30;
+
+const DESCRIPTIONS: [&str; 5] = ["Call the bank", "Buy milk", "Water plants", "Return library books", "buy stamps"];
+
+fn tasks() -> Vec<Task> {
+    DESCRIPTIONS.iter().map(|description| Task::new(description.to_string())).collect()
+}
+
+fn selected(view: &MainView) -> &str {
+    view.selected_task().map(|task| task.description.as_str()).unwrap_or("")
+}
+
+fn main() {
+    println!("🔎 LazyTask Find Test");
+    println!("====================");
+    println!();
+
+    let handler = InputHandler::new(&Config::default());
+    let key = handler.handle_key_event_with_context(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE), false);
+    assert!(matches!(key, Action::Find));
+    println!("✅ f starts a find");
+
+    let mut view = MainView::new(&Config::default());
+    view.set_tasks_with_preserved_selection(tasks(), None);
+    assert_eq!(selected(&view), "Call the bank");
+
+    view.start_find();
+    view.find_push('b');
+    assert_eq!(selected(&view), "Call the bank");
+    view.find_push('u');
+    assert_eq!(selected(&view), "Buy milk");
+    println!("✅ Typing jumps to the first description containing the text, ignoring case");
+
+    view.find_next(true);
+    assert_eq!(selected(&view), "buy stamps");
+    view.find_next(true);
+    assert_eq!(selected(&view), "Buy milk");
+    view.find_next(false);
+    assert_eq!(selected(&view), "buy stamps");
+    println!("✅ Next and previous step through the matches, wrapping around");
+
+    view.find_push('x');
+    assert_eq!(selected(&view), "buy stamps");
+    view.find_pop();
+    assert_eq!(selected(&view), "buy stamps");
+    println!("✅ Without a match the selection stays put");
+
+    view.clear_find();
+    assert!(!view.is_finding());
+    assert_eq!(tasks().iter().filter(|task| view.matches_filters(task)).count(), DESCRIPTIONS.len());
+    println!("✅ Finding never filters tasks out");
+
+    let _fake = FakeTask::with_script("find", FAKE_TASK);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(tasks()));
+
+        ui.handle_action(Action::Find, &taskwarrior).await.expect("find");
+        assert!(ui.has_active_form());
+        for c in "buy".chars() {
+            ui.handle_action(Action::Character(c), &taskwarrior).await.expect("type");
+        }
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Find: buy█ 1/2"), "{}", text);
+        println!("✅ The title shows the query and which match is selected");
+
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("enter");
+        assert!(!ui.has_active_form());
+        assert_eq!(ui.key_context(), KeyContext::Find);
+        let key = |ui: &AppUI, c: char| {
+            handler.handle_key_event_in_view(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE), false, ui.key_context())
+        };
+        let action = key(&ui, 'n');
+        assert!(matches!(action, Action::FindNext), "{:?}", action);
+        ui.handle_action(action, &taskwarrior).await.expect("n");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Find: buy 2/2"), "{}", text);
+        assert!(!text.contains("Next ("), "{}", text);
+        let action = key(&ui, 'N');
+        assert!(matches!(action, Action::FindPrevious), "{:?}", action);
+        ui.handle_action(action, &taskwarrior).await.expect("N");
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("Find: buy 1/2"));
+        assert!(matches!(key(&ui, 'e'), Action::EditTask));
+        println!("✅ After Enter, n and N step through the matches; other task list keys still work");
+
+        ui.handle_action(Action::CheatSheet, &taskwarrior).await.expect("?");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Keys · Find") && text.contains("Next match") && text.contains("Previous match"), "{}", text);
+        assert!(text.contains("Edit selected task"), "{}", text);
+        ui.handle_action(Action::None, &taskwarrior).await.expect("close");
+        println!("✅ The cheat sheet lists n/N along with the task list keys");
+
+        ui.handle_action(Action::Back, &taskwarrior).await.expect("esc");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(!text.contains("Find:"), "{}", text);
+        assert!(text.contains("Tasks (5)"), "{}", text);
+        assert_eq!(ui.key_context(), KeyContext::TaskList);
+        assert!(matches!(key(&ui, 'n'), Action::NextView) && matches!(key(&ui, 'N'), Action::Annotations));
+        println!("✅ Esc ends the find, the whole list is still there and n/N are back to normal");
+    });
+
+    println!();
+    println!("🎉 Find test passed!");
+}
@@ -0,0 +1,91 @@
+// Test program for F / T: narrow the list to the selected task's project or first tag,
+// and press again to clear it.
+
+use chrono::{Duration, Utc};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 30;
+
+// Newest first in the default Age sort, so the first task given is the one selected
+fn task(description: &str, project: Option<&str>, tags: &[&str], age_hours: i64) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.project = project.map(str::to_string);
+    task.tags = tags.iter().map(|tag| tag.to_string()).collect();
+    task.entry = Utc::now() - Duration::hours(age_hours);
+    task
+}
+
+fn main() {
+    println!("🔎 LazyTask Focus Filter Test");
+    println!("============================");
+    println!();
+
+    let _fake = FakeTask::with_script("focus", FAKE_TASK);
+
+    let tasks = vec![
+        task("Draft the proposal", Some("work"), &["writing", "urgent"], 1),
+        task("Call the client", Some("work.clientA"), &["urgent"], 2),
+        task("Fix the fence", Some("home"), &["writing"], 3),
+        task("Read a novel", None, &[], 4),
+    ];
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(tasks.clone()));
+
+        ui.handle_action(Action::FilterByProject, &taskwarrior).await.expect("project");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Draft the proposal") && text.contains("Call the client"), "{}", text);
+        assert!(!text.contains("Fix the fence") && !text.contains("Read a novel"), "{}", text);
+        assert!(text.contains("Showing project work (F again to clear)"), "{}", text);
+        println!("✅ F shows the task's project and its subprojects");
+
+        ui.handle_action(Action::FilterByProject, &taskwarrior).await.expect("clear project");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Fix the fence") && text.contains("Read a novel"), "{}", text);
+        assert!(text.contains("Project filter cleared"), "{}", text);
+        println!("✅ F again brings the other projects back");
+
+        ui.handle_action(Action::FilterByTag, &taskwarrior).await.expect("tag");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Draft the proposal") && text.contains("Fix the fence"), "{}", text);
+        assert!(!text.contains("Call the client") && !text.contains("Read a novel"), "{}", text);
+        assert!(text.contains("Showing tag +writing (T again to clear)"), "{}", text);
+        println!("✅ T shows the tasks sharing the first tag");
+
+        ui.handle_action(Action::FilterByTag, &taskwarrior).await.expect("clear tag");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Call the client") && text.contains("Tag filter cleared"), "{}", text);
+        println!("✅ T again clears the tag filter");
+
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![task("Read a novel", None, &[], 0), tasks[2].clone()]));
+        ui.handle_action(Action::FilterByProject, &taskwarrior).await.expect("no project");
+        ui.handle_action(Action::FilterByTag, &taskwarrior).await.expect("no tags");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("The selected task has no tags") && text.contains("Fix the fence"), "{}", text);
+        println!("✅ A task without a project or tags leaves the filters alone");
+    });
+
+    println!();
+    println!("🎉 Focus filter test passed!");
+}
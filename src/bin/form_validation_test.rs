@@ -0,0 +1,75 @@
+// Test program for the task form's checks on the Due and Project fields
+
+use chrono::{TimeZone, Utc};
+use lazytask::data::models::Task;
+use lazytask::handlers::input::Action;
+use lazytask::ui::components::task_form::{FormField, TaskForm, TaskFormResult};
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::render;
+
+fn screen(form: &TaskForm) -> String {
+    render(120, 40, |f| form.render(f, f.area()))
+}
+
+fn save(form: &mut TaskForm) -> Option<Task> {
+    form.is_editing = false;
+    match form.handle_input(Action::Select).expect("input") {
+        Some(TaskFormResult::Save(task)) => Some(task),
+        _ => None,
+    }
+}
+
+fn main() {
+    println!("🧾 LazyTask Form Validation Test");
+    println!("===============================");
+    println!();
+
+    let mut form = TaskForm::new_task();
+    form.description_input = "File taxes".to_string();
+    form.due_input = "2025-13-45".to_string();
+    assert!(save(&mut form).is_none());
+    assert!(matches!(form.active_field, FormField::Due) && form.is_editing);
+    let error = form.due_error.clone().expect("due error");
+    assert!(error.contains("Invalid due date \"2025-13-45\""), "{}", error);
+    assert!(screen(&form).contains(&error));
+    println!("✅ An unparseable due date stops the save and shows under the field: {}", error);
+
+    form.handle_input(Action::Backspace).expect("backspace");
+    assert!(form.due_error.is_none());
+    form.handle_input(Action::MoveDown).expect("leave");
+    assert!(form.due_error.is_some());
+    println!("✅ Editing clears the error and leaving the field checks it again");
+
+    form.due_input.clear();
+    let task = save(&mut form).expect("saves without a due date");
+    assert_eq!(task.due, None);
+    form.due_input = "2025-03-14T09:00:00+02:00".to_string();
+    assert_eq!(save(&mut form).expect("rfc 3339").due, Some(Utc.with_ymd_and_hms(2025, 3, 14, 7, 0, 0).unwrap()));
+    form.due_input = "tomorrow 9:30".to_string();
+    assert!(save(&mut form).expect("natural language").due.is_some());
+    assert!(form.due_error.is_none());
+    println!("✅ A cleared field, RFC 3339 timestamps and Taskwarrior dates all save");
+
+    form.project_input = "Home Office".to_string();
+    form.active_field = FormField::Project;
+    form.handle_input(Action::MoveDown).expect("leave");
+    let error = form.project_error.clone().expect("project error");
+    assert!(error.contains("cannot contain spaces"), "{}", error);
+    assert!(save(&mut form).is_none());
+    assert!(matches!(form.active_field, FormField::Project));
+    assert!(screen(&form).contains(&error));
+    form.project_input = "home.office".to_string();
+    assert_eq!(save(&mut form).expect("valid project").project.as_deref(), Some("home.office"));
+    println!("✅ Project names are checked the same way: {}", error);
+
+    let mut task = Task::new("Repot the fern".to_string());
+    task.project = Some("home/garden".to_string());
+    let mut form = TaskForm::edit_task(task);
+    assert_eq!(save(&mut form).expect("untouched project").project.as_deref(), Some("home/garden"));
+    println!("✅ A project the task already had isn't checked unless it's edited");
+
+    println!();
+    println!("🎉 Form validation test passed!");
+}
@@ -0,0 +1,87 @@
+// Test program for fuzzy search: matching, ranking by score and the Ctrl+f toggle
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::data::search::{SearchMode, TaskSearch};
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::ui::views::main_view::MainView;
+
+fn task(description: &str) -> Task {
+    Task::new(description.to_string())
+}
+
+fn descriptions(tasks: &[Task]) -> Vec<&str> {
+    tasks.iter().map(|t| t.description.as_str()).collect()
+}
+
+fn main() {
+    println!("🔎 LazyTask Fuzzy Search Test");
+    println!("============================");
+    println!();
+
+    let handler = InputHandler::new(&Config::default());
+    let ctrl_f = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL);
+    assert!(matches!(handler.handle_key_event_with_context(ctrl_f, true), Action::ToggleSearchMode));
+    println!("✅ Ctrl+f in the filter panel toggles the search mode");
+
+    let mut search = TaskSearch::default();
+    assert_eq!(search.mode, SearchMode::Exact);
+    let report = task("Write quarterly report");
+    assert!(search.matches(&report, "report"));
+    assert!(!search.matches(&report, "wrqr"));
+    println!("✅ Exact mode is the default and needs a substring");
+
+    search.mode = search.mode.toggled();
+    assert_eq!(search.mode, SearchMode::Fuzzy);
+    assert!(search.matches(&report, "wrqr"));
+    assert!(search.matches(&report, "qrtly rprt"));
+    assert!(!search.matches(&report, "zebra"));
+    assert!(search.matches(&report, "  "));
+    println!("✅ Fuzzy mode matches scattered letters (\"wrqr\" → \"Write quarterly report\")");
+
+    let mut tagged = task("Buy milk");
+    tagged.tags = vec!["errand".to_string()];
+    tagged.project = Some("home".to_string());
+    assert!(search.matches(&tagged, "+errnd"));
+    assert!(search.matches(&tagged, "hme"));
+    println!("✅ Project and tags are searched too, a leading + still means a tag");
+
+    let mut tasks = vec![
+        task("Plan the report party"),
+        task("Review pull requests"),
+        task("report"),
+        task("Refactor parser"),
+    ];
+    search.sort_by_score(&mut tasks, "report");
+    assert_eq!(descriptions(&tasks)[0], "report");
+    assert!(search.score(&tasks[0], "report") >= search.score(&tasks[1], "report"));
+    assert_eq!(search.score(&tasks[3], "report"), None);
+    println!("✅ Ranking puts the best match first: {:?}", descriptions(&tasks));
+
+    // Through the main view, as the task list uses it
+    let mut view = MainView::new(&Config::default());
+    view.toggle_filter_focus();
+    for _ in 0..4 {
+        view.next_filter_section();
+    }
+    for c in "rprt".chars() {
+        view.handle_filter_character(c);
+    }
+    let sample = task("Write quarterly report");
+    assert!(!view.matches_filters(&sample));
+    assert!(!view.ranks_by_match());
+    view.toggle_search_mode();
+    assert_eq!(view.search_mode(), SearchMode::Fuzzy);
+    assert!(view.matches_filters(&sample));
+    assert!(view.ranks_by_match());
+    println!("✅ The task list filter follows the mode and ranks while fuzzy search has text");
+
+    view.toggle_search_mode();
+    assert_eq!(view.search_mode(), SearchMode::Exact);
+    assert!(!view.ranks_by_match());
+    println!("✅ Toggling again goes back to exact substring search");
+
+    println!();
+    println!("🎉 Fuzzy search test passed!");
+}
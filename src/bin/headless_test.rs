@@ -0,0 +1,84 @@
+// Test program for the subcommands that run without the TUI (`lazytask export`, `lazytask stats`).
+
+use lazytask::data::export::{ExportFormat, TaskExporter};
+use lazytask::data::models::Task;
+use lazytask::headless;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::FakeTask;
+
+const FAKE_TASK: &str = r#"#!/bin/sh
+case "$*" in
+  *--version*) echo '3.1.0' ;;
+  *+OVERDUE*export*) echo '[{"uuid": "11111111-1111-1111-1111-111111111111", "description": "Renew insurance", "status": "pending", "entry": "20250101T090000Z", "due": "20250201T090000Z", "project": "home"}]' ;;
+  *export*) echo '[
+    {"uuid": "11111111-1111-1111-1111-111111111111", "description": "Renew insurance", "status": "pending", "entry": "20250101T090000Z", "due": "20250201T090000Z", "project": "home", "priority": "H"},
+    {"uuid": "22222222-2222-2222-2222-222222222222", "description": "Write report", "status": "pending", "entry": "20250101T090000Z", "project": "work"},
+    {"uuid": "33333333-3333-3333-3333-333333333333", "description": "Send invoice", "status": "completed", "entry": "20250101T090000Z", "end": "20250105T090000Z", "project": "work"},
+    {"status": "pending"}
+  ]' ;;
+esac
+"#;
+
+fn main() {
+    println!("📜 LazyTask Headless Commands Test");
+    println!("=================================");
+    println!();
+
+    let fake = FakeTask::with_script("headless", FAKE_TASK);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task").with_cache_max_age(0);
+
+        let csv = fake.path("overdue.csv");
+        let count = headless::export(&taskwarrior, Some("+OVERDUE"), ExportFormat::Csv, &csv).await.expect("export");
+        assert_eq!(count, 1);
+        let written = std::fs::read_to_string(&csv).expect("csv written");
+        assert_eq!(written.lines().count(), 2, "{}", written);
+        assert!(written.contains("Renew insurance") && !written.contains("Write report"), "{}", written);
+        println!("✅ export --format csv --filter +OVERDUE writes only the matching tasks");
+
+        let mut awkward = Task::new("Buy \"good\" milk, eggs\nand bread".to_string());
+        awkward.id = Some(4);
+        awkward.project = Some("home, garden".to_string());
+        awkward.tags = vec!["shop".to_string(), "weekly".to_string()];
+        let quoted = fake.path("quoted.csv");
+        TaskExporter::export_to_file(&[awkward.clone()], &quoted, ExportFormat::Csv).expect("export");
+        let written = std::fs::read_to_string(&quoted).expect("csv written");
+        let expected = format!(
+            "4,{},pending,\"Buy \"\"good\"\" milk, eggs\nand bread\",\"home, garden\",,,shop;weekly\n",
+            awkward.uuid
+        );
+        assert_eq!(written.split_once('\n').map(|(_, rows)| rows), Some(expected.as_str()), "{}", written);
+        println!("✅ CSV fields with commas, quotes or line breaks are quoted, quotes doubled");
+
+        let json = fake.path("all.json");
+        let count = headless::export(&taskwarrior, None, ExportFormat::Json, &json).await.expect("export");
+        assert_eq!(count, 3);
+        assert_eq!(taskwarrior.skipped_tasks(), 1);
+        let back = TaskExporter::import_from_file(&json, ExportFormat::Json).expect("read back");
+        assert_eq!(back.len(), 3);
+        println!("✅ Without a filter every exported task is written; unreadable ones are left out");
+
+        let missing = fake.path("no-such-dir").join("out.json");
+        let error = headless::export(&taskwarrior, None, ExportFormat::Json, &missing).await.unwrap_err();
+        assert!(error.to_string().contains("Failed to write"), "{}", error);
+        println!("✅ A file that can't be written is an error: {}", error);
+
+        let stats = headless::stats(&taskwarrior, None).await.expect("stats");
+        assert!(stats.contains("Tasks      3 (2 pending, 1 completed, 0 deleted, 0 waiting)"), "{}", stats);
+        assert!(stats.contains("Priority   H 1, M 0, L 0, none 2"), "{}", stats);
+        let work = stats.lines().position(|line| line.starts_with("work")).expect("work row");
+        let home = stats.lines().position(|line| line.starts_with("home")).expect("home row");
+        assert!(work < home, "{}", stats);
+        assert!(stats.lines().nth(work).unwrap_or_default().ends_with("50%"), "{}", stats);
+        println!("✅ stats prints the dashboard's counts, busiest project first:");
+        println!("{}", stats);
+    });
+
+    println!();
+    println!("🎉 Headless commands test passed!");
+}
@@ -0,0 +1,103 @@
+// Test program to verify the help view is generated from the real key bindings
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::handlers::input::{Action, InputHandler, KeyContext};
+use lazytask::ui::components::help_view::HelpView;
+use ratatui::{backend::TestBackend, Terminal};
+
+fn render_lines(view: &mut HelpView, width: u16, height: u16) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("test terminal");
+    terminal.draw(|f| view.render(f, f.area())).expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| (0..width).map(|x| buffer[(x, y)].symbol().to_string()).collect())
+        .collect()
+}
+
+fn plain_text(view: &HelpView) -> Vec<String> {
+    view.lines().iter().map(|line| line.to_string()).collect()
+}
+
+fn main() {
+    println!("❓ LazyTask Help View Test");
+    println!("==========================");
+    println!();
+
+    let mut config = Config::default();
+    let handler = InputHandler::new(&config);
+    let bindings = handler.bindings();
+
+    // Every binding resolves to its own action in its own view, so the help never lists a dead key
+    for binding in &bindings {
+        let key = KeyEvent::new(binding.code, binding.modifiers);
+        let in_form = matches!(binding.context, KeyContext::Filter | KeyContext::Form);
+        let action = handler.handle_key_event_in_view(key, in_form, binding.context);
+        assert_eq!(format!("{:?}", action), format!("{:?}", binding.action), "{}", binding.key_label());
+    }
+    println!("✅ All {} bindings dispatch to the action they document", bindings.len());
+
+    assert!(matches!(
+        handler.handle_key_event_with_context(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL), false),
+        Action::Quit
+    ));
+    assert!(matches!(
+        handler.handle_key_event_with_context(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE), false),
+        Action::Character('z')
+    ));
+    assert!(matches!(
+        handler.handle_key_event_with_context(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE), true),
+        Action::Character('a')
+    ));
+    println!("✅ Ctrl+c quits and unbound letters fall through as characters");
+
+    let help = HelpView::new(&bindings);
+    let text = plain_text(&help);
+    for context in KeyContext::ALL {
+        assert!(text.iter().any(|line| line == context.label()), "missing section {}", context.label());
+    }
+    for expected in ["/", "Ctrl+e", "Shift+Tab", "PgDn"] {
+        assert!(text.iter().any(|line| line.trim_start().starts_with(expected)), "missing key {}", expected);
+    }
+    assert!(text.iter().any(|line| line.contains("q, Ctrl+c") && line.contains("Quit")));
+    assert!(!text.iter().any(|line| line.contains("j") && line.contains("Next task")));
+    println!("✅ Help lists every context, including filter, form and reports keys");
+
+    config.ui.vim_keys = true;
+    let vim_help = HelpView::new(&InputHandler::new(&config).bindings());
+    assert!(plain_text(&vim_help).iter().any(|line| line.contains("↓, j") && line.contains("Next task")));
+    println!("✅ Enabling vim_keys shows j/k/g/G in the help");
+
+    // A short terminal shows a window onto the list with a scroll position
+    let mut view = HelpView::new(&bindings);
+    let total = view.lines().len();
+    let lines = render_lines(&mut view, 60, 12);
+    assert!(lines[0].contains(&format!("1–10 of {}", total)), "{}", lines[0]);
+    assert!(lines[1].contains("Global"));
+
+    view.scroll_down();
+    view.scroll_down();
+    let lines = render_lines(&mut view, 60, 12);
+    assert!(lines[0].contains(&format!("3–12 of {}", total)), "{}", lines[0]);
+
+    view.scroll_to_bottom();
+    view.scroll_down();
+    let lines = render_lines(&mut view, 60, 12);
+    assert!(lines[0].contains(&format!("{}–{} of {}", total - 9, total, total)), "{}", lines[0]);
+
+    view.page_up();
+    let lines = render_lines(&mut view, 60, 12);
+    assert!(lines[0].contains(&format!("{}–{} of {}", total - 19, total - 10, total)), "{}", lines[0]);
+
+    view.scroll_to_top();
+    let lines = render_lines(&mut view, 60, 12);
+    assert!(lines[0].contains(&format!("1–10 of {}", total)), "{}", lines[0]);
+    println!("✅ Help body scrolls and stays within bounds on small terminals");
+
+    let lines = render_lines(&mut view, 60, total as u16 + 2);
+    assert!(lines[0].contains(" Help ") && !lines[0].contains(" of "));
+    println!("✅ No scroll indicator when everything fits");
+
+    println!();
+    println!("🎉 Help view checks passed");
+}
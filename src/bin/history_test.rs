@@ -0,0 +1,103 @@
+// Test program for reading a task's change history from `task info`
+
+use chrono::{Local, NaiveDateTime};
+use lazytask::data::models::Task;
+use lazytask::taskwarrior::TaskwarriorCLI;
+use lazytask::ui::components::task_detail::TaskDetailWidget;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::render;
+
+const INFO: &str = "
+Name          Value
+------------- ------------------------------------
+ID            3
+Description   Renew passport
+Status        Pending
+Project       admin
+Entered       2024-03-01 09:15:00 (4w)
+UUID          1f6c1a8e-0000-4000-8000-000000000003
+Urgency       2.9
+
+    project      1 *    1 =      1
+    age      0.082 *    2 =  0.164
+
+Date                Modification
+------------------- ------------------------------------
+2024-03-01 09:15:00 Description set to 'Renew passport'.
+                    Entry set to '2024-03-01 09:15:00'.
+                    Status set to 'pending'.
+2024-03-04 18:02:11 Project set to 'admin'.
+2024-03-10 07:30:00 Annotation of 'Book the photo booth
+                    first' added.
+                    Priority set to 'H'.
+";
+
+fn screen(widget: &TaskDetailWidget, task: &Task) -> String {
+    render(100, 40, |f| widget.render(f, f.area(), Some(task)))
+}
+
+fn main() {
+    println!("📜 LazyTask History Test");
+    println!("=======================");
+    println!();
+
+    let entries = TaskwarriorCLI::parse_info_history(INFO).expect("history parses");
+    let changes: Vec<&str> = entries.iter().map(|entry| entry.change.as_str()).collect();
+    assert_eq!(
+        changes,
+        [
+            "Description set to 'Renew passport'.",
+            "Entry set to '2024-03-01 09:15:00'.",
+            "Status set to 'pending'.",
+            "Project set to 'admin'.",
+            "Annotation of 'Book the photo booth first' added.",
+            "Priority set to 'H'.",
+        ]
+    );
+    println!("✅ Every modification is read, with wrapped lines joined back up");
+
+    // `task info` prints local time
+    let local = |s: &str| {
+        let naive = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").expect("date");
+        naive.and_local_timezone(Local).unwrap()
+    };
+    assert_eq!(entries[0].date, local("2024-03-01 09:15:00"));
+    assert_eq!(entries[2].date, entries[0].date);
+    assert_eq!(entries[3].date, local("2024-03-04 18:02:11"));
+    assert_eq!(entries[5].date, local("2024-03-10 07:30:00"));
+    println!("✅ Lines without a date belong to the date above them");
+
+    let no_history = INFO.split("Date                Modification").next().expect("info");
+    assert!(TaskwarriorCLI::parse_info_history(no_history).is_err());
+    assert!(TaskwarriorCLI::parse_info_history("").is_err());
+    let bad_date = INFO.replace("2024-03-04 18:02:11", "04/03/2024 18:02 ");
+    assert!(TaskwarriorCLI::parse_info_history(&bad_date).is_err());
+    println!("✅ Output without a readable history is an error");
+
+    let mut task = Task::new("Renew passport".to_string());
+    task.project = Some("admin".to_string());
+    let mut widget = TaskDetailWidget::new();
+    let text = screen(&widget, &task);
+    assert!(text.contains("History (from current fields)"), "{}", text);
+    assert!(text.contains("Project set to 'admin'"), "{}", text);
+    println!("✅ Without `task info` the panel falls back to an outline and says so");
+
+    widget.set_history(&task.uuid, entries.clone());
+    let text = screen(&widget, &task);
+    assert!(!text.contains("from current fields"), "{}", text);
+    let priority = text.find("Priority set to 'H'").expect("latest change shown");
+    let project = text.find("Project set to 'admin'").expect("project change shown");
+    let description = text.find("Description set to 'Renew passport'").expect("first change shown");
+    assert!(priority < project && project < description, "{}", text);
+    println!("✅ The recorded history is shown, latest change first");
+
+    let other = Task::new("Something else".to_string());
+    let text = screen(&widget, &other);
+    assert!(text.contains("History (from current fields)"), "{}", text);
+    println!("✅ History for one task isn't shown for another");
+
+    println!();
+    println!("🎉 History test passed!");
+}
@@ -0,0 +1,74 @@
+// Test program for the Markdown checklist export
+
+use chrono::{TimeZone, Utc};
+use lazytask::data::export::{ExportFormat, TaskExporter};
+use lazytask::data::models::{Priority, Task, TaskStatus};
+
+fn task(description: &str, project: Option<&str>) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.project = project.map(|p| p.to_string());
+    task
+}
+
+fn main() {
+    println!("📝 LazyTask Markdown Export Test");
+    println!("===============================");
+    println!();
+
+    let mut shelf = task("Assemble the shelf", Some("home"));
+    shelf.priority = Some(Priority::High);
+    shelf.due = Some(Utc.with_ymd_and_hms(2025, 10, 20, 17, 0, 0).unwrap());
+    shelf.tags = vec!["diy".to_string(), "weekend".to_string()];
+
+    let mut report = task("Send the\nquarterly report", Some("work"));
+    report.status = TaskStatus::Completed;
+
+    let mut paint = task("Paint the fence", Some("home"));
+    paint.priority = Some(Priority::Low);
+
+    let mut old = task("Old idea", None);
+    old.status = TaskStatus::Deleted;
+
+    let groceries = task("Buy groceries", None);
+
+    // Sections come out in project order whatever order the tasks are in
+    let tasks = vec![groceries, report, shelf, old, paint];
+    let expected = "\
+# Tasks
+
+## home
+
+- [ ] Assemble the shelf (priority: H, due: 2025-10-20) #diy #weekend
+- [ ] Paint the fence (priority: L)
+
+## work
+
+- [x] Send the quarterly report
+
+## (no project)
+
+- [ ] Buy groceries
+- [x] ~~Old idea~~
+";
+    let markdown = TaskExporter::render_markdown(&tasks);
+    assert_eq!(markdown, expected, "\n{}", markdown);
+    println!("✅ Tasks render as a checklist grouped by project, no project last");
+    println!("✅ Priority and due date are inline, tags are #tags");
+
+    let mut reversed = tasks.clone();
+    reversed.reverse();
+    let reversed_markdown = TaskExporter::render_markdown(&reversed);
+    assert!(reversed_markdown.find("## home") < reversed_markdown.find("## work"));
+    assert!(reversed_markdown.trim_end().ends_with("- [ ] Buy groceries"));
+    println!("✅ Project order doesn't depend on task order");
+
+    let path = std::env::temp_dir().join(format!("lazytask-markdown-export-{}.md", std::process::id()));
+    TaskExporter::export_to_file(&tasks, &path, ExportFormat::Markdown).expect("export");
+    assert_eq!(std::fs::read_to_string(&path).expect("read export"), expected);
+    assert!(TaskExporter::import_from_file(&path, ExportFormat::Markdown).is_err());
+    std::fs::remove_file(&path).ok();
+    println!("✅ export_to_file writes the same Markdown; importing it is refused");
+
+    println!();
+    println!("🎉 Markdown export checks passed");
+}
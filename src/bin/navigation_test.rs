@@ -0,0 +1,165 @@
+// Test program to verify task list navigation on empty and tiny lists
+
+use chrono::{Duration, Utc};
+use lazytask::data::models::Task;
+use lazytask::data::sort::DueGroup;
+use lazytask::ui::components::task_list::TaskListWidget;
+use ratatui::{backend::TestBackend, Terminal};
+
+// Render the widget into a test terminal and return the top border (title) row
+fn render_title(widget: &mut TaskListWidget, width: u16, height: u16) -> (String, String) {
+    let lines = render_lines(widget, width, height);
+    let right_edge: String = lines.iter().map(|line| line.chars().last().unwrap_or(' ')).collect();
+    (lines[0].clone(), right_edge)
+}
+
+fn render_lines(widget: &mut TaskListWidget, width: u16, height: u16) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("test terminal");
+    terminal.draw(|f| widget.render(f, f.area())).expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| (0..width).map(|x| buffer[(x, y)].symbol().to_string()).collect())
+        .collect()
+}
+
+fn main() {
+    println!("🧭 LazyTask Navigation Test");
+    println!("===========================");
+    println!();
+
+    // Regression: next/previous used to underflow `len() - 1` on an empty list
+    let mut widget = TaskListWidget::new(&[]);
+    widget.next();
+    widget.previous();
+    widget.select_first();
+    widget.select_last();
+    widget.page_up();
+    widget.page_down();
+    assert_eq!(widget.state.selected(), None);
+    assert!(widget.selected_task().is_none());
+    println!("✅ Empty list: navigation is a no-op");
+
+    // A filter that excludes everything clears the selection
+    widget.set_tasks(vec![Task::new("one".to_string()), Task::new("two".to_string())]);
+    assert_eq!(widget.state.selected(), Some(0));
+    widget.set_tasks(Vec::new());
+    assert_eq!(widget.state.selected(), None);
+    widget.set_tasks_with_preserved_selection(Vec::new(), Some("missing"));
+    assert_eq!(widget.state.selected(), None);
+    widget.next();
+    assert_eq!(widget.state.selected(), None);
+    println!("✅ Emptied list: selection cleared");
+
+    // Wrapping still works once tasks are back
+    widget.set_tasks(vec![Task::new("one".to_string()), Task::new("two".to_string())]);
+    widget.previous();
+    assert_eq!(widget.state.selected(), Some(1));
+    widget.next();
+    assert_eq!(widget.state.selected(), Some(0));
+    println!("✅ Two tasks: next/previous wrap around");
+
+    // Preserved selection: UUID present, UUID absent, empty list
+    let tasks: Vec<Task> = ["a", "b", "c", "d"].iter().map(|d| Task::new(d.to_string())).collect();
+    let uuids: Vec<String> = tasks.iter().map(|t| t.uuid.clone()).collect();
+    widget.set_tasks(tasks.clone());
+
+    let mut reordered = tasks.clone();
+    reordered.reverse();
+    widget.set_tasks_with_preserved_selection(reordered, Some(&uuids[1]));
+    assert_eq!(widget.selected_task_uuid().as_deref(), Some(uuids[1].as_str()));
+    assert_eq!(widget.state.selected(), Some(2));
+    println!("✅ UUID present: selection follows the task to its new index");
+
+    // Completing the selected task keeps the cursor at the same row
+    let remaining: Vec<Task> = tasks.iter().filter(|t| t.uuid != uuids[2]).cloned().collect();
+    widget.set_tasks(tasks.clone());
+    widget.state.select(Some(2));
+    widget.set_tasks_with_preserved_selection(remaining, Some(&uuids[2]));
+    assert_eq!(widget.state.selected(), Some(2));
+    assert_eq!(widget.selected_task_uuid().as_deref(), Some(uuids[3].as_str()));
+
+    // Removing the last row clamps to the new end of the list
+    widget.set_tasks_with_preserved_selection(tasks[..2].to_vec(), Some(&uuids[3]));
+    assert_eq!(widget.state.selected(), Some(1));
+    println!("✅ UUID absent: previous index kept and clamped to the list");
+
+    // Task completed elsewhere while the list is also re-sorted: land on its old neighbour
+    widget.set_tasks(tasks.clone());
+    widget.state.select(Some(2));
+    let resorted: Vec<Task> = [3, 1, 0].iter().map(|&i| tasks[i].clone()).collect();
+    widget.set_tasks_with_preserved_selection(resorted, Some(&uuids[2]));
+    assert_eq!(widget.selected_task_uuid().as_deref(), Some(uuids[3].as_str()));
+    assert_eq!(widget.state.selected(), Some(0));
+
+    // With nothing after it left, the row before it is used
+    widget.set_tasks(tasks.clone());
+    let without_tail: Vec<Task> = [1, 0].iter().map(|&i| tasks[i].clone()).collect();
+    widget.set_tasks_with_preserved_selection(without_tail, Some(&uuids[3]));
+    assert_eq!(widget.selected_task_uuid().as_deref(), Some(uuids[1].as_str()));
+    println!("✅ UUID absent after re-sort: nearest surviving neighbour selected");
+
+    widget.set_tasks_with_preserved_selection(Vec::new(), Some(&uuids[0]));
+    assert_eq!(widget.state.selected(), None);
+    widget.set_tasks_with_preserved_selection(tasks.clone(), None);
+    assert_eq!(widget.state.selected(), Some(0));
+    println!("✅ Empty list: selection cleared, first task selected once refilled");
+
+    // Scroll position: visible range in the title and a scrollbar on the right edge
+    let many: Vec<Task> = (0..50).map(|i| Task::new(format!("task {}", i))).collect();
+    widget.set_tasks(many);
+    // 15 rows high: borders and header leave 12 task rows
+    let (title, _) = render_title(&mut widget, 80, 15);
+    assert!(title.contains("Tasks (1–12 of 50)"), "title: {}", title);
+    for _ in 0..29 {
+        widget.next();
+    }
+    let (title, right_edge) = render_title(&mut widget, 80, 15);
+    assert!(title.contains("Tasks (19–30 of 50)"), "title: {}", title);
+    assert!(right_edge.contains('█'), "scrollbar thumb missing: {}", right_edge);
+    println!("✅ Long list: title shows the visible range and a scrollbar is drawn");
+
+    widget.set_tasks(tasks[..3].to_vec());
+    let (title, right_edge) = render_title(&mut widget, 80, 15);
+    assert!(title.contains("Tasks (3)"), "title: {}", title);
+    assert!(!right_edge.contains('█'));
+    println!("✅ Short list: plain count, no scrollbar");
+
+    // Grouped mode: section headers between due-date groups, never selectable
+    let now = Utc::now();
+    let due = |description: &str, offset: Option<Duration>| {
+        let mut task = Task::new(description.to_string());
+        task.due = offset.map(|offset| now + offset);
+        task
+    };
+    let mut grouped = vec![
+        due("someday", None),
+        due("late", Some(Duration::days(-2))),
+        due("far", Some(Duration::days(40))),
+        due("also late", Some(Duration::days(-1))),
+    ];
+    DueGroup::group(&mut grouped);
+    let order: Vec<&str> = grouped.iter().map(|t| t.description.as_str()).collect();
+    assert_eq!(order, vec!["late", "also late", "far", "someday"]);
+
+    let mut widget = TaskListWidget::new(&["id".to_string(), "description".to_string()]);
+    widget.set_grouped(true);
+    widget.set_tasks(grouped);
+    let lines = render_lines(&mut widget, 60, 15);
+    let text = lines.join("\n");
+    assert!(text.contains("▸ Overdue (2)"), "{}", text);
+    assert!(text.contains("▸ Due Later (1)"), "{}", text);
+    assert!(text.contains("▸ No Due Date (1)"), "{}", text);
+    assert!(lines[0].contains("Tasks (4)"), "title: {}", lines[0]);
+
+    let mut seen = Vec::new();
+    for _ in 0..4 {
+        seen.push(widget.selected_task().map(|t| t.description.clone()).expect("task selected"));
+        widget.next();
+    }
+    assert_eq!(seen, vec!["late", "also late", "far", "someday"]);
+    assert_eq!(widget.state.selected(), Some(0));
+    println!("✅ Grouped list: headers rendered, navigation only lands on tasks");
+
+    println!();
+    println!("🎉 All navigation checks passed");
+}
@@ -0,0 +1,58 @@
+// Test program for the "next actionable" view filter and ordering
+
+use chrono::{Duration, Utc};
+use lazytask::config::Config;
+use lazytask::data::models::{Task, TaskStatus};
+use lazytask::data::sort::SortKey;
+use lazytask::ui::views::main_view::MainView;
+
+fn task(description: &str, urgency: f64) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.urgency = urgency;
+    task
+}
+
+fn main() {
+    println!("⏭️  LazyTask Next View Test");
+    println!("==========================");
+    println!();
+
+    let ready_low = task("ready low", 1.5);
+    let ready_high = task("ready high", 9.0);
+
+    let mut waiting = task("waiting", 20.0);
+    waiting.status = TaskStatus::Waiting;
+
+    let mut wait_date = task("pending with future wait", 15.0);
+    wait_date.wait = Some(Utc::now() + Duration::days(3));
+
+    let mut wait_passed = task("wait date passed", 4.0);
+    wait_passed.wait = Some(Utc::now() - Duration::days(1));
+
+    let mut blocked = task("blocked", 12.0);
+    blocked.depends = vec![ready_high.uuid.clone()];
+
+    let mut completed = task("completed", 30.0);
+    completed.status = TaskStatus::Completed;
+
+    let mut dependency_done = task("dependency done", 2.0);
+    dependency_done.depends = vec![completed.uuid.clone()];
+
+    let all = [ready_low, waiting, wait_date, wait_passed, blocked, completed, dependency_done, ready_high];
+
+    let mut view = MainView::new(&Config::default());
+    view.update_available_filters(&all);
+    let mut next: Vec<Task> = all.iter().filter(|t| view.is_next_actionable(t)).cloned().collect();
+    SortKey::Urgency.sort(&mut next);
+
+    let descriptions: Vec<&str> = next.iter().map(|t| t.description.as_str()).collect();
+    println!("Next actionable: {:?}", descriptions);
+
+    assert_eq!(descriptions, vec!["ready high", "wait date passed", "dependency done", "ready low"]);
+    println!("✅ Waiting, blocked and completed tasks are excluded");
+    println!("✅ A task whose dependencies are all done counts as actionable");
+    println!("✅ Tasks are ordered by urgency, highest first");
+
+    println!();
+    println!("🎉 Next view checks passed");
+}
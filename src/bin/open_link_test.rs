@@ -0,0 +1,137 @@
+// Test program for opening URLs from the selected task with `O`.
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::{Annotation, Task};
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+use lazytask::utils::links::{find_urls, open_url, task_urls};
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 30;
+
+fn annotated(description: &str, notes: &[&str]) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.id = Some(1);
+    task.annotations = notes
+        .iter()
+        .map(|note| Annotation {
+            entry: Utc::now(),
+            description: note.to_string(),
+        })
+        .collect();
+    task
+}
+
+// The stand-in xdg-open appends each URL to `opened`; it runs in the background
+fn wait_for_opened(opened: &Path, lines: usize) -> Vec<String> {
+    let started = Instant::now();
+    loop {
+        let text = std::fs::read_to_string(opened).unwrap_or_default();
+        let urls: Vec<String> = text.lines().map(str::to_string).collect();
+        if urls.len() >= lines || started.elapsed() > Duration::from_secs(5) {
+            return urls;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+fn main() {
+    println!("🔗 LazyTask Open Link Test");
+    println!("=========================");
+    println!();
+
+    let handler = InputHandler::new(&Config::default());
+    let key = handler.handle_key_event_with_context(KeyEvent::new(KeyCode::Char('O'), KeyModifiers::SHIFT), false);
+    assert!(matches!(key, Action::OpenLink));
+    println!("✅ O opens a link");
+
+    assert_eq!(
+        find_urls("Read https://example.com/a?b=1&c=2, then (see http://docs.rs/ratatui). Done."),
+        ["https://example.com/a?b=1&c=2", "http://docs.rs/ratatui"]
+    );
+    assert_eq!(find_urls("<https://en.wikipedia.org/wiki/Rust_(programming_language)>"), ["https://en.wikipedia.org/wiki/Rust_(programming_language)"]);
+    assert!(find_urls("no links here, not even https:// alone").is_empty());
+    println!("✅ URLs are found in text without the punctuation around them");
+
+    let task = annotated("Review https://example.com/pr/1", &["also https://example.com/pr/1", "CI: https://ci.example.com/42"]);
+    assert_eq!(task_urls(&task), ["https://example.com/pr/1", "https://ci.example.com/42"]);
+    println!("✅ The description comes first, then annotations, each URL once");
+
+    std::env::remove_var("DISPLAY");
+    std::env::remove_var("WAYLAND_DISPLAY");
+    std::env::set_var("SSH_CONNECTION", "10.0.0.2 50000 10.0.0.1 22");
+    let error = open_url("https://example.com").unwrap_err();
+    assert!(error.to_string().contains("running over SSH"), "{}", error);
+    println!("✅ Without a display, opening fails with a reason: {}", error);
+
+    let fake = FakeTask::new("links");
+    let opened = fake.path("opened");
+    let fake_open = format!("#!/bin/sh\necho \"$1\" >> '{}'\n", opened.display());
+    fake.set_script(FAKE_TASK);
+    fake.install("xdg-open", &fake_open);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![annotated("Water plants", &[])]));
+
+        ui.handle_action(Action::OpenLink, &taskwarrior).await.expect("open");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("No URL in the task's description or annotations"), "{}", text);
+        println!("✅ A task without URLs says so");
+
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![annotated("Pay https://bank.example.com/bill", &[])]));
+        ui.handle_action(Action::OpenLink, &taskwarrior).await.expect("open");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("No display to open a browser on (running over SSH); URL: https://bank.example.com/bill"), "{}", text);
+        println!("✅ Over SSH the footer shows the URL instead");
+
+        std::env::set_var("DISPLAY", ":0");
+        ui.handle_action(Action::OpenLink, &taskwarrior).await.expect("open");
+        assert!(!ui.has_active_form());
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("Opened https://bank.example.com/bill"));
+        assert_eq!(wait_for_opened(&opened, 1), ["https://bank.example.com/bill"]);
+        println!("✅ A single URL opens straight away");
+
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![task.clone()]));
+        ui.handle_action(Action::OpenLink, &taskwarrior).await.expect("open");
+        assert!(ui.has_active_form());
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Open Link") && text.contains("2 https://ci.example.com/42"), "{}", text);
+        ui.handle_action(Action::MoveDown, &taskwarrior).await.expect("down");
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("enter");
+        assert!(!ui.has_active_form());
+        assert_eq!(wait_for_opened(&opened, 2)[1], "https://ci.example.com/42");
+
+        ui.handle_action(Action::OpenLink, &taskwarrior).await.expect("open");
+        ui.handle_action(Action::Character('1'), &taskwarrior).await.expect("1");
+        assert_eq!(wait_for_opened(&opened, 3)[2], "https://example.com/pr/1");
+        ui.handle_action(Action::OpenLink, &taskwarrior).await.expect("open");
+        ui.handle_action(Action::Back, &taskwarrior).await.expect("esc");
+        assert!(!ui.has_active_form());
+        println!("✅ Several URLs show a picker: Enter or a number opens one, Esc closes it");
+    });
+
+    println!();
+    println!("🎉 Open link test passed!");
+}
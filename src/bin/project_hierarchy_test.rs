@@ -0,0 +1,81 @@
+// Test program for hierarchical projects (work.clientA) in the project filter
+
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::ui::views::main_view::MainView;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::render;
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 50;
+
+fn task(description: &str, project: &str) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.project = Some(project.to_string());
+    task
+}
+
+fn shown<'a>(view: &MainView, tasks: &'a [Task]) -> Vec<&'a str> {
+    tasks.iter().filter(|t| view.matches_filters(t)).map(|t| t.description.as_str()).collect()
+}
+
+fn screen(view: &mut MainView) -> String {
+    render(WIDTH, HEIGHT, |f| view.render(f, f.area(), WIDTH))
+}
+
+fn main() {
+    println!("🌳 LazyTask Project Hierarchy Test");
+    println!("=================================");
+    println!();
+
+    let tasks = vec![
+        task("standup", "work"),
+        task("invoice", "work.clientA"),
+        task("deploy site", "work.clientA.web"),
+        task("commute", "work-home"),
+        task("weed beds", "home.garden"),
+    ];
+
+    let mut view = MainView::new(&Config::default());
+    view.update_available_filters(&tasks);
+    view.toggle_filter_focus();
+    view.next_filter_section();
+
+    let mut projects = Vec::new();
+    loop {
+        let project = view.highlighted_project().expect("project section").to_string();
+        if projects.last() == Some(&project) {
+            break;
+        }
+        projects.push(project);
+        view.handle_filter_navigation_down();
+    }
+    assert_eq!(
+        projects,
+        vec!["home", "home.garden", "work", "work.clientA", "work.clientA.web", "work-home"]
+    );
+    println!("✅ Parents are listed even without tasks of their own, each followed by its subprojects");
+
+    let panel = screen(&mut view);
+    assert!(panel.contains("[ ] work ") && panel.contains("[ ]   clientA") && panel.contains("[ ]     web"), "{}", panel);
+    println!("✅ Subprojects are indented under their parent");
+
+    view.show_only_project("work");
+    assert_eq!(shown(&view, &tasks), vec!["standup", "invoice", "deploy site"]);
+    view.show_only_project("home");
+    assert_eq!(shown(&view, &tasks), vec!["weed beds"]);
+    println!("✅ Selecting a parent includes all its subprojects, but not look-alikes such as work-home");
+
+    view.show_only_project("work");
+    view.handle_filter_character('e');
+    assert_eq!(shown(&view, &tasks), vec!["standup"]);
+    assert!(screen(&mut view).contains("Project (exact)"));
+    view.handle_filter_character('e');
+    assert_eq!(shown(&view, &tasks).len(), 3);
+    println!("✅ e in the Project section switches to exact matching and back");
+
+    println!();
+    println!("🎉 Project hierarchy checks passed");
+}
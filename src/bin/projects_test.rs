@@ -0,0 +1,90 @@
+// Test program for the Projects view and the shared per-project counts
+
+use lazytask::config::Config;
+use lazytask::data::models::{Project, Task, TaskStatus, NO_PROJECT};
+use lazytask::ui::views::main_view::MainView;
+use lazytask::ui::views::projects_view::ProjectsView;
+use ratatui::{backend::TestBackend, Terminal};
+
+fn task(description: &str, project: Option<&str>, status: TaskStatus) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.project = project.map(str::to_string);
+    task.status = status;
+    task
+}
+
+fn main() {
+    println!("📁 LazyTask Projects View Test");
+    println!("=============================");
+    println!();
+
+    let tasks = vec![
+        task("write report", Some("work"), TaskStatus::Pending),
+        task("send invoice", Some("work"), TaskStatus::Completed),
+        task("plan sprint", Some("work"), TaskStatus::Completed),
+        task("old idea", Some("work"), TaskStatus::Deleted),
+        task("wait for reply", Some("work"), TaskStatus::Waiting),
+        task("water plants", Some("home"), TaskStatus::Pending),
+        task("loose end", None, TaskStatus::Pending),
+    ];
+
+    let projects = Project::from_tasks(&tasks);
+    let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec![NO_PROJECT, "home", "work"]);
+    let work = &projects[2];
+    assert_eq!((work.pending_count, work.completed_count, work.deleted_count, work.task_count), (2, 2, 1, 5));
+    assert_eq!(work.completion_rate(), 50.0);
+    assert_eq!(projects[1].completion_rate(), 0.0);
+    println!("✅ Counts per project: waiting counts as pending, deleted stays out of the rate");
+
+    assert_eq!(ProjectsView::completion_bar(50.0, 10), "█████░░░░░  50%");
+    assert_eq!(ProjectsView::completion_bar(100.0, 4), "████ 100%");
+    assert_eq!(ProjectsView::completion_bar(0.0, 4), "░░░░   0%");
+    println!("✅ Completion bar: {}", ProjectsView::completion_bar(work.completion_rate(), 10));
+
+    let mut view = ProjectsView::default();
+    view.update_tasks(&tasks);
+    let listed: Vec<&str> = view.projects().iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(listed, vec!["home", "work"]);
+    assert_eq!(view.selected_project().map(|p| p.name.as_str()), Some("home"));
+    view.next();
+    view.next();
+    assert_eq!(view.selected_project().map(|p| p.name.as_str()), Some("work"));
+    view.select_first();
+    assert_eq!(view.selected_project().map(|p| p.name.as_str()), Some("home"));
+    println!("✅ Named projects are listed and navigable");
+
+    // A refresh that adds a project ahead of the selected one keeps the selection
+    view.select_last();
+    let mut more = tasks.clone();
+    more.push(task("fix bike", Some("errands"), TaskStatus::Pending));
+    view.update_tasks(&more);
+    assert_eq!(view.selected_project().map(|p| p.name.as_str()), Some("work"));
+    view.update_tasks(&[]);
+    assert!(view.selected_project().is_none());
+    view.update_tasks(&more);
+    println!("✅ Selection follows the project across refreshes");
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 8)).expect("test terminal");
+    terminal.draw(|f| view.render(f, f.area())).expect("draw");
+    let buffer = terminal.backend().buffer();
+    let row = |y: u16| (0..80).map(|x| buffer[(x, y)].symbol().to_string()).collect::<String>();
+    assert!(row(0).contains("Projects (3)"), "{}", row(0));
+    assert!(row(1).contains("Pending") && row(1).contains("Progress"), "{}", row(1));
+    let work_row = (2..8).map(row).find(|r| r.contains("work")).expect("work row");
+    assert!(work_row.contains("50%"), "{}", work_row);
+    println!("✅ Renders counts and progress: {}", work_row.trim_end());
+
+    // Picking a project narrows the task list to it
+    let mut main_view = MainView::new(&Config::default());
+    main_view.show_only_project("work");
+    let shown: Vec<&str> = tasks.iter()
+        .filter(|t| main_view.matches_filters(t))
+        .map(|t| t.description.as_str())
+        .collect();
+    assert_eq!(shown, vec!["write report"]);
+    println!("✅ Selecting a project filters the task list to it");
+
+    println!();
+    println!("🎉 Projects view checks passed");
+}
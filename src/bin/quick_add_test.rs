@@ -0,0 +1,42 @@
+// Test program for parsing quick-add lines in Taskwarrior syntax
+
+use lazytask::data::quick_add::QuickAdd;
+
+fn attrs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+fn main() {
+    println!("⚡ LazyTask Quick Add Test");
+    println!("=========================");
+    println!();
+
+    let parsed = QuickAdd::parse("Buy milk project:home +errand due:tomorrow priority:H").unwrap();
+    assert_eq!(parsed.description, "Buy milk");
+    assert_eq!(parsed.attributes, attrs(&[("project", "home"), ("+errand", ""), ("due", "tomorrow"), ("priority", "H")]));
+    println!("✅ project, +tag, due and priority become attributes");
+
+    let parsed = QuickAdd::parse("+work Call +phone Alice +urgent").unwrap();
+    assert_eq!(parsed.description, "Call Alice");
+    assert_eq!(parsed.attributes, attrs(&[("+work", ""), ("+phone", ""), ("+urgent", "")]));
+    println!("✅ Multiple tags anywhere in the line");
+
+    let parsed = QuickAdd::parse("\"Read project:alpha notes\" project:work 'due: soon'").unwrap();
+    assert_eq!(parsed.description, "Read project:alpha notes due: soon");
+    assert_eq!(parsed.attributes, attrs(&[("project", "work")]));
+    println!("✅ Quoted text stays in the description verbatim");
+
+    let parsed = QuickAdd::parse("Check https://example.com ratio 1:2 priority:m wait:monday").unwrap();
+    assert_eq!(parsed.description, "Check https://example.com ratio 1:2");
+    assert_eq!(parsed.attributes, attrs(&[("priority", "M"), ("wait", "monday")]));
+    println!("✅ URLs and other colons are not mistaken for attributes");
+
+    assert!(QuickAdd::parse("project:home +errand").is_err());
+    assert!(QuickAdd::parse("   ").is_err());
+    assert!(QuickAdd::parse("Fix bug priority:X").is_err());
+    assert!(QuickAdd::parse("\"unterminated quote").is_err());
+    println!("✅ Empty descriptions, bad priorities and open quotes are rejected");
+
+    println!();
+    println!("🎉 Quick add checks passed");
+}
@@ -0,0 +1,66 @@
+// Test program for asking before quitting with unsaved task form changes
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::ui::components::modal_dialog::{ConfirmDialog, ConfirmResult};
+use lazytask::ui::components::task_form::TaskForm;
+
+fn type_text(form: &mut TaskForm, text: &str) {
+    for c in text.chars() {
+        form.handle_input(Action::Character(c)).unwrap();
+    }
+}
+
+fn main() {
+    println!("🚪 LazyTask Quit Confirmation Test");
+    println!("=================================");
+    println!();
+
+    let handler = InputHandler::new(&Config::default());
+    let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+    let q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+    assert!(matches!(handler.handle_key_event_with_context(ctrl_c, false), Action::Quit));
+    assert!(matches!(handler.handle_key_event_with_context(ctrl_c, true), Action::Quit));
+    assert!(matches!(handler.handle_key_event_with_context(q, true), Action::Character('q')));
+    println!("✅ Ctrl+C is a quit attempt in forms too; q is just typed");
+
+    let mut form = TaskForm::new_task();
+    assert!(!form.is_dirty());
+    type_text(&mut form, "Buy milk");
+    assert!(form.is_dirty());
+    for _ in 0.."Buy milk".len() {
+        form.handle_input(Action::Backspace).unwrap();
+    }
+    assert!(!form.is_dirty());
+    println!("✅ New task form is dirty only while it differs from empty");
+
+    let mut task = Task::new("Water plants".to_string());
+    task.project = Some("home".to_string());
+    task.tags = vec!["garden".to_string()];
+    let tasks = vec![task.clone()];
+    let mut form = TaskForm::edit_task(task).with_dependency_ids(&tasks);
+    assert!(!form.is_dirty());
+    // Moving between fields isn't an edit
+    form.handle_input(Action::Tab).unwrap();
+    form.handle_input(Action::Tab).unwrap();
+    assert!(!form.is_dirty());
+    form.priority_index = 1;
+    assert!(form.is_dirty());
+    form.priority_index = 0;
+    form.tags_input.push_str(", outdoor");
+    assert!(form.is_dirty());
+    println!("✅ Edit form compares every field against the original task");
+
+    let mut dialog = ConfirmDialog::new("Unsaved Changes", "Quit?", "quit", "keep editing");
+    assert_eq!(dialog.handle_input(Action::Character('y')).unwrap(), Some(ConfirmResult::Confirm));
+    assert_eq!(dialog.handle_input(Action::Character('n')).unwrap(), Some(ConfirmResult::Cancel));
+    assert_eq!(dialog.handle_input(Action::Back).unwrap(), Some(ConfirmResult::Cancel));
+    assert_eq!(dialog.handle_input(Action::Select).unwrap(), Some(ConfirmResult::Cancel));
+    assert_eq!(dialog.handle_input(Action::Character('x')).unwrap(), None);
+    println!("✅ Only y confirms; n, Esc and Enter keep editing");
+
+    println!();
+    println!("🎉 Quit confirmation checks passed");
+}
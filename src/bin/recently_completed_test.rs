@@ -0,0 +1,105 @@
+// Test program for the recently completed view (`H`) and reopening a task with `o`.
+
+use chrono::{Duration, Utc};
+use lazytask::config::Config;
+use lazytask::data::models::{Task, TaskStatus};
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 30;
+
+// Completed `hours_ago`, without an ID like Taskwarrior's completed tasks
+fn completed(description: &str, hours_ago: i64) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.status = TaskStatus::Completed;
+    task.end = Some(Utc::now() - Duration::hours(hours_ago));
+    task
+}
+
+fn tasks() -> Vec<Task> {
+    let mut pending = Task::new("Plan the garden".to_string());
+    pending.id = Some(1);
+    vec![
+        pending,
+        completed("Pay the water bill", 30),
+        completed("Return library books", 3),
+        completed("Renew the passport", 24 * 20),
+    ]
+}
+
+fn main() {
+    println!("✔️  LazyTask Recently Completed Test");
+    println!("==================================");
+    println!();
+
+    let fake = FakeTask::new("recent");
+    let log = fake.path("commands");
+    let fake_task = format!(
+        "#!/bin/sh\ncase \"$*\" in\n  *--version*) echo '3.1.0' ;;\n  *modify*) echo \"$*\" >> '{}' ;;\nesac\n",
+        log.display()
+    );
+    fake.set_script(&fake_task);
+
+    let tasks = tasks();
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(tasks.clone()));
+
+        ui.handle_action(Action::Reopen, &taskwarrior).await.expect("reopen pending");
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("Only completed and deleted tasks can be reopened"));
+        assert!(!log.exists());
+        println!("✅ Pending tasks can't be reopened");
+
+        ui.handle_action(Action::RecentlyCompleted, &taskwarrior).await.expect("recent");
+        assert_eq!(ui.export_filter(), None);
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Completed in the last 7d (2)"), "{}", text);
+        let library = text.find("Return library books").expect("recent completion");
+        let bill = text.find("Pay the water bill").expect("yesterday's completion");
+        assert!(library < bill, "{}", text);
+        assert!(!text.contains("Renew the passport") && !text.contains("Plan the garden"), "{}", text);
+        println!("✅ H lists the last week's completions, newest first");
+
+        ui.handle_action(Action::Reopen, &taskwarrior).await.expect("reopen");
+        let commands = std::fs::read_to_string(&log).expect("task was run");
+        let reopened = &tasks[2];
+        assert!(commands.contains(&format!("{} modify status:pending end:", reopened.uuid)), "{}", commands);
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("Reopened 'Return library books'"));
+        println!("✅ o reopens the task by UUID: task {}", commands.trim());
+
+        ui.handle_action(Action::RecentlyCompleted, &taskwarrior).await.expect("back");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Plan the garden") && !text.contains("Pay the water bill"), "{}", text);
+        println!("✅ H again goes back to the filtered list");
+
+        ui.handle_action(Action::RecentlyCompleted, &taskwarrior).await.expect("recent");
+        ui.handle_action(Action::Filter, &taskwarrior).await.expect("filter panel");
+        ui.handle_action(Action::Filter, &taskwarrior).await.expect("close filter panel");
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("Plan the garden"));
+        println!("✅ Opening the filter panel leaves the view too");
+
+        let mut config = Config::default();
+        config.ui.recently_completed_days = 30;
+        config.taskwarrior.filter_on_export = true;
+        let mut ui = AppUI::new(&config).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(tasks.clone()));
+        ui.handle_action(Action::RecentlyCompleted, &taskwarrior).await.expect("recent");
+        assert_eq!(ui.export_filter().as_deref(), Some("status:completed"));
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Completed in the last 30d") && text.contains("Renew the passport"), "{}", text);
+        println!("✅ ui.recently_completed_days widens the window; filtered exports ask for completed tasks");
+    });
+
+    println!();
+    println!("🎉 Recently completed test passed!");
+}
@@ -0,0 +1,28 @@
+// Test program for the event loop's wake-ups: an idle terminal waits for input
+// instead of polling, and only loads in flight keep it checking regularly
+
+use std::time::Duration;
+
+use lazytask::app::{next_wake_up, BUSY_WAKE_UP, IDLE_REDRAW};
+
+fn main() {
+    println!("💤 LazyTask Redraw Test");
+    println!("======================");
+    println!();
+
+    assert_eq!(next_wake_up(true, true, Duration::ZERO), Duration::ZERO);
+    assert_eq!(next_wake_up(true, false, Duration::from_secs(5)), Duration::ZERO);
+    println!("✅ Queued bulk work runs without waiting");
+
+    assert_eq!(next_wake_up(false, true, Duration::ZERO), BUSY_WAKE_UP);
+    assert_eq!(next_wake_up(false, true, Duration::from_secs(90)), BUSY_WAKE_UP);
+    println!("✅ Running loads are checked every {:?}", BUSY_WAKE_UP);
+
+    assert_eq!(next_wake_up(false, false, Duration::ZERO), IDLE_REDRAW);
+    assert_eq!(next_wake_up(false, false, Duration::from_secs(45)), Duration::from_secs(15));
+    assert_eq!(next_wake_up(false, false, Duration::from_secs(75)), Duration::ZERO);
+    println!("✅ When idle the loop sleeps until the next {:?} redraw", IDLE_REDRAW);
+
+    println!();
+    println!("🎉 Redraw test passed!");
+}
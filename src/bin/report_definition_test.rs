@@ -0,0 +1,105 @@
+// Test program for showing the task list as a Taskwarrior report (report.<name>.* in taskrc).
+
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::data::report::{report_names, ReportDefinition};
+use lazytask::data::sort::SortKey;
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+  *_config*) printf 'default.command\\nreport.next.columns\\nreport.next.sort\\nreport.weekly.columns\\nreport.list.columns\\n' ;;
+  *rc.report.next.columns*) echo 'id,start.age,entry.age,priority,project,tags,due.relative,description,urgency' ;;
+  *rc.report.next.sort*) echo 'urgency-' ;;
+  *rc.report.next.filter*) echo 'status:pending -WAITING limit:page' ;;
+  *_get*) ;;
+esac
+";
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 30;
+
+fn main() {
+    println!("📑 LazyTask Report Definition Test");
+    println!("=================================");
+    println!();
+
+    let list = ReportDefinition::from_settings(
+        "list",
+        "id,start.age,entry.age,depends.indicator,priority,project,tags,recur.indicator,scheduled.countdown,due,until.remaining,description.count,urgency",
+        "start-,due+,project+,urgency-",
+        "status:pending -WAITING",
+    );
+    assert_eq!(
+        list.list_columns(),
+        ["id", "start", "age", "depends", "priority", "project", "tags", "recur", "scheduled", "due", "until", "description", "urgency"]
+    );
+    assert_eq!(list.sort_key(), Some(SortKey::Due));
+    assert_eq!(list.export_filter().as_deref(), Some("status:pending -WAITING"));
+    println!("✅ Column formats are dropped and the first sort column the list knows is used");
+
+    let completed = ReportDefinition::from_settings("completed", "end,description", "end/,end-", "status:completed limit:page");
+    assert_eq!(completed.sort_key(), None);
+    assert_eq!(completed.export_filter().as_deref(), Some("status:completed"));
+    let unfiltered = ReportDefinition::from_settings("all", "id,description", "", " limit:10 ");
+    assert_eq!(unfiltered.export_filter(), None);
+    println!("✅ limit: terms are left out of the export filter");
+
+    assert_eq!(report_names(""), ["next", "list", "completed"]);
+    assert_eq!(
+        report_names("report.weekly.columns\nreport.weekly.sort\nreport.next.columns\nreport.active.columns\n"),
+        ["next", "list", "completed", "active", "weekly"]
+    );
+    println!("✅ Built-in reports come first, then custom ones from taskrc");
+
+    let _fake = FakeTask::with_script("reports", FAKE_TASK);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+
+        let next = taskwarrior.report_definition("next").await.expect("next report");
+        assert_eq!(next.sort_key(), Some(SortKey::Urgency));
+        assert!(taskwarrior.report_definition("missing").await.is_err());
+        println!("✅ Definitions are read with task _get; undefined reports are an error");
+
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        let mut task = Task::new("Renew passport".to_string());
+        task.id = Some(1);
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![task]));
+        assert_eq!(ui.export_filter(), None);
+
+        ui.handle_action(Action::ReportMenu, &taskwarrior).await.expect("open menu");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Taskwarrior Reports") && text.contains("weekly"), "{}", text);
+        println!("✅ R opens a menu with the built-in and custom reports");
+
+        ui.handle_action(Action::MoveDown, &taskwarrior).await.expect("move");
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("apply");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(!text.contains("Taskwarrior Reports"), "{}", text);
+        assert!(text.contains(" next (1) · Urgency "), "{}", text);
+        assert!(text.contains("Urg") && text.contains("Tags"), "{}", text);
+        assert_eq!(ui.export_filter().as_deref(), Some("status:pending -WAITING"));
+        println!("✅ Picking a report applies its columns, sort order and filter");
+
+        ui.handle_action(Action::ReportMenu, &taskwarrior).await.expect("open menu");
+        ui.handle_action(Action::MoveUp, &taskwarrior).await.expect("move");
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("clear");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains(" Tasks (1) "), "{}", text);
+        assert_eq!(ui.export_filter(), None);
+        println!("✅ The first row goes back to LazyTask's own columns");
+    });
+
+    println!();
+    println!("🎉 Report definition test passed!");
+}
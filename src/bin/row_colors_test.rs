@@ -0,0 +1,93 @@
+// Test program for configurable task list row colors
+
+use chrono::{Duration, Utc};
+use lazytask::config::RowColorConfig;
+use lazytask::data::models::{Priority, Task};
+use lazytask::ui::components::task_list::{RowColors, TaskListWidget};
+use ratatui::{backend::TestBackend, style::{Color, Modifier}, Terminal};
+
+// Foreground color and boldness of each task row, top to bottom
+fn row_styles(colors: &RowColorConfig, tasks: &[Task]) -> Vec<(Color, bool)> {
+    let mut list = TaskListWidget::new(&["description".to_string()]);
+    list.set_row_colors(RowColors::new(colors));
+    list.set_tasks(tasks.to_vec());
+    list.state.select(None);
+
+    let height = tasks.len() as u16 + 3;
+    let mut terminal = Terminal::new(TestBackend::new(40, height)).expect("test terminal");
+    terminal.draw(|f| list.render(f, f.area())).expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..tasks.len() as u16)
+        .map(|i| {
+            let cell = &buffer[(1, i + 2)];
+            (cell.fg, cell.modifier.contains(Modifier::BOLD))
+        })
+        .collect()
+}
+
+fn task(description: &str, due_in_days: Option<i64>, priority: Option<Priority>) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.due = due_in_days.map(|days| Utc::now() + Duration::days(days));
+    task.priority = priority;
+    task
+}
+
+fn main() {
+    println!("🎨 LazyTask Row Colors Test");
+    println!("==========================");
+    println!();
+
+    let mut urgent = task("urgent", None, None);
+    urgent.urgency = 12.0;
+    let tasks = vec![
+        task("overdue", Some(-3), None),
+        task("today", Some(0), None),
+        task("high in 2 days", Some(2), Some(Priority::High)),
+        task("tomorrow", Some(1), None),
+        task("high", None, Some(Priority::High)),
+        task("medium", None, Some(Priority::Medium)),
+        task("low", None, Some(Priority::Low)),
+        urgent,
+        task("plain", None, None),
+    ];
+
+    let defaults = row_styles(&RowColorConfig::default(), &tasks);
+    assert_eq!(defaults, vec![
+        (Color::Red, true),
+        (Color::Red, true),
+        (Color::Red, true),
+        (Color::Yellow, true),
+        (Color::Red, false),
+        (Color::Yellow, false),
+        (Color::Green, false),
+        (Color::White, true),
+        (Color::White, false),
+    ]);
+    println!("✅ Defaults keep the built-in hierarchy");
+
+    // Turning a rule off lets the next one apply; thresholds are configurable
+    let custom: RowColorConfig = toml::from_str(
+        "due_today = \"\"\nhigh_priority_due_days = 0\nurgency_threshold = 15.0\nlow_priority = \"#00ff00\"",
+    ).expect("partial [ui.row_colors] table");
+    let styles = row_styles(&custom, &tasks);
+    assert_eq!(styles[1], (Color::White, false), "due today no longer red");
+    assert_eq!(styles[2], (Color::Red, false), "high priority in 2 days is just high priority");
+    assert_eq!(styles[6], (Color::Rgb(0, 255, 0), false));
+    assert_eq!(styles[7], (Color::White, false), "urgency 12 is below the new threshold");
+    assert_eq!(styles[0], (Color::Red, true), "unchanged rules keep their defaults");
+    println!("✅ Rules can be turned off and thresholds changed from TOML");
+
+    let off: RowColorConfig = toml::from_str("enabled = false\ndefault = \"gray\"").unwrap();
+    assert!(row_styles(&off, &tasks).iter().all(|style| *style == (Color::Gray, false)));
+    println!("✅ enabled = false draws every row in the default color");
+
+    let mut bad: RowColorConfig = toml::from_str("overdue = \"blurple\"\ndefault = \"\"").unwrap();
+    let warnings = bad.check_colors();
+    assert_eq!(warnings, vec!["Invalid ui.row_colors.overdue \"blurple\", using \"red\"".to_string()]);
+    assert_eq!(bad.overdue, "red");
+    assert_eq!(row_styles(&bad, &tasks)[8], (Color::White, false), "empty default stays white");
+    println!("✅ Unknown colors are reported and replaced: {}", warnings[0]);
+
+    println!();
+    println!("🎉 Row color checks passed");
+}
@@ -0,0 +1,141 @@
+// Test program for saving an edit to a task that was changed elsewhere after the form opened.
+
+use chrono::{Duration, Utc};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 40;
+
+// What `task <uuid> export` returns from now on
+fn set_latest(fake: &FakeTask, task: &Task) {
+    let json = serde_json::to_string(&vec![task.to_taskwarrior_json()]).expect("json");
+    std::fs::write(fake.path("latest.json"), json).expect("write export");
+}
+
+fn modifies(fake: &FakeTask) -> usize {
+    std::fs::read_to_string(fake.path("modified")).unwrap_or_default().lines().count()
+}
+
+// Open the form on the selected task, add to the description and press Enter to save
+async fn edit_and_save(ui: &mut AppUI, taskwarrior: &TaskwarriorIntegration) {
+    ui.handle_action(Action::EditTask, taskwarrior).await.expect("edit");
+    ui.handle_action(Action::Character('!'), taskwarrior).await.expect("type");
+    ui.handle_action(Action::Select, taskwarrior).await.expect("stop editing");
+    ui.handle_action(Action::Select, taskwarrior).await.expect("save");
+}
+
+fn main() {
+    println!("🔀 LazyTask Save Conflict Test");
+    println!("=============================");
+    println!();
+
+    let fake = FakeTask::new("conflict");
+    let fake_task = format!(
+        "#!/bin/sh\ncase \"$*\" in\n  *--version*) echo '3.1.0' ;;\n  *export*) cat '{0}/latest.json' ;;\n  *modify*|*add*) [ -e '{0}/fail' ] && {{ echo 'Unable to lock the data file' >&2; exit 1; }}; echo \"$*\" >> '{0}/modified' ;;\nesac\n",
+        fake.dir().display()
+    );
+    fake.set_script(&fake_task);
+
+    let mut loaded = Task::new("Book flights".to_string());
+    loaded.id = Some(1);
+    loaded.modified = Some(Utc::now() - Duration::hours(1));
+    let mut changed = loaded.clone();
+    changed.description = "Book flights and hotel".to_string();
+    changed.project = Some("travel".to_string());
+    changed.modified = Some(Utc::now());
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![loaded.clone()]));
+
+        set_latest(&fake, &loaded);
+        edit_and_save(&mut ui, &taskwarrior).await;
+        assert!(!ui.has_active_form());
+        assert_eq!(modifies(&fake), 1);
+        println!("✅ An unchanged task saves straight away");
+
+        set_latest(&fake, &changed);
+        edit_and_save(&mut ui, &taskwarrior).await;
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Task Changed Elsewhere"), "{}", text);
+        assert!(text.contains("(description, project)"), "{}", text);
+        assert_eq!(modifies(&fake), 1);
+        println!("✅ A newer modified time holds the save and says what changed");
+
+        ui.handle_action(Action::Back, &taskwarrior).await.expect("keep editing");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(!text.contains("Task Changed Elsewhere") && text.contains("Book flights!"), "{}", text);
+        assert!(ui.has_active_form());
+        assert_eq!(modifies(&fake), 1);
+        println!("✅ Esc goes back to the form with the edits kept");
+
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("save again");
+        ui.handle_action(Action::Character('o'), &taskwarrior).await.expect("overwrite");
+        assert!(!ui.has_active_form());
+        let commands = std::fs::read_to_string(fake.path("modified")).unwrap_or_default();
+        assert_eq!(modifies(&fake), 2);
+        assert!(commands.lines().last().unwrap_or_default().contains("description:Book flights!"), "{}", commands);
+        println!("✅ o saves over the other change");
+
+        edit_and_save(&mut ui, &taskwarrior).await;
+        ui.handle_action(Action::Character('r'), &taskwarrior).await.expect("reload");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(ui.has_active_form());
+        assert!(text.contains("Book flights and hotel") && text.contains("Project: travel"), "{}", text);
+        assert!(text.contains("your edits were dropped"), "{}", text);
+        assert_eq!(modifies(&fake), 2);
+        println!("✅ r reopens the form on the task as it is now");
+
+        // A failing `task modify` (here a held lock) keeps the form and its edits
+        ui.handle_action(Action::Back, &taskwarrior).await.expect("close");
+        std::fs::write(fake.path("fail"), "").expect("fail marker");
+        set_latest(&fake, &loaded);
+        edit_and_save(&mut ui, &taskwarrior).await;
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(ui.has_active_form() && text.contains("Book flights!"), "{}", text);
+        assert!(text.contains("Couldn't save the task: ") && text.contains("Unable to lock"), "{}", text);
+        assert_eq!(modifies(&fake), 2);
+        std::fs::remove_file(fake.path("fail")).expect("clear marker");
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("retry");
+        assert!(!ui.has_active_form());
+        assert_eq!(modifies(&fake), 3);
+        println!("✅ A failed save keeps the form open with the error, and can be retried");
+
+        std::fs::write(fake.path("fail"), "").expect("fail marker");
+        set_latest(&fake, &changed);
+        edit_and_save(&mut ui, &taskwarrior).await;
+        ui.handle_action(Action::Character('o'), &taskwarrior).await.expect("overwrite");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Task Changed Elsewhere") && text.contains("Couldn't save the task"), "{}", text);
+        std::fs::remove_file(fake.path("fail")).expect("clear marker");
+        ui.handle_action(Action::Character('o'), &taskwarrior).await.expect("overwrite again");
+        assert!(!ui.has_active_form() && !screen(&mut ui, WIDTH, HEIGHT).contains("Task Changed Elsewhere"));
+        assert_eq!(modifies(&fake), 4);
+        println!("✅ A failed overwrite keeps the question up to try again");
+
+        std::fs::write(fake.path("fail"), "").expect("fail marker");
+        ui.handle_action(Action::AddTask, &taskwarrior).await.expect("add");
+        for c in "Pack".chars() {
+            ui.handle_action(Action::Character(c), &taskwarrior).await.expect("type");
+        }
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("stop editing");
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("save");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(ui.has_active_form() && text.contains("Couldn't save the task"), "{}", text);
+        println!("✅ So does a failed add");
+    });
+
+    println!();
+    println!("🎉 Save conflict test passed!");
+}
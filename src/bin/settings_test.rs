@@ -0,0 +1,125 @@
+// Test program for the Settings view (F2) and reloading the config file with R while
+// running.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+use ratatui::{buffer::Buffer, style::Color};
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+const WIDTH: u16 = 140;
+const HEIGHT: u16 = 90;
+
+fn frame(ui: &mut AppUI) -> Buffer {
+    fake_task::frame(WIDTH, HEIGHT, |f| ui.draw(f))
+}
+
+fn key(handler: &InputHandler, ui: &AppUI, code: KeyCode) -> Action {
+    handler.handle_key_event_in_view(KeyEvent::new(code, KeyModifiers::NONE), ui.has_active_form(), ui.key_context())
+}
+
+// A config file with the defaults, changed by `edit`
+fn write_config(path: &std::path::Path, edit: impl FnOnce(&mut Config)) {
+    let mut config = Config::default();
+    config.taskwarrior.startup_filter = Some("project:work".to_string());
+    edit(&mut config);
+    config.save(path).expect("write config");
+}
+
+fn main() {
+    println!("⚙️  LazyTask Settings Test");
+    println!("========================");
+    println!();
+
+    let fake = FakeTask::with_script("settings", FAKE_TASK);
+
+    let path = fake.path("config.toml");
+    write_config(&path, |_| {});
+    // --filter on the command line, which a reload must not undo
+    let config = Config::load(path.to_str()).expect("config").with_startup_filter(Some("+next".to_string()));
+
+    let mut task = Task::new("Plan the offsite".to_string());
+    task.id = Some(1);
+    task.project = Some("work".to_string());
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut handler = InputHandler::new(&config);
+        let mut ui = AppUI::new(&config).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![task.clone()]));
+        assert!(!screen(&mut ui, WIDTH, HEIGHT).contains("│ID   Description"));
+
+        let action = key(&handler, &ui, KeyCode::F(2));
+        assert!(matches!(action, Action::Settings), "{:?}", action);
+        ui.handle_action(action, &taskwarrior).await.expect("settings");
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(!text.contains("Coming Soon"), "{}", text);
+        assert!(text.contains(&format!("Config file  {}", path.display())), "{}", text);
+        assert!(text.contains("[ui]") && text.contains("vim_keys = false"), "{}", text);
+        assert!(text.contains("startup_filter = \"+next\""), "{}", text);
+        println!("✅ F2 shows the active config, command line overrides included");
+
+        // Edit the file while the app runs
+        write_config(&path, |config| {
+            config.theme.name = "gruvbox".to_string();
+            config.ui.vim_keys = true;
+            config.ui.task_list_columns = vec!["id".to_string(), "description".to_string()];
+            config.ui.date_format = "%d.%m.%Y".to_string();
+        });
+
+        let action = key(&handler, &ui, KeyCode::Char('R'));
+        assert!(matches!(action, Action::ReloadConfig), "{:?}", action);
+        ui.handle_action(action, &taskwarrior).await.expect("reload");
+        let reloaded = ui.take_reloaded_config().expect("reloaded config");
+        assert!(ui.take_reloaded_config().is_none());
+        assert!(reloaded.ui.vim_keys && reloaded.theme.name == "gruvbox");
+        assert_eq!(reloaded.startup_filter(), Some("+next"));
+        let shown = frame(&mut ui);
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains(&format!("Reloaded {}", path.display())), "{}", text);
+        assert!(text.contains("vim_keys = true") && text.contains("date_format = \"%d.%m.%Y\""), "{}", text);
+        assert!(shown.content.iter().any(|cell| matches!(cell.bg, Color::Rgb(..))));
+        println!("✅ R re-reads the file: the view and the theme change at once");
+
+        handler = InputHandler::new(&reloaded);
+        ui.handle_action(key(&handler, &ui, KeyCode::Esc), &taskwarrior).await.expect("back");
+        assert!(matches!(key(&handler, &ui, KeyCode::Char('j')), Action::MoveDown));
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("│ID   Description"), "{}", text);
+        println!("✅ New key bindings (vim_keys) and task list columns apply without a restart");
+
+        ui.handle_action(Action::Settings, &taskwarrior).await.expect("settings");
+        std::fs::write(&path, "[ui\nvim_keys = ").expect("break config");
+        ui.handle_action(key(&handler, &ui, KeyCode::Char('R')), &taskwarrior).await.expect("reload");
+        assert!(ui.take_reloaded_config().is_none());
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Config not reloaded: Failed to parse config file"), "{}", text);
+        assert!(text.contains("vim_keys = true"), "{}", text);
+        println!("✅ A file that doesn't parse is reported and the running config is kept");
+
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.handle_action(Action::Settings, &taskwarrior).await.expect("settings");
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("none, built-in defaults"));
+        ui.handle_action(Action::ReloadConfig, &taskwarrior).await.expect("reload");
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("No config file to reload"));
+        assert!(ui.take_reloaded_config().is_none());
+        println!("✅ Without a config file there is nothing to reload");
+    });
+
+    println!();
+    println!("🎉 Settings test passed!");
+}
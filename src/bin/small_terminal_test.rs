@@ -0,0 +1,103 @@
+// Test program for drawing at terminal sizes down to 0×0: nothing may panic,
+// and below the minimum size a "terminal too small" notice is shown instead.
+
+use chrono::{Duration, Utc};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::{AppUI, MIN_HEIGHT, MIN_WIDTH};
+use lazytask::ui::views::main_view::COMPACT_WIDTH;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+fn tasks() -> Vec<Task> {
+    (0..30)
+        .map(|i| {
+            let mut task = Task::new(format!("Task number {} with a fairly long description", i));
+            task.id = Some(i + 1);
+            task.project = Some(format!("project{}.sub", i % 4));
+            task.tags = vec![format!("tag{}", i % 5)];
+            task.due = Some(Utc::now() + Duration::days(i as i64 - 5));
+            task
+        })
+        .collect()
+}
+
+fn main() {
+    println!("📏 LazyTask Small Terminal Test");
+    println!("==============================");
+    println!();
+
+    let _fake = FakeTask::with_script("small", FAKE_TASK);
+
+    let screens: Vec<(&str, Vec<Action>)> = vec![
+        ("task list", vec![]),
+        ("filter panel", vec![Action::Filter, Action::Tab, Action::Tab]),
+        ("detail overlay", vec![Action::ToggleDetail]),
+        ("task form", vec![Action::AddTask]),
+        ("date picker", vec![Action::AddTask, Action::DatePicker]),
+        ("annotations dialog", vec![Action::Annotations]),
+        ("reports menu", vec![Action::ReportMenu]),
+        ("export dialog", vec![Action::Export]),
+        ("snooze dialog", vec![Action::Snooze]),
+        ("delete confirmation", vec![Action::DeleteTask]),
+        ("reports view", vec![Action::Reports, Action::MoveRight]),
+        ("projects view", vec![Action::Projects]),
+        ("help screen", vec![Action::Help]),
+    ];
+
+    // Both sides of each breakpoint, the degenerate sizes and a few large ones; a full
+    // sweep would take minutes in a debug build
+    let widths = [0, 1, MIN_WIDTH - 1, MIN_WIDTH, MIN_WIDTH + 1, COMPACT_WIDTH - 1, COMPACT_WIDTH, COMPACT_WIDTH + 1, 120, 200];
+    let heights = [0, 1, MIN_HEIGHT - 1, MIN_HEIGHT, MIN_HEIGHT + 1, 24, 40, 60];
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+
+        for (name, actions) in &screens {
+            let mut ui = AppUI::new(&Config::default()).expect("app ui");
+            ui.start_loading(None);
+            ui.finish_loading(Ok(tasks()));
+            for action in actions {
+                ui.handle_action(action.clone(), &taskwarrior).await.expect("action");
+            }
+            for &width in &widths {
+                for &height in &heights {
+                    screen(&mut ui, width, height);
+                }
+            }
+            println!("✅ The {} draws at every sampled size", name);
+        }
+
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(tasks()));
+        ui.handle_action(Action::Annotations, &taskwarrior).await.expect("annotations");
+        for (width, height) in [(MIN_WIDTH - 1, MIN_HEIGHT), (MIN_WIDTH, MIN_HEIGHT - 1), (20, 3)] {
+            let text = screen(&mut ui, width, height);
+            assert!(text.contains("Terminal too small"), "{}", text);
+            assert!(!text.contains("Annotations"), "{}", text);
+        }
+        let text = screen(&mut ui, MIN_WIDTH - 1, 20);
+        assert!(text.contains(&format!("{}×20, needs {}×{}", MIN_WIDTH - 1, MIN_WIDTH, MIN_HEIGHT)), "{}", text);
+        println!("✅ Below {}×{} only the notice is drawn, with the current size", MIN_WIDTH, MIN_HEIGHT);
+
+        let text = screen(&mut ui, MIN_WIDTH, MIN_HEIGHT);
+        assert!(!text.contains("Terminal too small"), "{}", text);
+        assert!(text.contains("Annotations"), "{}", text);
+        println!("✅ At the minimum size the UI comes back with its open dialog");
+    });
+
+    println!();
+    println!("🎉 Small terminal test passed!");
+}
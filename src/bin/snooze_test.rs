@@ -0,0 +1,74 @@
+// Test program for snoozing a task with a wait date
+
+use chrono::{Duration, Utc};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::ui::components::snooze_dialog::{SnoozeDialog, SnoozeResult};
+use lazytask::ui::views::main_view::MainView;
+use lazytask::utils::formatting::DateFormat;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::render;
+
+fn type_text(dialog: &mut SnoozeDialog, text: &str) {
+    for c in text.chars() {
+        dialog.handle_input(Action::Character(c)).expect("input");
+    }
+}
+
+fn screen(dialog: &SnoozeDialog) -> String {
+    render(80, 20, |f| dialog.render(f, f.area()))
+}
+
+fn main() {
+    println!("💤 LazyTask Snooze Test");
+    println!("======================");
+    println!();
+
+    let handler = InputHandler::new(&Config::default());
+    let key = handler.handle_key_event_with_context(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::NONE), false);
+    assert!(matches!(key, Action::Snooze));
+    println!("✅ w opens the snooze prompt");
+
+    let task = Task::new("Call the landlord".to_string());
+    let mut dialog = SnoozeDialog::new(&task, DateFormat::default());
+    type_text(&mut dialog, "3d");
+    let until = match dialog.handle_input(Action::Select).expect("input") {
+        Some(SnoozeResult::Snooze(until)) => until,
+        other => panic!("expected a snooze, got {:?}", other),
+    };
+    let days = (until.date_naive() - Utc::now().date_naive()).num_days();
+    assert_eq!(days, 3);
+    println!("✅ 3d waits until three days from today");
+
+    for (input, expected_days) in [("1w", 7), ("tomorrow", 1)] {
+        let mut dialog = SnoozeDialog::new(&task, DateFormat::default());
+        type_text(&mut dialog, input);
+        assert!(screen(&dialog).contains("Hidden until"), "{}", screen(&dialog));
+        let until = dialog.wait_until().expect("valid duration");
+        assert_eq!((until.date_naive() - Utc::now().date_naive()).num_days(), expected_days);
+    }
+    println!("✅ 1w and tomorrow work too, with the date previewed while typing");
+
+    for input in ["yesterday", "someday"] {
+        let mut dialog = SnoozeDialog::new(&task, DateFormat::default());
+        type_text(&mut dialog, input);
+        assert!(dialog.handle_input(Action::Select).expect("input").is_none());
+        assert!(dialog.error.is_some());
+        println!("✅ \"{}\" is refused: {}", input, dialog.error.as_deref().unwrap_or_default());
+    }
+
+    let mut snoozed = Task::new("Snoozed".to_string());
+    snoozed.wait = Some(Utc::now() + Duration::days(3));
+    let plain = Task::new("Plain".to_string());
+    let view = MainView::new(&Config::default());
+    assert!(view.matches_filters(&plain));
+    assert!(!view.matches_filters(&snoozed));
+    println!("✅ A pending task with a future wait date is hidden from the Pending list");
+
+    println!();
+    println!("🎉 Snooze checks passed");
+}
@@ -0,0 +1,121 @@
+// Test program for the startup briefing: once per launch, a popup of the pending tasks that
+// are overdue or due today over the first task list, closed by any key and turned off with
+// `ui.startup_briefing = false`.
+
+use chrono::{Duration, Utc};
+use lazytask::config::Config;
+use lazytask::data::models::{Task, TaskStatus};
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+use lazytask::utils::formatting::{start_of_day, today};
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+fn task(id: u32, description: &str, due: Option<chrono::DateTime<Utc>>) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.id = Some(id);
+    task.due = due;
+    task
+}
+
+fn main() {
+    println!("📣 LazyTask Startup Briefing Test");
+    println!("=================================");
+    println!();
+
+    let _fake = FakeTask::with_script("startup-briefing", FAKE_TASK);
+
+    let now = Utc::now();
+    let tomorrow = start_of_day(today() + Duration::days(1));
+    let mut overdue = task(1, "Pay the rent", Some(now - Duration::days(3)));
+    overdue.project = Some("home".to_string());
+    let later_today = tomorrow - Duration::minutes(1);
+    let tasks = vec![
+        overdue,
+        task(2, "File the report", Some(now - Duration::hours(2))),
+        task(3, "Call the plumber", Some(later_today)),
+        task(4, "Plan the trip", Some(tomorrow + Duration::days(2))),
+        task(5, "Read a book", None),
+    ];
+    let mut done = task(6, "Renew the passport", Some(now - Duration::days(1)));
+    done.status = TaskStatus::Completed;
+    let mut loaded = tasks.clone();
+    loaded.push(done);
+
+    let config = Config::default();
+    assert!(config.ui.startup_briefing);
+    let parsed: Config = toml::from_str(&format!(
+        "{}\n",
+        toml::to_string(&config).expect("serialize").replace("startup_briefing = true", "startup_briefing = false")
+    ))
+    .expect("parse");
+    assert!(!parsed.ui.startup_briefing);
+    println!("✅ ui.startup_briefing is on by default and can be turned off");
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&config).expect("app ui");
+        ui.request_startup_briefing();
+        // A failed load doesn't use it up
+        ui.start_loading(None);
+        ui.finish_loading(Err(anyhow::anyhow!("task export failed")));
+        assert!(!screen(&mut ui, 160, 40).contains("Needs Attention"));
+
+        ui.start_loading(None);
+        ui.finish_loading(Ok(loaded.clone()));
+        let text = screen(&mut ui, 160, 40);
+        assert!(text.contains("Needs Attention") && text.contains("Overdue (2)") && text.contains("Due Today (1)"), "{}", text);
+        assert!(text.contains("Pay the rent · home") && text.contains("3d late") && text.contains("2h late"), "{}", text);
+        let at = lazytask::utils::formatting::display_time(&later_today).format("at %H:%M").to_string();
+        assert!(text.contains("Call the plumber") && text.contains(&at), "{}", text);
+        assert!(!text.contains("Renew the passport") && text.contains("ui.startup_briefing = false"), "{}", text);
+        // The list behind it has them too; look from the popup's title on
+        let popup = &text[text.find("Needs Attention").expect("popup")..];
+        let rent = popup.find("Pay the rent").expect("rent");
+        let report = popup.find("File the report").expect("report");
+        assert!(rent < report, "{}", text);
+        println!("✅ The first load lists overdue tasks (longest first) and what's due today");
+
+        // Any key closes it without acting: d would otherwise finish the selected task
+        ui.handle_action(Action::DoneTask, &taskwarrior).await.expect("close");
+        let text = screen(&mut ui, 160, 40);
+        assert!(!text.contains("Needs Attention") && text.contains("Pay the rent"), "{}", text);
+        assert!(!ui.has_pending_work());
+        println!("✅ Any key closes it and does nothing else");
+
+        ui.start_loading(None);
+        ui.finish_loading(Ok(loaded.clone()));
+        assert!(!screen(&mut ui, 160, 40).contains("Needs Attention"));
+        println!("✅ Later reloads don't bring it back");
+
+        let mut small = AppUI::new(&config).expect("app ui");
+        small.request_startup_briefing();
+        small.start_loading(None);
+        let many: Vec<Task> = (1..=30).map(|id| task(id, &format!("Late task {}", id), Some(now - Duration::days(id as i64)))).collect();
+        small.finish_loading(Ok(many));
+        let text = screen(&mut small, 80, 24);
+        assert!(text.contains("Overdue (30)") && text.contains("… more in the task list"), "{}", text);
+        println!("✅ A long list fits the terminal and points to the task list");
+
+        // Nothing overdue or due today: no popup at all
+        let mut quiet = AppUI::new(&config).expect("app ui");
+        quiet.request_startup_briefing();
+        quiet.start_loading(None);
+        quiet.finish_loading(Ok(vec![tasks[3].clone(), tasks[4].clone()]));
+        assert!(!screen(&mut quiet, 160, 40).contains("Needs Attention"));
+        println!("✅ Without anything urgent it stays out of the way");
+    });
+
+    println!();
+    println!("🎉 Startup briefing test passed!");
+}
@@ -0,0 +1,77 @@
+// Test program for `taskwarrior.startup_filter` / `--filter`: a Taskwarrior filter that every
+// export starts from.
+
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 30;
+
+fn main() {
+    println!("🎯 LazyTask Startup Filter Test");
+    println!("==============================");
+    println!();
+
+    let mut config = Config::default();
+    assert_eq!(config.startup_filter(), None);
+    config.taskwarrior.startup_filter = Some("  ".to_string());
+    assert_eq!(config.startup_filter(), None);
+    config.taskwarrior.startup_filter = Some("project:home".to_string());
+    let config = config.with_startup_filter(None);
+    assert_eq!(config.startup_filter(), Some("project:home"));
+    let config = config.with_startup_filter(Some(" project:work ".to_string()));
+    assert_eq!(config.startup_filter(), Some("project:work"));
+    println!("✅ --filter replaces the configured filter and blank filters are ignored");
+
+    let parsed: Config = toml::from_str(&toml::to_string(&Config::default()).expect("serialize")).expect("parse");
+    assert_eq!(parsed.startup_filter(), None);
+    println!("✅ Config files without startup_filter still load");
+
+    let _fake = FakeTask::with_script("startup", FAKE_TASK);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+
+        let mut ui = AppUI::new(&config).expect("app ui");
+        assert_eq!(ui.export_filter().as_deref(), Some("project:work"));
+        ui.start_loading(ui.export_filter());
+        ui.finish_loading(Ok(vec![Task::new("Send the invoice".to_string())]));
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("LazyTask v0.1  [project:work]"), "{}", text);
+        println!("✅ The first load exports with the filter, and the header shows it");
+
+        ui.handle_action(Action::Reports, &taskwarrior).await.expect("reports");
+        assert_eq!(ui.export_filter().as_deref(), Some("project:work"));
+        println!("✅ Reports cover the same slice of tasks");
+
+        let mut exporting = config.clone();
+        exporting.taskwarrior.filter_on_export = true;
+        let ui = AppUI::new(&exporting).expect("app ui");
+        assert_eq!(ui.export_filter().as_deref(), Some("( project:work ) ( status:pending )"));
+        println!("✅ With filter_on_export the status filter narrows it further");
+
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(Vec::new()));
+        assert_eq!(ui.export_filter(), None);
+        assert!(!screen(&mut ui, WIDTH, HEIGHT).contains("LazyTask v0.1  ["));
+        println!("✅ Without one, everything is exported as before");
+    });
+
+    println!();
+    println!("🎉 Startup filter test passed!");
+}
@@ -0,0 +1,206 @@
+// Test program for the Status filter panel options
+
+use chrono::{Duration, Utc};
+use lazytask::config::Config;
+use lazytask::data::models::{Priority, Task, TaskStatus};
+use lazytask::ui::components::task_list::TaskListWidget;
+use lazytask::ui::views::main_view::{MainView, StatusFilter};
+use lazytask::utils::formatting::DateFormat;
+use ratatui::{backend::TestBackend, style::Color, Terminal};
+
+fn task(description: &str, status: TaskStatus) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.status = status;
+    task
+}
+
+// Highlight `option` in the Status panel and press Space on it
+fn toggle(view: &mut MainView, option: StatusFilter) {
+    for _ in 0..StatusFilter::LIST.len() {
+        view.handle_filter_navigation_up();
+    }
+    let index = StatusFilter::LIST.iter().position(|s| *s == option).unwrap();
+    for _ in 0..index {
+        view.handle_filter_navigation_down();
+    }
+    view.toggle_current_selection();
+}
+
+fn shown<'a>(view: &MainView, tasks: &'a [Task]) -> Vec<&'a str> {
+    tasks.iter().filter(|t| view.matches_filters(t)).map(|t| t.description.as_str()).collect()
+}
+
+fn main() {
+    println!("☑️  LazyTask Status Filter Test");
+    println!("==============================");
+    println!();
+
+    let plain = task("plain", TaskStatus::Pending);
+    let mut active = task("active", TaskStatus::Pending);
+    active.start = Some(Utc::now() - Duration::hours(1));
+    let mut overdue = task("overdue", TaskStatus::Pending);
+    overdue.due = Some(Utc::now() - Duration::days(1));
+    let waiting = task("waiting", TaskStatus::Waiting);
+    let recurring = task("recurring", TaskStatus::Recurring);
+    let completed = task("completed", TaskStatus::Completed);
+    let deleted = task("deleted", TaskStatus::Deleted);
+    let tasks = [plain, active, overdue, waiting, recurring, completed, deleted];
+
+    let labels: Vec<&str> = StatusFilter::LIST.iter().map(|s| s.label()).collect();
+    assert_eq!(labels, vec![
+        "Pending", "Ready", "Active", "Overdue", "Completed", "Completed Today", "Completed This Week",
+        "Deleted", "Waiting", "Recurring",
+    ]);
+    println!("✅ Panel lists {:?}", labels);
+
+    let mut view = MainView::new(&Config::default());
+    assert_eq!(shown(&view, &tasks), vec!["plain", "active", "overdue"]);
+    assert_eq!(view.taskwarrior_filter().as_deref(), Some("status:pending"));
+    println!("✅ Pending is selected by default");
+
+    // Only Active: Overdue must not come along even though both are pending tasks
+    toggle(&mut view, StatusFilter::Pending);
+    toggle(&mut view, StatusFilter::Active);
+    assert_eq!(shown(&view, &tasks), vec!["active"]);
+    toggle(&mut view, StatusFilter::Active);
+    toggle(&mut view, StatusFilter::Overdue);
+    assert_eq!(shown(&view, &tasks), vec!["overdue"]);
+    assert_eq!(view.taskwarrior_filter().as_deref(), Some("+OVERDUE"));
+    println!("✅ Active and Overdue toggle independently");
+
+    toggle(&mut view, StatusFilter::Overdue);
+    toggle(&mut view, StatusFilter::Waiting);
+    toggle(&mut view, StatusFilter::Recurring);
+    assert_eq!(shown(&view, &tasks), vec!["waiting", "recurring"]);
+    assert_eq!(view.taskwarrior_filter().as_deref(), Some("( +WAITING or status:recurring )"));
+    println!("✅ Waiting and Recurring are reachable from the panel");
+
+    toggle(&mut view, StatusFilter::Waiting);
+    toggle(&mut view, StatusFilter::Recurring);
+    assert_eq!(shown(&view, &tasks).len(), tasks.len());
+    assert_eq!(view.taskwarrior_filter(), None);
+    println!("✅ No status selected shows everything");
+
+    view.clear_all_filters();
+    assert_eq!(shown(&view, &tasks), vec!["plain", "active", "overdue"]);
+    println!("✅ Clearing filters goes back to Pending only");
+
+    // Ready: pending tasks that can be worked on now, like Taskwarrior's +READY
+    let mut later = task("scheduled later", TaskStatus::Pending);
+    later.scheduled = Some(Utc::now() + Duration::days(2));
+    let mut now = task("scheduled earlier", TaskStatus::Pending);
+    now.scheduled = Some(Utc::now() - Duration::hours(1));
+    let mut snoozed = task("snoozed", TaskStatus::Pending);
+    snoozed.wait = Some(Utc::now() + Duration::days(1));
+    let blocker = task("blocker", TaskStatus::Pending);
+    let mut blocked = task("blocked", TaskStatus::Pending);
+    blocked.depends = vec![blocker.uuid.clone()];
+    let finished_dependency = task("finished dependency", TaskStatus::Completed);
+    let mut unblocked = task("unblocked", TaskStatus::Pending);
+    unblocked.depends = vec![finished_dependency.uuid.clone()];
+    let candidates = [later, now, snoozed, blocker, blocked, finished_dependency, unblocked, task("waiting", TaskStatus::Waiting)];
+    let open = Task::open_uuids(&candidates);
+    assert!(candidates[1].is_ready(&open) && !candidates[0].is_ready(&open) && !candidates[4].is_ready(&open));
+
+    let mut ready_view = MainView::new(&Config::default());
+    ready_view.update_available_filters(&candidates);
+    toggle(&mut ready_view, StatusFilter::Pending);
+    toggle(&mut ready_view, StatusFilter::Ready);
+    assert_eq!(shown(&ready_view, &candidates), vec!["scheduled earlier", "blocker", "unblocked"]);
+    assert_eq!(ready_view.taskwarrior_filter().as_deref(), Some("+READY"));
+    println!("✅ Ready leaves out future scheduled, waiting and blocked tasks");
+
+    // Completion windows are judged by the end timestamp
+    let finished = |description: &str, ago: Duration| {
+        let mut task = task(description, TaskStatus::Completed);
+        task.end = Some(Utc::now() - ago);
+        task
+    };
+    let mut done = vec![
+        finished("just now", Duration::seconds(5)),
+        finished("four days ago", Duration::days(4)),
+        finished("last month", Duration::days(30)),
+        task("no end", TaskStatus::Completed),
+    ];
+    // A deleted task with a recent end must not count as completed
+    let mut removed = task("removed", TaskStatus::Deleted);
+    removed.end = Some(Utc::now());
+    done.push(removed);
+
+    toggle(&mut view, StatusFilter::Pending);
+    toggle(&mut view, StatusFilter::CompletedToday);
+    assert_eq!(shown(&view, &done), vec!["just now"]);
+    assert_eq!(view.taskwarrior_filter().as_deref(), Some("status:completed"));
+    toggle(&mut view, StatusFilter::CompletedToday);
+    toggle(&mut view, StatusFilter::CompletedThisWeek);
+    assert_eq!(shown(&view, &done), vec!["just now", "four days ago"]);
+    toggle(&mut view, StatusFilter::Completed);
+    assert_eq!(shown(&view, &done), vec!["just now", "four days ago", "last month", "no end"]);
+    assert_eq!(view.taskwarrior_filter().as_deref(), Some("status:completed"));
+    println!("✅ Completed Today / This Week filter on the end timestamp");
+
+    // Completed column and row colours: finished work stays grey even when it was overdue
+    let mut late = finished("finished late", Duration::hours(1));
+    late.due = Some(Utc::now() - Duration::days(3));
+    late.priority = Some(Priority::High);
+    let mut pending_late = task("still late", TaskStatus::Pending);
+    pending_late.due = late.due;
+    let columns: Vec<String> = ["description", "completed"].iter().map(|c| c.to_string()).collect();
+    let mut list = TaskListWidget::new(&columns);
+    list.set_tasks(vec![late.clone(), pending_late]);
+    list.state.select(None);
+    let mut terminal = Terminal::new(TestBackend::new(80, 6)).expect("test terminal");
+    terminal.draw(|f| list.render(f, f.area())).expect("draw");
+    let buffer = terminal.backend().buffer();
+    let row = |y: u16| (0..80).map(|x| buffer[(x, y)].symbol().to_string()).collect::<String>();
+    assert!(row(1).contains("Completed"), "{}", row(1));
+    let end = DateFormat::default().short_date(&late.end.unwrap());
+    assert!(row(2).contains("finished late") && row(2).contains(&end), "{}", row(2));
+    assert_eq!(buffer[(1, 2)].fg, Color::DarkGray);
+    assert_eq!(buffer[(1, 3)].fg, Color::Red);
+    println!("✅ Completed column shows the end date; completed rows aren't painted red");
+
+    // C in the list flips completed and deleted tasks in and out, keeping other filters
+    let mut tasks = vec![
+        task("open", TaskStatus::Pending),
+        task("done", TaskStatus::Completed),
+        task("gone", TaskStatus::Deleted),
+        task("other project", TaskStatus::Completed),
+    ];
+    for t in tasks.iter_mut().take(3) {
+        t.project = Some("home".to_string());
+    }
+    let mut view = MainView::new(&Config::default());
+    view.show_only_project("home");
+    assert!(!view.shows_closed_tasks());
+    assert_eq!(shown(&view, &tasks), vec!["open"]);
+    view.toggle_closed_tasks();
+    assert!(view.shows_closed_tasks());
+    assert_eq!(shown(&view, &tasks), vec!["open", "done", "gone"]);
+    view.toggle_closed_tasks();
+    assert!(!view.shows_closed_tasks());
+    assert_eq!(shown(&view, &tasks), vec!["open"]);
+    println!("✅ The closed-task toggle adds and removes Completed and Deleted, keeping the project filter");
+
+    // From "All" (nothing selected) it hides closed tasks instead of narrowing to them
+    view.toggle_filter_focus();
+    toggle(&mut view, StatusFilter::Pending);
+    assert!(view.shows_closed_tasks());
+    view.toggle_closed_tasks();
+    assert_eq!(shown(&view, &tasks), vec!["open"]);
+    assert!(!view.taskwarrior_filter().unwrap_or_default().contains("completed"));
+
+    // Completed Today counts as closed too; only Pending is left afterwards
+    let mut view = MainView::new(&Config::default());
+    view.toggle_filter_focus();
+    toggle(&mut view, StatusFilter::Pending);
+    toggle(&mut view, StatusFilter::CompletedToday);
+    toggle(&mut view, StatusFilter::Deleted);
+    toggle(&mut view, StatusFilter::Completed);
+    view.toggle_closed_tasks();
+    assert_eq!(view.taskwarrior_filter().as_deref(), Some("status:pending"));
+    println!("✅ From All it hides closed tasks; removing every closed status leaves Pending");
+
+    println!();
+    println!("🎉 Status filter checks passed");
+}
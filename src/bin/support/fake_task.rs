@@ -0,0 +1,90 @@
+// Shared by the test programs: a stand-in `task` script first on PATH, and the text of
+// a drawn screen. Pulled in with `#[path = "support/fake_task.rs"] mod fake_task;`.
+
+// Each test program uses only some of it
+#![allow(dead_code)]
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+use lazytask::ui::app_ui::AppUI;
+use ratatui::{backend::TestBackend, buffer::Buffer, Frame, Terminal};
+
+// A temp dir put first on PATH, for scripts standing in for `task` and friends, so the
+// app UI and TaskwarriorIntegration run without Taskwarrior installed. A test's script
+// answers what it runs (`--version`, `export`, `_get`...) and can log the commands
+// LazyTask sends, fail or hang on purpose. Dropping it puts PATH back and removes the dir.
+pub struct FakeTask {
+    dir: PathBuf,
+    original_path: String,
+}
+
+impl FakeTask {
+    // `name` keeps the dirs of different test programs apart
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("lazytask-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).expect("temp dir");
+        let original_path = std::env::var("PATH").unwrap_or_default();
+        std::env::set_var("PATH", format!("{}:{}", dir.display(), original_path));
+        FakeTask { dir, original_path }
+    }
+
+    pub fn with_script(name: &str, script: &str) -> Self {
+        let fake = Self::new(name);
+        fake.set_script(script);
+        fake
+    }
+
+    // `task` runs this from now on
+    pub fn set_script(&self, script: &str) {
+        self.install("task", script);
+    }
+
+    // Any other command the test needs, e.g. xdg-open
+    pub fn install(&self, command: &str, script: &str) {
+        let path = self.dir.join(command);
+        std::fs::write(&path, script).expect("write fake script");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).expect("chmod");
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    // A file in the dir, e.g. a log the script appends to
+    pub fn path(&self, file: &str) -> PathBuf {
+        self.dir.join(file)
+    }
+}
+
+impl Drop for FakeTask {
+    fn drop(&mut self) {
+        std::env::set_var("PATH", &self.original_path);
+        std::fs::remove_dir_all(&self.dir).ok();
+    }
+}
+
+// A frame of `width` by `height` drawn by `draw`
+pub fn frame(width: u16, height: u16, draw: impl FnOnce(&mut Frame)) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("test terminal");
+    terminal.draw(draw).expect("draw");
+    terminal.backend().buffer().clone()
+}
+
+// The symbols of a frame, a line per row
+pub fn text(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .map(|y| (area.left()..area.right()).map(|x| buffer[(x, y)].symbol().to_string()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn render(width: u16, height: u16, draw: impl FnOnce(&mut Frame)) -> String {
+    text(&frame(width, height, draw))
+}
+
+// The whole app as it would show in a terminal of that size
+pub fn screen(ui: &mut AppUI, width: u16, height: u16) -> String {
+    render(width, height, |f| ui.draw(f))
+}
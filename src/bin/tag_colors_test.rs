@@ -0,0 +1,80 @@
+// Test program for per-tag colors from `theme.tag_colors`
+
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::ui::components::task_detail::TaskDetailWidget;
+use lazytask::ui::components::task_list::TaskListWidget;
+use lazytask::ui::themes::{TagColors, DEFAULT_TAG_COLOR};
+use ratatui::{backend::TestBackend, buffer::Buffer, style::Color, Terminal};
+
+fn task(description: &str, tags: &[&str]) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.tags = tags.iter().map(|tag| tag.to_string()).collect();
+    task
+}
+
+// Foreground color of the first cell of `text` on the screen
+fn color_of(buffer: &Buffer, text: &str) -> Color {
+    let area = buffer.area;
+    for y in 0..area.height {
+        let row: Vec<String> = (0..area.width).map(|x| buffer[(x, y)].symbol().to_string()).collect();
+        let line = row.concat();
+        if let Some(byte) = line.find(text) {
+            let x = line[..byte].chars().count() as u16;
+            return buffer[(x, y)].fg;
+        }
+    }
+    panic!("{:?} not on screen", text);
+}
+
+fn main() {
+    println!("🏷️  LazyTask Tag Colors Test");
+    println!("===========================");
+    println!();
+
+    let mut theme = Config::default().theme;
+    theme.tag_colors.insert("urgent".to_string(), "red".to_string());
+    theme.tag_colors.insert("home".to_string(), "#a6e3a1".to_string());
+    theme.tag_colors.insert("later".to_string(), "not-a-color".to_string());
+    let warnings = theme.check_tag_colors();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("theme.tag_colors.later"), "{:?}", warnings);
+    println!("✅ Unknown colors are reported: {}", warnings[0]);
+
+    let colors = TagColors::new(&theme);
+    assert_eq!(colors.color("urgent"), Color::Red);
+    assert_eq!(colors.color("home"), Color::Rgb(0xa6, 0xe3, 0xa1));
+    assert_eq!(colors.color("later"), DEFAULT_TAG_COLOR);
+    assert_eq!(colors.color("misc"), DEFAULT_TAG_COLOR);
+    println!("✅ Names and hex colors are parsed; other tags get the default");
+
+    let mut detail = TaskDetailWidget::new().with_tag_colors(colors.clone());
+    let mut terminal = Terminal::new(TestBackend::new(80, 40)).expect("test terminal");
+    let shown = task("Fix the sink", &["urgent", "misc"]);
+    terminal.draw(|f| detail.render(f, f.area(), Some(&shown))).expect("draw");
+    let buffer = terminal.backend().buffer();
+    assert_eq!(color_of(buffer, "urgent misc"), Color::Red);
+    assert_eq!(color_of(buffer, "misc"), DEFAULT_TAG_COLOR);
+    println!("✅ The detail panel colors each tag");
+
+    detail = TaskDetailWidget::new();
+    terminal.draw(|f| detail.render(f, f.area(), Some(&shown))).expect("draw");
+    assert_eq!(color_of(terminal.backend().buffer(), "urgent misc"), DEFAULT_TAG_COLOR);
+    println!("✅ Without tag colors every tag stays magenta");
+
+    let mut list = TaskListWidget::new(&["tags".to_string(), "description".to_string()]);
+    list.set_tag_colors(colors);
+    list.set_tasks(vec![task("Fix the sink", &["urgent", "home"]), task("Mow", &["home"]), task("Read", &["misc"])]);
+    list.state.select(None);
+    let mut terminal = Terminal::new(TestBackend::new(60, 8)).expect("test terminal");
+    terminal.draw(|f| list.render(f, f.area())).expect("draw");
+    let buffer = terminal.backend().buffer();
+    assert_eq!(color_of(buffer, "urge..."), Color::Red);
+    assert_eq!(color_of(buffer, "..."), Color::White);
+    assert_eq!(color_of(buffer, "home "), Color::Rgb(0xa6, 0xe3, 0xa1));
+    assert_eq!(color_of(buffer, "misc"), Color::White);
+    println!("✅ The Tags column colors configured tags and leaves the rest in the row color");
+
+    println!();
+    println!("🎉 Tag colors test passed!");
+}
@@ -0,0 +1,107 @@
+// Test program for the reports dashboard's tag table: counts per tag, scrolling when many
+// tags don't fit, and Enter to show a tag's tasks.
+
+use lazytask::config::Config;
+use lazytask::data::models::{Tag, Task, TaskStatus, NO_PROJECT};
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+use lazytask::ui::views::reports_view::{DashboardTable, ReportsView};
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+fn task(description: &str, tags: &[&str], status: TaskStatus) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.tags = tags.iter().map(|tag| tag.to_string()).collect();
+    task.status = status;
+    task
+}
+
+fn tasks() -> Vec<Task> {
+    vec![
+        task("Call the bank", &["phone", "errand"], TaskStatus::Pending),
+        task("Call mum", &["phone"], TaskStatus::Pending),
+        task("Buy stamps", &["errand"], TaskStatus::Completed),
+        task("Book dentist", &["phone"], TaskStatus::Waiting),
+        task("Old idea", &["someday"], TaskStatus::Deleted),
+        task("Water plants", &[], TaskStatus::Pending),
+    ]
+}
+
+fn render(view: &mut ReportsView, width: u16, height: u16) -> String {
+    fake_task::render(width, height, |f| view.render(f, f.area()))
+}
+
+fn main() {
+    println!("🏷️  LazyTask Tag Overview Test");
+    println!("=============================");
+    println!();
+
+    let tags = Tag::from_tasks(&tasks());
+    let summary: Vec<(&str, u32, u32)> = tags.iter().map(|tag| (tag.name.as_str(), tag.pending_count, tag.task_count)).collect();
+    assert_eq!(summary, [("phone", 3, 3), ("errand", 1, 2), ("someday", 0, 1)]);
+    println!("✅ Tags are counted (waiting counts as pending) and listed busiest first: {:?}", summary);
+
+    let config = Config::default();
+    let mut view = ReportsView::new(&config);
+    view.update_tasks(tasks());
+    let text = render(&mut view, 160, 40);
+    assert!(text.contains("By Tag"), "{}", text);
+    let phone = text.find("+phone").expect("phone row");
+    let errand = text.find("+errand").expect("errand row");
+    assert!(phone < errand, "{}", text);
+    println!("✅ The dashboard lists the tags next to the projects");
+
+    // More tags than fit: each tag gets one more task than the next
+    let mut many = Vec::new();
+    for n in 0..30 {
+        for i in 0..(31 - n) {
+            many.push(task(&format!("Task {} for t{}", i, n), &[&format!("t{}", n)], TaskStatus::Pending));
+        }
+    }
+    view.update_tasks(many);
+    assert_eq!(view.focused_table(), DashboardTable::Projects);
+    view.switch_table();
+    view.last_row();
+    assert_eq!(view.selected_tag(), Some("t29"));
+    let text = render(&mut view, 160, 40);
+    assert!(text.contains("By Tag (30/30)") && text.contains("+t29") && !text.contains("+t0 "), "{}", text);
+    view.first_row();
+    view.page_down();
+    assert!(!matches!(view.selected_tag(), Some("t0") | Some("t1")), "{:?}", view.selected_tag());
+    assert_eq!(view.selected_project(), Some(NO_PROJECT));
+    println!("✅ Tab moves Up/Down/paging to the tag table, which scrolls like the project table");
+
+    let narrow = render(&mut view, 60, 80);
+    assert!(narrow.contains("By Tag"), "{}", narrow);
+    println!("✅ The narrow layout stacks the tag table under the projects");
+
+    let _fake = FakeTask::with_script("tags", FAKE_TASK);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&config).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(tasks()));
+        ui.handle_action(Action::Reports, &taskwarrior).await.expect("reports");
+        ui.handle_action(Action::Tab, &taskwarrior).await.expect("tag table");
+        ui.handle_action(Action::MoveDown, &taskwarrior).await.expect("errand");
+        ui.handle_action(Action::Select, &taskwarrior).await.expect("show tag");
+        let text = screen(&mut ui, 160, 40);
+        assert!(text.contains("Showing tag +errand"), "{}", text);
+        assert!(text.contains("Call the bank") && !text.contains("Call mum") && !text.contains("Water plants"), "{}", text);
+        println!("✅ Enter on a tag shows its tasks in the list");
+    });
+
+    println!();
+    println!("🎉 Tag overview test passed!");
+}
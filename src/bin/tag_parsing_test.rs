@@ -0,0 +1,65 @@
+// Test program for tag parsing shared by the task form and quick add
+
+use lazytask::data::quick_add::QuickAdd;
+use lazytask::handlers::input::Action;
+use lazytask::ui::components::task_form::{TaskForm, TaskFormResult};
+use lazytask::utils::validation::parse_tags;
+
+fn tags(input: &str) -> Vec<String> {
+    parse_tags(input).unwrap()
+}
+
+fn main() {
+    println!("🏷️  LazyTask Tag Parsing Test");
+    println!("============================");
+    println!();
+
+    assert_eq!(tags("work, home"), vec!["work", "home"]);
+    assert_eq!(tags("work home"), vec!["work", "home"]);
+    assert_eq!(tags(" work,,home  errand "), vec!["work", "home", "errand"]);
+    assert!(tags("  ").is_empty());
+    println!("✅ Commas and whitespace both separate tags");
+
+    assert_eq!(tags("+work -home"), vec!["work", "home"]);
+    assert_eq!(tags("work +work"), vec!["work"]);
+    assert_eq!(tags("next_up, follow-up"), vec!["next_up", "follow-up"]);
+    println!("✅ Leading +/- is dropped and duplicates are listed once");
+
+    for bad in ["a.b", "+", "work, ok!", "++double"] {
+        let error = parse_tags(bad).unwrap_err().to_string();
+        assert!(error.starts_with("Invalid tag"), "{}: {}", bad, error);
+    }
+    println!("✅ Invalid tags are rejected: {}", parse_tags("a.b").unwrap_err());
+
+    // The form refuses to save and moves to the Tags field with the message
+    let mut form = TaskForm::new_task();
+    form.description_input = "Buy milk".to_string();
+    form.tags_input = "errand, bad.tag".to_string();
+    form.is_editing = false;
+    assert!(form.handle_input(Action::Select).unwrap().is_none());
+    assert!(form.error.as_deref().unwrap_or("").contains("bad.tag"));
+    assert!(form.is_editing);
+
+    // Editing clears the message, and a fixed list saves
+    form.handle_input(Action::Backspace).unwrap();
+    assert!(form.error.is_none());
+    form.tags_input = "+errand home".to_string();
+    form.is_editing = false;
+    match form.handle_input(Action::Select).unwrap() {
+        Some(TaskFormResult::Save(task)) => assert_eq!(task.tags, vec!["errand", "home"]),
+        _ => panic!("form should save valid tags"),
+    }
+    println!("✅ Task form shows the error instead of saving malformed tags");
+
+    let parsed = QuickAdd::parse("Buy milk +errand +home").unwrap();
+    assert_eq!(parsed.attributes, vec![
+        ("+errand".to_string(), String::new()),
+        ("+home".to_string(), String::new()),
+    ]);
+    let error = QuickAdd::parse("Buy milk +bad.tag").unwrap_err().to_string();
+    assert!(error.contains("bad.tag"), "{}", error);
+    println!("✅ Quick add uses the same rules: {}", error);
+
+    println!();
+    println!("🎉 Tag parsing checks passed");
+}
@@ -0,0 +1,107 @@
+// Test program for the full-screen task detail view: Enter on a task shows the whole detail
+// panel, e / d / A act on that task and Esc goes back to the list.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::{Priority, Task};
+use lazytask::handlers::input::{Action, InputHandler, KeyContext};
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 40;
+
+fn key(handler: &InputHandler, ui: &AppUI, code: KeyCode) -> Action {
+    handler.handle_key_event_in_view(KeyEvent::new(code, KeyModifiers::NONE), ui.has_active_form(), ui.key_context())
+}
+
+fn main() {
+    println!("🔎 LazyTask Task Detail View Test");
+    println!("=================================");
+    println!();
+
+    let fake = FakeTask::new("task-detail");
+    let log = fake.path("commands");
+    let fake_task = format!(
+        "#!/bin/sh\ncase \"$*\" in\n  *--version*) echo '3.1.0' ;;\n  *done*) echo \"$*\" >> '{}' ;;\nesac\n",
+        log.display()
+    );
+    fake.set_script(&fake_task);
+
+    let mut first = Task::new("Draft the proposal".to_string());
+    first.id = Some(1);
+    let mut second = Task::new("Review the contract".to_string());
+    second.id = Some(2);
+    second.project = Some("legal".to_string());
+    second.priority = Some(Priority::High);
+    second.tags = vec!["signoff".to_string()];
+    // Newest first, so Review the contract is selected at the top
+    first.entry = second.entry - chrono::Duration::hours(1);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let config = Config::default();
+        let handler = InputHandler::new(&config);
+        let mut ui = AppUI::new(&config).expect("app ui");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![first.clone(), second.clone()]));
+
+        let action = key(&handler, &ui, KeyCode::Enter);
+        assert!(matches!(action, Action::Select), "{:?}", action);
+        ui.handle_action(action, &taskwarrior).await.expect("open");
+        assert_eq!(ui.key_context(), KeyContext::TaskDetail);
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Task Details") && text.contains("Review the contract"), "{}", text);
+        assert!(text.contains("legal") && text.contains("signoff") && text.contains(&second.uuid), "{}", text);
+        assert!(!text.contains("Draft the proposal") && !text.contains("Status────"), "{}", text);
+        assert!(text.contains("[e]dit") && text.contains("[A]nnotations") && text.contains("back to list"), "{}", text);
+        println!("✅ Enter shows the selected task's full details across the screen");
+
+        // A manages annotations here rather than quick-adding a new task
+        let action = key(&handler, &ui, KeyCode::Char('A'));
+        assert!(matches!(action, Action::Annotations), "{:?}", action);
+        ui.handle_action(action, &taskwarrior).await.expect("annotations");
+        assert!(ui.has_active_form() && screen(&mut ui, WIDTH, HEIGHT).contains("Annotations (0)"));
+        ui.handle_action(Action::Back, &taskwarrior).await.expect("close");
+        assert!(!ui.has_active_form() && ui.key_context() == KeyContext::TaskDetail);
+        println!("✅ A opens the task's annotations; Esc closes them and stays in the view");
+
+        ui.handle_action(key(&handler, &ui, KeyCode::Char('e')), &taskwarrior).await.expect("edit");
+        assert!(ui.has_active_form());
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Review the contract") && text.contains("Save"), "{}", text);
+        ui.handle_action(Action::Back, &taskwarrior).await.expect("cancel");
+        assert!(!ui.has_active_form() && ui.key_context() == KeyContext::TaskDetail);
+        println!("✅ e edits the task shown");
+
+        ui.handle_action(key(&handler, &ui, KeyCode::Char('d')), &taskwarrior).await.expect("done");
+        let commands = std::fs::read_to_string(&log).expect("task was run");
+        assert!(commands.contains("2 done"), "{}", commands);
+        println!("✅ d marks it done: task {}", commands.trim());
+
+        // Task list keys that don't act on the task do nothing here
+        ui.handle_action(key(&handler, &ui, KeyCode::Char('a')), &taskwarrior).await.expect("add");
+        assert!(!ui.has_active_form());
+        println!("✅ Other task list keys are ignored in the view");
+
+        ui.handle_action(key(&handler, &ui, KeyCode::Esc), &taskwarrior).await.expect("back");
+        assert_eq!(ui.key_context(), KeyContext::TaskList);
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Draft the proposal") && text.contains("Review the contract"), "{}", text);
+        println!("✅ Esc returns to the list");
+
+        ui.start_loading(None);
+        ui.finish_loading(Ok(Vec::new()));
+        ui.handle_action(key(&handler, &ui, KeyCode::Enter), &taskwarrior).await.expect("enter");
+        assert_eq!(ui.key_context(), KeyContext::TaskList);
+        println!("✅ Enter on an empty list stays on the list");
+    });
+
+    println!();
+    println!("🎉 Task detail view test passed!");
+}
@@ -0,0 +1,82 @@
+// Test program for Taskwarrior commands that would stop to ask a question or hang:
+// they must pass the no-prompt overrides first and never wait on stdin.
+
+use std::time::{Duration, Instant};
+
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::{TaskwarriorCLI, TaskwarriorIntegration};
+use lazytask::ui::app_ui::AppUI;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{screen, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+  *hang*|*' 7 done') sleep 5 ;;
+  'rc.confirmation=no rc.recurrence.confirmation=no rc.bulk=0 '*) echo 'Created task 3.' ;;
+  *export*)
+    printf 'Proceed? (yes/no) '
+    read answer || { echo 'no answer' >&2; exit 1; }
+    echo '[]' ;;
+  *) echo \"asked for confirmation: $*\" >&2; exit 1 ;;
+esac
+";
+
+fn main() {
+    println!("🙋 LazyTask Task Prompt Test");
+    println!("===========================");
+    println!();
+
+    let _fake = FakeTask::with_script("prompt", FAKE_TASK);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let cli = TaskwarriorCLI::new(None, None);
+
+        cli.modify_task(1, &[("project", "home")]).await.expect("modify");
+        cli.modify_task_by_uuid("a1b2", &[("due", "")]).await.expect("modify by uuid");
+        cli.done_task(1).await.expect("done");
+        cli.delete_task(1).await.expect("delete");
+        cli.annotate_task("a1b2", "call back").await.expect("annotate");
+        cli.denotate_task("a1b2", "call back").await.expect("denotate");
+        assert_eq!(cli.add_task("Water plants", &[]).await.expect("add"), 3);
+        println!("✅ Every command that changes tasks turns confirmations off");
+
+        let started = Instant::now();
+        let error = cli.list_tasks(Some("+ask")).await.unwrap_err();
+        assert!(error.to_string().contains("no answer"), "{}", error);
+        assert!(started.elapsed() < Duration::from_secs(2));
+        println!("✅ A prompt that slips through reads end of input instead of waiting");
+
+        let started = Instant::now();
+        let error = cli
+            .with_timeout(Some(Duration::from_secs(1)))
+            .modify_task(1, &[("hang", "")])
+            .await
+            .unwrap_err();
+        let elapsed = started.elapsed();
+        assert!(error.to_string().contains("did not finish within 1s"), "{}", error);
+        assert!(elapsed >= Duration::from_millis(900) && elapsed < Duration::from_secs(3), "{:?}", elapsed);
+        println!("✅ A hung command is stopped after the timeout: {}", error);
+
+        // In the app a timed-out change lands in the status bar rather than ending it
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task").with_command_timeout(1);
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        let mut task = Task::new("Water plants".to_string());
+        task.id = Some(7);
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![task]));
+        ui.handle_action(Action::DoneTask, &taskwarrior).await.expect("done reports its own errors");
+        let text = screen(&mut ui, 160, 40);
+        assert!(text.contains("did not finish within 1s and was stopped"), "{}", text);
+        assert!(text.contains("Water plants"), "{}", text);
+        println!("✅ A timed-out action shows in the status bar and the app keeps going");
+    });
+
+    println!();
+    println!("🎉 Task prompt test passed!");
+}
@@ -0,0 +1,65 @@
+// Test program for the Taskwarrior JSON export (the format `task import` reads)
+
+use chrono::{TimeZone, Utc};
+use lazytask::data::export::{ExportFormat, TaskExporter};
+use lazytask::data::models::{format_taskwarrior_datetime, Annotation, Priority, Task, TaskStatus};
+use serde_json::Value;
+
+fn main() {
+    println!("📤 LazyTask Taskwarrior Export Test");
+    println!("===================================");
+    println!();
+
+    let entry = Utc.with_ymd_and_hms(2025, 10, 7, 19, 29, 37).unwrap();
+    assert_eq!(format_taskwarrior_datetime(&entry), "20251007T192937Z");
+    println!("✅ Dates use Taskwarrior's compact format: {}", format_taskwarrior_datetime(&entry));
+
+    let dependency = Task::new("Order the parts".to_string());
+    let mut task = Task::new("Assemble the shelf".to_string());
+    task.id = Some(7);
+    task.urgency = 8.5;
+    task.entry = entry;
+    task.due = Some(Utc.with_ymd_and_hms(2025, 10, 20, 17, 0, 0).unwrap());
+    task.status = TaskStatus::Waiting;
+    task.priority = Some(Priority::High);
+    task.project = Some("home".to_string());
+    task.tags = vec!["diy".to_string()];
+    task.depends = vec![dependency.uuid.clone()];
+    task.annotations = vec![Annotation { entry, description: "Needs a drill".to_string() }];
+
+    let path = std::env::temp_dir().join(format!("lazytask-taskwarrior-export-{}.json", std::process::id()));
+    TaskExporter::export_to_file(&[task.clone(), dependency], &path, ExportFormat::Taskwarrior).expect("export");
+
+    let text = std::fs::read_to_string(&path).expect("read export");
+    let json: Vec<Value> = serde_json::from_str(&text).expect("export is a JSON array");
+    assert_eq!(json.len(), 2);
+    let exported = &json[0];
+    assert_eq!(exported["status"], "waiting");
+    assert_eq!(exported["priority"], "H");
+    assert_eq!(exported["entry"], "20251007T192937Z");
+    assert_eq!(exported["due"], "20251020T170000Z");
+    assert_eq!(exported["depends"][0], task.depends[0].as_str());
+    assert_eq!(exported["annotations"][0]["entry"], "20251007T192937Z");
+    println!("✅ Status is lowercase, priority is H/M/L and every date is compact");
+
+    assert!(exported.get("id").is_none() && exported.get("urgency").is_none());
+    assert!(json[1].get("priority").is_none() && json[1].get("tags").is_none() && json[1].get("due").is_none());
+    println!("✅ id, urgency and unset fields are left out");
+
+    let imported = TaskExporter::import_from_file(&path, ExportFormat::Taskwarrior).expect("import");
+    let round_trip = &imported[0];
+    assert_eq!(round_trip.uuid, task.uuid);
+    assert_eq!(round_trip.status, TaskStatus::Waiting);
+    assert_eq!(round_trip.priority, Some(Priority::High));
+    assert_eq!(round_trip.entry, task.entry);
+    assert_eq!(round_trip.due, task.due);
+    assert_eq!(round_trip.tags, task.tags);
+    assert_eq!(round_trip.depends, task.depends);
+    assert_eq!(round_trip.annotations[0].description, "Needs a drill");
+    println!("✅ The export reads back through the Taskwarrior JSON parser unchanged");
+
+    std::fs::remove_file(&path).ok();
+
+    println!();
+    println!("🎉 Taskwarrior export checks passed");
+}
@@ -0,0 +1,120 @@
+// Test program for the built-in themes, the theme key (Ctrl+t) and writing the chosen
+// theme back to the config file.
+
+use lazytask::config::{with_theme_name, Config};
+use lazytask::handlers::input::Action;
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+use lazytask::ui::themes::Theme;
+use ratatui::{buffer::Buffer, style::Color};
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{text, FakeTask};
+
+const FAKE_TASK: &str = "#!/bin/sh
+case \"$*\" in
+  *--version*) echo '3.1.0' ;;
+esac
+";
+
+const WIDTH: u16 = 120;
+const HEIGHT: u16 = 30;
+
+fn frame(ui: &mut AppUI) -> Buffer {
+    fake_task::frame(WIDTH, HEIGHT, |f| ui.draw(f))
+}
+
+fn uses(buffer: &Buffer, color: Color) -> bool {
+    buffer.content.iter().any(|cell| cell.fg == color || cell.bg == color)
+}
+
+fn main() {
+    println!("🎨 LazyTask Theme Test");
+    println!("=====================");
+    println!();
+
+    assert_eq!(
+        Theme::names(),
+        ["terminal", "catppuccin-mocha", "catppuccin-latte", "gruvbox", "dracula", "high-contrast"]
+    );
+    assert_eq!(Theme::by_name(" Gruvbox ").map(|theme| theme.id), Some("gruvbox"));
+    assert!(Theme::by_name("solarized").is_none());
+    println!("✅ Built-in themes: {}", Theme::names().join(", "));
+
+    let fake = FakeTask::with_script("theme", FAKE_TASK);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.finish_loading(Ok(Vec::new()));
+        let plain = frame(&mut ui);
+        assert!(uses(&plain, Color::Cyan) && uses(&plain, Color::Yellow));
+        assert!(plain.content.iter().all(|cell| !matches!(cell.fg, Color::Rgb(..))));
+        println!("✅ The default terminal theme keeps the terminal's own colors");
+
+        let mut config = Config::default();
+        config.theme.name = "gruvbox".to_string();
+        let mut ui = AppUI::new(&config).expect("app ui");
+        ui.finish_loading(Ok(Vec::new()));
+        let themed = frame(&mut ui);
+        assert_eq!(text(&themed), text(&plain));
+        let gruvbox = Theme::gruvbox();
+        assert!(!uses(&themed, Color::Cyan) && !uses(&themed, Color::Yellow) && !uses(&themed, Color::Reset));
+        assert!(uses(&themed, gruvbox.get_color("primary")) && uses(&themed, gruvbox.get_color("background")));
+        println!("✅ theme.name = \"gruvbox\" recolors the same screen with its palette");
+
+        config.theme.colors.insert("primary".to_string(), "#ff0000".to_string());
+        let mut ui = AppUI::new(&config).expect("app ui");
+        ui.finish_loading(Ok(Vec::new()));
+        let overridden = frame(&mut ui);
+        assert!(uses(&overridden, Color::Rgb(255, 0, 0)) && !uses(&overridden, gruvbox.get_color("primary")));
+        println!("✅ theme.colors overrides a role on top of the theme");
+
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        ui.finish_loading(Ok(Vec::new()));
+        assert_eq!(ui.switched_theme(), None);
+        ui.handle_action(Action::CycleTheme, &taskwarrior).await.expect("next theme");
+        assert_eq!(ui.switched_theme(), Some("catppuccin-mocha"));
+        let mocha = frame(&mut ui);
+        assert!(text(&mocha).contains("Theme: Catppuccin Mocha"), "{}", text(&mocha));
+        assert!(uses(&mocha, Theme::catppuccin_mocha().get_color("background")));
+        for _ in 1..Theme::names().len() {
+            ui.handle_action(Action::CycleTheme, &taskwarrior).await.expect("next theme");
+        }
+        assert_eq!(ui.switched_theme(), Some("terminal"));
+        assert!(!frame(&mut ui).content.iter().any(|cell| matches!(cell.bg, Color::Rgb(..))));
+        println!("✅ Ctrl+t cycles through every theme and back, redrawing in each");
+    });
+
+    // Writing the choice back keeps the rest of the file
+    let original = "# my setup\n[theme]\nname = \"dracula\"  # dark\n\n[theme.colors]\nname = \"not this one\"\n\n[ui]\nvim_keys = true\n";
+    let updated = with_theme_name(original, "gruvbox");
+    assert_eq!(updated, original.replace("name = \"dracula\"  # dark", "name = \"gruvbox\""));
+    assert_eq!(with_theme_name("[theme]\n\n[ui]\n", "dracula"), "[theme]\nname = \"dracula\"\n\n[ui]\n");
+    assert_eq!(with_theme_name("[ui]\nvim_keys = true", "dracula"), "[ui]\nvim_keys = true\n\n[theme]\nname = \"dracula\"\n");
+    println!("✅ Only theme.name changes in the file; a missing key or table is added");
+
+    let path = fake.path("config.toml");
+    let mut config = Config::default();
+    config.theme.name = "solarized".to_string();
+    config.theme.colors.insert("border".to_string(), "red".to_string());
+    config.theme.colors.insert("error".to_string(), "not-a-color".to_string());
+    config.save(&path).expect("write config");
+    let loaded = Config::load(path.to_str()).expect("config with a bad theme still loads");
+    assert_eq!(loaded.warnings.len(), 3, "{:?}", loaded.warnings);
+    assert!(loaded.warnings.iter().any(|warning| warning.starts_with("Unknown theme \"solarized\"")));
+    assert!(loaded.theme.colors.is_empty());
+    println!("✅ Unknown themes, roles and colors are reported: {}", loaded.warnings[0]);
+
+    loaded.save_theme_name("high-contrast").expect("save theme");
+    let reloaded = Config::load(path.to_str()).expect("reload");
+    assert_eq!(reloaded.theme.name, "high-contrast");
+    assert_eq!(Theme::from_config(&reloaded.theme).id, "high-contrast");
+    println!("✅ The theme picked on quit is the one loaded next time");
+
+    println!();
+    println!("🎉 Theme test passed!");
+}
@@ -0,0 +1,91 @@
+// Test program for the dashboard's time panel: time worked per day from `start` and `end`,
+// with running tasks counted up to now and stretches past midnight split between days
+
+use chrono::{Duration, Utc};
+use lazytask::config::Config;
+use lazytask::data::models::{Task, TaskStatus};
+use lazytask::ui::components::report_panel::TaskSummaryCache;
+use lazytask::ui::views::reports_view::ReportsView;
+use lazytask::utils::formatting::{format_hours_minutes, start_of_day, today};
+use ratatui::{backend::TestBackend, Terminal};
+
+fn render(view: &mut ReportsView, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("test terminal");
+    terminal.draw(|f| view.render(f, f.area())).expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| (0..width).map(|x| buffer[(x, y)].symbol().to_string()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn worked(description: &str, status: TaskStatus, start: chrono::DateTime<Utc>, end: Option<chrono::DateTime<Utc>>) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.status = status;
+    task.start = Some(start);
+    task.end = end;
+    task
+}
+
+fn main() {
+    println!("⏱️  LazyTask Time Tracking Test");
+    println!("==============================");
+    println!();
+
+    assert_eq!(format_hours_minutes(Duration::minutes(45)), "45m");
+    assert_eq!(format_hours_minutes(Duration::minutes(125)), "2h 05m");
+    assert_eq!(format_hours_minutes(Duration::seconds(-30)), "0m");
+    println!("✅ Durations read as 45m or 2h 05m");
+
+    let now = Utc::now();
+    let midnight = start_of_day(today());
+    // Running since midnight, so all of it is today's
+    let running = worked("Write the release notes", TaskStatus::Pending, midnight, None);
+    // 23:00 yesterday to 00:30 today
+    let late = worked("Fix the build", TaskStatus::Completed, midnight - Duration::hours(1), Some(midnight + Duration::minutes(30)));
+    let two_days_ago = midnight - Duration::days(2) + Duration::hours(9);
+    let earlier = worked("Review budgets", TaskStatus::Completed, two_days_ago, Some(two_days_ago + Duration::hours(2)));
+    let old = worked("Plan the move", TaskStatus::Completed, midnight - Duration::days(20), Some(midnight - Duration::days(20) + Duration::hours(3)));
+    let deleted = worked("Abandoned", TaskStatus::Deleted, midnight - Duration::hours(5), Some(midnight - Duration::hours(4)));
+    let mut no_start = Task::new("Never started".to_string());
+    no_start.status = TaskStatus::Completed;
+    no_start.end = Some(now);
+
+    assert_eq!(running.tracked_interval(now), Some((midnight, now)));
+    assert_eq!(late.tracked_interval(now), Some((midnight - Duration::hours(1), midnight + Duration::minutes(30))));
+    assert_eq!(deleted.tracked_interval(now), None);
+    assert_eq!(no_start.tracked_interval(now), None);
+    println!("✅ Active tasks count from start to now, completed ones from start to end");
+
+    let tasks = vec![running.clone(), late, earlier, old, deleted, no_start];
+    let cache = TaskSummaryCache::from_tasks(&tasks, 1);
+    assert_eq!(cache.daily_time.len(), 7);
+    let expected_today = Duration::minutes(30) + (Utc::now() - midnight);
+    assert!((cache.daily_time[6] - expected_today).num_minutes().abs() <= 1, "{:?}", cache.daily_time);
+    assert_eq!(cache.daily_time[5], Duration::hours(1));
+    assert_eq!(cache.daily_time[4], Duration::hours(2));
+    assert!(cache.daily_time[..4].iter().all(|time| time.is_zero()), "{:?}", cache.daily_time);
+    println!("✅ Time is added up per day; a stretch past midnight counts towards both days");
+
+    let mut view = ReportsView::new(&Config::default());
+    view.update_tasks(tasks.clone());
+    let text = render(&mut view, 160, 44);
+    assert!(text.contains("Time Tracked (from start/end)"), "{}", text);
+    assert!(text.contains(&format!("Today: {}", format_hours_minutes(cache.daily_time[6]))), "{}", text);
+    assert!(text.contains("▶") && text.contains("Write the release notes"), "{}", text);
+    let yesterday = (today() - Duration::days(1)).format("%a %m-%d").to_string();
+    assert!(text.contains(&format!("{}  1h 00m █", yesterday)), "{}", text);
+    println!("✅ The dashboard shows today, the running task and a bar per day");
+
+    let text = render(&mut view, 90, 80);
+    assert!(text.contains("Time Tracked") && text.contains("Today:"), "{}", text);
+    println!("✅ The narrow layout has the panel too");
+
+    view.update_tasks(vec![Task::new("Just an idea".to_string())]);
+    let text = render(&mut view, 160, 44);
+    assert!(text.contains("Today: 0m") && text.contains("Nothing started in this time"), "{}", text);
+    println!("✅ Without started tasks the panel says how to start the clock");
+
+    println!();
+    println!("🎉 Time tracking test passed!");
+}
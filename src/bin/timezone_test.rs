@@ -0,0 +1,86 @@
+// Test program for local-time days: dates are shown in the local time zone and "today",
+// due groups and calendar days start at local midnight, unless ui.use_utc is set.
+// Typed dates and times are read in local time too. TZ is set to a zone ahead of UTC,
+// so the local day and the UTC day differ.
+
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::data::sort::DueGroup;
+use lazytask::ui::components::task_form::TaskForm;
+use lazytask::ui::components::calendar_view::CalendarWidget;
+use lazytask::utils::formatting::{day_of, set_use_utc, start_of_day, today, DateFormat};
+use ratatui::{backend::TestBackend, Terminal};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+}
+
+fn due_task(description: &str, due: chrono::DateTime<Utc>) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.due = Some(due);
+    task
+}
+
+// The calendar's daily stats panel for the selected day
+fn calendar_day(selected: chrono::DateTime<Utc>, tasks: Vec<Task>) -> String {
+    let calendar = CalendarWidget::new(selected, tasks, DateFormat::new(&Config::default().ui));
+    let mut terminal = Terminal::new(TestBackend::new(120, 50)).expect("test terminal");
+    terminal.draw(|f| calendar.render(f, f.area())).expect("draw");
+    terminal.backend().buffer().content.iter().map(|cell| cell.symbol()).collect()
+}
+
+fn main() {
+    println!("🌏 LazyTask Time Zone Test");
+    println!("=========================");
+    println!();
+
+    // New Zealand summer time, UTC+13: 20:00 UTC on the 14th is 09:00 on the 15th
+    std::env::set_var("TZ", "Pacific/Auckland");
+    let morning = Utc.with_ymd_and_hms(2030, 3, 14, 20, 0, 0).unwrap();
+    assert!(!Config::default().ui.use_utc);
+    assert_eq!(day_of(&morning), date(2030, 3, 15));
+    let dates = DateFormat::new(&Config::default().ui);
+    assert_eq!(dates.datetime(&morning), "2030-03-15 09:00");
+    assert_eq!(today(), day_of(&Utc::now()));
+    println!("✅ Dates are shown in local time: {} UTC is {}", morning.format("%Y-%m-%d %H:%M"), dates.datetime(&morning));
+
+    let start = start_of_day(date(2030, 3, 15));
+    assert_eq!(start, Utc.with_ymd_and_hms(2030, 3, 14, 11, 0, 0).unwrap());
+    assert_eq!(day_of(&(start - Duration::seconds(1))), date(2030, 3, 14));
+    println!("✅ A day starts at local midnight ({} UTC)", start.format("%Y-%m-%d %H:%M"));
+
+    // Typed dates and times mean the local clock; the form shows them back the same way
+    let typed = TaskForm::parse_taskwarrior_date("2030-03-15 17:00").expect("date and time");
+    assert_eq!(typed, Utc.with_ymd_and_hms(2030, 3, 15, 4, 0, 0).unwrap());
+    assert_eq!(TaskForm::parse_taskwarrior_date("2030-03-15T17:00"), Some(typed));
+    assert_eq!(TaskForm::parse_taskwarrior_date("2030-03-15"), Some(start));
+    assert_eq!(TaskForm::parse_taskwarrior_date("tomorrow"), Some(start_of_day(today() + Duration::days(1))));
+    let tomorrow_morning = TaskForm::parse_taskwarrior_date("tomorrow 9:30").expect("tomorrow");
+    assert_eq!(dates.datetime(&tomorrow_morning), format!("{} 09:30", today() + Duration::days(1)));
+    assert_eq!(TaskForm::edit_task(due_task("Book the ferry", typed)).due_input, "2030-03-15 17:00");
+    assert_eq!(TaskForm::edit_task(due_task("Book the ferry", start)).due_input, "2030-03-15");
+    println!("✅ Typed dates are read in local time: 2030-03-15 17:00 is {} UTC", typed.format("%Y-%m-%d %H:%M"));
+
+    // Due 15:00 local on the 15th (02:00 UTC): today here, but tomorrow by the UTC clock
+    let afternoon = due_task("Pick up the keys", Utc.with_ymd_and_hms(2030, 3, 15, 2, 0, 0).unwrap());
+    let last_night = due_task("Water the plants", Utc.with_ymd_and_hms(2030, 3, 14, 9, 0, 0).unwrap());
+    assert_eq!(DueGroup::of(&afternoon, morning), DueGroup::Today);
+    let shown = calendar_day(morning, vec![afternoon.clone(), last_night.clone()]);
+    assert!(shown.contains("Pick up the keys") && !shown.contains("Water the plants"), "{}", shown);
+    println!("✅ Due today and the calendar follow the local day");
+
+    set_use_utc(true);
+    assert_eq!(day_of(&morning), date(2030, 3, 14));
+    assert_eq!(dates.datetime(&morning), "2030-03-14 20:00");
+    assert_eq!(start_of_day(date(2030, 3, 15)), Utc.with_ymd_and_hms(2030, 3, 15, 0, 0, 0).unwrap());
+    assert_eq!(DueGroup::of(&afternoon, morning), DueGroup::ThisWeek);
+    assert_eq!(TaskForm::parse_taskwarrior_date("2030-03-15 17:00"), Some(Utc.with_ymd_and_hms(2030, 3, 15, 17, 0, 0).unwrap()));
+    let shown = calendar_day(morning, vec![afternoon.clone(), last_night.clone()]);
+    assert!(shown.contains("Water the plants") && !shown.contains("Pick up the keys"), "{}", shown);
+    println!("✅ ui.use_utc shows times and counts days in UTC");
+    set_use_utc(false);
+
+    println!();
+    println!("🎉 Time zone test passed!");
+}
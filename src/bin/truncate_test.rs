@@ -0,0 +1,35 @@
+// Test program to verify multibyte-safe text truncation
+
+use lazytask::utils::formatting::truncate_text;
+
+fn main() {
+    println!("✂️  LazyTask Truncation Test");
+    println!("===========================");
+    println!();
+
+    let cases: [(&str, usize, &str); 6] = [
+        ("short", 10, "short"),
+        ("exactly ten", 11, "exactly ten"),
+        ("a plain ascii description", 10, "a plain..."),
+        ("🎉🎉🎉🎉🎉🎉🎉🎉", 5, "🎉🎉..."),
+        ("買い物リストを更新する", 8, "買い物リス..."),
+        ("café crème brûlée", 8, "café ..."),
+    ];
+
+    for (input, max_length, expected) in cases {
+        let result = truncate_text(input, max_length);
+        assert_eq!(result, expected, "truncate_text({:?}, {})", input, max_length);
+        assert!(result.chars().count() <= max_length.max(3));
+        println!("✅ {:?} ({}) -> {:?}", input, max_length, result);
+    }
+
+    // Every cut point across a multibyte string must be safe
+    let mixed = "Fix 🐛 in 日本語 parser — naïve";
+    for max_length in 0..=mixed.chars().count() + 2 {
+        let _ = truncate_text(mixed, max_length);
+    }
+    println!("✅ All cut points of {:?} are safe", mixed);
+
+    println!();
+    println!("🎉 Truncation is multibyte-safe!");
+}
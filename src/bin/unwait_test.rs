@@ -0,0 +1,90 @@
+// Test program for the wait countdown in the detail panel and unwaiting with `W`.
+
+use chrono::{Duration, Utc};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazytask::config::Config;
+use lazytask::data::models::Task;
+use lazytask::handlers::input::{Action, InputHandler};
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::ui::app_ui::AppUI;
+use lazytask::ui::components::task_detail::TaskDetailWidget;
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::{render, screen, FakeTask};
+
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 30;
+
+fn waiting_task(days: i64) -> Task {
+    let mut task = Task::new("Renew the lease".to_string());
+    task.id = Some(4);
+    // A little over, so the countdown doesn't tick down a day mid-test
+    task.wait = Some(Utc::now() + Duration::days(days) + Duration::minutes(5));
+    task
+}
+
+fn main() {
+    println!("⏳ LazyTask Unwait Test");
+    println!("======================");
+    println!();
+
+    let handler = InputHandler::new(&Config::default());
+    let key = handler.handle_key_event_with_context(KeyEvent::new(KeyCode::Char('W'), KeyModifiers::SHIFT), false);
+    assert!(matches!(key, Action::Unwait));
+    println!("✅ W unwaits the selected task");
+
+    let task = waiting_task(3);
+    let text = render(80, 30, |f| TaskDetailWidget::new().render(f, f.area(), Some(&task)));
+    assert!(text.contains("Waiting until") && text.contains("(in 3d)"), "{}", text);
+
+    let mut expired = waiting_task(0);
+    expired.wait = Some(Utc::now() - Duration::hours(1));
+    let text = render(80, 30, |f| TaskDetailWidget::new().render(f, f.area(), Some(&expired)));
+    assert!(!text.contains("Waiting until"), "{}", text);
+    println!("✅ The detail panel counts down to a future wait date only");
+
+    let fake = FakeTask::new("unwait");
+    let log = fake.path("commands");
+    let fake_task = format!(
+        "#!/bin/sh\ncase \"$*\" in\n  *--version*) echo '3.1.0' ;;\n  *modify*) echo \"$*\" >> '{}' ;;\nesac\n",
+        log.display()
+    );
+    fake.set_script(&fake_task);
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+        let mut ui = AppUI::new(&Config::default()).expect("app ui");
+        let mut plain = Task::new("Water plants".to_string());
+        plain.id = Some(5);
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![plain]));
+
+        ui.handle_action(Action::Unwait, &taskwarrior).await.expect("unwait");
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("The selected task isn't waiting"));
+        assert!(!log.exists());
+        println!("✅ A task that isn't waiting is left alone");
+
+        // Tick Waiting in the Status filter, so the waiting task is in the list to select
+        ui.handle_action(Action::Filter, &taskwarrior).await.expect("filter panel");
+        for _ in 0..8 {
+            ui.handle_action(Action::MoveDown, &taskwarrior).await.expect("down");
+        }
+        ui.handle_action(Action::Space, &taskwarrior).await.expect("waiting");
+        ui.handle_action(Action::Filter, &taskwarrior).await.expect("apply");
+        ui.start_loading(None);
+        ui.finish_loading(Ok(vec![task.clone()]));
+        let text = screen(&mut ui, WIDTH, HEIGHT);
+        assert!(text.contains("Renew the lease"), "{}", text);
+
+        ui.handle_action(Action::Unwait, &taskwarrior).await.expect("unwait");
+        let commands = std::fs::read_to_string(&log).expect("task was run");
+        assert!(commands.contains(&format!("{} modify wait:", task.uuid)), "{}", commands);
+        assert!(screen(&mut ui, WIDTH, HEIGHT).contains("Task 4 is pending again"));
+        println!("✅ Unwaiting clears the wait date: task {}", commands.trim());
+    });
+
+    println!();
+    println!("🎉 Unwait test passed!");
+}
@@ -0,0 +1,63 @@
+// Test program for the urgency bar in the task detail panel
+
+use lazytask::data::models::Task;
+use lazytask::ui::components::task_detail::TaskDetailWidget;
+use ratatui::{backend::TestBackend, style::Color, Terminal};
+
+// The detail panel's urgency row, and the color of its first bar cell
+fn urgency_row(urgency: f64, width: u16) -> (String, Option<Color>) {
+    let mut task = Task::new("Pay rent".to_string());
+    task.id = Some(1);
+    task.urgency = urgency;
+
+    let mut terminal = Terminal::new(TestBackend::new(width, 40)).expect("test terminal");
+    terminal
+        .draw(|f| TaskDetailWidget::new().render(f, f.area(), Some(&task)))
+        .expect("draw");
+    let buffer = terminal.backend().buffer();
+
+    (0..40)
+        .map(|y| (0..width).map(|x| buffer[(x, y)].symbol().to_string()).collect::<String>())
+        .enumerate()
+        .find(|(_, row)| row.contains("Urgency"))
+        .map(|(y, row)| {
+            let bar_x = row.chars().position(|c| c == '█' || c == '░');
+            let color = bar_x.map(|x| buffer[(x as u16, y as u16)].fg);
+            (row, color)
+        })
+        .expect("urgency row")
+}
+
+fn main() {
+    println!("📊 LazyTask Urgency Bar Test");
+    println!("===========================");
+    println!();
+
+    assert_eq!(TaskDetailWidget::urgency_bar(0.0, 10), "░░░░░░░░░░");
+    assert_eq!(TaskDetailWidget::urgency_bar(-3.0, 10), "░░░░░░░░░░");
+    assert_eq!(TaskDetailWidget::urgency_bar(10.0, 10), "█████░░░░░");
+    assert_eq!(TaskDetailWidget::urgency_bar(20.0, 10), "██████████");
+    assert_eq!(TaskDetailWidget::urgency_bar(250.0, 10), "██████████");
+    assert_eq!(TaskDetailWidget::urgency_bar(7.0, 0), "");
+    println!("✅ The bar is proportional up to 20, empty at 0 or below, full past the max");
+
+    let (row, color) = urgency_row(12.5, 60);
+    assert!(row.contains("12.5 ") && row.contains("█"), "{}", row);
+    assert_eq!(color, Some(Color::Red));
+    assert_eq!(row.chars().filter(|&c| c == '█' || c == '░').count(), 20);
+    println!("✅ Wide panel: {}", row.trim_end_matches([' ', '│']).trim());
+
+    assert_eq!(urgency_row(6.0, 60).1, Some(Color::Yellow));
+    assert_eq!(urgency_row(0.0, 60).1, Some(Color::Green));
+    println!("✅ Bar colors follow the existing thresholds (green < 5 ≤ yellow < 10 ≤ red)");
+
+    for width in [34, 24, 21] {
+        let (row, _) = urgency_row(999.9, width);
+        assert!(row.contains("999.9"), "width {}: {}", width, row);
+        assert!(row.ends_with('│'), "width {} overflowed: {}", width, row);
+    }
+    println!("✅ Narrow panels shrink the bar (or drop it) and keep the number");
+
+    println!();
+    println!("🎉 Urgency bar test passed!");
+}
@@ -0,0 +1,95 @@
+// Test program for urgency coefficients: read from the taskrc with one `task _get` at
+// startup, and used by calculate_urgency (Taskwarrior's tag factor and due date curve)
+// for exported tasks that come without an urgency.
+
+use chrono::{Duration, Utc};
+use lazytask::data::models::{Priority, Task};
+use lazytask::taskwarrior::TaskwarriorIntegration;
+use lazytask::utils::helpers::{calculate_urgency, UrgencyCoefficients};
+
+#[path = "support/fake_task.rs"]
+mod fake_task;
+use fake_task::FakeTask;
+
+// Due, start and so on are left out so the urgency is priority alone
+const EXPORT: &str = r#"[{"uuid":"a1b2c3d4-0000-4000-8000-000000000001","description":"Renew the passport","status":"pending","entry":"20250101T000000Z","priority":"H"},{"uuid":"a1b2c3d4-0000-4000-8000-000000000002","description":"Book the dentist","status":"pending","entry":"20250101T000000Z","urgency":3.5}]"#;
+
+fn close(actual: f64, expected: f64) -> bool {
+    (actual - expected).abs() < 0.01
+}
+
+fn main() {
+    println!("📈 LazyTask Urgency Coefficients Test");
+    println!("=====================================");
+    println!();
+
+    let fake = FakeTask::new("urgency-coefficients");
+    let log = fake.path("commands");
+    // High priority raised to 10 and due to 20 in the taskrc; the rest as shipped.
+    // The export has one task with Taskwarrior's urgency and one without.
+    let fake_task = format!(
+        "#!/bin/sh\ncase \"$*\" in\n  *--version*) echo '3.1.0' ;;\n  *_get*urgency*) echo \"$*\" >> '{}'; printf '10.0\\n3.9\\n1.8\\n1.0\\n4.0\\n1.0\\n20.0\\n' ;;\n  *export*) echo '{}' ;;\nesac\n",
+        log.display(),
+        EXPORT
+    );
+    fake.set_script(&fake_task);
+
+    let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+    let commands = std::fs::read_to_string(&log).expect("task _get was run");
+    assert_eq!(commands.lines().count(), 1, "{}", commands);
+    assert!(UrgencyCoefficients::KEYS.iter().all(|key| commands.contains(&format!("rc.{}", key))), "{}", commands);
+    println!("✅ One task _get reads every coefficient: {}", commands.trim());
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    let tasks = runtime.block_on(taskwarrior.list_tasks(None)).expect("export");
+    let urgency = |description: &str| tasks.iter().find(|task| task.description == description).expect("exported").urgency;
+    assert!(close(urgency("Renew the passport"), 10.0), "{:?}", tasks);
+    assert!(close(urgency("Book the dentist"), 3.5), "{:?}", tasks);
+    println!("✅ A task exported without an urgency gets one from the taskrc coefficients: {:.1}", urgency("Renew the passport"));
+
+    let defaults = UrgencyCoefficients::default();
+    let mut task = Task::new("Renew the passport".to_string());
+    task.priority = Some(Priority::High);
+    assert!(close(calculate_urgency(&task, &defaults), 6.0));
+    task.project = Some("Travel".to_string());
+    assert!(close(calculate_urgency(&task, &defaults), 7.0));
+    println!("✅ Priority and project add their coefficients and nothing else");
+
+    let with_tags = |count: usize| {
+        let mut task = Task::new("Pack".to_string());
+        task.tags = (0..count).map(|n| format!("tag{}", n)).collect();
+        calculate_urgency(&task, &defaults)
+    };
+    assert!(close(with_tags(0), 0.0) && close(with_tags(1), 0.8) && close(with_tags(2), 0.9));
+    assert!(close(with_tags(3), 1.0) && close(with_tags(5), 1.0));
+    println!("✅ Tags count 0.8, 0.9 and 1.0 times the coefficient for one, two and three or more");
+
+    // The due term alone: the task has no priority, project, tags or start
+    let due_term = |due: Option<chrono::DateTime<Utc>>| {
+        let mut task = Task::new("File the taxes".to_string());
+        task.due = due;
+        calculate_urgency(&task, &defaults) - calculate_urgency(&Task::new(String::new()), &defaults)
+    };
+    let now = Utc::now();
+    assert!(close(due_term(None), 0.0));
+    assert!(close(due_term(Some(now - Duration::days(7))), 12.0));
+    assert!(close(due_term(Some(now - Duration::days(30))), 12.0));
+    assert!(close(due_term(Some(now + Duration::days(14))), 0.2 * 12.0));
+    assert!(close(due_term(Some(now + Duration::days(60))), 0.2 * 12.0));
+    assert!(close(due_term(Some(now)), (14.0 * 0.8 / 21.0 + 0.2) * 12.0));
+    assert!(close(due_term(Some(now + Duration::days(7))), (7.0 * 0.8 / 21.0 + 0.2) * 12.0));
+    println!("✅ Due dates follow Taskwarrior's curve: 0.2 two weeks out, linear up to 1.0 a week overdue");
+
+    // An unexpected answer keeps the defaults rather than mixing up the keys
+    fake.set_script(&format!(
+        "#!/bin/sh\ncase \"$*\" in\n  *--version*) echo '3.1.0' ;;\n  *_get*urgency*) echo '10.0' ;;\n  *export*) echo '{}' ;;\nesac\n",
+        EXPORT
+    ));
+    let taskwarrior = TaskwarriorIntegration::new(None, None).expect("fake task");
+    let tasks = runtime.block_on(taskwarrior.list_tasks(None)).expect("export");
+    assert!(close(tasks[0].urgency, defaults.priority_high), "{:?}", tasks);
+    println!("✅ Coefficients that can't be read fall back to Taskwarrior's defaults");
+
+    println!();
+    println!("🎉 Urgency coefficients test passed!");
+}
@@ -0,0 +1,113 @@
+// Test program for the urgency threshold in the filter panel: the Urgency ≥ row under the
+// Due panel's Before/After inputs, typed or stepped with + and -
+
+use lazytask::config::Config;
+use lazytask::data::filters::TaskFilter;
+use lazytask::data::models::{Task, TaskStatus};
+use lazytask::ui::views::main_view::MainView;
+use ratatui::{backend::TestBackend, Terminal};
+
+fn task(description: &str, urgency: f64) -> Task {
+    let mut task = Task::new(description.to_string());
+    task.urgency = urgency;
+    task
+}
+
+fn shown<'a>(view: &MainView, tasks: &'a [Task]) -> Vec<&'a str> {
+    tasks.iter().filter(|t| view.matches_filters(t)).map(|t| t.description.as_str()).collect()
+}
+
+fn render(view: &mut MainView) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(160, 40)).expect("test terminal");
+    terminal.draw(|f| view.render(f, f.area(), 160)).expect("draw");
+    terminal.backend().buffer().content.iter().map(|cell| cell.symbol()).collect()
+}
+
+fn main() {
+    println!("🔥 LazyTask Urgency Filter Test");
+    println!("==============================");
+    println!();
+
+    let tasks = [
+        task("fix the outage", 14.2),
+        task("review the budget", 10.0),
+        task("water the plants", 3.5),
+        task("someday maybe", -1.0),
+    ];
+
+    let filter = TaskFilter { status: None, urgency_min: Some(10.0), ..TaskFilter::default() };
+    let matched: Vec<String> = filter.apply(&tasks).into_iter().map(|t| t.description).collect();
+    assert_eq!(matched, ["fix the outage", "review the budget"]);
+    assert_eq!(TaskFilter::default().urgency_min, None);
+    println!("✅ TaskFilter::urgency_min keeps tasks at or above the threshold");
+
+    let mut view = MainView::new(&Config::default());
+    view.update_available_filters(&tasks);
+    view.toggle_filter_focus();
+    for _ in 0..3 {
+        view.next_filter_section();
+    }
+    assert!(!view.is_typing_filter_text());
+    for _ in 0..10 {
+        view.handle_filter_navigation_down();
+    }
+    assert!(view.is_typing_filter_text());
+    for c in "10".chars() {
+        view.handle_filter_character(c);
+    }
+    assert_eq!(view.urgency_min(), Some(10.0));
+    assert_eq!(shown(&view, &tasks), vec!["fix the outage", "review the budget"]);
+    let text = render(&mut view);
+    assert!(text.contains("Urgency ≥ 10") && text.contains("Selected: Any · ≥ 10"), "{}", text);
+    println!("✅ Typing 10 on the Urgency ≥ row hides everything below 10");
+
+    view.handle_filter_character('+');
+    view.handle_filter_character('+');
+    view.handle_filter_character('+');
+    view.handle_filter_character('+');
+    view.handle_filter_character('+');
+    assert_eq!(view.urgency_min(), Some(15.0));
+    assert!(shown(&view, &tasks).is_empty());
+    view.handle_filter_character('-');
+    assert_eq!(view.urgency_min(), Some(14.0));
+    assert_eq!(shown(&view, &tasks), vec!["fix the outage"]);
+    println!("✅ + and - step the threshold by one");
+
+    view.handle_filter_character('x');
+    assert_eq!(view.urgency_min(), Some(14.0));
+    view.handle_filter_backspace();
+    view.handle_filter_character('.');
+    view.handle_filter_character('5');
+    assert_eq!(view.urgency_min(), Some(1.5));
+    assert_eq!(shown(&view, &tasks), vec!["fix the outage", "review the budget", "water the plants"]);
+    view.handle_filter_backspace();
+    view.handle_filter_backspace();
+    view.handle_filter_backspace();
+    view.handle_filter_character('-');
+    assert_eq!(view.urgency_min(), Some(-1.0));
+    assert_eq!(shown(&view, &tasks).len(), 4);
+    println!("✅ Decimals can be typed; other letters are ignored and - goes below zero");
+
+    // Combined with the other sections: only pending tasks by default
+    let mut done = task("shipped", 20.0);
+    done.status = TaskStatus::Completed;
+    view.handle_filter_backspace();
+    view.handle_filter_backspace();
+    view.handle_filter_character('5');
+    let mut with_done = tasks.to_vec();
+    with_done.push(done);
+    assert_eq!(shown(&view, &with_done), vec!["fix the outage", "review the budget"]);
+    println!("✅ The threshold combines with the status filter");
+
+    view.toggle_current_selection();
+    assert_eq!(view.urgency_min(), None);
+    assert_eq!(shown(&view, &tasks).len(), 4);
+    view.handle_filter_character('9');
+    view.clear_all_filters();
+    assert_eq!(view.urgency_min(), None);
+    assert!(!render(&mut view).contains("· ≥"));
+    println!("✅ Space on the row or C clears it");
+
+    println!();
+    println!("🎉 Urgency filter test passed!");
+}
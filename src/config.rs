@@ -3,6 +3,12 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use ratatui::style::Color;
+
+use crate::ui::themes::{Theme, COLOR_ROLES};
+use crate::utils::formatting::DateFormat;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Config {
@@ -10,12 +16,64 @@ pub struct Config {
     pub keybindings: KeyBindingsConfig,
     pub taskwarrior: TaskwarriorConfig,
     pub ui: UIConfig,
+    // Problems found while loading, shown once the UI is up
+    #[serde(skip)]
+    pub warnings: Vec<String>,
+    // File the config came from (or was created at), for writing a chosen theme back
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ThemeConfig {
     pub name: String,
     pub colors: HashMap<String, String>,
+    // Tag name -> color, used wherever that tag is shown
+    #[serde(default)]
+    pub tag_colors: HashMap<String, String>,
+}
+
+impl ThemeConfig {
+    // An unknown theme name falls back to the terminal's colors, and unknown roles or
+    // colors in `theme.colors` are dropped. Returns a warning for each.
+    pub fn check_theme(&mut self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if Theme::by_name(&self.name).is_none() {
+            warnings.push(format!(
+                "Unknown theme \"{}\", using the terminal's colors (available: {})",
+                self.name,
+                Theme::names().join(", ")
+            ));
+        }
+        self.colors.retain(|role, color| {
+            if !COLOR_ROLES.contains(&role.as_str()) {
+                warnings.push(format!("Unknown theme.colors.{}, expected one of {}", role, COLOR_ROLES.join(", ")));
+                false
+            } else if RowColorConfig::parse_color(color).is_err() {
+                warnings.push(format!("Invalid theme.colors.{} \"{}\", using the theme's color", role, color));
+                false
+            } else {
+                true
+            }
+        });
+        warnings.sort();
+        warnings
+    }
+
+    // Tags with an unknown color are dropped, so they get the default tag color.
+    // Returns a warning for each one.
+    pub fn check_tag_colors(&mut self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        self.tag_colors.retain(|tag, color| {
+            let valid = RowColorConfig::parse_color(color).is_ok();
+            if !valid {
+                warnings.push(format!("Invalid theme.tag_colors.{} \"{}\", using the default tag color", tag, color));
+            }
+            valid
+        });
+        warnings.sort();
+        warnings
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -30,6 +88,22 @@ pub struct TaskwarriorConfig {
     pub taskrc_path: Option<PathBuf>,
     pub data_location: Option<PathBuf>,
     pub sync_enabled: bool,
+    // Export only tasks matching the status filter instead of the whole database
+    #[serde(default)]
+    pub filter_on_export: bool,
+    // Seconds a `task export` result is reused for; changes made in LazyTask clear it
+    #[serde(default = "default_cache_max_age")]
+    pub cache_max_age: u64,
+    // Seconds a `task` command may run before it is stopped (0 = no limit)
+    #[serde(default = "default_command_timeout")]
+    pub command_timeout: u64,
+    // Taskwarrior filter every export starts from, e.g. "project:work"; the UI filters apply on top
+    #[serde(default)]
+    pub startup_filter: Option<String>,
+    // Write plain modifications straight to taskchampion.sqlite3 instead of running `task`.
+    // Experimental: skips hooks, and anything it can't do still goes through the CLI.
+    #[serde(default)]
+    pub direct_db_writes: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -38,6 +112,166 @@ pub struct UIConfig {
     pub show_help_bar: bool,
     pub task_list_columns: Vec<String>,
     pub refresh_interval: u64,
+    #[serde(default)]
+    pub vim_keys: bool,
+    // strftime patterns for dates shown in the UI
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
+    #[serde(default = "default_datetime_format")]
+    pub datetime_format: String,
+    // Far-off due dates in the task list's narrow Due column
+    #[serde(default = "default_short_date_format")]
+    pub short_date_format: String,
+    // Show times and count days (due today, overdue, calendar) in UTC instead of local time
+    #[serde(default)]
+    pub use_utc: bool,
+    #[serde(default)]
+    pub row_colors: RowColorConfig,
+    // Ask before deleting / completing a task from the list
+    #[serde(default = "default_true")]
+    pub confirm_delete: bool,
+    #[serde(default)]
+    pub confirm_done: bool,
+    // How far back `H` (recently completed) looks
+    #[serde(default = "default_recently_completed_days")]
+    pub recently_completed_days: i64,
+    // Popup of overdue tasks and tasks due today once the first load finishes
+    #[serde(default = "default_true")]
+    pub startup_briefing: bool,
+}
+
+// Task list row coloring. Rules are tried top to bottom and the first one that
+// applies colors the row; an empty color turns that rule off. Colors are names
+// ("red", "darkgray"), "#rrggbb" or a 0-255 palette index.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RowColorConfig {
+    // false draws every row in the default color
+    pub enabled: bool,
+    pub completed: String,
+    pub overdue: String,
+    pub due_today: String,
+    // High priority tasks due within `high_priority_due_days` days
+    pub high_priority_due_soon: String,
+    pub high_priority_due_days: i64,
+    pub due_tomorrow: String,
+    // Tasks depending on a task that is still pending or waiting
+    pub blocked: String,
+    pub high_priority: String,
+    pub medium_priority: String,
+    pub low_priority: String,
+    // Tasks without a matching rule whose urgency is at least `urgency_threshold`
+    pub high_urgency: String,
+    pub urgency_threshold: f64,
+    pub default: String,
+}
+
+impl Default for RowColorConfig {
+    fn default() -> Self {
+        RowColorConfig {
+            enabled: true,
+            completed: "darkgray".to_string(),
+            overdue: "red".to_string(),
+            due_today: "red".to_string(),
+            high_priority_due_soon: "red".to_string(),
+            high_priority_due_days: 2,
+            due_tomorrow: "yellow".to_string(),
+            blocked: "darkgray".to_string(),
+            high_priority: "red".to_string(),
+            medium_priority: "yellow".to_string(),
+            low_priority: "green".to_string(),
+            high_urgency: "white".to_string(),
+            urgency_threshold: 10.0,
+            default: "white".to_string(),
+        }
+    }
+}
+
+impl RowColorConfig {
+    // Unknown colors fall back to the defaults, like bad date formats do.
+    // Returns a warning for each color that was replaced.
+    pub fn check_colors(&mut self) -> Vec<String> {
+        let defaults = RowColorConfig::default();
+        let mut warnings = Vec::new();
+        let fields = [
+            ("completed", &mut self.completed, defaults.completed),
+            ("overdue", &mut self.overdue, defaults.overdue),
+            ("due_today", &mut self.due_today, defaults.due_today),
+            ("high_priority_due_soon", &mut self.high_priority_due_soon, defaults.high_priority_due_soon),
+            ("due_tomorrow", &mut self.due_tomorrow, defaults.due_tomorrow),
+            ("blocked", &mut self.blocked, defaults.blocked),
+            ("high_priority", &mut self.high_priority, defaults.high_priority),
+            ("medium_priority", &mut self.medium_priority, defaults.medium_priority),
+            ("low_priority", &mut self.low_priority, defaults.low_priority),
+            ("high_urgency", &mut self.high_urgency, defaults.high_urgency),
+            ("default", &mut self.default, defaults.default),
+        ];
+        for (name, color, default) in fields {
+            if Self::parse_color(color).is_err() {
+                warnings.push(format!("Invalid ui.row_colors.{} \"{}\", using \"{}\"", name, color, default));
+                *color = default;
+            }
+        }
+        warnings
+    }
+
+    // None for an empty (disabled) rule
+    pub fn parse_color(color: &str) -> Result<Option<Color>> {
+        if color.trim().is_empty() {
+            return Ok(None);
+        }
+        Color::from_str(color.trim())
+            .map(Some)
+            .map_err(|_| anyhow::anyhow!("unknown color \"{}\"", color))
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_cache_max_age() -> u64 {
+    crate::taskwarrior::DEFAULT_CACHE_SECONDS
+}
+
+fn default_command_timeout() -> u64 {
+    crate::taskwarrior::DEFAULT_COMMAND_TIMEOUT_SECONDS
+}
+
+fn default_date_format() -> String {
+    "%Y-%m-%d".to_string()
+}
+
+fn default_datetime_format() -> String {
+    "%Y-%m-%d %H:%M".to_string()
+}
+
+fn default_short_date_format() -> String {
+    "%m/%d".to_string()
+}
+
+fn default_recently_completed_days() -> i64 {
+    7
+}
+
+impl UIConfig {
+    // Invalid patterns would make chrono panic while drawing, so they fall back to
+    // the defaults. Returns a warning for each pattern that was replaced.
+    pub fn check_date_formats(&mut self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let fields = [
+            ("date_format", &mut self.date_format, default_date_format()),
+            ("datetime_format", &mut self.datetime_format, default_datetime_format()),
+            ("short_date_format", &mut self.short_date_format, default_short_date_format()),
+        ];
+        for (name, pattern, default) in fields {
+            if !DateFormat::is_valid_pattern(pattern) {
+                warnings.push(format!("Invalid ui.{} \"{}\", using \"{}\"", name, pattern, default));
+                *pattern = default;
+            }
+        }
+        warnings
+    }
 }
 
 impl Default for Config {
@@ -53,16 +287,11 @@ impl Default for Config {
         task_list_keys.insert("done_task".to_string(), "d".to_string());
         task_list_keys.insert("delete_task".to_string(), "Delete".to_string());
 
-        let mut colors = HashMap::new();
-        colors.insert("background".to_string(), "#1e1e2e".to_string());
-        colors.insert("foreground".to_string(), "#cdd6f4".to_string());
-        colors.insert("primary".to_string(), "#89b4fa".to_string());
-        colors.insert("secondary".to_string(), "#f38ba8".to_string());
-
         Config {
             theme: ThemeConfig {
-                name: "catppuccin-mocha".to_string(),
-                colors,
+                name: "terminal".to_string(),
+                colors: HashMap::new(),
+                tag_colors: HashMap::new(),
             },
             keybindings: KeyBindingsConfig {
                 global: global_keys,
@@ -73,6 +302,11 @@ impl Default for Config {
                 taskrc_path: None,
                 data_location: None,
                 sync_enabled: false,
+                filter_on_export: false,
+                cache_max_age: default_cache_max_age(),
+                command_timeout: default_command_timeout(),
+                startup_filter: None,
+                direct_db_writes: false,
             },
             ui: UIConfig {
                 default_view: "task_list".to_string(),
@@ -85,7 +319,19 @@ impl Default for Config {
                     "description".to_string(),
                 ],
                 refresh_interval: 1000,
+                vim_keys: false,
+                date_format: default_date_format(),
+                datetime_format: default_datetime_format(),
+                short_date_format: default_short_date_format(),
+                use_utc: false,
+                row_colors: RowColorConfig::default(),
+                confirm_delete: true,
+                confirm_done: false,
+                recently_completed_days: default_recently_completed_days(),
+                startup_briefing: true,
             },
+            warnings: Vec::new(),
+            path: None,
         }
     }
 }
@@ -102,18 +348,65 @@ impl Config {
             let config_contents = fs::read_to_string(&config_file_path)
                 .with_context(|| format!("Failed to read config file: {:?}", config_file_path))?;
             
-            let config: Config = toml::from_str(&config_contents)
+            let mut config: Config = toml::from_str(&config_contents)
                 .with_context(|| "Failed to parse config file")?;
-            
+
+            let mut warnings = config.ui.check_date_formats();
+            warnings.extend(config.ui.row_colors.check_colors());
+            warnings.extend(config.theme.check_theme());
+            warnings.extend(config.theme.check_tag_colors());
+            for warning in warnings {
+                log::warn!("{}", warning);
+                config.warnings.push(warning);
+            }
+
+            config.path = Some(config_file_path);
             Ok(config)
         } else {
             // Create default config file
-            let default_config = Config::default();
+            let mut default_config = Config::default();
             default_config.save(&config_file_path)?;
+            default_config.path = Some(config_file_path);
             Ok(default_config)
         }
     }
 
+    // Command line paths replace the configured ones, but only if they exist
+    pub fn with_taskwarrior_overrides(
+        mut self,
+        taskrc_path: Option<PathBuf>,
+        data_location: Option<PathBuf>,
+    ) -> Result<Self> {
+        if let Some(path) = taskrc_path {
+            if !path.is_file() {
+                anyhow::bail!("taskrc file not found: {}", path.display());
+            }
+            self.taskwarrior.taskrc_path = Some(path);
+        }
+
+        if let Some(path) = data_location {
+            if !path.is_dir() {
+                anyhow::bail!("Taskwarrior data directory not found: {}", path.display());
+            }
+            self.taskwarrior.data_location = Some(path);
+        }
+
+        Ok(self)
+    }
+
+    /// `--filter` replaces `taskwarrior.startup_filter` for this run; a blank one clears it
+    pub fn with_startup_filter(mut self, filter: Option<String>) -> Self {
+        if let Some(filter) = filter {
+            self.taskwarrior.startup_filter = Some(filter);
+        }
+        self
+    }
+
+    /// The startup filter to export with, if there is one that isn't blank
+    pub fn startup_filter(&self) -> Option<&str> {
+        self.taskwarrior.startup_filter.as_deref().map(str::trim).filter(|filter| !filter.is_empty())
+    }
+
     pub fn save(&self, path: &Path) -> Result<()> {
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)
@@ -129,6 +422,18 @@ impl Config {
         Ok(())
     }
 
+    // Write `theme.name` back to the config file, leaving the rest of it (comments
+    // included) as it is. Does nothing for a config that didn't come from a file.
+    pub fn save_theme_name(&self, name: &str) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {:?}", path))?;
+        fs::write(path, with_theme_name(&contents, name))
+            .with_context(|| format!("Failed to write config file: {:?}", path))
+    }
+
     fn default_config_path() -> Result<PathBuf> {
         let config_dir = dirs::config_dir()
             .ok_or_else(|| anyhow::anyhow!("Could not find config directory"))?;
@@ -137,3 +442,34 @@ impl Config {
     }
 }
 
+// `contents` with the `name` key of its [theme] table set to `name`, adding the key or
+// the table when they're missing
+pub fn with_theme_name(contents: &str, name: &str) -> String {
+    let setting = format!("name = \"{}\"", name);
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    let table = |line: &str| line.trim_start().starts_with('[');
+
+    match lines.iter().position(|line| line.trim() == "[theme]") {
+        Some(start) => {
+            let end = lines[start + 1..].iter().position(|line| table(line)).map_or(lines.len(), |i| start + 1 + i);
+            let key = lines[start + 1..end].iter().position(|line| {
+                line.split('=').next().is_some_and(|key| key.trim() == "name")
+            });
+            match key {
+                Some(i) => lines[start + 1 + i] = setting,
+                None => lines.insert(start + 1, setting),
+            }
+        }
+        None => {
+            if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push("[theme]".to_string());
+            lines.push(setting);
+        }
+    }
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    updated
+}
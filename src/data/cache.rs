@@ -1,4 +1,4 @@
-// Performance caching layer for task data
+// Short-lived cache of `task export` results, so repeated loads don't re-run the export
 
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
@@ -6,51 +6,71 @@ use std::time::{Duration, Instant};
 use crate::data::models::Task;
 
 pub struct TaskCache {
-    tasks: HashMap<String, CachedTask>,
+    // Keyed by the export filter; the unfiltered export is ""
+    exports: HashMap<String, CachedExport>,
     max_age: Duration,
+    // Bumped by invalidate_all, so an export that started before a change can't
+    // put its outdated result back afterwards
+    generation: u64,
 }
 
-struct CachedTask {
-    task: Task,
+struct CachedExport {
+    tasks: Vec<Task>,
     cached_at: Instant,
 }
 
 impl TaskCache {
+    /// A `max_age_seconds` of 0 turns caching off: every entry is stale at once.
     pub fn new(max_age_seconds: u64) -> Self {
         TaskCache {
-            tasks: HashMap::new(),
+            exports: HashMap::new(),
             max_age: Duration::from_secs(max_age_seconds),
+            generation: 0,
         }
     }
 
-    pub fn get(&self, uuid: &str) -> Option<&Task> {
-        if let Some(cached) = self.tasks.get(uuid) {
-            if cached.cached_at.elapsed() < self.max_age {
-                return Some(&cached.task);
-            }
+    fn key(filter: Option<&str>) -> String {
+        filter.map(|f| f.split_whitespace().collect::<Vec<_>>().join(" ")).unwrap_or_default()
+    }
+
+    /// Tasks from the last export with this filter, while they are younger than `max_age`.
+    pub fn get(&self, filter: Option<&str>) -> Option<&[Task]> {
+        if self.is_stale(filter) {
+            return None;
         }
-        None
+        self.exports.get(&Self::key(filter)).map(|cached| cached.tasks.as_slice())
     }
 
-    pub fn insert(&mut self, task: Task) {
-        let uuid = task.uuid.clone();
-        self.tasks.insert(uuid, CachedTask {
-            task,
+    pub fn insert(&mut self, filter: Option<&str>, tasks: Vec<Task>) {
+        if self.max_age.is_zero() {
+            return;
+        }
+        self.cleanup_expired();
+        self.exports.insert(Self::key(filter), CachedExport {
+            tasks,
             cached_at: Instant::now(),
         });
     }
 
-    pub fn remove(&mut self, uuid: &str) {
-        self.tasks.remove(uuid);
+    /// Whether a load with this filter has to run the export again.
+    pub fn is_stale(&self, filter: Option<&str>) -> bool {
+        self.exports
+            .get(&Self::key(filter))
+            .is_none_or(|cached| cached.cached_at.elapsed() >= self.max_age)
     }
 
-    pub fn clear(&mut self) {
-        self.tasks.clear();
+    /// Forget every export; called after anything that changes tasks.
+    pub fn invalidate_all(&mut self) {
+        self.exports.clear();
+        self.generation += 1;
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
     }
 
     pub fn cleanup_expired(&mut self) {
         let max_age = self.max_age;
-        self.tasks.retain(|_, cached| cached.cached_at.elapsed() < max_age);
+        self.exports.retain(|_, cached| cached.cached_at.elapsed() < max_age);
     }
 }
-
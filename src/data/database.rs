@@ -1,18 +1,49 @@
 // TaskChampion SQLite database access
 // This module provides direct access to the TaskChampion database for performance
+//
+// Schema assumptions (taskchampion.sqlite3 as written by Taskwarrior 3):
+// - `tasks (uuid STRING PRIMARY KEY, data STRING)`: `data` is a JSON object of string
+//   properties. Dates are Unix timestamps in seconds, each tag is a `tag_<name>` key with
+//   an empty value, and a missing key means the property isn't set.
+// - `operations (id INTEGER PRIMARY KEY AUTOINCREMENT, data STRING, ...)`: the local change
+//   log that `task sync` sends and `task undo` reads. `data` is a serialized Operation:
+//   the string "UndoPoint", or {"Update": {uuid, property, old_value, value, timestamp}}
+//   with null for an unset value. Newer versions add `uuid` and `synced` columns, which
+//   are generated or defaulted, so only `data` is written.
+// - `working_set (id INTEGER PRIMARY KEY, uuid STRING)`: the numeric IDs shown by `task`.
+//
+// Only plain property changes are written here. Anything that moves a task between
+// statuses, touches dependencies or needs Taskwarrior to interpret a value (`due:tomorrow`)
+// is left to the CLI. Hooks don't run for direct writes.
 
-use anyhow::Result;
-use rusqlite::Connection;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::json;
 
 use crate::data::models::Task;
 
+// How long to wait for a `task` command that has the database locked
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+// Properties that are stored as given
+const TEXT_PROPERTIES: [&str; 3] = ["description", "project", "priority"];
+// Properties holding a date, stored as a Unix timestamp
+const DATE_PROPERTIES: [&str; 4] = ["due", "wait", "scheduled", "until"];
+
 pub struct TaskChampionDB {
     conn: Connection,
 }
 
 impl TaskChampionDB {
-    pub fn new(db_path: std::path::PathBuf) -> Result<Self> {
-        let conn = Connection::open(db_path)?;
+    pub fn new(db_path: PathBuf) -> Result<Self> {
+        let conn = Connection::open(db_path)
+            .with_context(|| "Failed to open TaskChampion database")?;
+        conn.busy_timeout(BUSY_TIMEOUT)?;
         Ok(TaskChampionDB { conn })
     }
 
@@ -25,5 +56,133 @@ impl TaskChampionDB {
     pub async fn get_task(&self, _id: u32) -> Result<Option<Task>> {
         todo!("Direct database access not yet implemented")
     }
+
+    // The UUID behind a task ID, as `task <id>` would find it
+    pub fn working_set_uuid(&self, id: u32) -> Result<Option<String>> {
+        self.conn
+            .query_row("SELECT uuid FROM working_set WHERE id = ?1", params![id], |row| row.get(0))
+            .optional()
+            .with_context(|| format!("Failed to look up task {} in the working set", id))
+    }
+
+    // A task's stored properties, or None if there is no task with that UUID
+    pub fn task_data(&self, uuid: &str) -> Result<Option<BTreeMap<String, String>>> {
+        let data: Option<String> = self.conn
+            .query_row("SELECT data FROM tasks WHERE uuid = ?1", params![uuid], |row| row.get(0))
+            .optional()
+            .with_context(|| format!("Failed to read task {}", uuid))?;
+        data.map(|data| serde_json::from_str(&data).with_context(|| format!("Unexpected data for task {}", uuid)))
+            .transpose()
+    }
+
+    /// Apply `task <uuid> modify` attributes (as `TaskwarriorCLI::modify_task_by_uuid`
+    /// takes them) straight to the database, logging each change for sync and undo.
+    /// Ok(false) means some attribute needs the CLI and nothing was written.
+    pub fn modify_task(&mut self, uuid: &str, attributes: &[(&str, &str)]) -> Result<bool> {
+        let tx = self.conn.transaction()?;
+        let data: Option<String> = tx
+            .query_row("SELECT data FROM tasks WHERE uuid = ?1", params![uuid], |row| row.get(0))
+            .optional()?;
+        let Some(data) = data else {
+            bail!("No task {} in the TaskChampion database", uuid);
+        };
+        let old: BTreeMap<String, String> =
+            serde_json::from_str(&data).with_context(|| format!("Unexpected data for task {}", uuid))?;
+        // Older replicas can carry a combined `tags` property, which we'd leave out of step
+        if old.contains_key("tags") {
+            return Ok(false);
+        }
+
+        let Some(mut new) = apply_attributes(&old, attributes) else {
+            return Ok(false);
+        };
+        if new == old {
+            return Ok(true);
+        }
+
+        let now = Utc::now();
+        new.insert("modified".to_string(), now.timestamp().to_string());
+
+        // Same shape as Taskwarrior's own log: an undo point, then one update per property
+        let mut operations = vec![json!("UndoPoint")];
+        let properties: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+        for property in properties {
+            let (before, after) = (old.get(property), new.get(property));
+            if before != after {
+                operations.push(json!({
+                    "Update": {
+                        "uuid": uuid,
+                        "property": property,
+                        "old_value": before,
+                        "value": after,
+                        "timestamp": now,
+                    }
+                }));
+            }
+        }
+
+        tx.execute(
+            "UPDATE tasks SET data = ?1 WHERE uuid = ?2",
+            params![serde_json::to_string(&new)?, uuid],
+        )?;
+        for operation in operations {
+            tx.execute("INSERT INTO operations (data) VALUES (?1)", params![operation.to_string()])?;
+        }
+        tx.commit().with_context(|| format!("Failed to save changes to task {}", uuid))?;
+        Ok(true)
+    }
 }
 
+// The task's properties with the modifications made, or None if one of them isn't supported
+fn apply_attributes(task: &BTreeMap<String, String>, attributes: &[(&str, &str)]) -> Option<BTreeMap<String, String>> {
+    let mut task = task.clone();
+    for &(key, value) in attributes {
+        if let Some(tag) = key.strip_prefix('+') {
+            if tag.is_empty() || !value.is_empty() {
+                return None;
+            }
+            task.insert(format!("tag_{}", tag), String::new());
+        } else if let Some(tag) = key.strip_prefix('-') {
+            if tag.is_empty() || !value.is_empty() {
+                return None;
+            }
+            task.remove(&format!("tag_{}", tag));
+        } else if key == "tags" && value.is_empty() {
+            task.retain(|property, _| !property.starts_with("tag_"));
+        } else if TEXT_PROPERTIES.contains(&key) {
+            match value {
+                // Taskwarrior refuses to clear the description
+                "" if key == "description" => return None,
+                "" => {
+                    task.remove(key);
+                }
+                _ => {
+                    task.insert(key.to_string(), value.to_string());
+                }
+            }
+        } else if DATE_PROPERTIES.contains(&key) {
+            if value.is_empty() {
+                task.remove(key);
+            } else {
+                task.insert(key.to_string(), parse_absolute_date(value)?.timestamp().to_string());
+            }
+        } else {
+            return None;
+        }
+    }
+    Some(task)
+}
+
+// Dates that mean the same thing to us and to Taskwarrior. Named dates and durations
+// (`tomorrow`, `eow`, `2w`) are Taskwarrior's to work out.
+fn parse_absolute_date(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Some(date.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ") {
+        return Some(date.and_utc());
+    }
+    // A bare date is local midnight, as `task ... due:2025-03-14` reads it
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+    Local.from_local_datetime(&date.and_hms_opt(0, 0, 0)?).earliest().map(|date| date.with_timezone(&Utc))
+}
@@ -1,16 +1,23 @@
 // Import/export utilities for task data
 
 use anyhow::Result;
-use serde_json;
+use serde_json::{self, Value};
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
 use std::path::Path;
 
-use crate::data::models::Task;
+use crate::data::models::{Task, TaskStatus, NO_PROJECT};
 
+// Also the `--format` values of `lazytask export`
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
 pub enum ExportFormat {
     Json,
+    // JSON in the shape `task import` accepts
+    Taskwarrior,
     Csv,
+    // Checklist grouped by project, for pasting into notes
+    Markdown,
 }
 
 pub struct TaskExporter;
@@ -19,14 +26,18 @@ impl TaskExporter {
     pub fn export_to_file(tasks: &[Task], path: &Path, format: ExportFormat) -> Result<()> {
         match format {
             ExportFormat::Json => Self::export_json(tasks, path),
+            ExportFormat::Taskwarrior => Self::export_taskwarrior_json(tasks, path),
             ExportFormat::Csv => Self::export_csv(tasks, path),
+            ExportFormat::Markdown => Self::export_markdown(tasks, path),
         }
     }
 
     pub fn import_from_file(path: &Path, format: ExportFormat) -> Result<Vec<Task>> {
         match format {
             ExportFormat::Json => Self::import_json(path),
+            ExportFormat::Taskwarrior => Self::import_taskwarrior_json(path),
             ExportFormat::Csv => Self::import_csv(path),
+            ExportFormat::Markdown => anyhow::bail!("Markdown exports can't be imported"),
         }
     }
 
@@ -46,6 +57,25 @@ impl TaskExporter {
         Ok(tasks)
     }
 
+    // A JSON array `task import` accepts, so tasks can be round-tripped through
+    // the official importer (and read back from `task export` output)
+    pub fn export_taskwarrior_json(tasks: &[Task], path: &Path) -> Result<()> {
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+        let json: Vec<Value> = tasks.iter().map(Task::to_taskwarrior_json).collect();
+        let json = serde_json::to_string_pretty(&json)?;
+        writer.write_all(json.as_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn import_taskwarrior_json(path: &Path) -> Result<Vec<Task>> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+        let json: Vec<Value> = serde_json::from_reader(reader)?;
+        json.iter().map(Task::from_json).collect()
+    }
+
     fn export_csv(tasks: &[Task], path: &Path) -> Result<()> {
         let file = File::create(path)?;
         let mut writer = BufWriter::new(file);
@@ -55,24 +85,90 @@ impl TaskExporter {
         
         // Write task data
         for task in tasks {
-            writeln!(
-                writer,
-                "{},{},{},{},{},{},{},{}",
+            let fields = [
                 task.id.map(|id| id.to_string()).unwrap_or_default(),
-                task.uuid,
-                task.status.as_str(),
-                task.description,
-                task.project.as_deref().unwrap_or(""),
-                task.priority.as_ref().map(|p| p.as_str()).unwrap_or(""),
+                task.uuid.clone(),
+                task.status.as_str().to_string(),
+                task.description.clone(),
+                task.project.clone().unwrap_or_default(),
+                task.priority.as_ref().map(|p| p.as_str().to_string()).unwrap_or_default(),
                 task.due.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default(),
-                task.tags.join(";")
-            )?;
+                task.tags.join(";"),
+            ];
+            let row: Vec<String> = fields.iter().map(|field| Self::csv_field(field)).collect();
+            writeln!(writer, "{}", row.join(","))?;
         }
         
         writer.flush()?;
         Ok(())
     }
 
+    // RFC 4180: a field with a comma, quote or line break goes in quotes, with its quotes doubled
+    fn csv_field(value: &str) -> String {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+
+    fn export_markdown(tasks: &[Task], path: &Path) -> Result<()> {
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+        writer.write_all(Self::render_markdown(tasks).as_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Markdown checklist with a section per project, sorted by name and with tasks
+    /// without a project last. Tasks keep their order within a section, so exporting
+    /// the same list again gives the same file.
+    pub fn render_markdown(tasks: &[Task]) -> String {
+        let mut projects: BTreeMap<Option<&str>, Vec<&Task>> = BTreeMap::new();
+        for task in tasks {
+            projects.entry(task.project.as_deref()).or_default().push(task);
+        }
+        // None sorts first in a BTreeMap; the stable sort moves it to the end
+        let mut sections: Vec<(Option<&str>, Vec<&Task>)> = projects.into_iter().collect();
+        sections.sort_by_key(|(project, _)| project.is_none());
+
+        let mut markdown = String::from("# Tasks\n");
+        for (project, tasks) in sections {
+            markdown.push_str(&format!("\n## {}\n\n", project.unwrap_or(NO_PROJECT)));
+            for task in tasks {
+                markdown.push_str(&Self::markdown_item(task));
+                markdown.push('\n');
+            }
+        }
+        markdown
+    }
+
+    // `- [ ] Description (priority: H, due: 2025-10-20) #tag`; deleted tasks are struck through
+    fn markdown_item(task: &Task) -> String {
+        // One line per task, whatever the description contains
+        let description = task.description.split_whitespace().collect::<Vec<_>>().join(" ");
+        let mut item = match task.status {
+            TaskStatus::Completed => format!("- [x] {}", description),
+            TaskStatus::Deleted => format!("- [x] ~~{}~~", description),
+            _ => format!("- [ ] {}", description),
+        };
+
+        let details: Vec<String> = [
+            task.priority.as_ref().map(|p| format!("priority: {}", p.as_str())),
+            task.due.map(|d| format!("due: {}", d.format("%Y-%m-%d"))),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !details.is_empty() {
+            item.push_str(&format!(" ({})", details.join(", ")));
+        }
+        for tag in &task.tags {
+            item.push_str(&format!(" #{}", tag));
+        }
+        item
+    }
+
     fn import_csv(_path: &Path) -> Result<Vec<Task>> {
         // TODO: Implement CSV import
         todo!("CSV import not yet implemented")
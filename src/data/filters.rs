@@ -17,6 +17,8 @@ pub struct TaskFilter {
     pub is_active: Option<bool>,
     pub is_overdue: Option<bool>,
     pub is_blocked: Option<bool>,
+    // Only tasks at or above this urgency
+    pub urgency_min: Option<f64>,
 }
 
 impl Default for TaskFilter {
@@ -32,6 +34,7 @@ impl Default for TaskFilter {
             is_active: None,
             is_overdue: None,
             is_blocked: None,
+            urgency_min: None,
         }
     }
 }
@@ -105,17 +108,9 @@ impl TaskFilter {
             }
         }
 
-        // Description contains filter (searches description, project, and tags)
+        // Description contains filter (searches description, project, tags, and annotations)
         if let Some(text) = &self.description_contains {
-            let search_text = text.to_lowercase();
-            let matches_description = task.description.to_lowercase().contains(&search_text);
-            let matches_project = task.project.as_ref()
-                .map(|p| p.to_lowercase().contains(&search_text))
-                .unwrap_or(false);
-            let matches_tags = task.tags.iter()
-                .any(|tag| tag.to_lowercase().contains(&search_text));
-            
-            if !matches_description && !matches_project && !matches_tags {
+            if !task.matches_search(text) {
                 return false;
             }
         }
@@ -139,6 +134,12 @@ impl TaskFilter {
             }
         }
 
+        if let Some(urgency_min) = self.urgency_min {
+            if task.urgency < urgency_min {
+                return false;
+            }
+        }
+
         true
     }
 
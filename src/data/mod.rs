@@ -1,7 +1,10 @@
 pub mod models;
 pub mod database;
-pub mod cli_interface;
 pub mod filters;
 pub mod cache;
 pub mod export;
+pub mod sort;
+pub mod search;
+pub mod report;
 
+pub mod quick_add;
@@ -1,8 +1,8 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use std::collections::HashMap;
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
@@ -49,18 +49,98 @@ pub struct Annotation {
     pub description: String,
 }
 
+/// One line of a task's change history, as `task info` lists it
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub date: DateTime<Utc>,
+    pub change: String,
+}
+
+/// Project name the per-project counts use for tasks without one
+pub const NO_PROJECT: &str = "(no project)";
+
 #[derive(Debug, Clone)]
 pub struct Project {
     pub name: String,
     pub task_count: u32,
     pub completed_count: u32,
     pub pending_count: u32,
+    pub deleted_count: u32,
+}
+
+impl Project {
+    /// Count tasks per project, sorted by name. Tasks without a project are
+    /// collected under [`NO_PROJECT`]; waiting and recurring tasks count as pending.
+    pub fn from_tasks(tasks: &[Task]) -> Vec<Project> {
+        let mut projects: HashMap<&str, Project> = HashMap::new();
+        for task in tasks {
+            let name = task.project.as_deref().unwrap_or(NO_PROJECT);
+            let project = projects.entry(name).or_insert_with(|| Project {
+                name: name.to_string(),
+                task_count: 0,
+                completed_count: 0,
+                pending_count: 0,
+                deleted_count: 0,
+            });
+
+            match task.status {
+                TaskStatus::Pending | TaskStatus::Waiting | TaskStatus::Recurring => project.pending_count += 1,
+                TaskStatus::Completed => project.completed_count += 1,
+                TaskStatus::Deleted => project.deleted_count += 1,
+            }
+            project.task_count += 1;
+        }
+
+        let mut projects: Vec<Project> = projects.into_values().collect();
+        projects.sort_by(|a, b| a.name.cmp(&b.name));
+        projects
+    }
+
+    /// Percentage of pending + completed tasks that are done; deleted tasks don't count.
+    pub fn completion_rate(&self) -> f32 {
+        let active_total = self.pending_count + self.completed_count;
+        if active_total > 0 {
+            self.completed_count as f32 / active_total as f32 * 100.0
+        } else {
+            0.0
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Tag {
     pub name: String,
     pub task_count: u32,
+    // Pending, waiting and recurring tasks with the tag, as for projects
+    pub pending_count: u32,
+}
+
+impl Tag {
+    /// Count tasks per tag, most pending tasks first (then most tasks, then by name)
+    pub fn from_tasks(tasks: &[Task]) -> Vec<Tag> {
+        let mut tags: HashMap<&str, Tag> = HashMap::new();
+        for task in tasks {
+            for name in &task.tags {
+                let tag = tags.entry(name).or_insert_with(|| Tag {
+                    name: name.clone(),
+                    task_count: 0,
+                    pending_count: 0,
+                });
+                if matches!(task.status, TaskStatus::Pending | TaskStatus::Waiting | TaskStatus::Recurring) {
+                    tag.pending_count += 1;
+                }
+                tag.task_count += 1;
+            }
+        }
+
+        let mut tags: Vec<Tag> = tags.into_values().collect();
+        tags.sort_by(|a, b| {
+            b.pending_count.cmp(&a.pending_count)
+                .then(b.task_count.cmp(&a.task_count))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        tags
+    }
 }
 
 impl Task {
@@ -93,11 +173,6 @@ impl Task {
             .and_then(|v| v.as_u64())
             .map(|v| v as u32);
 
-        let uuid = json.get("uuid")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| anyhow::anyhow!("Task UUID is required"))?
-            .to_string();
-
         let status = json.get("status")
             .and_then(|v| v.as_str())
             .map(TaskStatus::from_str)
@@ -108,6 +183,22 @@ impl Task {
             .ok_or_else(|| anyhow::anyhow!("Task description is required"))?
             .to_string();
 
+        // Rather than lose a task over a missing or mangled UUID, give it one for this
+        // session. Taskwarrior won't know it, so changes by UUID won't find the task.
+        let uuid = match json.get("uuid").and_then(|v| v.as_str()) {
+            Some(uuid) if uuid::Uuid::parse_str(uuid).is_ok() => uuid.to_string(),
+            uuid => {
+                let synthetic = uuid::Uuid::new_v4().to_string();
+                log::warn!(
+                    "Task \"{}\" has {}, showing it as {}",
+                    description,
+                    uuid.map_or_else(|| "no UUID".to_string(), |uuid| format!("an invalid UUID \"{}\"", uuid)),
+                    synthetic
+                );
+                synthetic
+            }
+        };
+
         let project = json.get("project")
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
@@ -157,6 +248,20 @@ impl Task {
                 .collect())
             .unwrap_or_else(Vec::new);
 
+        // Taskwarrior 2.6+ exports an array of UUIDs; older versions a comma-separated string
+        let depends = match json.get("depends") {
+            Some(Value::Array(arr)) => arr.iter()
+                .filter_map(|v| v.as_str())
+                .map(|s| s.to_string())
+                .collect(),
+            Some(Value::String(s)) => s.split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+                .collect(),
+            _ => Vec::new(),
+        };
+
         let annotations = json.get("annotations")
             .and_then(|v| v.as_array())
             .map(|arr| arr.iter()
@@ -183,7 +288,7 @@ impl Task {
             wait,
             scheduled,
             until,
-            depends: Vec::new(),
+            depends,
             tags,
             annotations,
             urgency,
@@ -191,6 +296,61 @@ impl Task {
         })
     }
 
+    /// The task as `task import` reads it: lowercase status, `H`/`M`/`L` priority and
+    /// `20251007T192937Z` dates. Unset fields are left out, and so are `id` and `urgency`,
+    /// which Taskwarrior computes itself.
+    pub fn to_taskwarrior_json(&self) -> Value {
+        let mut json = Map::new();
+        json.insert("uuid".to_string(), Value::from(self.uuid.as_str()));
+        json.insert("status".to_string(), Value::from(self.status.as_str()));
+        json.insert("description".to_string(), Value::from(self.description.as_str()));
+        json.insert("entry".to_string(), Value::from(format_taskwarrior_datetime(&self.entry)));
+
+        if let Some(project) = &self.project {
+            json.insert("project".to_string(), Value::from(project.as_str()));
+        }
+        if let Some(priority) = &self.priority {
+            json.insert("priority".to_string(), Value::from(priority.as_str()));
+        }
+
+        let dates = [
+            ("due", self.due),
+            ("modified", self.modified),
+            ("start", self.start),
+            ("end", self.end),
+            ("wait", self.wait),
+            ("scheduled", self.scheduled),
+            ("until", self.until),
+        ];
+        for (name, date) in dates {
+            if let Some(date) = date {
+                json.insert(name.to_string(), Value::from(format_taskwarrior_datetime(&date)));
+            }
+        }
+
+        if !self.tags.is_empty() {
+            json.insert("tags".to_string(), Value::from(self.tags.clone()));
+        }
+        // The array form, as Taskwarrior 2.6+ exports it
+        if !self.depends.is_empty() {
+            json.insert("depends".to_string(), Value::from(self.depends.clone()));
+        }
+        if !self.annotations.is_empty() {
+            let annotations = self.annotations.iter()
+                .map(|annotation| json!({
+                    "entry": format_taskwarrior_datetime(&annotation.entry),
+                    "description": annotation.description,
+                }))
+                .collect();
+            json.insert("annotations".to_string(), Value::Array(annotations));
+        }
+        for (name, value) in &self.udas {
+            json.entry(name.clone()).or_insert_with(|| Value::from(value.as_str()));
+        }
+
+        Value::Object(json)
+    }
+
     pub fn is_active(&self) -> bool {
         self.start.is_some() && self.status == TaskStatus::Pending
     }
@@ -203,19 +363,126 @@ impl Task {
         }
     }
 
+    /// Completed at or after `since`, judged by the `end` timestamp.
+    pub fn completed_since(&self, since: DateTime<Utc>) -> bool {
+        self.status == TaskStatus::Completed && self.end.is_some_and(|end| end >= since)
+    }
+
+    /// Roughly when the task was worked on: from `start` until `now` while it is active, or
+    /// until `end` for a completed task that kept its `start`. Taskwarrior only remembers
+    /// the latest start, so earlier start/stop stretches aren't counted.
+    pub fn tracked_interval(&self, now: DateTime<Utc>) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let start = self.start?;
+        let end = match self.status {
+            TaskStatus::Pending => now,
+            TaskStatus::Completed => self.end?,
+            _ => return None,
+        };
+        (end > start).then_some((start, end))
+    }
+
+    /// Names of the user-facing attributes that differ in `other`, another copy of this task
+    pub fn changed_fields(&self, other: &Task) -> Vec<&'static str> {
+        let annotations = |task: &Task| task.annotations.iter().map(|a| a.description.clone()).collect::<Vec<_>>();
+        [
+            ("status", self.status != other.status),
+            ("description", self.description != other.description),
+            ("project", self.project != other.project),
+            ("priority", self.priority != other.priority),
+            ("due", self.due != other.due),
+            ("start", self.start != other.start),
+            ("wait", self.wait != other.wait),
+            ("scheduled", self.scheduled != other.scheduled),
+            ("tags", self.tags != other.tags),
+            ("depends", self.depends != other.depends),
+            ("annotations", annotations(self) != annotations(other)),
+        ]
+        .into_iter()
+        .filter_map(|(name, changed)| changed.then_some(name))
+        .collect()
+    }
+
+    /// Hidden until its wait date: either still marked waiting, or pending with a future `wait`.
+    pub fn is_waiting(&self) -> bool {
+        self.status == TaskStatus::Waiting || self.wait.is_some_and(|wait| wait > Utc::now())
+    }
+
+    /// Taskwarrior's `+READY`: pending, not waiting, scheduled (if at all) for now or
+    /// earlier, and not blocked by any of `open_uuids` (see `open_uuids`).
+    pub fn is_ready(&self, open_uuids: &HashSet<String>) -> bool {
+        self.status == TaskStatus::Pending
+            && !self.is_waiting()
+            && self.scheduled.is_none_or(|scheduled| scheduled <= Utc::now())
+            && !self.is_blocked_by(open_uuids)
+    }
+
+    // Has dependencies at all; whether they still hold it up needs the other tasks (is_blocked_by)
     pub fn is_blocked(&self) -> bool {
         !self.depends.is_empty()
     }
 
+    /// UUIDs of the tasks that can still block others: pending or waiting ones.
+    pub fn open_uuids(tasks: &[Task]) -> HashSet<String> {
+        tasks
+            .iter()
+            .filter(|task| matches!(task.status, TaskStatus::Pending | TaskStatus::Waiting))
+            .map(|task| task.uuid.clone())
+            .collect()
+    }
+
+    /// Whether any dependency is still open. Dependencies that are done, deleted or
+    /// not among the loaded tasks don't count, so a task whose dependencies are all
+    /// finished is not blocked.
+    pub fn is_blocked_by(&self, open_uuids: &HashSet<String>) -> bool {
+        self.depends.iter().any(|uuid| open_uuids.contains(uuid))
+    }
+
+    /// Value for a `depends:` modification turning `original` into this task's dependencies:
+    /// current UUIDs are (re-)added, dropped ones are prefixed with `-`. None if both are empty.
+    pub fn depends_modification(&self, original: &[String]) -> Option<String> {
+        let removed = original.iter()
+            .filter(|uuid| !self.depends.contains(uuid))
+            .map(|uuid| format!("-{}", uuid));
+        let values: Vec<String> = self.depends.iter().cloned().chain(removed).collect();
+        (!values.is_empty()).then(|| values.join(","))
+    }
+
+    /// Case-insensitive search across description, project, tags and annotations.
+    /// A leading `+` is ignored when matching tags, so `+home` finds the `home` tag.
+    /// An empty (or whitespace-only) query matches every task.
+    pub fn matches_search(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return true;
+        }
+
+        let tag_query = query.strip_prefix('+').unwrap_or(&query);
+
+        self.description.to_lowercase().contains(&query)
+            || self.project.as_ref()
+                .map(|p| p.to_lowercase().contains(&query))
+                .unwrap_or(false)
+            || (!tag_query.is_empty() && self.tags.iter()
+                .any(|tag| tag.to_lowercase().contains(tag_query)))
+            || self.annotations.iter()
+                .any(|annotation| annotation.description.to_lowercase().contains(&query))
+    }
+
     fn parse_taskwarrior_date(date_str: &str) -> Option<DateTime<Utc>> {
         parse_taskwarrior_datetime(date_str)
     }
 }
 
+/// Taskwarrior's compact UTC date format, e.g. `20251007T192937Z`; the inverse of
+/// the parser below. Sub-second precision is dropped, as Taskwarrior does.
+pub fn format_taskwarrior_datetime(date: &DateTime<Utc>) -> String {
+    date.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
 fn parse_taskwarrior_datetime(date_str: &str) -> Option<DateTime<Utc>> {
     // Taskwarrior uses format: 20251007T192937Z
     // We need to convert to: 2025-10-07T19:29:37Z for parsing
-    if date_str.len() == 16 && date_str.ends_with('Z') {
+    if date_str.len() == 16 && date_str.is_ascii() && date_str.ends_with('Z') {
         let formatted = format!(
             "{}-{}-{}T{}:{}:{}Z",
             &date_str[0..4],   // YYYY
@@ -274,6 +541,16 @@ impl Priority {
         }
     }
 
+    /// Next priority in the None → High → Medium → Low → None cycle
+    pub fn cycle(current: Option<&Priority>) -> Option<Priority> {
+        match current {
+            None => Some(Priority::High),
+            Some(Priority::High) => Some(Priority::Medium),
+            Some(Priority::Medium) => Some(Priority::Low),
+            Some(Priority::Low) => None,
+        }
+    }
+
     pub fn as_char(&self) -> char {
         match self {
             Priority::High => 'H',
@@ -0,0 +1,104 @@
+// One-line task entry in Taskwarrior's `task add` syntax
+
+use anyhow::{bail, Result};
+
+use crate::utils::validation::parse_tags;
+
+// Attributes passed to `task add` untouched besides the ones checked below
+const PASSTHROUGH_ATTRIBUTES: [&str; 5] = ["wait", "scheduled", "until", "recur", "depends"];
+
+/// A parsed quick-add line, ready for `TaskwarriorIntegration::add_task`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuickAdd {
+    pub description: String,
+    /// `(name, value)` pairs; tags are `("+tag", "")` like in the task form
+    pub attributes: Vec<(String, String)>,
+}
+
+impl QuickAdd {
+    /// Parse `Buy milk project:home +errand due:tomorrow priority:H`.
+    /// Quoted text is always description, so `"ratio 1:2"` stays literal.
+    pub fn parse(line: &str) -> Result<QuickAdd> {
+        let mut words = Vec::new();
+        let mut attributes = Vec::new();
+
+        for (token, quoted) in tokenize(line)? {
+            if quoted {
+                if !token.is_empty() {
+                    words.push(token);
+                }
+                continue;
+            }
+
+            if token.len() > 1 && token.starts_with('+') {
+                for tag in parse_tags(&token)? {
+                    attributes.push((format!("+{}", tag), String::new()));
+                }
+                continue;
+            }
+
+            match token.split_once(':') {
+                Some(("project", value)) if !value.is_empty() => {
+                    attributes.push(("project".to_string(), value.to_string()));
+                }
+                Some(("priority", value)) => {
+                    let priority = value.to_uppercase();
+                    if !matches!(priority.as_str(), "H" | "M" | "L") {
+                        bail!("Priority must be H, M or L, not \"{}\"", value);
+                    }
+                    attributes.push(("priority".to_string(), priority));
+                }
+                Some(("due", value)) if !value.is_empty() => {
+                    attributes.push(("due".to_string(), value.to_string()));
+                }
+                Some((name, value)) if PASSTHROUGH_ATTRIBUTES.contains(&name) && !value.is_empty() => {
+                    attributes.push((name.to_string(), value.to_string()));
+                }
+                _ => words.push(token),
+            }
+        }
+
+        let description = words.join(" ");
+        if description.trim().is_empty() {
+            bail!("Description can't be empty");
+        }
+
+        Ok(QuickAdd { description, attributes })
+    }
+}
+
+// Split on whitespace, keeping "double" or 'single' quoted runs together.
+// Returns each token with whether it was quoted.
+fn tokenize(line: &str) -> Result<Vec<(String, bool)>> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' || c == '\'' {
+            chars.next();
+            let mut token = String::new();
+            loop {
+                match chars.next() {
+                    Some(next) if next == c => break,
+                    Some(next) => token.push(next),
+                    None => bail!("Missing closing {}", c),
+                }
+            }
+            tokens.push((token, true));
+        } else {
+            let mut token = String::new();
+            while let Some(&next) = chars.peek() {
+                if next.is_whitespace() {
+                    break;
+                }
+                token.push(next);
+                chars.next();
+            }
+            tokens.push((token, false));
+        }
+    }
+
+    Ok(tokens)
+}
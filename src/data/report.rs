@@ -0,0 +1,84 @@
+// Taskwarrior report definitions (`report.<name>.columns`, `.sort`, `.filter` in taskrc)
+
+use crate::data::sort::SortKey;
+
+/// Reports every Taskwarrior install has, listed first in the reports menu
+pub const BUILTIN_REPORTS: [&str; 3] = ["next", "list", "completed"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportDefinition {
+    pub name: String,
+    // As written in taskrc, e.g. "id,start.age,project,due.relative,description.count"
+    pub columns: Vec<String>,
+    // e.g. "start-,due+,project+,urgency-"
+    pub sort: Vec<String>,
+    pub filter: String,
+}
+
+impl ReportDefinition {
+    /// Build from the raw `rc.report.<name>.*` values
+    pub fn from_settings(name: &str, columns: &str, sort: &str, filter: &str) -> Self {
+        let split = |list: &str| -> Vec<String> {
+            list.split(',').map(str::trim).filter(|item| !item.is_empty()).map(str::to_string).collect()
+        };
+        ReportDefinition {
+            name: name.to_string(),
+            columns: split(columns),
+            sort: split(sort),
+            filter: filter.trim().to_string(),
+        }
+    }
+
+    /// Column names for the task list. Formats (`due.relative`) are dropped and
+    /// `entry` becomes `age`; columns the list can't show are left to the list to skip.
+    pub fn list_columns(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|column| match column.split('.').next().unwrap_or(column) {
+                "entry" => "age".to_string(),
+                name => name.to_string(),
+            })
+            .collect()
+    }
+
+    /// The first sort column the task list can sort by. Directions aren't
+    /// followed: each sort key has its own fixed order.
+    pub fn sort_key(&self) -> Option<SortKey> {
+        self.sort.iter().find_map(|column| {
+            match column.trim_end_matches(['+', '-', '/']) {
+                "urgency" => Some(SortKey::Urgency),
+                "due" => Some(SortKey::Due),
+                "priority" => Some(SortKey::Priority),
+                "project" => Some(SortKey::Project),
+                "entry" => Some(SortKey::Age),
+                _ => None,
+            }
+        })
+    }
+
+    /// The filter for `task export`. `limit:` only makes sense for a printed
+    /// report, so it is left out; None when nothing remains.
+    pub fn export_filter(&self) -> Option<String> {
+        let terms: Vec<&str> = self
+            .filter
+            .split_whitespace()
+            .filter(|term| !term.starts_with("limit:"))
+            .collect();
+        (!terms.is_empty()).then(|| terms.join(" "))
+    }
+}
+
+/// Report names from `task _config` output (one setting name per line), built-in
+/// reports first and the rest alphabetically. Without any output, just the built-ins.
+pub fn report_names(config_names: &str) -> Vec<String> {
+    let mut custom: Vec<String> = config_names
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("report.")?.strip_suffix(".columns"))
+        .filter(|name| !BUILTIN_REPORTS.contains(name))
+        .map(str::to_string)
+        .collect();
+    custom.sort();
+    custom.dedup();
+
+    BUILTIN_REPORTS.iter().map(|name| name.to_string()).chain(custom).collect()
+}
@@ -0,0 +1,74 @@
+// Task search: exact substring matching, or fuzzy matching ranked by score
+
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use std::cmp::Reverse;
+
+use crate::data::models::Task;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SearchMode {
+    #[default]
+    Exact,
+    Fuzzy,
+}
+
+impl SearchMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SearchMode::Exact => "Exact",
+            SearchMode::Fuzzy => "Fuzzy",
+        }
+    }
+
+    pub fn toggled(&self) -> SearchMode {
+        match self {
+            SearchMode::Exact => SearchMode::Fuzzy,
+            SearchMode::Fuzzy => SearchMode::Exact,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TaskSearch {
+    pub mode: SearchMode,
+    matcher: SkimMatcherV2,
+}
+
+impl TaskSearch {
+    /// Whether `task` matches `query` in the current mode. An empty (or
+    /// whitespace-only) query matches every task in both modes.
+    pub fn matches(&self, task: &Task, query: &str) -> bool {
+        match self.mode {
+            SearchMode::Exact => task.matches_search(query),
+            SearchMode::Fuzzy => query.trim().is_empty() || self.score(task, query).is_some(),
+        }
+    }
+
+    /// Fuzzy score for `query`, the best over the same fields exact search looks at
+    /// (description, project, tags, annotations). None when nothing matches.
+    pub fn score(&self, task: &Task, query: &str) -> Option<i64> {
+        let query = query.trim();
+        if query.is_empty() {
+            return None;
+        }
+        let tag_query = query.strip_prefix('+').unwrap_or(query);
+
+        std::iter::once(self.matcher.fuzzy_match(&task.description, query))
+            .chain(task.project.iter().map(|project| self.matcher.fuzzy_match(project, query)))
+            .chain(task.tags.iter().map(|tag| self.matcher.fuzzy_match(tag, tag_query)))
+            .chain(task.annotations.iter().map(|annotation| self.matcher.fuzzy_match(&annotation.description, query)))
+            .flatten()
+            .max()
+    }
+
+    /// Whether results are ranked by score: fuzzy mode with something typed.
+    pub fn ranks(&self, query: &str) -> bool {
+        self.mode == SearchMode::Fuzzy && !query.trim().is_empty()
+    }
+
+    /// Best matches first. The sort is stable, so equal scores keep their current order.
+    pub fn sort_by_score(&self, tasks: &mut [Task], query: &str) {
+        tasks.sort_by_cached_key(|task| Reverse(self.score(task, query)));
+    }
+}
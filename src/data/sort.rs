@@ -0,0 +1,239 @@
+// Sort orders for the task list
+
+use chrono::{DateTime, Datelike, Duration, Utc};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::data::models::{Priority, Task};
+use crate::utils::formatting::day_of;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortKey {
+    Age,
+    Due,
+    Priority,
+    Project,
+    Urgency,
+    // Prerequisites before the tasks that depend on them, most urgent first otherwise
+    Dependencies,
+}
+
+impl SortKey {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortKey::Age => "Age",
+            SortKey::Due => "Due",
+            SortKey::Priority => "Priority",
+            SortKey::Project => "Project",
+            SortKey::Urgency => "Urgency",
+            SortKey::Dependencies => "Dependencies",
+        }
+    }
+
+    /// Sort in place. Ties fall back to entry date, newest first, so the
+    /// order stays stable across reloads.
+    pub fn sort(&self, tasks: &mut [Task]) {
+        if *self == SortKey::Dependencies {
+            return Self::sort_by_dependencies(tasks);
+        }
+        tasks.sort_by(|a, b| self.compare(a, b).then_with(|| b.entry.cmp(&a.entry)));
+    }
+
+    // Kahn's algorithm, taking the most urgent task whose prerequisites are all placed.
+    // Dependencies on tasks that aren't in `tasks` are ignored. Taskwarrior refuses
+    // cycles, but if one shows up the most urgent task on it goes first and the rest follow.
+    fn sort_by_dependencies(tasks: &mut [Task]) {
+        let index: HashMap<&str, usize> = tasks.iter().enumerate().map(|(i, task)| (task.uuid.as_str(), i)).collect();
+        let prerequisites: Vec<Vec<usize>> = tasks
+            .iter()
+            .enumerate()
+            .map(|(i, task)| {
+                let mut prerequisites: Vec<usize> = task.depends.iter()
+                    .filter_map(|uuid| index.get(uuid.as_str()).copied())
+                    .filter(|&p| p != i)
+                    .collect();
+                prerequisites.sort_unstable();
+                prerequisites.dedup();
+                prerequisites
+            })
+            .collect();
+        let mut waiting_on: Vec<usize> = prerequisites.iter().map(Vec::len).collect();
+        let mut dependents = vec![Vec::new(); tasks.len()];
+        for (i, task_prerequisites) in prerequisites.iter().enumerate() {
+            for &prerequisite in task_prerequisites {
+                dependents[prerequisite].push(i);
+            }
+        }
+
+        let ready = |i: usize| Ready { urgency: tasks[i].urgency, entry: tasks[i].entry, index: i };
+        let mut queued = vec![false; tasks.len()];
+        let mut heap = BinaryHeap::new();
+        for i in (0..tasks.len()).filter(|&i| waiting_on[i] == 0) {
+            queued[i] = true;
+            heap.push(ready(i));
+        }
+
+        let mut order = Vec::with_capacity(tasks.len());
+        while order.len() < tasks.len() {
+            let next = match heap.pop() {
+                Some(next) => next.index,
+                // Only tasks on a cycle (or waiting on one) are left
+                None => match (0..tasks.len()).find(|&i| !queued[i]) {
+                    Some(start) => {
+                        let next = Self::cycle_from(start, &prerequisites, &queued).into_iter().map(ready).max()
+                            .map_or(start, |next| next.index);
+                        queued[next] = true;
+                        next
+                    }
+                    None => break,
+                },
+            };
+            order.push(next);
+            for &dependent in &dependents[next] {
+                waiting_on[dependent] = waiting_on[dependent].saturating_sub(1);
+                if waiting_on[dependent] == 0 && !queued[dependent] {
+                    queued[dependent] = true;
+                    heap.push(ready(dependent));
+                }
+            }
+        }
+
+        let sorted: Vec<Task> = order.into_iter().map(|i| tasks[i].clone()).collect();
+        tasks.clone_from_slice(&sorted);
+    }
+
+    // Every unplaced task still has an unplaced prerequisite, so following them from
+    // `start` has to come back around; returns the tasks on that loop
+    fn cycle_from(start: usize, prerequisites: &[Vec<usize>], placed: &[bool]) -> Vec<usize> {
+        let mut path = vec![start];
+        let mut current = start;
+        while let Some(&next) = prerequisites[current].iter().find(|&&p| !placed[p]) {
+            if let Some(position) = path.iter().position(|&i| i == next) {
+                return path.split_off(position);
+            }
+            path.push(next);
+            current = next;
+        }
+        path
+    }
+
+    fn compare(&self, a: &Task, b: &Task) -> Ordering {
+        match self {
+            // Newest first
+            SortKey::Age => b.entry.cmp(&a.entry),
+            // Soonest first, undated tasks last
+            SortKey::Due => match (a.due, b.due) {
+                (Some(a_due), Some(b_due)) => a_due.cmp(&b_due),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+            // High → Medium → Low → none
+            SortKey::Priority => Self::priority_rank(&a.priority).cmp(&Self::priority_rank(&b.priority)),
+            // Alphabetical, tasks without a project last
+            SortKey::Project => match (&a.project, &b.project) {
+                (Some(a_project), Some(b_project)) => a_project.to_lowercase().cmp(&b_project.to_lowercase()),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+            // Most urgent first
+            SortKey::Urgency => b.urgency.total_cmp(&a.urgency),
+            // Handled by sort_by_dependencies; order within independent tasks
+            SortKey::Dependencies => b.urgency.total_cmp(&a.urgency),
+        }
+    }
+
+    fn priority_rank(priority: &Option<Priority>) -> u8 {
+        match priority {
+            Some(Priority::High) => 0,
+            Some(Priority::Medium) => 1,
+            Some(Priority::Low) => 2,
+            None => 3,
+        }
+    }
+}
+
+// A task the dependency order can place next; the heap yields the most urgent, then newest
+struct Ready {
+    urgency: f64,
+    entry: DateTime<Utc>,
+    index: usize,
+}
+
+impl Ord for Ready {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.urgency
+            .total_cmp(&other.urgency)
+            .then_with(|| self.entry.cmp(&other.entry))
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for Ready {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Ready {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Ready {}
+
+// Due-date sections for the grouped task list, in display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DueGroup {
+    Overdue,
+    Today,
+    ThisWeek,
+    Later,
+    // Past due dates on tasks that aren't pending (completed, deleted)
+    Earlier,
+    NoDueDate,
+}
+
+impl DueGroup {
+    pub fn of(task: &Task, now: DateTime<Utc>) -> Self {
+        let Some(due) = task.due else {
+            return DueGroup::NoDueDate;
+        };
+        if task.is_overdue() {
+            return DueGroup::Overdue;
+        }
+
+        let today = day_of(&now);
+        let due_date = day_of(&due);
+        // Weeks start on Monday, like the due filter's This Week preset
+        let next_monday = today + Duration::days(7 - today.weekday().num_days_from_monday() as i64);
+        if due_date == today {
+            DueGroup::Today
+        } else if due_date < today {
+            DueGroup::Earlier
+        } else if due_date < next_monday {
+            DueGroup::ThisWeek
+        } else {
+            DueGroup::Later
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DueGroup::Overdue => "Overdue",
+            DueGroup::Today => "Due Today",
+            DueGroup::ThisWeek => "Due This Week",
+            DueGroup::Later => "Due Later",
+            DueGroup::Earlier => "Earlier",
+            DueGroup::NoDueDate => "No Due Date",
+        }
+    }
+
+    /// Reorder tasks into sections; the stable sort keeps the current order within each one.
+    pub fn group(tasks: &mut [Task]) {
+        let now = Utc::now();
+        tasks.sort_by_key(|task| DueGroup::of(task, now));
+    }
+}
@@ -3,25 +3,65 @@ use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
 use std::time::Duration;
 
 use crate::config::Config;
+use crate::data::sort::SortKey;
 
 #[derive(Debug, Clone)]
 pub enum Action {
     Quit,
     Refresh,
     Help,
+    CheatSheet,
+    Settings,
+    ReloadConfig,
+    CycleTheme,
     AddTask,
+    QuickAdd,
     EditTask,
     DoneTask,
     DeleteTask,
+    CyclePriority,
+    Annotations,
+    Snooze,
+    Unwait,
+    Reopen,
+    CopyUuid,
+    CopyDescription,
+    CopyAddCommand,
+    BatchTag,
+    ReportMenu,
+    Find,
+    FindNext,
+    FindPrevious,
+    OpenLink,
+    SortBy(SortKey),
+    NextView,
+    RecentlyCompleted,
+    ToggleGrouping,
+    ToggleClosedTasks,
+    ToggleBlocked,
+    FilterByProject,
+    FilterByTag,
+    ToggleDetail,
+    CycleLayout,
+    Sync,
+    ExternalEditor,
+    ToggleSearchMode,
+    DatePicker,
     MoveUp,
     MoveDown,
     MoveLeft,
     MoveRight,
+    FirstTask,
+    LastTask,
+    PageUp,
+    PageDown,
     Select,
     Back,
     Filter,
     Context,
     Reports,
+    Projects,
+    Export,
     Character(char),
     Backspace,
     None,
@@ -54,46 +94,260 @@ impl InputHandler {
     }
 
     pub fn handle_key_event_with_context(&self, key: KeyEvent, in_form: bool) -> Action {
-        if in_form {
-            match key.code {
-                KeyCode::Esc => Action::Back,
-                KeyCode::Enter => Action::Select,
-                KeyCode::Up => Action::MoveUp,
-                KeyCode::Down => Action::MoveDown,
-                KeyCode::Left => Action::MoveLeft,   // Enable cursor movement in forms
-                KeyCode::Right => Action::MoveRight, // Enable cursor movement in forms
-                KeyCode::Tab => Action::Tab, // Tab for section navigation in filters
-                KeyCode::BackTab => Action::MoveUp, // Shift+Tab moves to previous field (same as up arrow)
-                KeyCode::Backspace => Action::Backspace,
-                KeyCode::Char(' ') => Action::Space, // Space for toggling filters
-                KeyCode::Char(c) => Action::Character(c),
-                _ => Action::None,
-            }
+        self.handle_key_event_in_view(key, in_form, KeyContext::TaskList)
+    }
+
+    // Keys are looked up in list order, except that a binding for the view on screen
+    // wins over another view's, so the calendar can give keys like 1-7 a meaning of its
+    // own. Global and modified bindings (Ctrl+c) still come first.
+    pub fn handle_key_event_in_view(&self, key: KeyEvent, in_form: bool, view: KeyContext) -> Action {
+        let matching: Vec<Binding> = self.bindings().into_iter()
+            .filter(|b| b.context.is_input_mode() == in_form)
+            .filter(|b| b.matches(&key))
+            .collect();
+        let binding = match matching.first() {
+            Some(first) if first.context != KeyContext::Global => matching.iter()
+                .find(|b| b.context == view && b.modifiers == first.modifiers)
+                .or(Some(first)),
+            first => first,
+        }
+        .cloned();
+
+        match (binding, key.code) {
+            (Some(binding), _) => binding.action,
+            // Catch-all for other characters (t, <, >, etc) and typed text
+            (None, KeyCode::Char(c)) => Action::Character(c),
+            (None, _) => Action::None,
+        }
+    }
+
+    // Every binding the handler knows about, in lookup order. The help view
+    // renders this same list, so it stays correct when keys change.
+    pub fn bindings(&self) -> Vec<Binding> {
+        use KeyContext::*;
+
+        let mut bindings = vec![
+            Binding::new(KeyCode::Char('q'), Action::Quit, Global, "Quit"),
+            Binding::ctrl('c', Action::Quit, Global, "Quit"),
+            Binding::new(KeyCode::F(1), Action::Help, Global, "Show this help"),
+            Binding::new(KeyCode::Char('?'), Action::CheatSheet, Global, "Keys for this view in a popup (any key closes)"),
+            Binding::new(KeyCode::F(2), Action::Settings, Global, "Settings: the active config"),
+            Binding::new(KeyCode::F(5), Action::Refresh, Global, "Reload tasks"),
+            Binding::ctrl('t', Action::CycleTheme, Global, "Switch color theme (kept on quit)"),
+            Binding::new(KeyCode::Char('r'), Action::Reports, Global, "Reports"),
+            Binding::new(KeyCode::Char('P'), Action::Projects, Global, "Projects"),
+            Binding::new(KeyCode::Esc, Action::Back, Global, "Back / close"),
+            Binding::new(KeyCode::Char('a'), Action::AddTask, TaskList, "Add new task"),
+            Binding::new(KeyCode::Char('A'), Action::QuickAdd, TaskList, "Quick add in Taskwarrior syntax"),
+            Binding::new(KeyCode::Char('e'), Action::EditTask, TaskList, "Edit selected task"),
+            Binding::new(KeyCode::Char('d'), Action::DoneTask, TaskList, "Mark task as done (reopens a completed one)"),
+            Binding::new(KeyCode::Delete, Action::DeleteTask, TaskList, "Delete selected task"),
+            Binding::new(KeyCode::Char('p'), Action::CyclePriority, TaskList, "Cycle priority (None/H/M/L)"),
+            Binding::new(KeyCode::Char('N'), Action::Annotations, TaskList, "Manage annotations"),
+            Binding::new(KeyCode::Char('w'), Action::Snooze, TaskList, "Snooze: hide the task until a wait date"),
+            Binding::new(KeyCode::Char('W'), Action::Unwait, TaskList, "Unwait: bring a waiting task back now"),
+            Binding::new(KeyCode::Char('o'), Action::Reopen, TaskList, "Reopen a completed or deleted task"),
+            Binding::new(KeyCode::Char('Y'), Action::CopyUuid, TaskList, "Copy the task's UUID to the clipboard"),
+            Binding::ctrl('y', Action::CopyDescription, TaskList, "Copy the task's description to the clipboard"),
+            Binding::new(KeyCode::Char('X'), Action::CopyAddCommand, TaskList, "Copy a `task add` command that recreates the task"),
+            Binding::new(KeyCode::Char('B'), Action::BatchTag, TaskList, "Add or remove a tag on every listed task (asks first)"),
+            Binding::new(KeyCode::Char('R'), Action::ReportMenu, TaskList, "Show the list as a Taskwarrior report"),
+            Binding::new(KeyCode::Char('f'), Action::Find, TaskList, "Find: jump to tasks as you type (n/N next/previous)"),
+            Binding::new(KeyCode::Char('O'), Action::OpenLink, TaskList, "Open a URL from the task in the browser"),
+            Binding::new(KeyCode::Char('/'), Action::Filter, TaskList, "Open the filter panel"),
+            Binding::new(KeyCode::Char('x'), Action::Export, TaskList, "Export tasks"),
+            // Same action as the Reports toggle, so the two contexts can share the key
+            Binding::new(KeyCode::Char('c'), Action::Context, TaskList, "Show the task's due date in the calendar"),
+            Binding::new(KeyCode::Char('n'), Action::NextView, TaskList, "Toggle next actionable view"),
+            Binding::new(KeyCode::Char('H'), Action::RecentlyCompleted, TaskList, "Toggle recently completed tasks"),
+            Binding::new(KeyCode::Char('v'), Action::ToggleGrouping, TaskList, "Toggle due date sections"),
+            Binding::new(KeyCode::Char('i'), Action::ToggleDetail, TaskList, "Show / hide task details (compact layout)"),
+            Binding::new(KeyCode::Enter, Action::Select, TaskList, "Open the selected task full screen"),
+            Binding::new(KeyCode::Char('L'), Action::CycleLayout, TaskList, "Switch layout (auto / split / compact)"),
+            Binding::new(KeyCode::Char('C'), Action::ToggleClosedTasks, TaskList, "Show / hide completed and deleted tasks"),
+            Binding::new(KeyCode::Char('b'), Action::ToggleBlocked, TaskList, "Show / hide tasks blocked by dependencies"),
+            Binding::new(KeyCode::Char('F'), Action::FilterByProject, TaskList, "Show only the task's project (again to clear)"),
+            Binding::new(KeyCode::Char('T'), Action::FilterByTag, TaskList, "Show only the task's first tag (again to clear)"),
+            Binding::new(KeyCode::Char('S'), Action::Sync, TaskList, "Sync with the Taskwarrior server"),
+            Binding::new(KeyCode::Char('1'), Action::SortBy(SortKey::Due), TaskList, "Sort by due date"),
+            Binding::new(KeyCode::Char('2'), Action::SortBy(SortKey::Priority), TaskList, "Sort by priority"),
+            Binding::new(KeyCode::Char('3'), Action::SortBy(SortKey::Project), TaskList, "Sort by project"),
+            Binding::new(KeyCode::Char('4'), Action::SortBy(SortKey::Urgency), TaskList, "Sort by urgency"),
+            Binding::new(KeyCode::Char('5'), Action::SortBy(SortKey::Age), TaskList, "Sort by age"),
+            Binding::new(KeyCode::Char('6'), Action::SortBy(SortKey::Dependencies), TaskList, "Sort by dependencies (prerequisites first)"),
+            Binding::new(KeyCode::Up, Action::MoveUp, TaskList, "Previous task"),
+            Binding::new(KeyCode::Down, Action::MoveDown, TaskList, "Next task"),
+        ];
+
+        // Vim-style navigation (opt-in so plain letters stay free)
+        if self.config.ui.vim_keys {
+            bindings.extend([
+                Binding::new(KeyCode::Char('k'), Action::MoveUp, TaskList, "Previous task"),
+                Binding::new(KeyCode::Char('j'), Action::MoveDown, TaskList, "Next task"),
+                Binding::new(KeyCode::Char('g'), Action::FirstTask, TaskList, "First task"),
+                Binding::new(KeyCode::Char('G'), Action::LastTask, TaskList, "Last task"),
+            ]);
+        }
+
+        bindings.extend([
+            Binding::new(KeyCode::Home, Action::FirstTask, TaskList, "First task"),
+            Binding::new(KeyCode::End, Action::LastTask, TaskList, "Last task"),
+            Binding::new(KeyCode::PageUp, Action::PageUp, TaskList, "Page up"),
+            Binding::new(KeyCode::PageDown, Action::PageDown, TaskList, "Page down"),
+            // The full-screen view of the task selected in the list
+            Binding::new(KeyCode::Char('e'), Action::EditTask, TaskDetail, "Edit this task"),
+            Binding::new(KeyCode::Char('d'), Action::DoneTask, TaskDetail, "Mark this task done (reopens a completed one)"),
+            Binding::new(KeyCode::Char('A'), Action::Annotations, TaskDetail, "Add, edit and delete this task's annotations"),
+            Binding::new(KeyCode::Char('N'), Action::Annotations, TaskDetail, "Add, edit and delete this task's annotations"),
+            // The task list after Enter ends typing a find; the other task list keys still work
+            Binding::new(KeyCode::Char('n'), Action::FindNext, Find, "Next match"),
+            Binding::new(KeyCode::Char('N'), Action::FindPrevious, Find, "Previous match"),
+            Binding::new(KeyCode::Char('c'), Action::Context, Reports, "Toggle calendar / dashboard"),
+            Binding::new(KeyCode::Tab, Action::Tab, Reports, "Switch between the project and tag tables (dashboard)"),
+            Binding::new(KeyCode::Enter, Action::Select, Reports, "Show the selected project's or tag's tasks (dashboard)"),
+            Binding::new(KeyCode::Left, Action::MoveLeft, Reports, "Previous day (calendar)"),
+            Binding::new(KeyCode::Right, Action::MoveRight, Reports, "Next day (calendar)"),
+            Binding::new(KeyCode::Char('<'), Action::Character('<'), Reports, "Previous month (calendar)"),
+            Binding::new(KeyCode::Char('>'), Action::Character('>'), Reports, "Next month (calendar)"),
+            Binding::new(KeyCode::Char('t'), Action::Character('t'), Reports, "Jump to today (calendar)"),
+            Binding::new(KeyCode::Char('g'), Action::Character('g'), Reports, "Go to a date: friday, +2w, eom, 2025-03-14 (calendar)"),
+        ]);
+        // Next Monday (1) to Sunday (7) after the selected day
+        bindings.extend(('1'..='7').map(|c| {
+            Binding::new(KeyCode::Char(c), Action::Character(c), Reports, "Next Monday..Sunday (calendar)")
+        }));
+
+        bindings.extend([
+            Binding::new(KeyCode::Enter, Action::Select, Projects, "Show the project's tasks"),
+            Binding::new(KeyCode::Up, Action::MoveUp, Projects, "Previous project"),
+            Binding::new(KeyCode::Down, Action::MoveDown, Projects, "Next project"),
+            Binding::new(KeyCode::Char('R'), Action::ReloadConfig, Settings, "Reload the config file from disk"),
+            Binding::new(KeyCode::Up, Action::MoveUp, Settings, "Scroll up"),
+            Binding::new(KeyCode::Down, Action::MoveDown, Settings, "Scroll down"),
+            // Filter panel and forms (text input reaches them as Character)
+            Binding::ctrl('c', Action::Quit, Filter, "Quit"),
+            Binding::new(KeyCode::Esc, Action::Back, Filter, "Close the filter panel"),
+            Binding::ctrl('f', Action::ToggleSearchMode, Filter, "Switch search between exact and fuzzy"),
+            Binding::new(KeyCode::Enter, Action::Select, Filter, "Apply filters"),
+            Binding::new(KeyCode::Tab, Action::Tab, Filter, "Next filter section"),
+            Binding::new(KeyCode::Up, Action::MoveUp, Filter, "Previous item"),
+            Binding::new(KeyCode::Down, Action::MoveDown, Filter, "Next item"),
+            Binding::new(KeyCode::Char(' '), Action::Space, Filter, "Toggle highlighted item"),
+            Binding::new(KeyCode::Char('r'), Action::Character('r'), Filter, "Rename highlighted project"),
+            Binding::new(KeyCode::Char('C'), Action::Character('C'), Filter, "Clear all filters"),
+            Binding::new(KeyCode::Char('+'), Action::Character('+'), Filter, "Raise the urgency threshold (Urgency ≥ row)"),
+            Binding::new(KeyCode::Char('-'), Action::Character('-'), Filter, "Lower the urgency threshold (Urgency ≥ row)"),
+            Binding::new(KeyCode::Backspace, Action::Backspace, Filter, "Delete search text"),
+            Binding::ctrl('c', Action::Quit, Form, "Quit (asks first if the form has unsaved changes)"),
+            Binding::ctrl('e', Action::ExternalEditor, Form, "Edit description in $EDITOR"),
+            Binding::ctrl('d', Action::DatePicker, Form, "Pick the due date from a calendar"),
+            Binding::new(KeyCode::Enter, Action::Select, Form, "Save"),
+            Binding::new(KeyCode::Esc, Action::Back, Form, "Cancel"),
+            Binding::new(KeyCode::Tab, Action::Tab, Form, "Next field"),
+            Binding::new(KeyCode::BackTab, Action::MoveUp, Form, "Previous field"),
+            Binding::new(KeyCode::Up, Action::MoveUp, Form, "Previous field"),
+            Binding::new(KeyCode::Down, Action::MoveDown, Form, "Next field"),
+            Binding::new(KeyCode::Left, Action::MoveLeft, Form, "Move cursor left"),
+            Binding::new(KeyCode::Right, Action::MoveRight, Form, "Move cursor right"),
+            Binding::new(KeyCode::Backspace, Action::Backspace, Form, "Delete character"),
+            Binding::new(KeyCode::Char(' '), Action::Space, Form, "Space / toggle"),
+        ]);
+
+        bindings
+    }
+}
+
+// Where a binding applies; Filter and Form keys are read in input mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyContext {
+    Global,
+    TaskList,
+    TaskDetail,
+    Find,
+    Reports,
+    Projects,
+    Settings,
+    Filter,
+    Form,
+}
+
+impl KeyContext {
+    pub const ALL: [KeyContext; 9] = [
+        KeyContext::Global,
+        KeyContext::TaskList,
+        KeyContext::TaskDetail,
+        KeyContext::Find,
+        KeyContext::Filter,
+        KeyContext::Form,
+        KeyContext::Reports,
+        KeyContext::Projects,
+        KeyContext::Settings,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeyContext::Global => "Global",
+            KeyContext::TaskList => "Task List",
+            KeyContext::TaskDetail => "Task Detail",
+            KeyContext::Find => "Find",
+            KeyContext::Reports => "Reports",
+            KeyContext::Projects => "Projects",
+            KeyContext::Settings => "Settings",
+            KeyContext::Filter => "Filter Panel",
+            KeyContext::Form => "Task Form & Dialogs",
+        }
+    }
+
+    fn is_input_mode(&self) -> bool {
+        matches!(self, KeyContext::Filter | KeyContext::Form)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+    pub action: Action,
+    pub context: KeyContext,
+    pub description: &'static str,
+}
+
+impl Binding {
+    fn new(code: KeyCode, action: Action, context: KeyContext, description: &'static str) -> Self {
+        Binding { code, modifiers: KeyModifiers::NONE, action, context, description }
+    }
+
+    fn ctrl(c: char, action: Action, context: KeyContext, description: &'static str) -> Self {
+        Binding { code: KeyCode::Char(c), modifiers: KeyModifiers::CONTROL, action, context, description }
+    }
+
+    // Plain bindings ignore modifiers (Shift arrives with capitals), so
+    // modified bindings are listed ahead of them
+    fn matches(&self, key: &KeyEvent) -> bool {
+        key.code == self.code && key.modifiers.contains(self.modifiers)
+    }
+
+    // Label shown in the help view, e.g. "Ctrl+c", "F1", "PgDn"
+    pub fn key_label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::BackTab => "Shift+Tab".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            other => format!("{:?}", other),
+        };
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            format!("Ctrl+{}", key)
         } else {
-            match key.code {
-                KeyCode::Char('q') => Action::Quit,
-                KeyCode::F(1) => Action::Help,
-                KeyCode::F(5) => Action::Refresh,
-                KeyCode::Char('a') => Action::AddTask,
-                KeyCode::Char('e') => Action::EditTask,
-                KeyCode::Char('d') => Action::DoneTask,
-                KeyCode::Delete => Action::DeleteTask,
-                KeyCode::Up => Action::MoveUp,
-                KeyCode::Down => Action::MoveDown,
-                KeyCode::Left => Action::MoveLeft,
-                KeyCode::Right => Action::MoveRight,
-                KeyCode::Enter => Action::Select,
-                KeyCode::Esc => Action::Back,
-                KeyCode::Char('/') => Action::Filter,
-                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::Quit,
-                KeyCode::Char('c') => Action::Context,
-                KeyCode::Char('r') => Action::Reports,
-                KeyCode::Tab => Action::Tab,
-                KeyCode::Backspace => Action::Backspace,
-                KeyCode::Char(' ') => Action::Space,
-                KeyCode::Char(c) => Action::Character(c), // Catch-all for other characters (t, <, >, etc)
-                _ => Action::None,
-            }
+            key
         }
     }
 }
@@ -0,0 +1,74 @@
+// Subcommands that run without the TUI, for scripts: `lazytask export` and `lazytask stats`
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::data::export::{ExportFormat, TaskExporter};
+use crate::data::models::{Project, Task};
+use crate::taskwarrior::TaskwarriorIntegration;
+use crate::ui::components::report_panel::TaskSummaryCache;
+
+// Export what `filter` matches, as the TUI's export dialog would. Returns how many tasks were written.
+pub async fn export(
+    taskwarrior: &TaskwarriorIntegration,
+    filter: Option<&str>,
+    format: ExportFormat,
+    path: &Path,
+) -> Result<usize> {
+    let tasks = load_tasks(taskwarrior, filter).await?;
+    TaskExporter::export_to_file(&tasks, path, format)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(tasks.len())
+}
+
+pub async fn stats(taskwarrior: &TaskwarriorIntegration, filter: Option<&str>) -> Result<String> {
+    let tasks = load_tasks(taskwarrior, filter).await?;
+    Ok(render_stats(&tasks))
+}
+
+async fn load_tasks(taskwarrior: &TaskwarriorIntegration, filter: Option<&str>) -> Result<Vec<Task>> {
+    let tasks = taskwarrior.list_tasks(filter).await?;
+    let skipped = taskwarrior.skipped_tasks();
+    if skipped > 0 {
+        eprintln!("warning: {} task(s) couldn't be read and were left out (run with --verbose for details)", skipped);
+    }
+    Ok(tasks)
+}
+
+// The dashboard's numbers as plain text, projects busiest first
+pub fn render_stats(tasks: &[Task]) -> String {
+    let summary = TaskSummaryCache::from_tasks(tasks, 0);
+    let mut lines = vec![
+        format!(
+            "Tasks      {} ({} pending, {} completed, {} deleted, {} waiting)",
+            summary.total, summary.pending, summary.completed, summary.deleted, summary.waiting
+        ),
+        format!("Active     {}", summary.active),
+        format!("Overdue    {}", summary.overdue),
+        format!(
+            "Priority   H {}, M {}, L {}, none {}",
+            summary.high_priority, summary.medium_priority, summary.low_priority, summary.no_priority
+        ),
+        format!("Urgency    {:.1} average", summary.avg_urgency),
+        format!("Last 7d    {} added, {} completed", summary.recent_tasks, summary.completed_this_week),
+    ];
+
+    let mut projects = Project::from_tasks(tasks);
+    projects.sort_by_key(|project| std::cmp::Reverse(project.pending_count + project.completed_count));
+    if !projects.is_empty() {
+        let width = projects.iter().map(|project| project.name.chars().count()).max().unwrap_or(0).max(7);
+        lines.push(String::new());
+        lines.push(format!("{:<width$}  Pending  Completed  Done", "Project"));
+        for project in &projects {
+            lines.push(format!(
+                "{:<width$}  {:>7}  {:>9}  {:>3.0}%",
+                project.name,
+                project.pending_count,
+                project.completed_count,
+                project.completion_rate()
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
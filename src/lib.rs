@@ -6,4 +6,5 @@ pub mod taskwarrior;
 pub mod ui;
 pub mod handlers;
 pub mod data;
-pub mod utils;
\ No newline at end of file
+pub mod utils;
+pub mod headless;
\ No newline at end of file
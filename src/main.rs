@@ -1,5 +1,7 @@
 use anyhow::Result;
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use std::io::Write;
+use std::path::PathBuf;
 
 mod app;
 mod config;
@@ -8,8 +10,12 @@ mod ui;
 mod handlers;
 mod data;
 mod utils;
+mod headless;
 
 use app::App;
+use config::Config;
+use data::export::ExportFormat;
+use taskwarrior::TaskwarriorIntegration;
 
 #[derive(Parser)]
 #[command(
@@ -19,19 +25,80 @@ use app::App;
 )]
 struct Cli {
     /// Configuration file path
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     config: Option<String>,
+
+    /// Taskwarrior taskrc file to use instead of the configured one
+    #[arg(long, value_name = "PATH", global = true)]
+    taskrc: Option<PathBuf>,
+
+    /// Taskwarrior data directory to use instead of the configured one
+    #[arg(long, value_name = "PATH", global = true)]
+    data: Option<PathBuf>,
     
-    /// Verbose output
-    #[arg(short, long)]
+    /// Taskwarrior filter to start from, e.g. "project:work" (overrides taskwarrior.startup_filter)
+    #[arg(short, long, value_name = "FILTER", global = true)]
+    filter: Option<String>,
+
+    /// Log Taskwarrior commands and errors to lazytask.log in the config directory
+    #[arg(short, long, global = true)]
     verbose: bool,
+
+    /// Run a command and exit instead of starting the TUI
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Write the tasks matching --filter to a file
+    Export {
+        /// File format
+        #[arg(long, value_enum, default_value = "json")]
+        format: ExportFormat,
+
+        /// File to write
+        path: PathBuf,
+    },
+    /// Print the dashboard's statistics for the tasks matching --filter
+    Stats,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+
+    if cli.verbose {
+        let log_path = utils::logging::init_file_logger()?;
+        log::info!("lazytask {} starting, logging to {}", env!("CARGO_PKG_VERSION"), log_path.display());
+    }
     
-    let mut app = App::new(cli.config.as_deref(), cli.verbose)?;
+    // Subcommands never touch the terminal, so their output can be piped
+    if let Some(command) = cli.command {
+        let config = Config::load(cli.config.as_deref())?
+            .with_taskwarrior_overrides(cli.taskrc, cli.data)?
+            .with_startup_filter(cli.filter);
+        let taskwarrior = TaskwarriorIntegration::from_config(&config.taskwarrior)?;
+        utils::formatting::set_use_utc(config.ui.use_utc);
+        match command {
+            Command::Export { format, path } => {
+                let count = headless::export(&taskwarrior, config.startup_filter(), format, &path).await?;
+                eprintln!("Exported {} task(s) to {}", count, path.display());
+            }
+            Command::Stats => {
+                let stats = headless::stats(&taskwarrior, config.startup_filter()).await?;
+                // A closed pipe (`lazytask stats | head`) only means the reader has seen enough
+                if let Err(e) = writeln!(std::io::stdout(), "{}", stats) {
+                    if e.kind() != std::io::ErrorKind::BrokenPipe {
+                        return Err(e.into());
+                    }
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    let mut app = App::new(cli.config.as_deref(), cli.taskrc, cli.data, cli.filter)?;
     app.run().await?;
     
     Ok(())
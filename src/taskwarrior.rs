@@ -1,32 +1,79 @@
-use anyhow::{Context, Result};
-use rusqlite::Connection;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
 use serde_json::Value;
+use std::fmt;
+use std::future::Future;
+use std::io::{self, Read};
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Output, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::config::TaskwarriorConfig;
+use crate::data::cache::TaskCache;
+use crate::data::database::TaskChampionDB;
+use crate::data::models::{HistoryEntry, Task};
+use crate::data::report::{self, ReportDefinition};
+use crate::utils::helpers::{calculate_urgency, UrgencyCoefficients};
+
+// Returned when the `task` executable can't be found, so the UI can explain it
+#[derive(Debug)]
+pub struct TaskBinaryNotFound;
+
+impl fmt::Display for TaskBinaryNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Taskwarrior `task` binary not found in PATH; please install Taskwarrior")
+    }
+}
+
+impl std::error::Error for TaskBinaryNotFound {}
+
+// How long an export is reused when nothing has been changed through LazyTask
+pub const DEFAULT_CACHE_SECONDS: u64 = 5;
+
+// How long a `task` command may run before it is stopped, so a hung one can't freeze the UI
+pub const DEFAULT_COMMAND_TIMEOUT_SECONDS: u64 = 60;
 
-use crate::data::models::Task;
+// Put in front of every command that changes tasks. Taskwarrior would otherwise stop to
+// ask about deletes, bulk changes (`bulk=0` never counts as bulk) and recurring tasks
+// (`no` changes only the chosen instance) on a stdin nobody is typing into.
+const NO_PROMPTS: [&str; 3] = ["rc.confirmation=no", "rc.recurrence.confirmation=no", "rc.bulk=0"];
 
 pub struct TaskwarriorIntegration {
     cli: TaskwarriorCLI,
-    db: Option<TaskChampionDB>,
+    db: Option<Mutex<TaskChampionDB>>,
+    // `taskwarrior.direct_db_writes`: try modifications in the database before the CLI
+    direct_db_writes: bool,
+    // Shared with loads running in the background
+    cache: Arc<Mutex<TaskCache>>,
+    // Tasks the last export that ran couldn't read; also set from background loads
+    skipped: Arc<AtomicUsize>,
 }
 
+#[derive(Clone)]
 pub struct TaskwarriorCLI {
     taskrc_path: Option<PathBuf>,
-}
-
-pub struct TaskChampionDB {
-    conn: Connection,
+    data_location: Option<PathBuf>,
+    // None waits for as long as a command takes
+    timeout: Option<Duration>,
+    // For exported tasks that come without an urgency
+    urgency_coefficients: UrgencyCoefficients,
 }
 
 impl TaskwarriorIntegration {
     pub fn new(taskrc_path: Option<PathBuf>, data_location: Option<PathBuf>) -> Result<Self> {
-        let cli = TaskwarriorCLI::new(taskrc_path.clone());
+        let cli = TaskwarriorCLI::new(taskrc_path.clone(), data_location.clone());
+
+        // Fail early with a clear error if Taskwarrior isn't installed
+        let version = cli.version()?;
+        log::info!("Using Taskwarrior {}", version);
         
         let db = if let Some(data_path) = data_location {
             let db_path = data_path.join("taskchampion.sqlite3");
             if db_path.exists() {
-                Some(TaskChampionDB::new(db_path)?)
+                Some(Mutex::new(TaskChampionDB::new(db_path)?))
             } else {
                 None
             }
@@ -35,7 +82,7 @@ impl TaskwarriorIntegration {
             if let Ok(data_path) = Self::get_data_location(&cli) {
                 let db_path = PathBuf::from(data_path).join("taskchampion.sqlite3");
                 if db_path.exists() {
-                    Some(TaskChampionDB::new(db_path)?)
+                    Some(Mutex::new(TaskChampionDB::new(db_path)?))
                 } else {
                     None
                 }
@@ -44,13 +91,106 @@ impl TaskwarriorIntegration {
             }
         };
 
-        Ok(TaskwarriorIntegration { cli, db })
+        // Read urgency coefficients once so local urgency matches Taskwarrior's
+        let urgency_coefficients = Self::load_urgency_coefficients(&cli);
+
+        Ok(TaskwarriorIntegration {
+            cli: cli.with_urgency_coefficients(urgency_coefficients),
+            db,
+            direct_db_writes: false,
+            cache: Arc::new(Mutex::new(TaskCache::new(DEFAULT_CACHE_SECONDS))),
+            skipped: Arc::new(AtomicUsize::new(0)),
+        })
     }
 
-    pub async fn list_tasks(&self, filter: Option<&str>) -> Result<Vec<Task>> {
+    // Set up as the `[taskwarrior]` config section says
+    pub fn from_config(config: &TaskwarriorConfig) -> Result<Self> {
+        Ok(Self::new(config.taskrc_path.clone(), config.data_location.clone())?
+            .with_cache_max_age(config.cache_max_age)
+            .with_command_timeout(config.command_timeout)
+            .with_direct_db_writes(config.direct_db_writes))
+    }
+
+    // 0 turns the export cache off
+    pub fn with_cache_max_age(self, seconds: u64) -> Self {
+        *lock_cache(&self.cache) = TaskCache::new(seconds);
+        self
+    }
+
+    // 0 lets commands run for as long as they take
+    pub fn with_command_timeout(mut self, seconds: u64) -> Self {
+        self.cli = self.cli.with_timeout((seconds > 0).then(|| Duration::from_secs(seconds)));
+        self
+    }
+
+    // Only takes effect when the TaskChampion database was found
+    pub fn with_direct_db_writes(mut self, enabled: bool) -> Self {
+        if enabled && self.db.is_none() {
+            log::warn!("direct_db_writes is on, but no taskchampion.sqlite3 was found; changes go through the CLI");
+        }
+        self.direct_db_writes = enabled;
+        self
+    }
+
+    // How many tasks the last export had that couldn't be read and were left out
+    pub fn skipped_tasks(&self) -> usize {
+        self.skipped.load(Ordering::Relaxed)
+    }
+
+    // Reuses a recent export with the same filter. The future owns what it needs,
+    // so it can be spawned and awaited off the UI loop.
+    pub fn list_tasks(&self, filter: Option<&str>) -> impl Future<Output = Result<Vec<Task>>> + Send + 'static {
         // For now, always use CLI since DB implementation is not complete
         // TODO: Implement direct database access for better performance
-        self.cli.list_tasks(filter).await
+        let cli = self.cli.clone();
+        let cache = Arc::clone(&self.cache);
+        let skipped = Arc::clone(&self.skipped);
+        let filter = filter.map(str::to_string);
+        async move {
+            let (cached, generation) = {
+                let cache = lock_cache(&cache);
+                (cache.get(filter.as_deref()).map(<[Task]>::to_vec), cache.generation())
+            };
+            if let Some(tasks) = cached {
+                return Ok(tasks);
+            }
+
+            let (tasks, unreadable) = cli.export(filter.as_deref()).await?;
+            skipped.store(unreadable, Ordering::Relaxed);
+            let mut cache = lock_cache(&cache);
+            // Something changed while exporting; this result may already be out of date
+            if cache.generation() == generation {
+                cache.insert(filter.as_deref(), tasks.clone());
+            }
+            Ok(tasks)
+        }
+    }
+
+    // Next load runs `task export` again, e.g. for an explicit refresh
+    pub fn invalidate_cache(&self) {
+        lock_cache(&self.cache).invalidate_all();
+    }
+
+    // Every change goes through here, whether or not it worked: a failed bulk
+    // command may still have changed some tasks
+    fn invalidating<T>(&self, result: Result<T>) -> Result<T> {
+        self.invalidate_cache();
+        result
+    }
+
+    // The task's real change history. Like `list_tasks`, the future can be spawned on its own.
+    pub fn task_info(&self, uuid: &str) -> impl Future<Output = Result<Vec<HistoryEntry>>> + Send + 'static {
+        let cli = self.cli.clone();
+        let uuid = uuid.to_string();
+        async move { cli.task_info(&uuid).await }
+    }
+
+    pub async fn report_names(&self) -> Result<Vec<String>> {
+        self.cli.report_names().await
+    }
+
+    pub async fn report_definition(&self, name: &str) -> Result<ReportDefinition> {
+        self.cli.report_definition(name).await
     }
 
     pub async fn get_task(&self, id: u32) -> Result<Option<Task>> {
@@ -58,50 +198,294 @@ impl TaskwarriorIntegration {
         self.cli.get_task(id).await
     }
 
+    pub async fn get_task_by_uuid(&self, uuid: &str) -> Result<Option<Task>> {
+        self.cli.get_task_by_uuid(uuid).await
+    }
+
     pub async fn add_task(&self, description: &str, attributes: &[(&str, &str)]) -> Result<u32> {
-        self.cli.add_task(description, attributes).await
+        self.invalidating(self.cli.add_task(description, attributes).await)
     }
 
     pub async fn modify_task(&self, id: u32, attributes: &[(&str, &str)]) -> Result<()> {
-        self.cli.modify_task(id, attributes).await
+        if self.modify_in_db(&id.to_string(), attributes) {
+            return self.invalidating(Ok(()));
+        }
+        self.invalidating(self.cli.modify_task(id, attributes).await)
+    }
+
+    pub async fn modify_task_by_uuid(&self, uuid: &str, attributes: &[(&str, &str)]) -> Result<()> {
+        if self.modify_in_db(uuid, attributes) {
+            return self.invalidating(Ok(()));
+        }
+        self.invalidating(self.cli.modify_task_by_uuid(uuid, attributes).await)
+    }
+
+    // With direct writes on, make the change in the database. False leaves it to the CLI:
+    // writes are off, the change needs Taskwarrior, or the write failed and was rolled back.
+    fn modify_in_db(&self, filter: &str, attributes: &[(&str, &str)]) -> bool {
+        let Some(db) = self.db.as_ref().filter(|_| self.direct_db_writes) else {
+            return false;
+        };
+        let mut db = db.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Same filters the CLI is given: a working set ID or a UUID
+        let uuid = match filter.parse::<u32>() {
+            Ok(id) => db.working_set_uuid(id),
+            Err(_) => Ok(Some(filter.to_string())),
+        };
+        let result = uuid.and_then(|uuid| match uuid {
+            Some(uuid) => db.modify_task(&uuid, attributes),
+            None => Ok(false),
+        });
+        match result {
+            Ok(true) => true,
+            Ok(false) => {
+                log::debug!("Modifying task {} through the CLI: {:?} needs Taskwarrior", filter, attributes);
+                false
+            }
+            Err(e) => {
+                log::warn!("Direct write to task {} failed, using the CLI instead: {:#}", filter, e);
+                false
+            }
+        }
+    }
+
+    pub async fn annotate_task(&self, uuid: &str, text: &str) -> Result<()> {
+        self.invalidating(self.cli.annotate_task(uuid, text).await)
+    }
+
+    pub async fn denotate_task(&self, uuid: &str, text: &str) -> Result<()> {
+        self.invalidating(self.cli.denotate_task(uuid, text).await)
     }
 
     pub async fn done_task(&self, id: u32) -> Result<()> {
-        self.cli.done_task(id).await
+        self.invalidating(self.cli.done_task(id).await)
     }
 
     pub async fn delete_task(&self, id: u32) -> Result<()> {
-        self.cli.delete_task(id).await
+        self.invalidating(self.cli.delete_task(id).await)
+    }
+
+    pub async fn reopen_task(&self, uuid: &str) -> Result<()> {
+        self.invalidating(self.cli.reopen_task(uuid).await)
+    }
+
+    pub async fn sync(&self) -> Result<String> {
+        self.invalidating(self.cli.sync().await)
     }
 
     fn get_data_location(cli: &TaskwarriorCLI) -> Result<String> {
         cli.execute_command(&["_get", "rc.data.location"])
     }
+
+    // One `task _get` for all of them rather than a command per coefficient
+    fn load_urgency_coefficients(cli: &TaskwarriorCLI) -> UrgencyCoefficients {
+        let keys: Vec<String> = UrgencyCoefficients::KEYS.iter().map(|key| format!("rc.{}", key)).collect();
+        let mut args = vec!["_get"];
+        args.extend(keys.iter().map(String::as_str));
+        let output = cli.execute_command(&args).unwrap_or_default();
+        // One value per line, in the order of the keys
+        let values: Vec<&str> = output.split_whitespace().collect();
+        // A key Taskwarrior doesn't know prints nothing, so the rest wouldn't line up
+        if values.len() != UrgencyCoefficients::KEYS.len() {
+            log::debug!("Using the default urgency coefficients; task _get printed {:?}", output);
+            return UrgencyCoefficients::default();
+        }
+        UrgencyCoefficients::from_lookup(|key| {
+            let index = UrgencyCoefficients::KEYS.iter().position(|known| *known == key)?;
+            Some(values[index].to_string())
+        })
+    }
+}
+
+// Taskwarrior's spelling of %Y-%m-%d %H:%M:%S, which `task info` prints in local time
+const INFO_DATE_FORMAT: &str = "Y-M-D H:N:S";
+
+fn parse_info_date(text: &str) -> Result<DateTime<Utc>> {
+    let naive = NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S")
+        .with_context(|| format!("unexpected date in task info history: {}", text))?;
+    // An hour that a DST change skips or repeats still gets a date
+    Ok(Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|local| local.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&naive)))
+}
+
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            pipe.read_to_end(&mut bytes).ok();
+        }
+        bytes
+    })
+}
+
+// A panic while the lock was held can't leave the cache half-updated, so keep using it
+fn lock_cache(cache: &Mutex<TaskCache>) -> MutexGuard<'_, TaskCache> {
+    cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
 }
 
 impl TaskwarriorCLI {
-    pub fn new(taskrc_path: Option<PathBuf>) -> Self {
-        TaskwarriorCLI { taskrc_path }
+    pub fn new(taskrc_path: Option<PathBuf>, data_location: Option<PathBuf>) -> Self {
+        TaskwarriorCLI {
+            taskrc_path,
+            data_location,
+            timeout: Some(Duration::from_secs(DEFAULT_COMMAND_TIMEOUT_SECONDS)),
+            urgency_coefficients: UrgencyCoefficients::default(),
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_urgency_coefficients(mut self, coefficients: UrgencyCoefficients) -> Self {
+        self.urgency_coefficients = coefficients;
+        self
     }
 
     pub async fn list_tasks(&self, filter: Option<&str>) -> Result<Vec<Task>> {
-        let mut args = vec!["export"];
-        if let Some(f) = filter {
-            args.insert(0, f);
+        Ok(self.export(filter).await?.0)
+    }
+
+    // The tasks, and how many the export had that couldn't be read
+    async fn export(&self, filter: Option<&str>) -> Result<(Vec<Task>, usize)> {
+        let output = self.run(&Self::export_args(filter)).await?;
+        Self::parse_export_counting(&output, &self.urgency_coefficients)
+    }
+
+    // The filter is split on whitespace, one Taskwarrior argument per term
+    fn export_args(filter: Option<&str>) -> Vec<&str> {
+        let mut args: Vec<&str> = filter.map(|f| f.split_whitespace().collect()).unwrap_or_default();
+        args.push("export");
+        args
+    }
+
+    // An empty database exports `[]` (or nothing at all) and gives an empty list.
+    // Output that isn't a JSON array is an error; single tasks that don't parse
+    // are logged and skipped so one odd task doesn't hide the rest.
+    pub fn parse_export(output: &str) -> Result<Vec<Task>> {
+        Ok(Self::parse_export_counting(output, &UrgencyCoefficients::default())?.0)
+    }
+
+    // Like parse_export, also counting the tasks that were skipped. A task without an
+    // urgency gets one worked out with `coefficients`.
+    pub fn parse_export_counting(output: &str, coefficients: &UrgencyCoefficients) -> Result<(Vec<Task>, usize)> {
+        if output.trim().is_empty() {
+            return Ok((Vec::new(), 0));
         }
 
-        let output = self.execute_command(&args)?;
-        let tasks: Vec<Value> = serde_json::from_str(&output)
-            .with_context(|| "Failed to parse task export JSON")?;
+        let tasks: Vec<Value> = serde_json::from_str(output).map_err(|e| {
+            let start: String = output.trim().chars().take(40).collect();
+            anyhow::anyhow!("task export returned invalid JSON ({}), starting with: {}", e, start)
+        })?;
 
         let mut result = Vec::new();
+        let mut skipped = 0;
         for task_json in tasks {
-            if let Ok(task) = Task::from_json(&task_json) {
-                result.push(task);
+            match Task::from_json(&task_json) {
+                Ok(mut task) => {
+                    if task_json.get("urgency").is_none() {
+                        task.urgency = calculate_urgency(&task, coefficients);
+                    }
+                    result.push(task);
+                }
+                Err(e) => {
+                    skipped += 1;
+                    log::warn!(
+                        "Skipping task {} from export: {}",
+                        task_json.get("uuid").and_then(Value::as_str).unwrap_or("without uuid"),
+                        e
+                    );
+                }
             }
         }
 
-        Ok(result)
+        Ok((result, skipped))
+    }
+
+    // By UUID rather than ID so completed and deleted tasks have a history too.
+    // The date format and width are pinned so the table parses the same for everyone.
+    pub async fn task_info(&self, uuid: &str) -> Result<Vec<HistoryEntry>> {
+        let output = self
+            .run(&[
+                "rc.color=off",
+                "rc.defaultwidth=500",
+                &format!("rc.dateformat.info={}", INFO_DATE_FORMAT),
+                uuid,
+                "info",
+            ])
+            .await?;
+        Self::parse_info_history(&output)
+    }
+
+    // Reads the "Date / Modification" table at the end of `task info`. A date applies
+    // to the lines under it until the next one; wrapped lines are joined back up.
+    // Output without the table (or with dates we can't read) is an error.
+    pub fn parse_info_history(output: &str) -> Result<Vec<HistoryEntry>> {
+        let mut lines = output.lines();
+        let Some(dashes) = lines
+            .by_ref()
+            .skip_while(|line| !(line.trim_start().starts_with("Date") && line.contains("Modification")))
+            .nth(1)
+        else {
+            bail!("task info has no modification history");
+        };
+
+        // The column starts where the second run of dashes does
+        let column = dashes
+            .find(' ')
+            .filter(|_| dashes.starts_with('-'))
+            .and_then(|space| dashes[space..].find('-').map(|offset| space + offset));
+        let Some(column) = column else {
+            bail!("unexpected task info history header: {}", dashes);
+        };
+
+        let mut entries: Vec<HistoryEntry> = Vec::new();
+        for line in lines.take_while(|line| !line.trim().is_empty()) {
+            let date_text: String = line.chars().take(column).collect();
+            let change: String = line.chars().skip(column).collect();
+            let change = change.trim().to_string();
+
+            let date = match date_text.trim() {
+                "" => match entries.last_mut() {
+                    // A change is a sentence; one without its full stop yet was wrapped
+                    Some(last) if !last.change.ends_with('.') => {
+                        last.change = format!("{} {}", last.change, change);
+                        continue;
+                    }
+                    Some(last) => last.date,
+                    None => bail!("task info history starts without a date"),
+                },
+                text => parse_info_date(text)?,
+            };
+            entries.push(HistoryEntry { date, change });
+        }
+
+        if entries.is_empty() {
+            bail!("task info has an empty modification history");
+        }
+        Ok(entries)
+    }
+
+    // Every report configured in taskrc, custom ones included
+    pub async fn report_names(&self) -> Result<Vec<String>> {
+        let output = self.run(&["_config"]).await?;
+        Ok(report::report_names(&output))
+    }
+
+    // A report without columns isn't defined
+    pub async fn report_definition(&self, name: &str) -> Result<ReportDefinition> {
+        let setting = |field: &str| format!("rc.report.{}.{}", name, field);
+        let columns = self.run(&["_get", &setting("columns")]).await?;
+        if columns.is_empty() {
+            bail!("Taskwarrior has no report named '{}'", name);
+        }
+        let sort = self.run(&["_get", &setting("sort")]).await?;
+        let filter = self.run(&["_get", &setting("filter")]).await?;
+        Ok(ReportDefinition::from_settings(name, &columns, &sort, &filter))
     }
 
     pub async fn get_task(&self, id: u32) -> Result<Option<Task>> {
@@ -110,6 +494,12 @@ impl TaskwarriorCLI {
         Ok(tasks.into_iter().next())
     }
 
+    // Unlike an ID, a UUID still finds the task after it's renumbered, completed or deleted
+    pub async fn get_task_by_uuid(&self, uuid: &str) -> Result<Option<Task>> {
+        let tasks = self.list_tasks(Some(uuid)).await?;
+        Ok(tasks.into_iter().find(|task| task.uuid == uuid))
+    }
+
     pub async fn add_task(&self, description: &str, attributes: &[(&str, &str)]) -> Result<u32> {
         let mut args = vec!["add".to_string(), description.to_string()];
         
@@ -123,27 +513,42 @@ impl TaskwarriorCLI {
             }
         }
 
+        // Make sure the "Created task N." line is printed even with a quiet verbose setting
+        args.push("rc.verbose=new-id".to_string());
+
         let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-        let output = self.execute_command(&args_refs)?;
-        
-        // Parse the task ID from output like "Created task 42."
-        let id_str = output
-            .split_whitespace()
-            .find(|word| word.ends_with('.') && word[..word.len()-1].chars().all(|c| c.is_ascii_digit()))
-            .map(|word| &word[..word.len()-1])  // Remove the trailing dot
-            .or_else(|| output.split_whitespace().find(|word| word.chars().all(|c| c.is_ascii_digit())))
-            .ok_or_else(|| anyhow::anyhow!("Could not parse task ID from output: {}", output))?;
+        let output = self.run_change(&args_refs).await?;
         
-        id_str.parse().with_context(|| "Failed to parse task ID")
+        Self::parse_created_task_id(&output)
+            .ok_or_else(|| anyhow::anyhow!("Could not parse task ID from output: {}", output))
+    }
+
+    /// Pull the new ID out of `task add` output such as "Created task 42." or
+    /// "Created task 7 (recurrence template).", ignoring any other lines.
+    pub fn parse_created_task_id(output: &str) -> Option<u32> {
+        output.lines().find_map(|line| {
+            let rest = line.trim().strip_prefix("Created task ")?;
+            let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+            digits.parse().ok()
+        })
     }
 
     pub async fn modify_task(&self, id: u32, attributes: &[(&str, &str)]) -> Result<()> {
-        let mut args = vec![id.to_string(), "modify".to_string()];
+        self.modify_matching(&id.to_string(), attributes).await
+    }
+
+    // UUIDs also reach completed and deleted tasks, which have no working ID
+    pub async fn modify_task_by_uuid(&self, uuid: &str, attributes: &[(&str, &str)]) -> Result<()> {
+        self.modify_matching(uuid, attributes).await
+    }
+
+    async fn modify_matching(&self, filter: &str, attributes: &[(&str, &str)]) -> Result<()> {
+        let mut args = vec![filter.to_string(), "modify".to_string()];
         
         for (key, value) in attributes {
             if value.is_empty() {
                 // Special case: for clearing attributes like "tags:", "project:", etc.
-                if *key == "tags" || *key == "project" || *key == "priority" || *key == "due" {
+                if *key == "tags" || *key == "project" || *key == "priority" || *key == "due" || *key == "wait" {
                     args.push(format!("{}:", key));
                 } else {
                     // For tags without values (like +tag)
@@ -157,34 +562,138 @@ impl TaskwarriorCLI {
 
         let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
         
-        self.execute_command(&args_refs)?;
+        self.run_change(&args_refs).await?;
+        Ok(())
+    }
+
+    // `--` keeps text such as "due:friday" or "+home" from being read as modifications
+    pub async fn annotate_task(&self, uuid: &str, text: &str) -> Result<()> {
+        self.run_change(&[uuid, "annotate", "--", text]).await?;
+        Ok(())
+    }
+
+    // Taskwarrior removes the first annotation whose text matches exactly
+    // (falling back to a partial match), so identical annotations go oldest first
+    pub async fn denotate_task(&self, uuid: &str, text: &str) -> Result<()> {
+        self.run_change(&[uuid, "denotate", "--", text]).await?;
         Ok(())
     }
 
     pub async fn done_task(&self, id: u32) -> Result<()> {
         let id_str = id.to_string();
-        self.execute_command(&[&id_str, "done"])?;
+        self.run_change(&[&id_str, "done"]).await?;
         Ok(())
     }
 
     pub async fn delete_task(&self, id: u32) -> Result<()> {
         let id_str = id.to_string();
-        // Use rc.confirmation=no to avoid interactive confirmation prompt
-        self.execute_command(&[&id_str, "delete", "rc.confirmation=no"])?;
+        self.run_change(&[&id_str, "delete"]).await?;
         Ok(())
     }
 
+    // Back to pending, without the end date. Completed and deleted tasks drop out of the
+    // working set and have no ID, so they're found by UUID.
+    pub async fn reopen_task(&self, uuid: &str) -> Result<()> {
+        self.run_change(&[uuid, "modify", "status:pending", "end:"]).await?;
+        Ok(())
+    }
+
+    // Run `task sync` and return its output (the last line is the summary)
+    pub async fn sync(&self) -> Result<String> {
+        self.run(&["sync"]).await
+    }
+
+    // Probe `task --version`; also tells us whether the binary exists at all
+    pub fn version(&self) -> Result<String> {
+        let output = Self::spawn(Command::new("task").arg("--version"), &["--version"], self.timeout)?;
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    // stdin is closed, so a prompt nothing else turned off gets an immediate EOF
+    // instead of waiting for an answer. Past the timeout the command is killed.
+    fn spawn(cmd: &mut Command, args: &[&str], timeout: Option<Duration>) -> Result<Output> {
+        cmd.stdin(Stdio::null()).stdout(Stdio::piped()).stderr(Stdio::piped());
+        let mut child = match cmd.spawn() {
+            Ok(child) => child,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Err(TaskBinaryNotFound.into()),
+            Err(e) => {
+                return Err(anyhow::Error::new(e)
+                    .context(format!("Failed to execute task command: {:?}", args)))
+            }
+        };
+        let Some(timeout) = timeout else {
+            return child.wait_with_output().context("Failed to read task command output");
+        };
+
+        // Both pipes are drained while waiting, so a long export can't fill one and stall
+        let stdout = read_pipe(child.stdout.take());
+        let stderr = read_pipe(child.stderr.take());
+        let deadline = Instant::now() + timeout;
+        let status = loop {
+            if let Some(status) = child.try_wait().context("Failed to wait for task command")? {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                child.kill().ok();
+                child.wait().ok();
+                bail!(
+                    "task {} did not finish within {}s and was stopped",
+                    args.join(" "),
+                    timeout.as_secs()
+                );
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+
+        Ok(Output {
+            status,
+            stdout: stdout.join().unwrap_or_default(),
+            stderr: stderr.join().unwrap_or_default(),
+        })
+    }
+
+    // `task` runs on tokio's blocking pool, so a slow command (a sync, a big modify)
+    // doesn't hold up the runtime thread the UI and background loads share
+    async fn run(&self, args: &[&str]) -> Result<String> {
+        let cli = self.clone();
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        tokio::task::spawn_blocking(move || {
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+            cli.execute_command(&args)
+        })
+        .await
+        .context("task command was interrupted")?
+    }
+
+    // For commands that change tasks; see NO_PROMPTS. The overrides go first, since
+    // anything after a `--` would be taken as annotation text.
+    async fn run_change(&self, args: &[&str]) -> Result<String> {
+        let args: Vec<&str> = NO_PROMPTS.iter().copied().chain(args.iter().copied()).collect();
+        self.run(&args).await
+    }
+
     fn execute_command(&self, args: &[&str]) -> Result<String> {
         let mut cmd = Command::new("task");
         
         if let Some(taskrc) = &self.taskrc_path {
             cmd.arg(format!("rc:{}", taskrc.display()));
         }
+
+        // Point every command at the chosen database, not just the direct DB reader
+        if let Some(data_location) = &self.data_location {
+            cmd.arg(format!("rc.data.location={}", data_location.display()));
+        }
         
         cmd.args(args);
         
-        let output = cmd.output()
-            .with_context(|| format!("Failed to execute task command: {:?}", args))?;
+        let started = Instant::now();
+        let output = Self::spawn(&mut cmd, args, self.timeout)?;
+        log::debug!(
+            "task {} -> {} in {:?}",
+            args.join(" "),
+            output.status,
+            started.elapsed()
+        );
 
         let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
         let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
@@ -200,29 +709,10 @@ impl TaskwarriorCLI {
             } else {
                 format!("Task command failed: {}", stderr)
             };
+            log::warn!("{}", error_msg);
             return Err(anyhow::anyhow!("{}", error_msg));
         }
 
         Ok(stdout)
     }
 }
-
-impl TaskChampionDB {
-    pub fn new(db_path: PathBuf) -> Result<Self> {
-        let conn = Connection::open(db_path)
-            .with_context(|| "Failed to open TaskChampion database")?;
-        
-        Ok(TaskChampionDB { conn })
-    }
-
-    pub async fn list_tasks(&self, _filter: Option<&str>) -> Result<Vec<Task>> {
-        // Placeholder implementation - would need to understand TaskChampion schema
-        // For now, fall back to CLI implementation
-        todo!("Direct TaskChampion DB access not yet implemented")
-    }
-
-    pub async fn get_task(&self, _id: u32) -> Result<Option<Task>> {
-        // Placeholder implementation
-        todo!("Direct TaskChampion DB access not yet implemented")
-    }
-}
@@ -1,93 +1,632 @@
 use anyhow::Result;
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
 
-use crate::config::Config;
-use crate::data::models::Task;
-use crate::handlers::input::Action;
+use std::time::Instant;
+
+use crate::config::{Config, UIConfig};
+use crate::data::export::TaskExporter;
+use crate::data::report::{ReportDefinition, BUILTIN_REPORTS};
+use crate::data::models::{HistoryEntry, Priority, Task, TaskStatus, NO_PROJECT};
+use crate::data::sort::{DueGroup, SortKey};
+use crate::handlers::input::{Action, InputHandler, KeyContext};
 use crate::taskwarrior::TaskwarriorIntegration;
+use crate::ui::components::annotation_dialog::{AnnotationDialog, AnnotationDialogResult};
+use crate::ui::components::link_picker::{LinkPicker, LinkPickerResult};
+use crate::ui::components::report_menu::{ReportMenu, ReportMenuResult};
+use crate::ui::components::save_conflict_dialog::{SaveConflictDialog, SaveConflictResult};
+use crate::ui::components::snooze_dialog::{SnoozeDialog, SnoozeResult};
+use crate::ui::components::date_jump_dialog::{DateJumpDialog, DateJumpResult};
+use crate::ui::components::export_dialog::{ExportDialog, ExportDialogResult};
+use crate::ui::components::batch_tag_dialog::{BatchTagDialog, BatchTagResult, TagOperation};
+use crate::ui::components::cheat_sheet::CheatSheet;
+use crate::ui::components::startup_briefing::StartupBriefing;
+use crate::ui::components::help_view::HelpView;
+use crate::ui::components::modal_dialog::{ConfirmDialog, ConfirmResult};
+use crate::ui::components::quick_add_dialog::{QuickAddDialog, QuickAddResult};
+use crate::ui::components::rename_dialog::{RenameDialogResult, RenameProjectDialog};
 use crate::ui::components::task_form::{TaskForm, TaskFormResult};
-use crate::ui::views::main_view::MainView;
-use crate::ui::views::reports_view::ReportsView;
+use crate::ui::themes::Theme;
+use crate::ui::views::main_view::{MainView, PanelLayout, COMPACT_WIDTH};
+use crate::ui::views::projects_view::ProjectsView;
+use crate::ui::views::reports_view::{DashboardTable, ReportsView};
+use crate::ui::views::settings_view::SettingsView;
+use crate::utils::clipboard::SystemClipboard;
+use crate::utils::links::{open_url, task_urls};
+use crate::utils::formatting::{set_use_utc, shell_quote, truncate_text, DateFormat};
+
+// Below this size only a "terminal too small" notice is drawn
+pub const MIN_WIDTH: u16 = 40;
+pub const MIN_HEIGHT: u16 = 12;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AppView {
     TaskList,
     TaskDetail,
     Reports,
+    Projects,
     Settings,
     Help,
 }
 
+impl AppView {
+    // Views that `ui.default_view` can name
+    pub const STARTUP_NAMES: [&'static str; 3] = ["task_list", "reports", "projects"];
+
+    pub fn from_name(name: &str) -> Option<AppView> {
+        match name.trim().to_lowercase().as_str() {
+            "task_list" => Some(AppView::TaskList),
+            "reports" => Some(AppView::Reports),
+            "projects" => Some(AppView::Projects),
+            _ => None,
+        }
+    }
+}
+
+// One-line feedback shown in the footer until the next key press
+pub struct StatusMessage {
+    pub text: String,
+    pub is_error: bool,
+}
+
+// Task list changes that can ask for confirmation first
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TaskChange {
+    Done,
+    Delete,
+}
+
+impl TaskChange {
+    fn needs_confirmation(self, ui: &UIConfig) -> bool {
+        match self {
+            TaskChange::Done => ui.confirm_done,
+            TaskChange::Delete => ui.confirm_delete,
+        }
+    }
+
+    fn verb(self) -> &'static str {
+        match self {
+            TaskChange::Done => "complete",
+            TaskChange::Delete => "delete",
+        }
+    }
+
+    // The same dialog for both, worded for the change at hand
+    fn confirm_dialog(self, task: &Task) -> ConfirmDialog {
+        let (title, question, answer) = match self {
+            TaskChange::Done => ("Complete Task", "Mark as done", "done"),
+            TaskChange::Delete => ("Delete Task", "Delete", "delete"),
+        };
+        let message = format!("{} \"{}\"?", question, truncate_text(&task.description, 80));
+        ConfirmDialog::new(title, &message, answer, "keep")
+    }
+}
+
+// A done / delete waiting for y or n
+struct PendingTaskChange {
+    dialog: ConfirmDialog,
+    change: TaskChange,
+    uuid: String,
+}
+
+// A tag change for every listed task waiting for y or n
+struct PendingBatchTag {
+    dialog: ConfirmDialog,
+    batch: BatchTag,
+}
+
+// An edit held back because the task changed elsewhere after the form opened
+struct SaveConflict {
+    dialog: SaveConflictDialog,
+    edited: Task,
+    latest: Task,
+}
+
+// Header badge counts, recomputed when tasks load rather than per frame
+#[derive(Default)]
+struct TaskCounts {
+    pending: usize,
+    active: usize,
+    overdue: usize,
+}
+
+impl TaskCounts {
+    fn from_tasks(tasks: &[Task]) -> Self {
+        let mut counts = TaskCounts::default();
+        for task in tasks.iter().filter(|task| task.status == TaskStatus::Pending) {
+            counts.pending += 1;
+            if task.is_active() {
+                counts.active += 1;
+            }
+            if task.is_overdue() {
+                counts.overdue += 1;
+            }
+        }
+        counts
+    }
+}
+
+// `task sync` runs one loop iteration after it's requested so "Syncing..." gets drawn first
+#[derive(PartialEq)]
+enum SyncState {
+    Requested,
+    Running,
+}
+
+// A tag being added to or removed from the listed tasks; like a project rename,
+// one task is modified per app loop iteration
+struct BatchTag {
+    tag: String,
+    operation: TagOperation,
+    remaining: Vec<String>,
+    total: usize,
+    failures: Vec<String>,
+}
+
+// A project rename in progress; one task is modified per app loop iteration
+struct ProjectRename {
+    from: String,
+    to: String,
+    remaining: Vec<String>,
+    total: usize,
+    failures: Vec<String>,
+}
+
 pub struct AppUI {
     config: Config,
     current_view: AppView,
     show_help_bar: bool,
+    sort_key: SortKey,
+    // Set once a sort key is picked; until then fuzzy search ranks by match score
+    sort_chosen: bool,
     main_view: MainView,
     reports_view: ReportsView,
+    projects_view: ProjectsView,
+    help_view: HelpView,
+    settings_view: SettingsView,
+    // Key popup over the current view (?); the next key closes it
+    cheat_sheet: Option<CheatSheet>,
+    // Armed by the app at launch; the first successful load turns it into the popup
+    startup_briefing_pending: bool,
+    startup_briefing: Option<StartupBriefing>,
     tasks: Vec<Task>,
     filtered_tasks: Vec<Task>,
+    task_counts: TaskCounts,
     task_form: Option<TaskForm>,
+    export_dialog: Option<ExportDialog>,
+    rename_dialog: Option<RenameProjectDialog>,
+    batch_tag_dialog: Option<BatchTagDialog>,
+    batch_tag_confirm: Option<PendingBatchTag>,
+    quick_add_dialog: Option<QuickAddDialog>,
+    annotation_dialog: Option<AnnotationDialog>,
+    snooze_dialog: Option<SnoozeDialog>,
+    date_jump_dialog: Option<DateJumpDialog>,
+    report_menu: Option<ReportMenu>,
+    link_picker: Option<LinkPicker>,
+    // Taskwarrior report whose columns, sort and filter the task list is using
+    active_report: Option<ReportDefinition>,
+    // Asks before quitting with unsaved task form edits
+    quit_confirm: Option<ConfirmDialog>,
+    task_confirm: Option<PendingTaskChange>,
+    save_conflict: Option<SaveConflict>,
+    quit_requested: bool,
+    project_rename: Option<ProjectRename>,
+    batch_tag: Option<BatchTag>,
+    sync_state: Option<SyncState>,
+    status_message: Option<StatusMessage>,
     // Track the task UUID to preserve selection after operations
     preserve_selection_uuid: Option<String>,
+    // Background task loading, driven by App::run
+    reload_requested: bool,
+    // Task whose `task info` history was last asked for
+    history_requested: Option<String>,
+    pending_loads: usize,
+    loading_started: Option<Instant>,
+    // Taskwarrior filter used for the most recent export (see filter_on_export)
+    loaded_filter: Option<String>,
+    // Whether any export has succeeded, so an empty list can be told apart from a failed load
+    tasks_loaded: bool,
+    // Unreadable tasks last mentioned in the footer, so reloads don't repeat it
+    skipped_reported: usize,
+    // Description waiting to be opened in $EDITOR; App suspends the TUI for it
+    editor_request: Option<String>,
+    clipboard: SystemClipboard,
+    theme: Theme,
+    // Set once the theme key is used, so the choice is written back to the config on quit
+    theme_switched: bool,
+    // Set when the config file was reloaded, so App can rebuild its key bindings
+    config_reloaded: bool,
 }
 
 impl AppUI {
     pub fn new(config: &Config) -> Result<Self> {
+        // Before any view works out what "today" is
+        set_use_utc(config.ui.use_utc);
+        let mut warnings = config.warnings.clone();
+        let current_view = AppView::from_name(&config.ui.default_view).unwrap_or_else(|| {
+            let warning = format!(
+                "Unknown ui.default_view \"{}\" (expected {}), starting in task_list",
+                config.ui.default_view,
+                AppView::STARTUP_NAMES.join(", ")
+            );
+            log::warn!("{}", warning);
+            warnings.push(warning);
+            AppView::TaskList
+        });
+
         Ok(AppUI {
             config: config.clone(),
-            current_view: AppView::TaskList,
+            current_view,
             show_help_bar: config.ui.show_help_bar,
-            main_view: MainView::new(),
-            reports_view: ReportsView::new(),
+            sort_key: SortKey::Age,
+            sort_chosen: false,
+            main_view: MainView::new(config),
+            reports_view: ReportsView::new(config),
+            projects_view: ProjectsView::default(),
+            help_view: HelpView::new(&InputHandler::new(config).bindings()),
+            settings_view: SettingsView::new(config),
+            cheat_sheet: None,
+            startup_briefing_pending: false,
+            startup_briefing: None,
             tasks: Vec::new(),
             filtered_tasks: Vec::new(),
+            task_counts: TaskCounts::default(),
             task_form: None,
+            export_dialog: None,
+            rename_dialog: None,
+            batch_tag_dialog: None,
+            batch_tag_confirm: None,
+            quick_add_dialog: None,
+            annotation_dialog: None,
+            snooze_dialog: None,
+            date_jump_dialog: None,
+            report_menu: None,
+            link_picker: None,
+            active_report: None,
+            quit_confirm: None,
+            task_confirm: None,
+            save_conflict: None,
+            quit_requested: false,
+            project_rename: None,
+            batch_tag: None,
+            sync_state: None,
+            // Config problems (e.g. a bad date format) are shown until the first key press
+            status_message: warnings.first().map(|warning| StatusMessage {
+                text: warning.clone(),
+                is_error: true,
+            }),
             preserve_selection_uuid: None,
+            reload_requested: false,
+            history_requested: None,
+            pending_loads: 0,
+            loading_started: None,
+            loaded_filter: None,
+            tasks_loaded: false,
+            skipped_reported: 0,
+            editor_request: None,
+            clipboard: SystemClipboard::default(),
+            theme: Theme::from_config(&config.theme),
+            theme_switched: false,
+            config_reloaded: false,
         })
     }
 
-    pub async fn load_tasks(&mut self, taskwarrior: &TaskwarriorIntegration) -> Result<()> {
-        // Load all tasks (not just pending) and sort by entry date (newest first)
-        let mut tasks = taskwarrior.list_tasks(None).await?;
-        tasks.sort_by(|a, b| b.entry.cmp(&a.entry)); // Newest first
+    // Ask the app loop to reload tasks; the export runs off the UI thread
+    fn request_reload(&mut self) {
+        self.reload_requested = true;
+    }
+
+    pub fn take_reload_request(&mut self) -> bool {
+        std::mem::take(&mut self.reload_requested)
+    }
+
+    // The selected task's UUID when its history hasn't been asked for yet
+    pub fn take_history_request(&mut self) -> Option<String> {
+        let uuid = self.main_view.selected_task_uuid()?;
+        if self.history_requested.as_ref() == Some(&uuid) {
+            return None;
+        }
+        self.history_requested = Some(uuid.clone());
+        Some(uuid)
+    }
+
+    // If `task info` couldn't be read the detail panel keeps its outline from the task's fields
+    pub fn finish_history(&mut self, uuid: &str, result: Result<Vec<HistoryEntry>>) {
+        match result {
+            Ok(entries) => self.main_view.set_task_history(uuid, entries),
+            Err(e) => log::debug!("No history for task {}: {}", uuid, e),
+        }
+    }
+
+    // What to pass to `task export`; reports and project counts always need the full task history.
+    // A Taskwarrior report's filter only exists on the Taskwarrior side, so it is always exported.
+    // The startup filter narrows every export, so the whole app works on that slice of tasks.
+    pub fn export_filter(&self) -> Option<String> {
+        let filter = if matches!(self.current_view, AppView::Reports | AppView::Projects) {
+            None
+        } else {
+            let view_filter = if self.config.taskwarrior.filter_on_export {
+                self.main_view.taskwarrior_filter()
+            } else {
+                None
+            };
+            match self.active_report.as_ref().and_then(ReportDefinition::export_filter) {
+                Some(report_filter) => Some(match view_filter {
+                    Some(view_filter) => format!("( {} ) {}", report_filter, view_filter),
+                    None => report_filter,
+                }),
+                None => view_filter,
+            }
+        };
+        match (self.startup_filter(), filter) {
+            (Some(startup_filter), Some(filter)) => Some(format!("( {} ) ( {} )", startup_filter, filter)),
+            (Some(startup_filter), None) => Some(startup_filter.to_string()),
+            (None, filter) => filter,
+        }
+    }
+
+    pub fn startup_filter(&self) -> Option<&str> {
+        self.config.startup_filter()
+    }
+
+    // Show the task list as a Taskwarrior report, or (None) go back to the configured columns
+    fn apply_report(&mut self, report: Option<ReportDefinition>) {
+        match report {
+            Some(ref report) => {
+                self.main_view.set_report(Some(&report.name), &report.list_columns());
+                self.main_view.clear_filters_for_report();
+                if let Some(sort_key) = report.sort_key() {
+                    self.sort_key = sort_key;
+                    self.sort_key.sort(&mut self.tasks);
+                    self.main_view.set_sort_key(sort_key);
+                }
+                self.status_message = Some(StatusMessage {
+                    text: format!("Showing the '{}' report", report.name),
+                    is_error: false,
+                });
+            }
+            None => {
+                self.main_view.set_report(None, &self.config.ui.task_list_columns);
+                self.main_view.clear_all_filters();
+                self.status_message = Some(StatusMessage {
+                    text: "Back to LazyTask's own columns and filters".to_string(),
+                    is_error: false,
+                });
+            }
+        }
+        self.active_report = report;
+        // The report's filter changes what gets exported
+        self.request_reload();
+        self.apply_filters();
+    }
+
+    fn reload_if_export_filter_changed(&mut self) {
+        if self.config.taskwarrior.filter_on_export && self.export_filter() != self.loaded_filter {
+            self.request_reload();
+        }
+    }
+
+    // The config after a reload, once, for the parts App owns (key bindings, saving the theme)
+    pub fn take_reloaded_config(&mut self) -> Option<Config> {
+        std::mem::take(&mut self.config_reloaded).then(|| self.config.clone())
+    }
+
+    pub fn take_quit_request(&mut self) -> bool {
+        std::mem::take(&mut self.quit_requested)
+    }
+
+    pub fn take_editor_request(&mut self) -> Option<String> {
+        self.editor_request.take()
+    }
+
+    pub fn finish_external_edit(&mut self, result: Result<Option<String>>) {
+        let Some(form) = self.task_form.as_mut() else {
+            return;
+        };
+        self.status_message = Some(match result {
+            Ok(Some(description)) => {
+                form.set_description(description);
+                StatusMessage { text: "Description updated from editor".to_string(), is_error: false }
+            }
+            Ok(None) => StatusMessage { text: "Description unchanged".to_string(), is_error: false },
+            Err(e) => StatusMessage { text: e.to_string(), is_error: true },
+        });
+    }
+
+    pub fn start_loading(&mut self, filter: Option<String>) {
+        self.loaded_filter = filter;
+        if self.pending_loads == 0 {
+            self.loading_started = Some(Instant::now());
+        }
+        self.pending_loads += 1;
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.pending_loads > 0
+    }
+
+    // Show what's overdue or due today once the next load finishes; only once per launch
+    pub fn request_startup_briefing(&mut self) {
+        self.startup_briefing_pending = true;
+    }
+
+    pub fn finish_loading(&mut self, result: Result<Vec<Task>>) {
+        self.pending_loads = self.pending_loads.saturating_sub(1);
+        if self.pending_loads == 0 {
+            self.loading_started = None;
+        }
+
+        match result {
+            Ok(tasks) => {
+                // Plain refreshes have no target, so stay on the task under the cursor
+                if self.preserve_selection_uuid.is_none() {
+                    self.preserve_selection_uuid = self.main_view.selected_task_uuid();
+                }
+                if self.startup_briefing_pending {
+                    self.startup_briefing_pending = false;
+                    self.startup_briefing = StartupBriefing::from_tasks(&tasks);
+                }
+                self.tasks_loaded = true;
+                self.set_tasks(tasks);
+                // A reload usually follows a change, so the history is fetched again
+                self.history_requested = None;
+            }
+            Err(e) => {
+                self.preserve_selection_uuid = None;
+                self.status_message = Some(StatusMessage {
+                    text: format!("Failed to load tasks: {}", e),
+                    is_error: true,
+                });
+            }
+        }
+    }
+
+    // Tasks the export had that couldn't be read (e.g. no description). Said when the
+    // number changes, so the list isn't silently incomplete.
+    pub fn report_error(&mut self, error: &anyhow::Error) {
+        self.status_message = Some(StatusMessage {
+            text: error.to_string().lines().next().unwrap_or_default().to_string(),
+            is_error: true,
+        });
+    }
+
+    pub fn report_skipped_tasks(&mut self, count: usize) {
+        if count == self.skipped_reported {
+            return;
+        }
+        self.skipped_reported = count;
+        if count > 0 {
+            self.status_message = Some(StatusMessage {
+                text: format!(
+                    "{} task{} couldn't be read and {} not shown (run with --verbose for details)",
+                    count,
+                    if count == 1 { "" } else { "s" },
+                    if count == 1 { "is" } else { "are" }
+                ),
+                is_error: true,
+            });
+        }
+    }
+
+    fn set_tasks(&mut self, mut tasks: Vec<Task>) {
+        self.sort_key.sort(&mut tasks);
+        self.task_counts = TaskCounts::from_tasks(&tasks);
         self.tasks = tasks.clone();
         
         // Update available filters in main view
         self.main_view.update_available_filters(&self.tasks);
         
-        // Update reports view with all tasks
+        // Update reports and projects views with all tasks
+        self.projects_view.update_tasks(&tasks);
         self.reports_view.update_tasks(tasks);
         
         self.apply_filters();
-        Ok(())
     }
 
     fn apply_filters(&mut self) {
+        self.main_view.refresh_due_range();
+        self.main_view.refresh_option_counts();
+
         // Apply custom filters based on selections
         self.filtered_tasks = self.tasks
             .iter()
             .filter(|task| self.main_view.matches_filters(task))
             .cloned()
             .collect();
+        let overridden = self.main_view.is_next_view() || self.main_view.is_recently_completed();
+        let ranked_by_match = !overridden && !self.sort_chosen && self.main_view.ranks_by_match();
+        if self.main_view.is_next_view() {
+            SortKey::Urgency.sort(&mut self.filtered_tasks);
+        } else if self.main_view.is_recently_completed() {
+            self.filtered_tasks.sort_by_key(|task| std::cmp::Reverse(task.end));
+        } else if ranked_by_match {
+            self.main_view.sort_by_match(&mut self.filtered_tasks);
+        }
+        self.main_view.set_ranked_by_match(ranked_by_match);
+        // Sections must be contiguous, so group after sorting
+        if self.main_view.is_grouped() {
+            DueGroup::group(&mut self.filtered_tasks);
+        }
         
+        self.main_view.set_empty_message(self.empty_list_message());
+
         // Use preserved selection if available
         let preserve_uuid = self.preserve_selection_uuid.as_deref();
         self.main_view.set_tasks_with_preserved_selection(self.filtered_tasks.clone(), preserve_uuid);
         
         // Clear the preserve UUID after using it
         self.preserve_selection_uuid = None;
+
+        // Client-side filtering above still applies; this only narrows the next export
+        self.reload_if_export_filter_changed();
+    }
+
+    // Placeholder for an empty task list. A filtered export can't tell whether
+    // the database itself is empty, so only an unfiltered one says "no tasks yet".
+    fn empty_list_message(&self) -> Option<String> {
+        if !self.tasks_loaded {
+            None
+        } else if self.main_view.is_recently_completed() {
+            Some(format!("Nothing completed in the last {} days — press 'H' to go back", self.main_view.recent_days()))
+        } else if self.tasks.is_empty() && self.loaded_filter.is_none() {
+            Some("No tasks yet — press 'a' to add one".to_string())
+        } else {
+            Some("No tasks match the current filters — press '/' to change them".to_string())
+        }
+    }
+
+    // Which view's key bindings take precedence; the calendar has keys of its own
+    pub fn key_context(&self) -> KeyContext {
+        match self.current_view {
+            AppView::Reports if self.reports_view.is_calendar_mode() => KeyContext::Reports,
+            AppView::Projects => KeyContext::Projects,
+            AppView::Settings => KeyContext::Settings,
+            AppView::TaskDetail => KeyContext::TaskDetail,
+            AppView::TaskList if self.main_view.is_finding() => KeyContext::Find,
+            _ => KeyContext::TaskList,
+        }
+    }
+
+    // Keys the cheat sheet lists besides the global ones. Unlike key_context, the
+    // dashboard gets the Reports keys too (Tab, Enter), not the task list's.
+    fn cheat_sheet_context(&self) -> KeyContext {
+        match self.current_view {
+            AppView::TaskList if self.main_view.is_finding() => KeyContext::Find,
+            AppView::TaskList => KeyContext::TaskList,
+            AppView::TaskDetail => KeyContext::TaskDetail,
+            AppView::Reports => KeyContext::Reports,
+            AppView::Projects => KeyContext::Projects,
+            AppView::Settings => KeyContext::Settings,
+            AppView::Help => KeyContext::Global,
+        }
     }
 
     pub fn has_active_form(&self) -> bool {
-        self.task_form.is_some() || self.main_view.is_filter_focused()
+        self.task_form.is_some()
+            || self.export_dialog.is_some()
+            || self.rename_dialog.is_some()
+            || self.batch_tag_dialog.is_some()
+            || self.batch_tag_confirm.is_some()
+            || self.quick_add_dialog.is_some()
+            || self.annotation_dialog.is_some()
+            || self.snooze_dialog.is_some()
+            || self.date_jump_dialog.is_some()
+            || self.report_menu.is_some()
+            || self.link_picker.is_some()
+            || self.quit_confirm.is_some()
+            || self.task_confirm.is_some()
+            || self.save_conflict.is_some()
+            || self.main_view.is_filter_focused()
+            || self.main_view.is_find_typing()
     }
 
-    fn task_to_attributes(task: &Task) -> Vec<(String, String)> {
+    // `original_depends` are the dependencies before editing, so dropped ones get removed
+    fn task_to_attributes(task: &Task, original_depends: &[String]) -> Vec<(String, String)> {
         let mut attributes = Vec::new();
 
         // Add description (this was missing!)
@@ -123,18 +662,100 @@ impl AppUI {
 
         // Add due date if present, otherwise clear it
         if let Some(due) = task.due {
-            let due_str = due.format("%Y-%m-%d").to_string();
-            attributes.push(("due".to_string(), due_str));
+            // The exact instant: Taskwarrior would read a bare date as local midnight
+            attributes.push(("due".to_string(), due.format("%Y-%m-%dT%H:%M:%SZ").to_string()));
         } else {
             attributes.push(("due".to_string(), "".to_string()));
         }
 
+        // Re-send current dependencies so an edit never loses them
+        if let Some(depends) = task.depends_modification(original_depends) {
+            attributes.push(("depends".to_string(), depends));
+        }
+
         attributes
     }
 
+    // The `task add` command that recreates `task` elsewhere: the same attributes an edit
+    // sends, as CLI arguments. The description goes after `--`, so words in it like
+    // "due:friday" stay part of the text.
+    pub fn add_command(task: &Task) -> String {
+        let mut words = vec!["task".to_string(), "add".to_string()];
+        for (name, value) in Self::task_to_attributes(task, &task.depends) {
+            match name.as_str() {
+                // The description comes last; dependencies are UUIDs that only mean something here
+                "description" | "tags" | "depends" => {}
+                tag if tag.starts_with('+') => words.push(shell_quote(tag)),
+                _ if value.is_empty() => {}
+                _ => words.push(shell_quote(&format!("{}:{}", name, value))),
+            }
+        }
+        words.push("--".to_string());
+        words.push(shell_quote(&task.description));
+        words.join(" ")
+    }
 
+    // The theme recolors the finished frame, so widgets can keep drawing with named colors
     pub fn draw(&mut self, f: &mut Frame) {
+        self.draw_screen(f);
+        self.theme.apply(f.buffer_mut());
+    }
+
+    // The theme picked with the theme key this session, if any
+    pub fn switched_theme(&self) -> Option<&'static str> {
+        self.theme_switched.then_some(self.theme.id)
+    }
+
+    // Re-read the config file (R in Settings). Theme, keys, columns, dates and colors
+    // change right away; the Taskwarrior section, like --taskrc/--data/--filter, stays
+    // as the app was started with until the next start.
+    fn reload_config(&mut self) {
+        let Some(path) = self.config.path.clone() else {
+            self.status_message = Some(StatusMessage {
+                text: "No config file to reload; these are the built-in defaults".to_string(),
+                is_error: true,
+            });
+            return;
+        };
+        let mut config = match Config::load(path.to_str()) {
+            Ok(config) => config,
+            Err(e) => {
+                // A file that doesn't parse leaves everything as it was
+                self.status_message = Some(StatusMessage {
+                    text: format!("Config not reloaded: {:#}", e),
+                    is_error: true,
+                });
+                return;
+            }
+        };
+        config.taskwarrior = self.config.taskwarrior.clone();
+
+        set_use_utc(config.ui.use_utc);
+        self.theme = Theme::from_config(&config.theme);
+        self.theme_switched = false;
+        self.show_help_bar = config.ui.show_help_bar;
+        self.main_view.apply_config(&config);
+        if self.active_report.is_none() {
+            self.main_view.set_report(None, &config.ui.task_list_columns);
+        }
+        self.reports_view.apply_config(&config);
+        self.help_view = HelpView::new(&InputHandler::new(&config).bindings());
+        self.settings_view.set_config(&config);
+        self.status_message = Some(match config.warnings.first() {
+            Some(warning) => StatusMessage { text: warning.clone(), is_error: true },
+            None => StatusMessage { text: format!("Reloaded {}", path.display()), is_error: false },
+        });
+        self.config = config;
+        self.config_reloaded = true;
+        self.apply_filters();
+    }
+
+    fn draw_screen(&mut self, f: &mut Frame) {
         let size = f.area();
+        if size.width < MIN_WIDTH || size.height < MIN_HEIGHT {
+            self.draw_too_small(f, size);
+            return;
+        }
         
         // Create responsive dashboard layout that adapts to window size
         let terminal_height = size.height;
@@ -168,7 +789,8 @@ impl AppUI {
             }
             AppView::TaskDetail => self.draw_task_detail(f, main_chunks[1]),
             AppView::Reports => self.draw_reports(f, main_chunks[1]),
-            AppView::Settings => self.draw_settings(f, main_chunks[1]),
+            AppView::Projects => self.projects_view.render(f, main_chunks[1]),
+            AppView::Settings => self.settings_view.render(f, main_chunks[1]),
             AppView::Help => self.draw_help(f, main_chunks[1]),
         }
 
@@ -179,42 +801,195 @@ impl AppUI {
         if let Some(ref form) = self.task_form {
             form.render(f, size);
         }
+
+        // Draw export dialog as overlay if open
+        if let Some(ref dialog) = self.export_dialog {
+            dialog.render(f, size);
+        }
+
+        // Draw project rename prompt as overlay if open
+        if let Some(ref dialog) = self.rename_dialog {
+            dialog.render(f, size);
+        }
+
+        // Draw the batch tag prompt, then its confirmation, as overlays if open
+        if let Some(ref dialog) = self.batch_tag_dialog {
+            dialog.render(f, size);
+        }
+        if let Some(ref pending) = self.batch_tag_confirm {
+            pending.dialog.render(f, size);
+        }
+
+        // Draw quick-add prompt as overlay if open
+        if let Some(ref dialog) = self.quick_add_dialog {
+            dialog.render(f, size);
+        }
+
+        // Draw annotation manager as overlay if open
+        if let Some(ref dialog) = self.annotation_dialog {
+            dialog.render(f, size);
+        }
+
+        // Draw snooze prompt as overlay if open
+        if let Some(ref dialog) = self.snooze_dialog {
+            dialog.render(f, size);
+        }
+
+        // Draw the calendar's go-to-date prompt as overlay if open
+        if let Some(ref dialog) = self.date_jump_dialog {
+            dialog.render(f, size);
+        }
+
+        // Draw reports menu as overlay if open
+        if let Some(ref menu) = self.report_menu {
+            menu.render(f, size);
+        }
+
+        // Draw link picker as overlay if open
+        if let Some(ref picker) = self.link_picker {
+            picker.render(f, size);
+        }
+
+        // Draw done / delete confirmation as overlay if open
+        if let Some(ref pending) = self.task_confirm {
+            pending.dialog.render(f, size);
+        }
+
+        // Draw the changed-elsewhere warning over the form it came from
+        if let Some(ref conflict) = self.save_conflict {
+            conflict.dialog.render(f, size);
+        }
+
+        // Draw the startup briefing over the first task list
+        if let Some(ref briefing) = self.startup_briefing {
+            briefing.render(f, size);
+        }
+
+        // Draw the key cheat sheet over the view it describes
+        if let Some(ref sheet) = self.cheat_sheet {
+            sheet.render(f, size);
+        }
+
+        // The quit confirmation goes over everything, including the form it protects
+        if let Some(ref dialog) = self.quit_confirm {
+            dialog.render(f, size);
+        }
     }
 
     pub async fn handle_action(&mut self, action: Action, taskwarrior: &TaskwarriorIntegration) -> Result<()> {
-        // Remove old filter handling that was intercepting actions
+        // Status messages only last until the next key press
+        self.status_message = None;
+
+        // Any key closes the cheat sheet, and does nothing else
+        if self.cheat_sheet.take().is_some() {
+            return Ok(());
+        }
+
+        // Likewise for the startup briefing
+        if self.startup_briefing.take().is_some() {
+            return Ok(());
+        }
+
+        // Quitting goes through here so unsaved form edits can hold it up;
+        // quitting again from the confirmation doesn't ask twice
+        if let Some(ref mut dialog) = self.quit_confirm {
+            let result = match action {
+                Action::Quit => Some(ConfirmResult::Confirm),
+                action => dialog.handle_input(action)?,
+            };
+            match result {
+                Some(ConfirmResult::Confirm) => self.quit_requested = true,
+                Some(ConfirmResult::Cancel) => self.quit_confirm = None,
+                None => {}
+            }
+            return Ok(());
+        }
+        if let Action::Quit = action {
+            if self.task_form.as_ref().is_some_and(TaskForm::is_dirty) {
+                self.quit_confirm = Some(ConfirmDialog::new(
+                    "Unsaved Changes",
+                    "The task form has unsaved changes. Quit and discard them?",
+                    "quit",
+                    "keep editing",
+                ));
+            } else {
+                self.quit_requested = true;
+            }
+            return Ok(());
+        }
+
+        // Ctrl+E in the task form hands the description to $EDITOR
+        if let (Action::ExternalEditor, Some(form)) = (&action, &self.task_form) {
+            self.editor_request = Some(form.description_input.clone());
+            return Ok(());
+        }
+
+        // Handle the changed-elsewhere warning if a save is waiting on it
+        if let Some(ref mut conflict) = self.save_conflict {
+            if let Some(result) = conflict.dialog.handle_input(action.clone())? {
+                let Some(conflict) = self.save_conflict.take() else {
+                    return Ok(());
+                };
+                match result {
+                    SaveConflictResult::Overwrite => match self.save_task_edit(&conflict.edited, taskwarrior).await {
+                        Ok(()) => {
+                            self.task_form = None;
+                            self.request_reload();
+                        }
+                        // Still undecided: the question stays up so o can be tried again
+                        Err(e) => {
+                            self.report_save_error(&e);
+                            self.save_conflict = Some(conflict);
+                        }
+                    },
+                    SaveConflictResult::Reload => {
+                        self.task_form = Some(TaskForm::edit_task(conflict.latest).with_dependency_ids(&self.tasks));
+                        self.request_reload();
+                        self.status_message = Some(StatusMessage {
+                            text: "Reloaded the task as it is now; your edits were dropped".to_string(),
+                            is_error: false,
+                        });
+                    }
+                    SaveConflictResult::Cancel => {}
+                }
+            }
+            return Ok(());
+        }
 
         // Handle form actions if form is open
         if let Some(ref mut form) = self.task_form {
             if let Some(result) = form.handle_input(action.clone())? {
                 match result {
                     TaskFormResult::Save(task) => {
-                        if let Some(task_id) = task.id {
-                            // Update existing task - preserve selection on the same task
-                            self.preserve_selection_uuid = Some(task.uuid.clone());
-                            
-                            let attributes = Self::task_to_attributes(&task);
-                            let attributes_refs: Vec<(&str, &str)> = attributes.iter()
-                                .map(|(k, v)| (k.as_str(), v.as_str()))
-                                .collect();
-                            
-                            taskwarrior.modify_task(task_id, &attributes_refs).await?;
+                        let saved = if task.id.is_some() {
+                            // Someone may have changed the task with `task` since the form opened;
+                            // the form's own copy still has the `modified` it was loaded with
+                            let opened_with = form.task.clone();
+                            if let Some(latest) = Self::changed_elsewhere(&opened_with, taskwarrior).await {
+                                self.save_conflict = Some(SaveConflict {
+                                    dialog: SaveConflictDialog::new(self.conflict_message(&opened_with, &latest)),
+                                    edited: task,
+                                    latest,
+                                });
+                                return Ok(());
+                            }
+                            self.save_task_edit(&task, taskwarrior).await
                         } else {
-                            // Add new task - we'll need to find the newly created task by description
-                            // For now, preserve current selection or go to newest (first in list)
-                            self.preserve_selection_uuid = self.main_view.selected_task_uuid();
-                            
-                            let attributes = Self::task_to_attributes(&task);
+                            // Add new task - the reload keeps the cursor on the current task
+                            let attributes = Self::task_to_attributes(&task, &[]);
                             let attributes_refs: Vec<(&str, &str)> = attributes.iter()
                                 .map(|(k, v)| (k.as_str(), v.as_str()))
                                 .collect();
-                            let _new_task_id = taskwarrior.add_task(&task.description, &attributes_refs).await?;
-                            
-                            // For new tasks, we'll select the first task (newest) since tasks are sorted by entry date
-                            self.preserve_selection_uuid = None; // Let it go to newest task
+                            taskwarrior.add_task(&task.description, &attributes_refs).await.map(|_| ())
+                        };
+                        match saved {
+                            Ok(()) => {
+                                self.task_form = None;
+                                self.request_reload();
+                            }
+                            // Keep the form and its edits so the save can be retried
+                            Err(e) => self.report_save_error(&e),
                         }
-                        self.task_form = None;
-                        self.load_tasks(taskwarrior).await?;
                     }
                     TaskFormResult::Cancel => {
                         self.task_form = None;
@@ -224,71 +999,397 @@ impl AppUI {
             }
         }
 
-        match action {
-            Action::Quit => {
-                // This will be handled by the main app loop
-            }
-            Action::Help => {
-                self.current_view = AppView::Help;
-            }
-            Action::Reports => {
-                self.current_view = AppView::Reports;
+        // Handle export dialog actions if it is open
+        if let Some(ref mut dialog) = self.export_dialog {
+            if let Some(result) = dialog.handle_input(action.clone())? {
+                self.export_dialog = None;
+                if let ExportDialogResult::Export(path, format) = result {
+                    self.export_filtered_tasks(path, format);
+                }
             }
-            Action::Context => {
-                // Toggle calendar mode when in Reports view
-                if matches!(self.current_view, AppView::Reports) {
-                    self.reports_view.toggle_mode();
+            return Ok(());
+        }
+
+        // Handle project rename prompt if it is open
+        if let Some(ref mut dialog) = self.rename_dialog {
+            if let Some(result) = dialog.handle_input(action.clone())? {
+                self.rename_dialog = None;
+                if let RenameDialogResult::Rename { from, to } = result {
+                    self.start_project_rename(from, to);
                 }
             }
-            Action::Back => {
-                if self.task_form.is_some() {
-                    self.task_form = None;
-                } else if matches!(self.current_view, AppView::TaskList) && self.main_view.is_filter_focused() {
-                    // Single ESC to exit filter mode (only in TaskList view)
-                    self.main_view.exit_filter_mode();
-                    self.apply_filters(); // Apply filters when exiting
-                } else {
-                    self.current_view = AppView::TaskList;
+            return Ok(());
+        }
+
+        // Handle the batch tag prompt and its confirmation if open
+        if let Some(ref mut dialog) = self.batch_tag_dialog {
+            if let Some(result) = dialog.handle_input(action.clone())? {
+                self.batch_tag_dialog = None;
+                if let BatchTagResult::Apply { tag, operation } = result {
+                    self.confirm_batch_tag(tag, operation);
                 }
             }
-            Action::MoveUp => {
-                if matches!(self.current_view, AppView::TaskList) && self.main_view.is_filter_focused() {
-                    self.main_view.handle_filter_navigation_up();
-                } else if matches!(self.current_view, AppView::Reports) && self.reports_view.is_calendar_mode() {
-                    // Navigate date backwards by one week in calendar mode
-                    self.reports_view.navigate_date(crate::ui::views::reports_view::DateNavigation::PrevWeek);
-                } else if self.task_form.is_none() && matches!(self.current_view, AppView::TaskList) {
-                    self.main_view.previous_task();
+            return Ok(());
+        }
+        if let Some(ref mut pending) = self.batch_tag_confirm {
+            match pending.dialog.handle_input(action.clone())? {
+                Some(ConfirmResult::Confirm) => {
+                    self.batch_tag = self.batch_tag_confirm.take().map(|pending| pending.batch);
                 }
+                Some(ConfirmResult::Cancel) => self.batch_tag_confirm = None,
+                None => {}
             }
-            Action::MoveDown => {
-                if matches!(self.current_view, AppView::TaskList) && self.main_view.is_filter_focused() {
-                    self.main_view.handle_filter_navigation_down();
-                } else if matches!(self.current_view, AppView::Reports) && self.reports_view.is_calendar_mode() {
-                    // Navigate date forward by one week in calendar mode
-                    self.reports_view.navigate_date(crate::ui::views::reports_view::DateNavigation::NextWeek);
-                } else if self.task_form.is_none() && matches!(self.current_view, AppView::TaskList) {
-                    self.main_view.next_task();
+            return Ok(());
+        }
+
+        // Handle quick-add prompt if it is open
+        if let Some(ref mut dialog) = self.quick_add_dialog {
+            if let Some(result) = dialog.handle_input(action.clone())? {
+                match result {
+                    QuickAddResult::Add(quick_add) => {
+                        let attributes: Vec<(&str, &str)> = quick_add.attributes.iter()
+                            .map(|(k, v)| (k.as_str(), v.as_str()))
+                            .collect();
+                        match taskwarrior.add_task(&quick_add.description, &attributes).await {
+                            Ok(task_id) => {
+                                self.quick_add_dialog = None;
+                                self.status_message = Some(StatusMessage {
+                                    text: format!("Created task {}", task_id),
+                                    is_error: false,
+                                });
+                                self.request_reload();
+                            }
+                            // Keep the prompt open so the line can be fixed
+                            Err(e) => dialog.error = Some(e.to_string().lines().next().unwrap_or_default().to_string()),
+                        }
+                    }
+                    QuickAddResult::Cancel => {
+                        self.quick_add_dialog = None;
+                    }
                 }
             }
-            Action::MoveLeft => {
-                if matches!(self.current_view, AppView::Reports) && self.reports_view.is_calendar_mode() {
-                    // Navigate date backwards by one day in calendar mode
-                    self.reports_view.navigate_date(crate::ui::views::reports_view::DateNavigation::PrevDay);
+            return Ok(());
+        }
+
+        // Handle annotation manager if it is open; it stays open after each change
+        if let Some(ref mut dialog) = self.annotation_dialog {
+            if let Some(result) = dialog.handle_input(action.clone())? {
+                let uuid = dialog.task_uuid.clone();
+                let outcome = match &result {
+                    AnnotationDialogResult::Close => {
+                        self.annotation_dialog = None;
+                        return Ok(());
+                    }
+                    AnnotationDialogResult::Add(text) => taskwarrior.annotate_task(&uuid, text).await
+                        .map(|_| "Annotation added"),
+                    // Taskwarrior has no in-place edit, so the old text is removed and the new one added
+                    AnnotationDialogResult::Edit { old, new } => match taskwarrior.denotate_task(&uuid, old).await {
+                        Ok(_) => match taskwarrior.annotate_task(&uuid, new).await {
+                            Ok(_) => Ok("Annotation updated"),
+                            Err(e) => {
+                                dialog.apply(&AnnotationDialogResult::Delete(old.clone()));
+                                Err(anyhow::anyhow!("Removed the old annotation but couldn't add the new one: {}", e))
+                            }
+                        },
+                        Err(e) => Err(e),
+                    },
+                    AnnotationDialogResult::Delete(text) => taskwarrior.denotate_task(&uuid, text).await
+                        .map(|_| "Annotation deleted"),
+                };
+                match outcome {
+                    Ok(message) => {
+                        dialog.apply(&result);
+                        self.status_message = Some(StatusMessage {
+                            text: message.to_string(),
+                            is_error: false,
+                        });
+                    }
+                    Err(e) => {
+                        log::error!("Failed to change annotations on {}: {}", uuid, e);
+                        dialog.error = Some(e.to_string().lines().next().unwrap_or_default().to_string());
+                    }
                 }
+                self.preserve_selection_uuid = Some(uuid);
+                self.request_reload();
             }
-            Action::MoveRight => {
-                if matches!(self.current_view, AppView::Reports) && self.reports_view.is_calendar_mode() {
+            return Ok(());
+        }
+
+        // Handle a done / delete confirmation if one is open
+        if let Some(ref mut pending) = self.task_confirm {
+            match pending.dialog.handle_input(action.clone())? {
+                Some(ConfirmResult::Confirm) => {
+                    if self.is_loading() {
+                        // Same rule as pressing the key during a load; the question stays open
+                        self.status_message = Some(StatusMessage {
+                            text: "Still loading tasks, try again in a moment".to_string(),
+                            is_error: false,
+                        });
+                    } else if let Some(pending) = self.task_confirm.take() {
+                        self.run_task_change(pending.change, &pending.uuid, taskwarrior).await;
+                    }
+                }
+                Some(ConfirmResult::Cancel) => self.task_confirm = None,
+                None => {}
+            }
+            return Ok(());
+        }
+
+        // Handle reports menu if it is open
+        if let Some(ref mut menu) = self.report_menu {
+            match menu.handle_input(action.clone())? {
+                Some(ReportMenuResult::Apply(name)) => match taskwarrior.report_definition(&name).await {
+                    Ok(report) => {
+                        self.report_menu = None;
+                        self.apply_report(Some(report));
+                    }
+                    // Keep the menu open so another report can be picked
+                    Err(e) => {
+                        self.status_message = Some(StatusMessage {
+                            text: format!("Failed to read report '{}': {}", name, e),
+                            is_error: true,
+                        });
+                    }
+                },
+                Some(ReportMenuResult::Clear) => {
+                    self.report_menu = None;
+                    if self.active_report.is_some() {
+                        self.apply_report(None);
+                    }
+                }
+                Some(ReportMenuResult::Cancel) => self.report_menu = None,
+                None => {}
+            }
+            return Ok(());
+        }
+
+        // Handle link picker if it is open
+        if let Some(ref mut picker) = self.link_picker {
+            match picker.handle_input(action.clone())? {
+                Some(LinkPickerResult::Open(url)) => {
+                    self.link_picker = None;
+                    self.open_link(&url);
+                }
+                Some(LinkPickerResult::Cancel) => self.link_picker = None,
+                None => {}
+            }
+            return Ok(());
+        }
+
+        // Handle snooze prompt if it is open
+        if let Some(ref mut dialog) = self.snooze_dialog {
+            if let Some(result) = dialog.handle_input(action.clone())? {
+                match result {
+                    SnoozeResult::Snooze(until) => {
+                        let uuid = dialog.task_uuid.clone();
+                        let wait = until.format("%Y-%m-%dT%H:%M:%SZ").to_string();
+                        match taskwarrior.modify_task_by_uuid(&uuid, &[("wait", &wait)]).await {
+                            Ok(_) => {
+                                self.snooze_dialog = None;
+                                self.status_message = Some(StatusMessage {
+                                    text: format!("Snoozed until {}", DateFormat::new(&self.config.ui).datetime(&until)),
+                                    is_error: false,
+                                });
+                                // The task drops out of view, so keep the cursor on its neighbour
+                                self.preserve_selection_uuid = self.neighbour_of_selected();
+                                self.request_reload();
+                            }
+                            // Keep the prompt open so another date can be tried
+                            Err(e) => dialog.error = Some(e.to_string().lines().next().unwrap_or_default().to_string()),
+                        }
+                    }
+                    SnoozeResult::Cancel => {
+                        self.snooze_dialog = None;
+                    }
+                }
+            }
+            return Ok(());
+        }
+
+        // Handle the calendar's go-to-date prompt if it is open
+        if let Some(ref mut dialog) = self.date_jump_dialog {
+            if let Some(result) = dialog.handle_input(action.clone())? {
+                if let DateJumpResult::Jump(date) = result {
+                    self.reports_view.jump_to(date);
+                }
+                self.date_jump_dialog = None;
+            }
+            return Ok(());
+        }
+
+        // Type-ahead find takes the keys it uses; the rest work as usual
+        if matches!(self.current_view, AppView::TaskList)
+            && self.main_view.is_finding()
+            && !self.main_view.is_filter_focused()
+            && self.handle_find_action(&action)
+        {
+            return Ok(());
+        }
+
+        match action {
+            Action::Help => {
+                self.current_view = AppView::Help;
+                self.help_view.scroll_to_top();
+            }
+            Action::CheatSheet => {
+                let bindings = InputHandler::new(&self.config).bindings();
+                self.cheat_sheet = Some(CheatSheet::new(&bindings, self.cheat_sheet_context()));
+            }
+            Action::Settings => {
+                self.current_view = AppView::Settings;
+                self.settings_view.scroll_to_top();
+            }
+            Action::ReloadConfig => self.reload_config(),
+            Action::Reports => {
+                self.current_view = AppView::Reports;
+                self.reload_if_export_filter_changed();
+            }
+            Action::Projects => {
+                self.current_view = AppView::Projects;
+                self.reload_if_export_filter_changed();
+            }
+            Action::Context => {
+                // Toggle calendar mode when in Reports view; from the list, open the
+                // calendar on the selected task's due date
+                if matches!(self.current_view, AppView::Reports) {
+                    self.reports_view.toggle_mode();
+                } else if matches!(self.current_view, AppView::TaskList) {
+                    self.show_selected_task_in_calendar();
+                }
+            }
+            Action::Back => {
+                if self.task_form.is_some() {
+                    self.task_form = None;
+                } else if matches!(self.current_view, AppView::TaskList) && self.main_view.is_filter_focused() {
+                    // Single ESC to exit filter mode (only in TaskList view)
+                    self.main_view.exit_filter_mode();
+                    self.apply_filters(); // Apply filters when exiting
+                } else if matches!(self.current_view, AppView::TaskList) && self.main_view.is_detail_overlay_open() {
+                    self.main_view.close_detail_overlay();
+                } else {
+                    self.current_view = AppView::TaskList;
+                    self.reload_if_export_filter_changed();
+                }
+            }
+            Action::MoveUp => {
+                if matches!(self.current_view, AppView::Help) {
+                    self.help_view.scroll_up();
+                } else if matches!(self.current_view, AppView::Settings) {
+                    self.settings_view.scroll_up();
+                } else if matches!(self.current_view, AppView::Projects) {
+                    self.projects_view.previous();
+                } else if matches!(self.current_view, AppView::TaskList) && self.main_view.is_filter_focused() {
+                    self.main_view.handle_filter_navigation_up();
+                } else if matches!(self.current_view, AppView::Reports) && self.reports_view.is_calendar_mode() {
+                    // Navigate date backwards by one week in calendar mode
+                    self.reports_view.navigate_date(crate::ui::views::reports_view::DateNavigation::PrevWeek);
+                } else if matches!(self.current_view, AppView::Reports) {
+                    self.reports_view.previous_row();
+                } else if self.task_form.is_none() && matches!(self.current_view, AppView::TaskList) {
+                    self.main_view.previous_task();
+                }
+            }
+            Action::MoveDown => {
+                if matches!(self.current_view, AppView::Help) {
+                    self.help_view.scroll_down();
+                } else if matches!(self.current_view, AppView::Settings) {
+                    self.settings_view.scroll_down();
+                } else if matches!(self.current_view, AppView::Projects) {
+                    self.projects_view.next();
+                } else if matches!(self.current_view, AppView::TaskList) && self.main_view.is_filter_focused() {
+                    self.main_view.handle_filter_navigation_down();
+                } else if matches!(self.current_view, AppView::Reports) && self.reports_view.is_calendar_mode() {
+                    // Navigate date forward by one week in calendar mode
+                    self.reports_view.navigate_date(crate::ui::views::reports_view::DateNavigation::NextWeek);
+                } else if matches!(self.current_view, AppView::Reports) {
+                    self.reports_view.next_row();
+                } else if self.task_form.is_none() && matches!(self.current_view, AppView::TaskList) {
+                    self.main_view.next_task();
+                }
+            }
+            Action::FirstTask => {
+                if matches!(self.current_view, AppView::Help) {
+                    self.help_view.scroll_to_top();
+                } else if matches!(self.current_view, AppView::Settings) {
+                    self.settings_view.scroll_to_top();
+                } else if matches!(self.current_view, AppView::Projects) {
+                    self.projects_view.select_first();
+                } else if matches!(self.current_view, AppView::Reports) && !self.reports_view.is_calendar_mode() {
+                    self.reports_view.first_row();
+                } else if self.task_form.is_none() && matches!(self.current_view, AppView::TaskList) && !self.main_view.is_filter_focused() {
+                    self.main_view.first_task();
+                }
+            }
+            Action::LastTask => {
+                if matches!(self.current_view, AppView::Help) {
+                    self.help_view.scroll_to_bottom();
+                } else if matches!(self.current_view, AppView::Settings) {
+                    self.settings_view.scroll_to_bottom();
+                } else if matches!(self.current_view, AppView::Projects) {
+                    self.projects_view.select_last();
+                } else if matches!(self.current_view, AppView::Reports) && !self.reports_view.is_calendar_mode() {
+                    self.reports_view.last_row();
+                } else if self.task_form.is_none() && matches!(self.current_view, AppView::TaskList) && !self.main_view.is_filter_focused() {
+                    self.main_view.last_task();
+                }
+            }
+            Action::PageUp => {
+                if matches!(self.current_view, AppView::Help) {
+                    self.help_view.page_up();
+                } else if matches!(self.current_view, AppView::Settings) {
+                    self.settings_view.page_up();
+                } else if matches!(self.current_view, AppView::Projects) {
+                    self.projects_view.page_up();
+                } else if matches!(self.current_view, AppView::Reports) && !self.reports_view.is_calendar_mode() {
+                    self.reports_view.page_up();
+                } else if self.task_form.is_none() && matches!(self.current_view, AppView::TaskList) && !self.main_view.is_filter_focused() {
+                    self.main_view.page_up();
+                }
+            }
+            Action::PageDown => {
+                if matches!(self.current_view, AppView::Help) {
+                    self.help_view.page_down();
+                } else if matches!(self.current_view, AppView::Settings) {
+                    self.settings_view.page_down();
+                } else if matches!(self.current_view, AppView::Projects) {
+                    self.projects_view.page_down();
+                } else if matches!(self.current_view, AppView::Reports) && !self.reports_view.is_calendar_mode() {
+                    self.reports_view.page_down();
+                } else if self.task_form.is_none() && matches!(self.current_view, AppView::TaskList) && !self.main_view.is_filter_focused() {
+                    self.main_view.page_down();
+                }
+            }
+            Action::MoveLeft => {
+                if matches!(self.current_view, AppView::Reports) && self.reports_view.is_calendar_mode() {
+                    // Navigate date backwards by one day in calendar mode
+                    self.reports_view.navigate_date(crate::ui::views::reports_view::DateNavigation::PrevDay);
+                }
+            }
+            Action::MoveRight => {
+                if matches!(self.current_view, AppView::Reports) && self.reports_view.is_calendar_mode() {
                     // Navigate date forward by one day in calendar mode
                     self.reports_view.navigate_date(crate::ui::views::reports_view::DateNavigation::NextDay);
                 }
             }
+            Action::CycleTheme => {
+                self.theme = self.theme.next(&self.config.theme);
+                self.theme_switched = true;
+                self.status_message = Some(StatusMessage {
+                    text: format!("Theme: {} (Ctrl+t for the next one)", self.theme.name),
+                    is_error: false,
+                });
+            }
             Action::Refresh => {
-                self.load_tasks(taskwarrior).await?;
+                // An explicit refresh should pick up changes made outside LazyTask
+                taskwarrior.invalidate_cache();
+                self.request_reload();
             }
             Action::Filter => {
                 // Only allow filter toggle in TaskList view
                 if matches!(self.current_view, AppView::TaskList) {
+                    // Editing filters implies leaving the next and recently completed
+                    // views, or the edits would be invisible
+                    self.main_view.show_filtered_list();
                     self.main_view.toggle_filter_focus();
                     if !self.main_view.is_filter_focused() {
                         // Exiting filter mode - apply filters
@@ -296,10 +1397,22 @@ impl AppUI {
                     }
                 }
             }
+            Action::ToggleSearchMode => {
+                if matches!(self.current_view, AppView::TaskList) && self.task_form.is_none() {
+                    self.main_view.toggle_search_mode();
+                    self.apply_filters();
+                    self.status_message = Some(StatusMessage {
+                        text: format!("{} search", self.main_view.search_mode().label()),
+                        is_error: false,
+                    });
+                }
+            }
             Action::Tab => {
-                // Only handle Tab for filter navigation in TaskList view
+                // Filter sections in the TaskList view, the project and tag tables on the dashboard
                 if matches!(self.current_view, AppView::TaskList) && self.main_view.is_filter_focused() {
                     self.main_view.next_filter_section();
+                } else if matches!(self.current_view, AppView::Reports) && !self.reports_view.is_calendar_mode() {
+                    self.reports_view.switch_table();
                 }
             }
             _ => {
@@ -314,12 +1427,23 @@ impl AppUI {
                             self.main_view.toggle_current_selection();
                             self.apply_filters();
                         }
+                        Action::Character('r') if self.main_view.highlighted_project().is_some() => {
+                            self.open_rename_dialog();
+                        }
+                        Action::Character('C') if !self.main_view.is_typing_filter_text() => {
+                            self.main_view.clear_all_filters();
+                            self.apply_filters();
+                            self.status_message = Some(StatusMessage {
+                                text: "Filters cleared".to_string(),
+                                is_error: false,
+                            });
+                        }
                         Action::Character(c) => {
-                            self.main_view.handle_search_character(c);
+                            self.main_view.handle_filter_character(c);
                             self.apply_filters();
                         }
                         Action::Backspace => {
-                            self.main_view.handle_search_backspace();
+                            self.main_view.handle_filter_backspace();
                             self.apply_filters();
                         }
                         Action::Select => {
@@ -340,6 +1464,13 @@ impl AppUI {
                             Action::Character('t') => {
                                 self.reports_view.navigate_date(crate::ui::views::reports_view::DateNavigation::Today);
                             }
+                            Action::Character('g') => {
+                                self.date_jump_dialog = Some(DateJumpDialog::new(DateFormat::new(&self.config.ui)));
+                            }
+                            Action::Character(c @ '1'..='7') => {
+                                let weekday = chrono::Weekday::try_from(c as u8 - b'1').unwrap_or(chrono::Weekday::Mon);
+                                self.reports_view.jump_to_weekday(weekday);
+                            }
                             _ => {}
                         }
                     }
@@ -347,6 +1478,9 @@ impl AppUI {
                     // Handle other actions based on current view
                     match self.current_view {
                         AppView::TaskList => self.handle_task_list_action(action, taskwarrior).await?,
+                        AppView::TaskDetail => self.handle_task_detail_action(action, taskwarrior).await?,
+                        AppView::Projects => self.handle_projects_action(action),
+                        AppView::Reports => self.handle_reports_action(action),
                         _ => {}
                     }
                 }
@@ -357,12 +1491,21 @@ impl AppUI {
 
     fn draw_header(&self, f: &mut Frame, area: Rect) {
         // Create header content with title and shortcuts
-        let header_content = Line::from(vec![
+        let mut header_spans = vec![
             Span::styled("LazyTask v0.1", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        ];
+        // The slice of tasks this run started with, since Clear All doesn't remove it
+        if let Some(filter) = self.startup_filter() {
+            header_spans.push(Span::styled(format!("  [{}]", filter), Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)));
+        }
+        header_spans.extend([
             Span::raw("                    "),
             Span::styled("[F1]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
             Span::styled(" Help", Style::default().fg(Color::White)),
             Span::raw("    "),
+            Span::styled("[?]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled(" Keys", Style::default().fg(Color::White)),
+            Span::raw("    "),
             Span::styled("[F5]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
             Span::styled(" Refresh", Style::default().fg(Color::White)),
             Span::raw("    "),
@@ -371,89 +1514,83 @@ impl AppUI {
             Span::raw("    "),
             Span::styled("[r]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
             Span::styled(" Reports", Style::default().fg(Color::White)),
+            Span::raw("    "),
+            Span::styled("[P]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled(" Projects", Style::default().fg(Color::White)),
         ]);
 
-        let header = Paragraph::new(header_content)
-            .block(Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan))
-            )
+        if let Some(started) = self.loading_started {
+            const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+            let frame = (started.elapsed().as_millis() / 100) as usize % SPINNER.len();
+            header_spans.push(Span::raw("    "));
+            header_spans.push(Span::styled(
+                format!("{} Loading tasks...", SPINNER[frame]),
+                Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        // Task count badges, pinned to the right so narrow terminals cut shortcuts first
+        let overdue_style = if self.task_counts.overdue > 0 {
+            Style::default().fg(Color::White).bg(Color::Red).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let counts = Line::from(vec![
+            Span::styled(format!("{} pending", self.task_counts.pending), Style::default().fg(Color::Cyan)),
+            Span::raw("  "),
+            Span::styled(format!("{} active", self.task_counts.active), Style::default().fg(Color::Green)),
+            Span::raw("  "),
+            Span::styled(format!(" {} overdue ", self.task_counts.overdue), overdue_style),
+        ]);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(counts.width() as u16 + 1)])
+            .split(inner);
+
+        let header = Paragraph::new(Line::from(header_spans))
             .style(Style::default().fg(Color::White))
             .alignment(ratatui::layout::Alignment::Left);
-        
-        f.render_widget(header, area);
+        f.render_widget(header, chunks[0]);
+
+        let counts = Paragraph::new(counts)
+            .alignment(ratatui::layout::Alignment::Right);
+        f.render_widget(counts, chunks[1]);
     }
 
     fn draw_task_detail(&self, f: &mut Frame, area: Rect) {
-        // Show selected task detail in full view
-        let selected_task = self.main_view.selected_task();
-        let detail_text = if let Some(task) = selected_task {
-            format!("Task Detail:\n\n{}", task.description)
-        } else {
-            "No task selected".to_string()
-        };
-        let detail = Paragraph::new(detail_text)
-            .block(Block::default().title("Task Detail").borders(Borders::ALL));
-        f.render_widget(detail, area);
+        self.main_view.render_selected_detail(f, area);
     }
 
-    fn draw_reports(&self, f: &mut Frame, area: Rect) {
+    fn draw_reports(&mut self, f: &mut Frame, area: Rect) {
         self.reports_view.render(f, area);
     }
 
-    fn draw_settings(&self, f: &mut Frame, area: Rect) {
-        let settings = Paragraph::new("Settings View - Coming Soon")
-            .block(Block::default().title("Settings").borders(Borders::ALL));
-        
-        f.render_widget(settings, area);
-    }
-
-    fn draw_help(&self, f: &mut Frame, area: Rect) {
-        let help_text = vec![
-            Line::from("Keyboard Shortcuts:"),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("q", Style::default().fg(Color::Yellow)),
-                Span::raw("     - Quit application"),
-            ]),
-            Line::from(vec![
-                Span::styled("F1", Style::default().fg(Color::Yellow)),
-                Span::raw("    - Show this help"),
-            ]),
-            Line::from(vec![
-                Span::styled("a", Style::default().fg(Color::Yellow)),
-                Span::raw("     - Add new task"),
-            ]),
-            Line::from(vec![
-                Span::styled("e", Style::default().fg(Color::Yellow)),
-                Span::raw("     - Edit selected task"),
-            ]),
-            Line::from(vec![
-                Span::styled("d", Style::default().fg(Color::Yellow)),
-                Span::raw("     - Mark task as done"),
-            ]),
-            Line::from(vec![
-                Span::styled("Del", Style::default().fg(Color::Yellow)),
-                Span::raw("   - Delete selected task"),
-            ]),
-            Line::from(""),
-            Line::from("Press ESC to go back"),
-        ];
-
-        let help = Paragraph::new(help_text)
-            .block(Block::default().title("Help").borders(Borders::ALL));
-        
-        f.render_widget(help, area);
+    fn draw_help(&mut self, f: &mut Frame, area: Rect) {
+        self.help_view.render(f, area);
     }
 
 
     fn draw_footer_panel(&self, f: &mut Frame, area: Rect) {
-        let help_content = if self.task_form.is_some() {
+        let help_content = if let Some(ref message) = self.status_message {
+            let color = if message.is_error { Color::Red } else { Color::Green };
+            Line::from(vec![
+                Span::styled(message.text.clone(), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            ])
+        } else if self.task_form.is_some() {
             Line::from(vec![
                 Span::styled("↑↓", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                 Span::raw(" Navigate fields  "),
                 Span::styled("←→", Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
                 Span::raw(" Move cursor  "),
+                Span::styled("Ctrl+E", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::raw(" $EDITOR  "),
                 Span::styled("Enter", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
                 Span::raw(" Save  "),
                 Span::styled("Esc", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
@@ -469,6 +1606,10 @@ impl AppUI {
                 Span::raw(" Toggle  "),
                 Span::styled("Type", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
                 Span::raw(" Search  "),
+                Span::styled("r", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::raw(" Rename project  "),
+                Span::styled("C", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::raw(" Clear all  "),
                 Span::styled("Esc", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
                 Span::raw(" Exit"),
             ])
@@ -486,8 +1627,12 @@ impl AppUI {
                         Span::raw("ete  "),
                         Span::styled("[/]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
                         Span::raw("filter  "),
+                        Span::styled("[x]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                        Span::raw(" export  "),
                         Span::styled("[r]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
                         Span::raw("eports  "),
+                        Span::styled("[C]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                        Span::raw(if self.main_view.shows_closed_tasks() { " hide closed  " } else { " show closed  " }),
                         Span::styled("[q]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
                         Span::raw("uit"),
                     ])
@@ -510,6 +1655,10 @@ impl AppUI {
                         ])
                     } else {
                         Line::from(vec![
+                            Span::styled("[↑↓]", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                            Span::raw(" project  "),
+                            Span::styled("[Enter]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                            Span::raw(" show tasks  "),
                             Span::styled("[c]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
                             Span::raw("alendar  "),
                             Span::styled("[ESC]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
@@ -519,16 +1668,46 @@ impl AppUI {
                         ])
                     }
                 }
+                AppView::Projects => {
+                    Line::from(vec![
+                        Span::styled("[↑↓]", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                        Span::raw(" select  "),
+                        Span::styled("[Enter]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                        Span::raw(" show tasks  "),
+                        Span::styled("[ESC]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                        Span::raw(" back  "),
+                        Span::styled("[q]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                        Span::raw("uit"),
+                    ])
+                }
                 AppView::Help => {
                     Line::from(vec![
+                        Span::styled("[↑↓]", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                        Span::raw(" scroll  "),
                         Span::styled("[ESC]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
                         Span::raw(" back"),
                     ])
                 }
-                _ => {
+                AppView::Settings => {
                     Line::from(vec![
+                        Span::styled("[↑↓]", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                        Span::raw(" scroll  "),
+                        Span::styled("[R]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                        Span::raw(" reload from disk  "),
                         Span::styled("[ESC]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-                        Span::raw(" back  "),
+                        Span::raw(" back"),
+                    ])
+                }
+                AppView::TaskDetail => {
+                    Line::from(vec![
+                        Span::styled("[e]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                        Span::raw("dit  "),
+                        Span::styled("[d]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                        Span::raw("one  "),
+                        Span::styled("[A]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                        Span::raw("nnotations  "),
+                        Span::styled("[ESC]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                        Span::raw(" back to list  "),
                         Span::styled("[q]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
                         Span::raw("uit"),
                     ])
@@ -547,85 +1726,830 @@ impl AppUI {
         f.render_widget(footer_panel, area);
     }
 
+    fn export_filtered_tasks(&mut self, path: std::path::PathBuf, format: crate::data::export::ExportFormat) {
+        // Surface write errors in the footer instead of aborting the app
+        self.status_message = Some(match TaskExporter::export_to_file(&self.filtered_tasks, &path, format) {
+            Ok(()) => {
+                let shown_path = std::fs::canonicalize(&path).unwrap_or(path);
+                StatusMessage {
+                    text: format!("Exported {} tasks to {}", self.filtered_tasks.len(), shown_path.display()),
+                    is_error: false,
+                }
+            }
+            Err(e) => StatusMessage {
+                text: format!("Export to {} failed: {}", path.display(), e),
+                is_error: true,
+            },
+        });
+    }
+
+    // Task to select once the selected one leaves the list: the next one, or the
+    // previous one at the end (None when it is the only task)
+    fn neighbour_of_selected(&self) -> Option<String> {
+        let current_index = self.main_view.selected_index().unwrap_or(0);
+        if current_index + 1 < self.filtered_tasks.len() {
+            Some(self.filtered_tasks[current_index + 1].uuid.clone())
+        } else if current_index > 0 {
+            Some(self.filtered_tasks[current_index - 1].uuid.clone())
+        } else {
+            None
+        }
+    }
+
+    fn open_rename_dialog(&mut self) {
+        if self.project_rename.is_some() {
+            self.status_message = Some(StatusMessage {
+                text: "A project rename is already in progress".to_string(),
+                is_error: false,
+            });
+            return;
+        }
+
+        if let Some(project) = self.main_view.highlighted_project() {
+            let task_count = self.tasks.iter()
+                .filter(|task| task.project.as_deref() == Some(project))
+                .count();
+            self.rename_dialog = Some(RenameProjectDialog::new(project.to_string(), task_count));
+        }
+    }
+
+    fn start_project_rename(&mut self, from: String, to: String) {
+        // Every task in the project, completed ones included, so history stays consistent
+        let remaining: Vec<String> = self.tasks.iter()
+            .filter(|task| task.project.as_deref() == Some(from.as_str()))
+            .map(|task| task.uuid.clone())
+            .collect();
+
+        self.project_rename = Some(ProjectRename {
+            total: remaining.len(),
+            remaining,
+            from,
+            to,
+            failures: Vec::new(),
+        });
+    }
+
+    // Stands in for everything else until the terminal is big enough again; open
+    // dialogs and typed input are kept
+    fn draw_too_small(&self, f: &mut Frame, area: Rect) {
+        let text = vec![
+            Line::from(Span::styled("Terminal too small", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))),
+            Line::from(format!("{}×{}, needs {}×{}", area.width, area.height, MIN_WIDTH, MIN_HEIGHT)),
+        ];
+        let top = area.height.saturating_sub(text.len() as u16) / 2;
+        let notice_area = Rect {
+            y: area.y + top,
+            height: area.height - top,
+            ..area
+        };
+        f.render_widget(Paragraph::new(text).alignment(Alignment::Center).wrap(Wrap { trim: true }), notice_area);
+    }
+
+    pub fn has_pending_work(&self) -> bool {
+        self.project_rename.is_some() || self.batch_tag.is_some() || self.sync_state.is_some()
+    }
+
+    fn start_sync(&mut self) {
+        if !self.config.taskwarrior.sync_enabled {
+            self.status_message = Some(StatusMessage {
+                text: "Sync is disabled; set sync_enabled = true under [taskwarrior] in config.toml".to_string(),
+                is_error: false,
+            });
+            return;
+        }
+        if self.sync_state.is_none() {
+            self.sync_state = Some(SyncState::Requested);
+        }
+    }
+
+    async fn process_sync(&mut self, taskwarrior: &TaskwarriorIntegration) {
+        match self.sync_state {
+            Some(SyncState::Requested) => {
+                self.sync_state = Some(SyncState::Running);
+                self.status_message = Some(StatusMessage {
+                    text: "Syncing with the Taskwarrior server...".to_string(),
+                    is_error: false,
+                });
+            }
+            Some(SyncState::Running) => {
+                self.sync_state = None;
+                self.status_message = Some(match taskwarrior.sync().await {
+                    Ok(output) => {
+                        self.preserve_selection_uuid = self.main_view.selected_task_uuid();
+                        self.request_reload();
+                        let summary = output.lines().map(str::trim).rfind(|l| !l.is_empty());
+                        StatusMessage {
+                            text: format!("Sync complete{}", summary.map(|s| format!(": {}", s)).unwrap_or_default()),
+                            is_error: false,
+                        }
+                    }
+                    Err(e) => {
+                        log::error!("task sync failed: {}", e);
+                        // Server errors span several lines; keep them readable in the one-line footer
+                        let error = e.to_string().split_whitespace().collect::<Vec<_>>().join(" ");
+                        StatusMessage { text: format!("Sync failed: {}", error), is_error: true }
+                    }
+                });
+            }
+            None => {}
+        }
+    }
+
+    // Advance queued work by one step so the UI can redraw progress in between
+    pub async fn process_pending_work(&mut self, taskwarrior: &TaskwarriorIntegration) {
+        if self.sync_state.is_some() {
+            self.process_sync(taskwarrior).await;
+            return;
+        }
+
+        if self.batch_tag.is_some() {
+            self.process_batch_tag(taskwarrior).await;
+            return;
+        }
+
+        let Some(rename) = self.project_rename.as_mut() else {
+            return;
+        };
+
+        if let Some(uuid) = rename.remaining.pop() {
+            if let Err(e) = taskwarrior.modify_task_by_uuid(&uuid, &[("project", rename.to.as_str())]).await {
+                rename.failures.push(e.to_string());
+            }
+            self.status_message = Some(StatusMessage {
+                text: format!(
+                    "Renaming project {} → {}: {}/{}",
+                    rename.from,
+                    rename.to,
+                    rename.total - rename.remaining.len(),
+                    rename.total
+                ),
+                is_error: false,
+            });
+        }
+
+        if !rename.remaining.is_empty() {
+            return;
+        }
+
+        if let Some(rename) = self.project_rename.take() {
+            let renamed = rename.total - rename.failures.len();
+            self.status_message = Some(match rename.failures.first() {
+                None => StatusMessage {
+                    text: format!("Renamed project {} to {} on {} tasks", rename.from, rename.to, renamed),
+                    is_error: false,
+                },
+                Some(first_error) => StatusMessage {
+                    text: format!(
+                        "Renamed project {} to {} on {} of {} tasks; {} failed: {}",
+                        rename.from, rename.to, renamed, rename.total, rename.failures.len(), first_error
+                    ),
+                    is_error: true,
+                },
+            });
+
+            if renamed > 0 {
+                self.main_view.rename_project_filter(&rename.from, &rename.to);
+            }
+            self.preserve_selection_uuid = self.main_view.selected_task_uuid();
+            self.request_reload();
+        }
+    }
+
+    fn open_batch_tag_dialog(&mut self) {
+        if self.project_rename.is_some() || self.batch_tag.is_some() {
+            self.status_message = Some(StatusMessage {
+                text: "Wait for the change in progress to finish".to_string(),
+                is_error: false,
+            });
+        } else if self.filtered_tasks.is_empty() {
+            self.status_message = Some(StatusMessage {
+                text: "No tasks listed to tag".to_string(),
+                is_error: false,
+            });
+        } else {
+            self.batch_tag_dialog = Some(BatchTagDialog::new(self.filtered_tasks.len()));
+        }
+    }
+
+    // Ask before touching the listed tasks, counting only those the change affects
+    fn confirm_batch_tag(&mut self, tag: String, operation: TagOperation) {
+        let remaining: Vec<String> = self.filtered_tasks.iter()
+            .filter(|task| task.tags.contains(&tag) == (operation == TagOperation::Remove))
+            .map(|task| task.uuid.clone())
+            .collect();
+        let sign = operation.sign();
+
+        if remaining.is_empty() {
+            let text = match operation {
+                TagOperation::Add => format!("Every listed task already has {}{}", sign, tag),
+                TagOperation::Remove => format!("No listed task has {}{}", sign, tag),
+            };
+            self.status_message = Some(StatusMessage { text, is_error: false });
+            return;
+        }
+
+        let task_word = if remaining.len() == 1 { "task" } else { "tasks" };
+        let (question, answer) = match operation {
+            TagOperation::Add => (format!("Add +{} to {} listed {}?", tag, remaining.len(), task_word), "add"),
+            TagOperation::Remove => (format!("Remove -{} from {} listed {}?", tag, remaining.len(), task_word), "remove"),
+        };
+        self.batch_tag_confirm = Some(PendingBatchTag {
+            dialog: ConfirmDialog::new("Tag Listed Tasks", &question, answer, "cancel"),
+            batch: BatchTag {
+                tag,
+                operation,
+                total: remaining.len(),
+                remaining,
+                failures: Vec::new(),
+            },
+        });
+    }
+
+    async fn process_batch_tag(&mut self, taskwarrior: &TaskwarriorIntegration) {
+        let Some(batch) = self.batch_tag.as_mut() else {
+            return;
+        };
+        let modification = format!("{}{}", batch.operation.sign(), batch.tag);
+
+        if let Some(uuid) = batch.remaining.pop() {
+            // By UUID, so completed and deleted tasks in the list are reached too
+            if let Err(e) = taskwarrior.modify_task_by_uuid(&uuid, &[(modification.as_str(), "")]).await {
+                batch.failures.push(e.to_string());
+            }
+            let verb = match batch.operation {
+                TagOperation::Add => "Adding",
+                TagOperation::Remove => "Removing",
+            };
+            self.status_message = Some(StatusMessage {
+                text: format!("{} {}: {}/{}", verb, modification, batch.total - batch.remaining.len(), batch.total),
+                is_error: false,
+            });
+        }
+
+        if !batch.remaining.is_empty() {
+            return;
+        }
+
+        if let Some(batch) = self.batch_tag.take() {
+            let updated = batch.total - batch.failures.len();
+            let done = match batch.operation {
+                TagOperation::Add => format!("Added {} to", modification),
+                TagOperation::Remove => format!("Removed {} from", modification),
+            };
+            self.status_message = Some(match batch.failures.first() {
+                None => StatusMessage {
+                    text: format!("{} {} tasks", done, updated),
+                    is_error: false,
+                },
+                Some(first_error) => StatusMessage {
+                    text: format!(
+                        "{} {} of {} tasks; {} failed: {}",
+                        done, updated, batch.total, batch.failures.len(), first_error
+                    ),
+                    is_error: true,
+                },
+            });
+            self.preserve_selection_uuid = self.main_view.selected_task_uuid();
+            self.request_reload();
+        }
+    }
+
+    fn show_selected_task_in_calendar(&mut self) {
+        let selected = self.main_view.selected_task();
+        let due = selected.and_then(|task| task.due);
+        if selected.is_some() && due.is_none() {
+            self.status_message = Some(StatusMessage {
+                text: "Task has no due date, showing today".to_string(),
+                is_error: false,
+            });
+        }
+        self.reports_view.show_in_calendar(due);
+        self.current_view = AppView::Reports;
+        self.reload_if_export_filter_changed();
+    }
+
+    // The full-screen detail view acts on the task it shows, the one selected in the list
+    async fn handle_task_detail_action(&mut self, action: Action, taskwarrior: &TaskwarriorIntegration) -> Result<()> {
+        match action {
+            Action::EditTask | Action::DoneTask | Action::Annotations => {
+                self.handle_task_list_action(action, taskwarrior).await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn handle_projects_action(&mut self, action: Action) {
+        if let Action::Select = action {
+            if let Some(project) = self.projects_view.selected_project() {
+                let name = project.name.clone();
+                self.show_project_tasks(&name);
+            }
+        }
+    }
+
+    // Enter on the dashboard's project table; "(no project)" can't be filtered on
+    fn handle_reports_action(&mut self, action: Action) {
+        if let Action::Select = action {
+            if self.reports_view.is_calendar_mode() {
+                return;
+            }
+            if self.reports_view.focused_table() == DashboardTable::Tags {
+                if let Some(tag) = self.reports_view.selected_tag() {
+                    let tag = tag.to_string();
+                    self.show_tag_tasks(&tag);
+                }
+            } else if let Some(name) = self.reports_view.selected_project().filter(|name| *name != NO_PROJECT) {
+                let name = name.to_string();
+                self.show_project_tasks(&name);
+            }
+        }
+    }
+
+    fn show_tag_tasks(&mut self, tag: &str) {
+        self.main_view.show_only_tag(tag);
+        self.current_view = AppView::TaskList;
+        self.apply_filters();
+        self.status_message = Some(StatusMessage {
+            text: format!("Showing tag +{} (/ to change filters)", tag),
+            is_error: false,
+        });
+    }
+
+    fn show_project_tasks(&mut self, name: &str) {
+        self.main_view.show_only_project(name);
+        self.current_view = AppView::TaskList;
+        self.apply_filters();
+        self.status_message = Some(StatusMessage {
+            text: format!("Showing project {} (/ to change filters)", name),
+            is_error: false,
+        });
+    }
+
     async fn handle_task_list_action(&mut self, action: Action, taskwarrior: &TaskwarriorIntegration) -> Result<()> {
+        // Task IDs can be renumbered by the export in flight, so hold off on changes
+        if self.is_loading() && matches!(action, Action::EditTask | Action::DoneTask | Action::DeleteTask | Action::CyclePriority) {
+            self.status_message = Some(StatusMessage {
+                text: "Still loading tasks, try again in a moment".to_string(),
+                is_error: false,
+            });
+            return Ok(());
+        }
+
         match action {
             Action::AddTask => {
-                self.task_form = Some(TaskForm::new_task());
+                self.task_form = Some(TaskForm::new_task().with_dependency_ids(&self.tasks));
             }
-            Action::EditTask => {
+            Action::QuickAdd => {
+                self.quick_add_dialog = Some(QuickAddDialog::default());
+            }
+            Action::Annotations => {
                 if let Some(task) = self.main_view.selected_task() {
-                    self.task_form = Some(TaskForm::edit_task(task.clone()));
+                    self.annotation_dialog = Some(AnnotationDialog::new(task, DateFormat::new(&self.config.ui)));
                 }
             }
-            Action::DoneTask => {
+            Action::Snooze => {
                 if let Some(task) = self.main_view.selected_task() {
-                    if let Some(task_id) = task.id {
-                        // Find the next task to select after completing this one
-                        let current_index = self.main_view.selected_index().unwrap_or(0);
-                        let next_task_uuid = if current_index + 1 < self.filtered_tasks.len() {
-                            // Select next task
-                            Some(self.filtered_tasks[current_index + 1].uuid.clone())
-                        } else if current_index > 0 {
-                            // Select previous task if we're at the end
-                            Some(self.filtered_tasks[current_index - 1].uuid.clone())
-                        } else {
-                            None // No other tasks available
-                        };
-                        
-                        self.preserve_selection_uuid = next_task_uuid;
-                        
-                        // Attempt to complete the task with better error handling
-                        match taskwarrior.done_task(task_id).await {
-                            Ok(_) => {
-                                // Successfully completed, reload tasks
-                                self.load_tasks(taskwarrior).await?;
-                            }
-                            Err(e) => {
-                                // If completion fails, don't crash - just show the error and continue
-                                eprintln!("Failed to complete task {}: {}", task_id, e);
-                                // Clear the preserve UUID since operation failed
-                                self.preserve_selection_uuid = None;
+                    self.snooze_dialog = Some(SnoozeDialog::new(task, DateFormat::new(&self.config.ui)));
+                }
+            }
+            Action::Reopen => self.reopen_selected(taskwarrior).await,
+            Action::Unwait => {
+                if self.main_view.selected_task().is_some_and(|task| !task.is_waiting()) {
+                    self.status_message = Some(StatusMessage {
+                        text: "The selected task isn't waiting".to_string(),
+                        is_error: true,
+                    });
+                } else if let Some(task) = self.main_view.selected_task() {
+                    let uuid = task.uuid.clone();
+                    let label = task.id.map_or_else(|| format!("'{}'", truncate_text(&task.description, 40)), |id| id.to_string());
+                    self.status_message = Some(match taskwarrior.modify_task_by_uuid(&uuid, &[("wait", "")]).await {
+                        Ok(_) => {
+                            // Like snoozing, the task may leave the Waiting list in view
+                            self.preserve_selection_uuid = self.neighbour_of_selected();
+                            self.request_reload();
+                            StatusMessage {
+                                text: format!("Task {} is pending again", label),
+                                is_error: false,
                             }
                         }
+                        Err(e) => StatusMessage {
+                            text: format!("Failed to unwait task: {}", e.to_string().lines().next().unwrap_or_default()),
+                            is_error: true,
+                        },
+                    });
+                }
+            }
+            Action::Find => self.main_view.start_find(),
+            Action::ReportMenu => {
+                // Without `task _config` the built-in reports are still there
+                let reports = taskwarrior.report_names().await.unwrap_or_else(|e| {
+                    log::warn!("Couldn't list Taskwarrior reports: {}", e);
+                    BUILTIN_REPORTS.iter().map(|name| name.to_string()).collect()
+                });
+                let active = self.active_report.as_ref().map(|report| report.name.as_str());
+                self.report_menu = Some(ReportMenu::new(reports, active));
+            }
+            Action::OpenLink => {
+                if let Some(task) = self.main_view.selected_task() {
+                    let mut urls = task_urls(task);
+                    match urls.len() {
+                        0 => {
+                            self.status_message = Some(StatusMessage {
+                                text: "No URL in the task's description or annotations".to_string(),
+                                is_error: true,
+                            });
+                        }
+                        1 => self.open_link(&urls.remove(0)),
+                        _ => self.link_picker = Some(LinkPicker::new(urls)),
                     }
                 }
             }
-            Action::DeleteTask => {
+            Action::Select if self.main_view.selected_task().is_some() => {
+                self.main_view.close_detail_overlay();
+                self.current_view = AppView::TaskDetail;
+            }
+            Action::BatchTag => self.open_batch_tag_dialog(),
+            Action::CopyAddCommand => {
                 if let Some(task) = self.main_view.selected_task() {
-                    if let Some(task_id) = task.id {
-                        // Find the next task to select after deleting this one
-                        let current_index = self.main_view.selected_index().unwrap_or(0);
-                        let next_task_uuid = if current_index + 1 < self.filtered_tasks.len() {
-                            // Select next task
-                            Some(self.filtered_tasks[current_index + 1].uuid.clone())
-                        } else if current_index > 0 {
-                            // Select previous task if we're at the end
-                            Some(self.filtered_tasks[current_index - 1].uuid.clone())
+                    let command = Self::add_command(task);
+                    self.copy_to_clipboard("task add command", &command);
+                }
+            }
+            Action::CopyUuid => {
+                if let Some(task) = self.main_view.selected_task() {
+                    let uuid = task.uuid.clone();
+                    self.copy_to_clipboard("UUID", &uuid);
+                }
+            }
+            Action::CopyDescription => {
+                if let Some(task) = self.main_view.selected_task() {
+                    let description = task.description.clone();
+                    self.copy_to_clipboard("description", &description);
+                }
+            }
+            Action::Export => {
+                self.export_dialog = Some(ExportDialog::new());
+            }
+            Action::Sync => {
+                self.start_sync();
+            }
+            // Toggling is the guard; it fails when the split layout already shows details
+            Action::ToggleDetail if !self.main_view.toggle_detail_overlay() => {
+                self.status_message = Some(StatusMessage {
+                    text: "Details are already shown beside the list (L switches layout)".to_string(),
+                    is_error: false,
+                });
+            }
+            Action::ToggleDetail => {}
+            Action::CycleLayout => {
+                self.main_view.cycle_layout();
+                let layout = self.main_view.layout();
+                self.status_message = Some(StatusMessage {
+                    text: if layout == PanelLayout::Auto {
+                        format!("Layout: auto (compact below {} columns)", COMPACT_WIDTH)
+                    } else {
+                        format!("Layout: {}", layout.label())
+                    },
+                    is_error: false,
+                });
+            }
+            Action::ToggleClosedTasks => {
+                self.main_view.toggle_closed_tasks();
+                self.apply_filters();
+                self.status_message = Some(StatusMessage {
+                    text: if self.main_view.shows_closed_tasks() {
+                        "Showing completed and deleted tasks".to_string()
+                    } else {
+                        "Hiding completed and deleted tasks".to_string()
+                    },
+                    is_error: false,
+                });
+            }
+            Action::ToggleBlocked => {
+                let enabled = !self.main_view.is_unblocked_only();
+                self.main_view.set_unblocked_only(enabled);
+                self.preserve_selection_uuid = self.main_view.selected_task_uuid();
+                self.apply_filters();
+                self.status_message = Some(StatusMessage {
+                    text: if enabled {
+                        "Hiding tasks blocked by open dependencies".to_string()
+                    } else {
+                        "Showing blocked tasks".to_string()
+                    },
+                    is_error: false,
+                });
+            }
+            Action::FilterByProject => {
+                let Some(task) = self.main_view.selected_task() else {
+                    return Ok(());
+                };
+                let text = match task.project.clone() {
+                    Some(project) => {
+                        self.preserve_selection_uuid = Some(task.uuid.clone());
+                        let focused = self.main_view.toggle_project_focus(&project);
+                        self.apply_filters();
+                        if focused {
+                            format!("Showing project {} (F again to clear)", project)
                         } else {
-                            None // No other tasks available
-                        };
-                        
-                        self.preserve_selection_uuid = next_task_uuid;
-                        
-                        // Attempt to delete the task with better error handling
-                        match taskwarrior.delete_task(task_id).await {
+                            "Project filter cleared".to_string()
+                        }
+                    }
+                    None => "The selected task has no project".to_string(),
+                };
+                self.status_message = Some(StatusMessage { text, is_error: false });
+            }
+            Action::FilterByTag => {
+                let Some(task) = self.main_view.selected_task() else {
+                    return Ok(());
+                };
+                let text = match task.tags.first().cloned() {
+                    Some(tag) => {
+                        self.preserve_selection_uuid = Some(task.uuid.clone());
+                        let focused = self.main_view.toggle_tag_focus(&tag);
+                        self.apply_filters();
+                        if focused {
+                            format!("Showing tag +{} (T again to clear)", tag)
+                        } else {
+                            "Tag filter cleared".to_string()
+                        }
+                    }
+                    None => "The selected task has no tags".to_string(),
+                };
+                self.status_message = Some(StatusMessage { text, is_error: false });
+            }
+            Action::ToggleGrouping => {
+                let grouped = !self.main_view.is_grouped();
+                self.main_view.set_grouped(grouped);
+                self.preserve_selection_uuid = self.main_view.selected_task_uuid();
+                self.apply_filters();
+            }
+            Action::NextView => {
+                let enabled = !self.main_view.is_next_view();
+                self.main_view.set_next_view(enabled);
+                self.preserve_selection_uuid = self.main_view.selected_task_uuid();
+                self.apply_filters();
+                self.status_message = Some(StatusMessage {
+                    text: if enabled {
+                        "Next actionable: pending, not waiting or blocked, by urgency (n to go back)".to_string()
+                    } else {
+                        "Back to filtered task list".to_string()
+                    },
+                    is_error: false,
+                });
+            }
+            Action::RecentlyCompleted => {
+                let enabled = !self.main_view.is_recently_completed();
+                self.main_view.set_recently_completed(enabled);
+                self.preserve_selection_uuid = self.main_view.selected_task_uuid();
+                self.apply_filters();
+                self.status_message = Some(StatusMessage {
+                    text: if enabled {
+                        format!(
+                            "Completed in the last {} days, newest first (o to reopen, H to go back)",
+                            self.main_view.recent_days()
+                        )
+                    } else {
+                        "Back to filtered task list".to_string()
+                    },
+                    is_error: false,
+                });
+            }
+            Action::SortBy(sort_key) => {
+                self.sort_key = sort_key;
+                self.sort_chosen = true;
+                self.sort_key.sort(&mut self.tasks);
+                self.main_view.set_sort_key(sort_key);
+                // Keep the cursor on the same task after reordering
+                self.preserve_selection_uuid = self.main_view.selected_task_uuid();
+                self.apply_filters();
+            }
+            Action::CyclePriority => {
+                if let Some(task) = self.main_view.selected_task() {
+                    if let Some(task_id) = task.id {
+                        let uuid = task.uuid.clone();
+                        let next_priority = Priority::cycle(task.priority.as_ref());
+                        // An empty value clears the priority (sent as "priority:")
+                        let value = next_priority.as_ref().map(|p| p.as_str()).unwrap_or("");
+
+                        match taskwarrior.modify_task(task_id, &[("priority", value)]).await {
                             Ok(_) => {
-                                // Successfully deleted, reload tasks
-                                self.load_tasks(taskwarrior).await?;
+                                self.preserve_selection_uuid = Some(uuid);
+                                self.request_reload();
                             }
                             Err(e) => {
-                                // If delete fails, don't crash - just show the error and continue
-                                eprintln!("Failed to delete task {}: {}", task_id, e);
-                                // Clear the preserve UUID since operation failed
-                                self.preserve_selection_uuid = None;
-                                // Don't propagate the error to avoid crashing the application
+                                self.status_message = Some(StatusMessage {
+                                    text: format!("Failed to change priority of task {}: {}", task_id, e),
+                                    is_error: true,
+                                });
                             }
                         }
                     }
                 }
             }
+            Action::EditTask => {
+                if let Some(task) = self.main_view.selected_task() {
+                    self.task_form = Some(TaskForm::edit_task(task.clone()).with_dependency_ids(&self.tasks));
+                }
+            }
+            // d toggles: a completed task (in the completed views or a status:completed
+            // filter) is reopened rather than failing to complete again
+            Action::DoneTask if self.main_view.selected_task().is_some_and(|task| task.status == TaskStatus::Completed) => {
+                self.reopen_selected(taskwarrior).await
+            }
+            Action::DoneTask => self.request_task_change(TaskChange::Done, taskwarrior).await,
+            Action::DeleteTask => self.request_task_change(TaskChange::Delete, taskwarrior).await,
             _ => {}
         }
         Ok(())
     }
+
+    // Set the selected completed or deleted task back to pending
+    async fn reopen_selected(&mut self, taskwarrior: &TaskwarriorIntegration) {
+        if self.main_view.selected_task().is_some_and(|task| !matches!(task.status, TaskStatus::Completed | TaskStatus::Deleted)) {
+            self.status_message = Some(StatusMessage {
+                text: "Only completed and deleted tasks can be reopened".to_string(),
+                is_error: true,
+            });
+        } else if let Some(task) = self.main_view.selected_task() {
+            let uuid = task.uuid.clone();
+            let description = truncate_text(&task.description, 40);
+            self.status_message = Some(match taskwarrior.reopen_task(&uuid).await {
+                Ok(_) => {
+                    // The recently completed view loses the task. Elsewhere it stays in view,
+                    // or the list falls back to its neighbour if a status filter drops it
+                    self.preserve_selection_uuid = if self.main_view.is_recently_completed() {
+                        self.neighbour_of_selected()
+                    } else {
+                        Some(uuid)
+                    };
+                    self.request_reload();
+                    StatusMessage {
+                        text: format!("Reopened '{}'", description),
+                        is_error: false,
+                    }
+                }
+                Err(e) => StatusMessage {
+                    text: format!("Failed to reopen task: {}", e.to_string().lines().next().unwrap_or_default()),
+                    is_error: true,
+                },
+            });
+        }
+    }
+
+    // A failed `task add` / `task modify` (lock held, bad date, timeout) shows in the footer
+    // and in the form, which stays open
+    fn report_save_error(&mut self, error: &anyhow::Error) {
+        let text = format!("Couldn't save the task: {}", error.to_string().lines().next().unwrap_or_default());
+        if let Some(form) = self.task_form.as_mut() {
+            form.error = Some(text.clone());
+        }
+        self.status_message = Some(StatusMessage { text, is_error: true });
+    }
+
+    async fn save_task_edit(&mut self, task: &Task, taskwarrior: &TaskwarriorIntegration) -> Result<()> {
+        let Some(task_id) = task.id else {
+            return Ok(());
+        };
+        // Update existing task - preserve selection on the same task
+        self.preserve_selection_uuid = Some(task.uuid.clone());
+
+        let original_depends = self.tasks.iter()
+            .find(|t| t.uuid == task.uuid)
+            .map(|t| t.depends.clone())
+            .unwrap_or_default();
+        let attributes = Self::task_to_attributes(task, &original_depends);
+        let attributes_refs: Vec<(&str, &str)> = attributes.iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+
+        taskwarrior.modify_task(task_id, &attributes_refs).await
+    }
+
+    // The task as it is now, if its `modified` moved on from the copy the form opened with.
+    // A failed lookup doesn't hold up the save; it's logged and the edit goes ahead.
+    async fn changed_elsewhere(opened_with: &Task, taskwarrior: &TaskwarriorIntegration) -> Option<Task> {
+        match taskwarrior.get_task_by_uuid(&opened_with.uuid).await {
+            Ok(latest) => latest.filter(|latest| latest.modified > opened_with.modified),
+            Err(e) => {
+                log::warn!("Couldn't check task {} for changes before saving: {}", opened_with.uuid, e);
+                None
+            }
+        }
+    }
+
+    fn conflict_message(&self, opened_with: &Task, latest: &Task) -> String {
+        let when = latest
+            .modified
+            .map(|modified| format!(" at {}", DateFormat::new(&self.config.ui).datetime(&modified)))
+            .unwrap_or_default();
+        let fields = opened_with.changed_fields(latest);
+        let what = if fields.is_empty() { String::new() } else { format!(" ({})", fields.join(", ")) };
+        format!("This task was changed outside LazyTask{}{} after you started editing it.", when, what)
+    }
+
+    // Complete or delete the selected task, asking first when `ui.confirm_done` /
+    // `ui.confirm_delete` says so
+    async fn request_task_change(&mut self, change: TaskChange, taskwarrior: &TaskwarriorIntegration) {
+        let Some(task) = self.main_view.selected_task() else {
+            return;
+        };
+        if change.needs_confirmation(&self.config.ui) {
+            self.task_confirm = Some(PendingTaskChange {
+                dialog: change.confirm_dialog(task),
+                change,
+                uuid: task.uuid.clone(),
+            });
+        } else {
+            let uuid = task.uuid.clone();
+            self.run_task_change(change, &uuid, taskwarrior).await;
+        }
+    }
+
+    async fn run_task_change(&mut self, change: TaskChange, uuid: &str, taskwarrior: &TaskwarriorIntegration) {
+        // Looked up again by UUID: a reload may have renumbered tasks since the question was asked
+        let Some(task_id) = self.tasks.iter().find(|task| task.uuid == uuid).and_then(|task| task.id) else {
+            return;
+        };
+
+        // Find the next task to select once this one leaves the list
+        self.preserve_selection_uuid = self.neighbour_of_selected();
+
+        let result = match change {
+            TaskChange::Done => taskwarrior.done_task(task_id).await,
+            TaskChange::Delete => taskwarrior.delete_task(task_id).await,
+        };
+        match result {
+            Ok(_) => self.request_reload(),
+            Err(e) => {
+                // If the change fails, don't crash - just show the error and continue
+                log::error!("Failed to {} task {}: {}", change.verb(), task_id, e);
+                self.status_message = Some(StatusMessage {
+                    text: format!("Failed to {} task {}: {}", change.verb(), task_id, e),
+                    is_error: true,
+                });
+                // Clear the preserve UUID since operation failed
+                self.preserve_selection_uuid = None;
+            }
+        }
+    }
+
+    // Like copying, a URL that can't be opened is shown in the footer to select by hand
+    fn open_link(&mut self, url: &str) {
+        self.status_message = Some(match open_url(url) {
+            Ok(()) => StatusMessage {
+                text: format!("Opened {}", truncate_text(url, 80)),
+                is_error: false,
+            },
+            Err(e) => {
+                log::warn!("{:#}; URL: {}", e, url);
+                StatusMessage {
+                    text: format!("{:#}; URL: {}", e, url),
+                    is_error: true,
+                }
+            }
+        });
+    }
+
+    // Without a clipboard (SSH, no display) the value goes to the log and the footer
+    // instead, where it can still be selected with the mouse
+    fn copy_to_clipboard(&mut self, what: &str, text: &str) {
+        self.status_message = Some(match self.clipboard.copy(text) {
+            Ok(()) => StatusMessage {
+                text: format!("Copied {} to the clipboard: {}", what, truncate_text(text, 60)),
+                is_error: false,
+            },
+            Err(e) => {
+                log::warn!("{:#}; task {}: {}", e, what, text);
+                StatusMessage {
+                    text: format!("No clipboard available; {}: {}", what, text),
+                    is_error: true,
+                }
+            }
+        });
+    }
+
+    // While typing, characters extend the query and arrows step through matches.
+    // After Enter, n / N step forwards / backwards. Esc ends the find either way.
+    fn handle_find_action(&mut self, action: &Action) -> bool {
+        if self.main_view.is_find_typing() {
+            match *action {
+                Action::Character(c) => self.main_view.find_push(c),
+                Action::Space => self.main_view.find_push(' '),
+                Action::Backspace => self.main_view.find_pop(),
+                Action::MoveDown => self.main_view.find_next(true),
+                Action::MoveUp => self.main_view.find_next(false),
+                Action::Select => self.main_view.finish_find_typing(),
+                Action::Back => self.main_view.clear_find(),
+                _ => return false,
+            }
+            return true;
+        }
+        match action {
+            Action::FindNext => self.main_view.find_next(true),
+            Action::FindPrevious => self.main_view.find_next(false),
+            Action::Back => self.main_view.clear_find(),
+            _ => return false,
+        }
+        true
+    }
 }
@@ -0,0 +1,341 @@
+// Lists a task's annotations and lets them be added, edited and removed
+
+use anyhow::Result;
+use chrono::Utc;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::data::models::{Annotation, Task};
+use crate::handlers::input::Action;
+use crate::utils::formatting::{truncate_text, DateFormat};
+
+// Which annotation the text input is for
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Editing {
+    New,
+    Existing(usize),
+}
+
+pub struct AnnotationDialog {
+    pub task_uuid: String,
+    pub task_description: String,
+    pub annotations: Vec<Annotation>,
+    pub input: String,
+    pub cursor: usize,
+    pub error: Option<String>,
+    selected: usize,
+    editing: Option<Editing>,
+    // Set by a first `d` on text that other annotations share; a second `d` deletes
+    confirm_delete: bool,
+    date_format: DateFormat,
+}
+
+// Taskwarrior finds annotations by their text, so results carry the text rather than an index
+#[derive(Debug, PartialEq)]
+pub enum AnnotationDialogResult {
+    Add(String),
+    Edit { old: String, new: String },
+    Delete(String),
+    Close,
+}
+
+impl AnnotationDialog {
+    pub fn new(task: &Task, date_format: DateFormat) -> Self {
+        AnnotationDialog {
+            task_uuid: task.uuid.clone(),
+            task_description: task.description.clone(),
+            annotations: task.annotations.clone(),
+            input: String::new(),
+            cursor: 0,
+            error: None,
+            selected: 0,
+            editing: None,
+            confirm_delete: false,
+            date_format,
+        }
+    }
+
+    pub fn selected_annotation(&self) -> Option<&Annotation> {
+        self.annotations.get(self.selected)
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    // How many annotations read exactly like the selected one (itself included)
+    pub fn selected_duplicates(&self) -> usize {
+        self.selected_annotation().map_or(0, |selected| {
+            self.annotations
+                .iter()
+                .filter(|annotation| annotation.description == selected.description)
+                .count()
+        })
+    }
+
+    pub fn handle_input(&mut self, action: Action) -> Result<Option<AnnotationDialogResult>> {
+        if self.is_editing() {
+            return Ok(self.handle_edit_input(action));
+        }
+
+        let confirm_delete = std::mem::take(&mut self.confirm_delete);
+        self.error = None;
+
+        match action {
+            Action::Back => return Ok(Some(AnnotationDialogResult::Close)),
+            Action::MoveUp => self.selected = self.selected.saturating_sub(1),
+            Action::MoveDown if self.selected + 1 < self.annotations.len() => self.selected += 1,
+            Action::Character('a') => self.start_editing(Editing::New, String::new()),
+            Action::Character('e') | Action::Select => {
+                if let Some(annotation) = self.selected_annotation() {
+                    let text = annotation.description.clone();
+                    self.start_editing(Editing::Existing(self.selected), text);
+                }
+            }
+            Action::Character('d') => {
+                if let Some(annotation) = self.selected_annotation() {
+                    let text = annotation.description.clone();
+                    let duplicates = self.selected_duplicates();
+                    if duplicates > 1 && !confirm_delete {
+                        self.confirm_delete = true;
+                        self.error = Some(format!(
+                            "{} annotations have this text; Taskwarrior removes the oldest. Press d again to delete it",
+                            duplicates
+                        ));
+                    } else {
+                        return Ok(Some(AnnotationDialogResult::Delete(text)));
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn handle_edit_input(&mut self, action: Action) -> Option<AnnotationDialogResult> {
+        match action {
+            Action::Back => {
+                self.editing = None;
+                self.error = None;
+            }
+            Action::Select => {
+                let text = self.input.trim().to_string();
+                if text.is_empty() {
+                    self.error = Some("Annotation can't be empty (use d to delete one)".to_string());
+                    return None;
+                }
+                let result = match self.editing {
+                    Some(Editing::Existing(index)) => {
+                        let old = self.annotations[index].description.clone();
+                        if old == text {
+                            self.editing = None;
+                            return None;
+                        }
+                        AnnotationDialogResult::Edit { old, new: text }
+                    }
+                    _ => AnnotationDialogResult::Add(text),
+                };
+                return Some(result);
+            }
+            Action::MoveLeft => {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            Action::MoveRight if self.cursor < self.input.chars().count() => {
+                self.cursor += 1;
+            }
+            Action::Character(c) => self.insert(c),
+            Action::Space => self.insert(' '),
+            Action::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                let byte_index = self.byte_index(self.cursor);
+                self.input.remove(byte_index);
+                self.error = None;
+            }
+            _ => {}
+        }
+        None
+    }
+
+    // Mirror a change Taskwarrior has accepted, so the list is right before the reload lands.
+    // Like `task denotate`, the oldest annotation with matching text is the one removed.
+    pub fn apply(&mut self, result: &AnnotationDialogResult) {
+        self.editing = None;
+        self.error = None;
+        match result {
+            AnnotationDialogResult::Add(text) => self.push(text),
+            AnnotationDialogResult::Edit { old, new } => {
+                self.remove_first(old);
+                self.push(new);
+            }
+            AnnotationDialogResult::Delete(text) => {
+                self.remove_first(text);
+                self.selected = self.selected.min(self.annotations.len().saturating_sub(1));
+            }
+            AnnotationDialogResult::Close => {}
+        }
+    }
+
+    fn push(&mut self, text: &str) {
+        self.annotations.push(Annotation {
+            entry: Utc::now(),
+            description: text.to_string(),
+        });
+        self.selected = self.annotations.len() - 1;
+    }
+
+    fn remove_first(&mut self, text: &str) {
+        if let Some(index) = self.annotations.iter().position(|annotation| annotation.description == text) {
+            self.annotations.remove(index);
+        }
+    }
+
+    fn start_editing(&mut self, editing: Editing, text: String) {
+        self.cursor = text.chars().count();
+        self.input = text;
+        self.editing = Some(editing);
+    }
+
+    fn insert(&mut self, c: char) {
+        let byte_index = self.byte_index(self.cursor);
+        self.input.insert(byte_index, c);
+        self.cursor += 1;
+        self.error = None;
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.input
+            .char_indices()
+            .nth(char_index)
+            .map(|(i, _)| i)
+            .unwrap_or(self.input.len())
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let width = area.width.min(80);
+        let height = 18.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(format!("Annotations ({})", self.annotations.len()))
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // Task
+                Constraint::Min(3),    // Annotations
+                Constraint::Length(if self.is_editing() { 3 } else { 0 }), // Text input
+                Constraint::Length(1), // Warning / error
+                Constraint::Length(1), // Instructions
+            ])
+            .split(inner_area);
+
+        let task_width = chunks[0].width as usize;
+        let task = Paragraph::new(Span::styled(
+            truncate_text(&self.task_description, task_width),
+            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+        ))
+        .style(Style::default().bg(Color::Black));
+        f.render_widget(task, chunks[0]);
+
+        let items: Vec<ListItem> = if self.annotations.is_empty() {
+            vec![ListItem::new(Span::styled(
+                "No annotations — press a to add one",
+                Style::default().fg(Color::DarkGray),
+            ))]
+        } else {
+            self.annotations
+                .iter()
+                .map(|annotation| {
+                    let date = format!("{}  ", self.date_format.short_date(&annotation.entry));
+                    // List borders and the date come out of the row width
+                    let text_width = (chunks[1].width as usize).saturating_sub(2 + date.chars().count());
+                    ListItem::new(Line::from(vec![
+                        Span::styled(date, Style::default().fg(Color::Cyan)),
+                        Span::raw(truncate_text(&annotation.description, text_width)),
+                    ]))
+                })
+                .collect()
+        };
+        let mut state = ListState::default();
+        if !self.annotations.is_empty() {
+            state.select(Some(self.selected));
+        }
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)))
+            .style(Style::default().bg(Color::Black).fg(Color::White))
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+        f.render_stateful_widget(list, chunks[1], &mut state);
+
+        if let Some(editing) = self.editing {
+            let label = if editing == Editing::New { "New: " } else { "Edit: " };
+            let (before, after) = self.input.split_at(self.byte_index(self.cursor));
+            let input = Paragraph::new(Line::from(vec![
+                Span::styled(label, Style::default().fg(Color::Yellow)),
+                Span::raw(before.to_string()),
+                Span::styled("█", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::raw(after.to_string()),
+            ]))
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)))
+            .style(Style::default().bg(Color::Black).fg(Color::White));
+            f.render_widget(input, chunks[2]);
+        }
+
+        // Errors first; otherwise warn that identical annotations can't be told apart
+        let notice = if let Some(ref error) = self.error {
+            Span::styled(error.clone(), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+        } else if self.selected_duplicates() > 1 {
+            Span::styled(
+                format!("{} annotations have this text; changes apply to the oldest", self.selected_duplicates()),
+                Style::default().fg(Color::Yellow),
+            )
+        } else {
+            Span::raw("")
+        };
+        f.render_widget(
+            Paragraph::new(Line::from(notice)).style(Style::default().bg(Color::Black)).alignment(Alignment::Center),
+            chunks[3],
+        );
+
+        let key = |k: &'static str| Span::styled(k, Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
+        let footer = if self.is_editing() {
+            Line::from(vec![key("Enter"), Span::raw(" save  "), key("Esc"), Span::raw(" back")])
+        } else {
+            Line::from(vec![
+                key("a"),
+                Span::raw(" add  "),
+                key("e"),
+                Span::raw(" edit  "),
+                key("d"),
+                Span::raw(" delete  "),
+                key("Esc"),
+                Span::raw(" close"),
+            ])
+        };
+        let instructions = Paragraph::new(footer)
+            .style(Style::default().bg(Color::Black).fg(Color::White))
+            .alignment(Alignment::Center);
+        f.render_widget(instructions, chunks[4]);
+    }
+}
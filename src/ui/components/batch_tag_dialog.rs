@@ -0,0 +1,181 @@
+// Prompt for a tag to add to or remove from every task in the filtered list
+
+use anyhow::Result;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::handlers::input::Action;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TagOperation {
+    Add,
+    Remove,
+}
+
+impl TagOperation {
+    // Taskwarrior's modification prefix: +tag adds, -tag removes
+    pub fn sign(&self) -> char {
+        match self {
+            TagOperation::Add => '+',
+            TagOperation::Remove => '-',
+        }
+    }
+
+    fn toggled(&self) -> TagOperation {
+        match self {
+            TagOperation::Add => TagOperation::Remove,
+            TagOperation::Remove => TagOperation::Add,
+        }
+    }
+}
+
+pub struct BatchTagDialog {
+    pub task_count: usize,
+    pub operation: TagOperation,
+    pub tag_input: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BatchTagResult {
+    Apply { tag: String, operation: TagOperation },
+    Cancel,
+}
+
+impl BatchTagDialog {
+    pub fn new(task_count: usize) -> Self {
+        BatchTagDialog {
+            task_count,
+            operation: TagOperation::Add,
+            tag_input: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn handle_input(&mut self, action: Action) -> Result<Option<BatchTagResult>> {
+        match action {
+            Action::Back => return Ok(Some(BatchTagResult::Cancel)),
+            Action::Tab | Action::MoveUp | Action::MoveDown => {
+                self.operation = self.operation.toggled();
+            }
+            Action::Select => {
+                let tag = self.tag_input.trim();
+                if tag.is_empty() {
+                    self.error = Some("Type a tag name".to_string());
+                } else if tag.chars().any(char::is_whitespace) {
+                    self.error = Some("Tags can't contain spaces".to_string());
+                } else {
+                    return Ok(Some(BatchTagResult::Apply {
+                        tag: tag.to_string(),
+                        operation: self.operation,
+                    }));
+                }
+            }
+            // Typing +tag or -tag picks the operation as well
+            Action::Character(c @ ('+' | '-')) if self.tag_input.is_empty() => {
+                self.operation = if c == '+' { TagOperation::Add } else { TagOperation::Remove };
+            }
+            Action::Character(c) => {
+                self.tag_input.push(c);
+                self.error = None;
+            }
+            Action::Space => {
+                self.tag_input.push(' ');
+            }
+            Action::Backspace => {
+                self.tag_input.pop();
+                self.error = None;
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let width = area.width.min(60);
+        let height = 11.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("Tag Listed Tasks")
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // Summary
+                Constraint::Length(1), // Add / remove
+                Constraint::Length(3), // Tag
+                Constraint::Min(1),    // Instructions / error
+            ])
+            .split(inner_area);
+
+        let task_word = if self.task_count == 1 { "task" } else { "tasks" };
+        let summary = Paragraph::new(Line::from(format!("Every listed task ({} {})", self.task_count, task_word)))
+            .style(Style::default().bg(Color::Black).fg(Color::White));
+        f.render_widget(summary, chunks[0]);
+
+        let option = |operation: TagOperation, label: &'static str| {
+            if operation == self.operation {
+                Span::styled(format!("(•) {}", label), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+            } else {
+                Span::styled(format!("( ) {}", label), Style::default().fg(Color::Gray))
+            }
+        };
+        let operations = Paragraph::new(Line::from(vec![
+            option(TagOperation::Add, "Add"),
+            Span::raw("   "),
+            option(TagOperation::Remove, "Remove"),
+        ]))
+        .style(Style::default().bg(Color::Black).fg(Color::White));
+        f.render_widget(operations, chunks[1]);
+
+        let tag_line = Line::from(vec![
+            Span::styled(format!("Tag: {}", self.operation.sign()), Style::default().fg(Color::Yellow)),
+            Span::raw(self.tag_input.clone()),
+            Span::styled("█", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        ]);
+        let tag = Paragraph::new(tag_line)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)))
+            .style(Style::default().bg(Color::Black).fg(Color::White));
+        f.render_widget(tag, chunks[2]);
+
+        let footer = if let Some(ref error) = self.error {
+            Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)))
+        } else {
+            Line::from(vec![
+                Span::styled("Tab", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::raw(" add / remove  "),
+                Span::styled("Enter", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::raw(" continue  "),
+                Span::styled("Esc", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::raw(" cancel"),
+            ])
+        };
+        let instructions = Paragraph::new(footer)
+            .style(Style::default().bg(Color::Black).fg(Color::White))
+            .alignment(Alignment::Center);
+        f.render_widget(instructions, chunks[3]);
+    }
+}
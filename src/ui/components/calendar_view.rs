@@ -7,19 +7,22 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
-use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use crate::data::models::{Priority, Task, TaskStatus};
+use crate::utils::formatting::{day_of, today, truncate_text, DateFormat};
 
 pub struct CalendarWidget {
     pub selected_date: DateTime<Utc>,
     pub tasks: Vec<Task>,
+    dates: DateFormat,
 }
 
 impl CalendarWidget {
-    pub fn new(selected_date: DateTime<Utc>, tasks: Vec<Task>) -> Self {
+    pub fn new(selected_date: DateTime<Utc>, tasks: Vec<Task>, dates: DateFormat) -> Self {
         CalendarWidget {
             selected_date,
             tasks,
+            dates,
         }
     }
 
@@ -38,18 +41,21 @@ impl CalendarWidget {
         self.render_daily_stats(f, chunks[1]);
     }
 
-    fn get_tasks_for_date(&self, date: DateTime<Utc>) -> Vec<&Task> {
-        let target_date = date.date_naive();
-        
+    // The selected day on the user's calendar (see `ui.use_utc`)
+    fn selected_day(&self) -> NaiveDate {
+        day_of(&self.selected_date)
+    }
+
+    fn get_tasks_for_date(&self, target_date: NaiveDate) -> Vec<&Task> {
         self.tasks.iter().filter(|task| {
             // Include tasks with due date on this day
-            let has_due_date = task.due.map_or(false, |due| due.date_naive() == target_date);
+            let has_due_date = task.due.map_or(false, |due| day_of(&due) == target_date);
             
             // Include tasks completed on this day
-            let completed_on_date = task.end.map_or(false, |end| end.date_naive() == target_date);
+            let completed_on_date = task.end.map_or(false, |end| day_of(&end) == target_date);
             
             // Include tasks created on this day
-            let created_on_date = task.entry.date_naive() == target_date;
+            let created_on_date = day_of(&task.entry) == target_date;
             
             has_due_date || completed_on_date || created_on_date
         }).collect()
@@ -57,7 +63,7 @@ impl CalendarWidget {
 
     fn render_calendar_grid(&self, f: &mut Frame, area: Rect) {
         // Calculate the 3 months to display (previous, current, next)
-        let center_date = self.selected_date;
+        let center_date = self.selected_day();
         
         // Create horizontal layout for 3 months
         let month_chunks = Layout::default()
@@ -75,44 +81,70 @@ impl CalendarWidget {
         self.render_single_month(f, month_chunks[2], center_date, 1);
     }
     
-    fn render_single_month(&self, f: &mut Frame, area: Rect, center_date: DateTime<Utc>, month_offset: i32) {
-        // Calculate the target month based on offset
-        let target_date = if month_offset < 0 {
-            center_date - Duration::days(30 * month_offset.abs() as i64)
-        } else if month_offset > 0 {
-            center_date + Duration::days(30 * month_offset as i64)
-        } else {
-            center_date
-        };
-        
-        let selected_year = self.selected_date.year();
-        let selected_month = self.selected_date.month();
-        let selected_day = self.selected_date.day();
-        
-        let target_year = target_date.year();
-        let target_month = target_date.month();
-        
-        // Get first day of target month
-        let first_day = NaiveDate::from_ymd_opt(target_year, target_month, 1)
-            .unwrap()
-            .and_hms_opt(0, 0, 0)
-            .unwrap()
-            .and_utc();
-        
-        // Get last day of target month
-        let days_in_month = if target_month == 12 {
-            NaiveDate::from_ymd_opt(target_year + 1, 1, 1)
-        } else {
-            NaiveDate::from_ymd_opt(target_year, target_month + 1, 1)
-        }.unwrap().pred_opt().unwrap().day();
+    /// Shift a (year, month) pair by whole months, e.g. (2025, 1) - 1 = (2024, 12)
+    pub fn offset_month(year: i32, month: u32, offset: i32) -> (i32, u32) {
+        let month_index = year * 12 + month as i32 - 1 + offset;
+        (month_index.div_euclid(12), month_index.rem_euclid(12) as u32 + 1)
+    }
 
-        // Build calendar
-        let month_name = match target_month {
+    pub fn month_name(month: u32) -> &'static str {
+        match month {
             1 => "January", 2 => "February", 3 => "March", 4 => "April",
             5 => "May", 6 => "June", 7 => "July", 8 => "August",
             9 => "September", 10 => "October", 11 => "November", 12 => "December",
             _ => "Unknown",
+        }
+    }
+
+    /// Weeks of a month as Monday-first rows of day numbers; days outside the month are None.
+    /// Empty only for months outside chrono's date range.
+    pub fn month_weeks(year: i32, month: u32) -> Vec<[Option<u32>; 7]> {
+        let Some(first_date) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            return Vec::new();
+        };
+        let (next_year, next_month) = Self::offset_month(year, month, 1);
+        let Some(days_in_month) = NaiveDate::from_ymd_opt(next_year, next_month, 1)
+            .and_then(|d| d.pred_opt())
+            .map(|d| d.day())
+        else {
+            return Vec::new();
+        };
+
+        let mut weeks = Vec::new();
+        let mut week = [None; 7];
+        let mut weekday = first_date.weekday().num_days_from_monday() as usize;
+        for day in 1..=days_in_month {
+            week[weekday] = Some(day);
+            weekday += 1;
+            if weekday == 7 {
+                weeks.push(week);
+                week = [None; 7];
+                weekday = 0;
+            }
+        }
+        if weekday > 0 {
+            weeks.push(week);
+        }
+        weeks
+    }
+
+    fn render_single_month(&self, f: &mut Frame, area: Rect, center_date: NaiveDate, month_offset: i32) {
+        // Calculate the target month from year/month fields so the side months
+        // are always the true previous/next months, whatever the selected day
+        let (target_year, target_month) = Self::offset_month(center_date.year(), center_date.month(), month_offset);
+        
+        let selected = self.selected_day();
+        let (selected_year, selected_month, selected_day) = (selected.year(), selected.month(), selected.day());
+        let today = today();
+        
+        // Only fails outside chrono's date range
+        let Some(first_date) = NaiveDate::from_ymd_opt(target_year, target_month, 1) else {
+            return;
         };
+        let weeks = Self::month_weeks(target_year, target_month);
+
+        // Build calendar
+        let month_name = Self::month_name(target_month);
         
         // Shorter title for 3-month view
         let title = if area.width < 35 {
@@ -161,98 +193,77 @@ impl CalendarWidget {
             ]),
         ];
 
-        // Calculate starting day of week (0 = Monday, 6 = Sunday)
-        let start_weekday = first_day.weekday().num_days_from_monday();
-        
         // Build week rows
-        let mut current_day = 1;
-        let mut current_weekday = start_weekday;
-        
-        while current_day <= days_in_month {
+        for (week_index, week) in weeks.iter().enumerate() {
             let mut week_line = Vec::new();
             
-            for _ in 0..7 {
-                if current_weekday < start_weekday && current_day == 1 {
-                    // Empty day before month starts - match header width (8 chars)
-                    week_line.push(Span::raw("        "));
-                    current_weekday += 1;
-                } else if current_day > days_in_month {
-                    // Empty day after month ends - match header width (8 chars)
+            for day in week {
+                let Some(current_day) = *day else {
+                    // Empty day outside the month - match header width (8 chars)
                     week_line.push(Span::raw("        "));
+                    continue;
+                };
+                // Actual day (always valid: it came from month_weeks)
+                let date = first_date.with_day(current_day).unwrap_or(first_date);
+                
+                let tasks_on_day = self.get_tasks_for_date(date);
+                let task_count = tasks_on_day.len();
+                
+                // Determine task indicators
+                let (indicator, indicator_color) = if task_count == 0 {
+                    ("  ", Color::White)
                 } else {
-                    // Actual day
-                    let date = NaiveDate::from_ymd_opt(target_year, target_month, current_day)
-                        .unwrap()
-                        .and_hms_opt(0, 0, 0)
-                        .unwrap()
-                        .and_utc();
-                    
-                    let tasks_on_day = self.get_tasks_for_date(date);
-                    let task_count = tasks_on_day.len();
-                    
-                    // Determine task indicators
-                    let (indicator, indicator_color) = if task_count == 0 {
-                        ("  ", Color::White)
-                    } else {
-                        let has_overdue = tasks_on_day.iter().any(|t| t.is_overdue());
-                        let has_pending = tasks_on_day.iter().any(|t| t.status == TaskStatus::Pending);
-                        let all_completed = tasks_on_day.iter().all(|t| t.status == TaskStatus::Completed);
-                        
-                        if has_overdue {
-                            ("⚠", Color::Red)
-                        } else if all_completed {
-                            ("✓", Color::Green)
-                        } else if has_pending {
-                            ("•", Color::Yellow)
-                        } else {
-                            ("○", Color::Cyan)
-                        }
-                    };
-                    
-                    // Check if this day is the selected date (must match month/year too)
-                    let is_selected = current_day == selected_day && 
-                                    target_year == selected_year && 
-                                    target_month == selected_month;
-                    
-                    let is_today = {
-                        let today = Utc::now();
-                        today.year() == target_year && 
-                        today.month() == target_month && 
-                        today.day() == current_day
-                    };
-                    
-                    // Format: "   DD   " (8 chars) with optional indicator
-                    // Always make date numbers BOLD for readability
-                    
-                    let mut style = if is_selected {
-                        Style::default().fg(Color::Black).bg(Color::Yellow)
-                    } else if is_today {
-                        Style::default().fg(Color::Cyan)
-                    } else if task_count > 0 {
-                        Style::default().fg(indicator_color)
-                    } else {
-                        Style::default().fg(Color::Gray)
-                    };
-                    
-                    // Always make date numbers bold
-                    style = style.add_modifier(Modifier::BOLD);
+                    let has_overdue = tasks_on_day.iter().any(|t| t.is_overdue());
+                    let has_pending = tasks_on_day.iter().any(|t| t.status == TaskStatus::Pending);
+                    let all_completed = tasks_on_day.iter().all(|t| t.status == TaskStatus::Completed);
                     
-                    // Always use 8-character width to match header
-                    if task_count > 0 && area.width >= 30 {
-                        // Format: "   DD·  " where · is the indicator (8 chars total)
-                        week_line.push(Span::styled(
-                            format!("   {:>2}{}  ", current_day, indicator), 
-                            style
-                        ));
+                    if has_overdue {
+                        ("⚠", Color::Red)
+                    } else if all_completed {
+                        ("✓", Color::Green)
+                    } else if has_pending {
+                        ("•", Color::Yellow)
                     } else {
-                        // Format: "   DD   " (8 chars, centered)
-                        week_line.push(Span::styled(
-                            format!("   {:>2}   ", current_day), 
-                            style
-                        ));
+                        ("○", Color::Cyan)
                     }
-                    
-                    current_day += 1;
+                };
+                
+                // Check if this day is the selected date (must match month/year too)
+                let is_selected = current_day == selected_day && 
+                                target_year == selected_year && 
+                                target_month == selected_month;
+                
+                let is_today = date == today;
+                
+                // Format: "   DD   " (8 chars) with optional indicator
+                // Always make date numbers BOLD for readability
+                
+                let mut style = if is_selected {
+                    Style::default().fg(Color::Black).bg(Color::Yellow)
+                } else if is_today {
+                    Style::default().fg(Color::Cyan)
+                } else if task_count > 0 {
+                    Style::default().fg(indicator_color)
+                } else {
+                    Style::default().fg(Color::Gray)
+                };
+                
+                // Always make date numbers bold
+                style = style.add_modifier(Modifier::BOLD);
+                
+                // Always use 8-character width to match header
+                if task_count > 0 && area.width >= 30 {
+                    // Format: "   DD·  " where · is the indicator (8 chars total)
+                    week_line.push(Span::styled(
+                        format!("   {:>2}{}  ", current_day, indicator), 
+                        style
+                    ));
+                } else {
+                    // Format: "   DD   " (8 chars, centered)
+                    week_line.push(Span::styled(
+                        format!("   {:>2}   ", current_day), 
+                        style
+                    ));
                 }
             }
             
@@ -261,7 +272,7 @@ impl CalendarWidget {
             
             // Add vertical spacing (blank line) between weeks for better readability
             // Don't add after the last week to save space
-            if current_day <= days_in_month {
+            if week_index + 1 < weeks.len() {
                 calendar_text.push(Line::from(""));
             }
         }
@@ -316,9 +327,10 @@ impl CalendarWidget {
     }
 
     fn render_daily_stats(&self, f: &mut Frame, area: Rect) {
-        let tasks_on_day = self.get_tasks_for_date(self.selected_date);
+        let day = self.selected_day();
+        let tasks_on_day = self.get_tasks_for_date(day);
         
-        let date_str = self.selected_date.format("%A, %B %d, %Y").to_string();
+        let date_str = format!("{}, {}", day.format("%A"), self.dates.naive_date(day));
         
         // Calculate stats
         let total_tasks = tasks_on_day.len();
@@ -328,13 +340,13 @@ impl CalendarWidget {
         let overdue = tasks_on_day.iter().filter(|t| t.is_overdue()).count();
         
         let with_due_date = tasks_on_day.iter().filter(|t| {
-            t.due.map_or(false, |due| due.date_naive() == self.selected_date.date_naive())
+            t.due.map_or(false, |due| day_of(&due) == day)
         }).count();
         let completed_on_date = tasks_on_day.iter().filter(|t| {
-            t.end.map_or(false, |end| end.date_naive() == self.selected_date.date_naive())
+            t.end.map_or(false, |end| day_of(&end) == day)
         }).count();
         let created_on_date = tasks_on_day.iter().filter(|t| {
-            t.entry.date_naive() == self.selected_date.date_naive()
+            day_of(&t.entry) == day
         }).count();
         
         let avg_urgency = if !tasks_on_day.is_empty() {
@@ -439,11 +451,7 @@ impl CalendarWidget {
                     None => "",
                 };
                 
-                let description = if task.description.len() > 50 {
-                    format!("{}...", &task.description[..47])
-                } else {
-                    task.description.clone()
-                };
+                let description = truncate_text(&task.description, 50);
                 
                 stats_text.push(Line::from(vec![
                     Span::raw("  "),
@@ -0,0 +1,169 @@
+// Cheat sheet: a popup of the keys for the view on screen, drawn over it. Any key closes it.
+// Built from the same bindings as the help view, so remapped keys show up here too.
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::handlers::input::{Binding, KeyContext};
+use crate::ui::components::help_view::merged_entries;
+use crate::utils::formatting::truncate_text;
+
+// Longer key lists ("1, 2, 3, 4, 5, 6, 7") push their description along instead of
+// widening every row
+const MAX_KEY_WIDTH: usize = 12;
+const MAX_DESCRIPTION_WIDTH: usize = 60;
+// Narrower columns than this read badly, so fewer columns and a pointer to F1 instead
+const MIN_DESCRIPTION_WIDTH: usize = 16;
+
+enum Row {
+    Header(&'static str),
+    Entry(String, &'static str),
+    Blank,
+}
+
+pub struct CheatSheet {
+    context: KeyContext,
+    rows: Vec<Row>,
+}
+
+impl CheatSheet {
+    // The keys for `context` first, then the global ones
+    pub fn new(bindings: &[Binding], context: KeyContext) -> Self {
+        let entries = merged_entries(bindings);
+        let mut rows = Vec::new();
+        let mut sections = vec![context];
+        // A find keeps the task list's keys, besides n/N
+        if context == KeyContext::Find {
+            sections.push(KeyContext::TaskList);
+        }
+        if context != KeyContext::Global {
+            sections.push(KeyContext::Global);
+        }
+        for section in sections {
+            if !rows.is_empty() {
+                rows.push(Row::Blank);
+            }
+            rows.push(Row::Header(section.label()));
+            rows.extend(
+                entries.iter()
+                    .filter(|(c, _, _)| *c == section)
+                    .map(|(_, keys, description)| Row::Entry(keys.clone(), description)),
+            );
+        }
+        CheatSheet { context, rows }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let key_width = self.rows.iter()
+            .filter_map(|row| match row {
+                Row::Entry(keys, _) => Some(keys.chars().count()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+            .min(MAX_KEY_WIDTH);
+        let description_width = self.rows.iter()
+            .filter_map(|row| match row {
+                Row::Entry(_, description) => Some(description.chars().count()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+            .min(MAX_DESCRIPTION_WIDTH);
+
+        // Borders and padding take four columns, borders and the footer four rows;
+        // leave a little of the view showing around the popup
+        let max_inner_width = area.width.saturating_sub(6) as usize;
+        let max_rows = area.height.saturating_sub(6).max(1) as usize;
+        let gap = 3;
+        let min_column = key_width + 2 + MIN_DESCRIPTION_WIDTH;
+        let fitting_columns = ((max_inner_width + gap) / (min_column + gap)).max(1);
+        let columns = self.rows.len().div_ceil(max_rows).clamp(1, fitting_columns);
+        let column_width = ((max_inner_width + gap) / columns - gap).min(key_width + 2 + description_width);
+        let rows_per_column = self.rows.len().div_ceil(columns).min(max_rows);
+        let shown = columns * rows_per_column;
+        let overflow = self.rows.len() > shown;
+
+        let width = (columns * (column_width + gap) - gap + 4) as u16;
+        let height = rows_per_column as u16 + 4;
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width: width.min(area.width),
+            height: height.min(area.height),
+        };
+
+        f.render_widget(Clear, popup_area);
+        let block = Block::default()
+            .title(format!(" Keys · {} ", self.context.label()))
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin { vertical: 1, horizontal: 2 });
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1), Constraint::Length(1)])
+            .split(inner_area);
+
+        let mut constraints = Vec::new();
+        for i in 0..columns {
+            if i > 0 {
+                constraints.push(Constraint::Length(gap as u16));
+            }
+            constraints.push(Constraint::Length(column_width as u16));
+        }
+        let column_areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(chunks[0]);
+
+        let description_room = column_width.saturating_sub(key_width + 2);
+        let mut lines: Vec<Line> = self.rows.iter().take(shown).map(|row| match row {
+            Row::Header(label) => Line::from(Span::styled(
+                *label,
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            )),
+            Row::Entry(keys, description) => {
+                let keys = format!("{:<width$}", keys, width = key_width);
+                let room = description_room.saturating_sub(keys.chars().count() - key_width);
+                Line::from(vec![
+                    Span::styled(keys, Style::default().fg(Color::Yellow)),
+                    Span::raw("  "),
+                    Span::raw(truncate_text(description, room)),
+                ])
+            }
+            Row::Blank => Line::from(""),
+        }).collect();
+        if overflow {
+            if let Some(last) = lines.last_mut() {
+                *last = Line::from(Span::styled("… more with F1", Style::default().fg(Color::DarkGray)));
+            }
+        }
+
+        for (i, column) in lines.chunks(rows_per_column).enumerate() {
+            if let Some(column_area) = column_areas.get(i * 2) {
+                let paragraph = Paragraph::new(column.to_vec())
+                    .style(Style::default().bg(Color::Black).fg(Color::White));
+                f.render_widget(paragraph, *column_area);
+            }
+        }
+
+        let footer = Paragraph::new(Line::from(vec![
+            Span::styled("Any key", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" closes  "),
+            Span::styled("F1", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" full help"),
+        ]))
+        .style(Style::default().bg(Color::Black).fg(Color::White))
+        .alignment(Alignment::Center);
+        f.render_widget(footer, chunks[2]);
+    }
+}
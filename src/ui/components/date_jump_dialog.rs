@@ -0,0 +1,162 @@
+// Prompt for a day to show in the calendar, in the task form's date syntax
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::handlers::input::Action;
+use crate::ui::components::task_form::TaskForm;
+use crate::utils::formatting::{day_of, DateFormat};
+
+pub struct DateJumpDialog {
+    pub input: String,
+    pub cursor: usize,
+    pub error: Option<String>,
+    date_format: DateFormat,
+}
+
+#[derive(Debug)]
+pub enum DateJumpResult {
+    Jump(DateTime<Utc>),
+    Cancel,
+}
+
+impl DateJumpDialog {
+    pub fn new(date_format: DateFormat) -> Self {
+        DateJumpDialog {
+            input: String::new(),
+            cursor: 0,
+            error: None,
+            date_format,
+        }
+    }
+
+    // Past days are fine here, unlike when snoozing
+    pub fn target(&self) -> Result<DateTime<Utc>, String> {
+        let input = self.input.trim();
+        if input.is_empty() {
+            return Err("Enter a date, e.g. friday".to_string());
+        }
+        TaskForm::parse_taskwarrior_date(input).ok_or_else(|| format!("Unknown date \"{}\"", input))
+    }
+
+    pub fn handle_input(&mut self, action: Action) -> Result<Option<DateJumpResult>> {
+        match action {
+            Action::Back => return Ok(Some(DateJumpResult::Cancel)),
+            Action::Select => match self.target() {
+                Ok(date) => return Ok(Some(DateJumpResult::Jump(date))),
+                Err(e) => self.error = Some(e),
+            },
+            Action::MoveLeft => {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            Action::MoveRight if self.cursor < self.input.chars().count() => {
+                self.cursor += 1;
+            }
+            Action::Character(c) => self.insert(c),
+            Action::Space => self.insert(' '),
+            Action::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                let byte_index = self.byte_index(self.cursor);
+                self.input.remove(byte_index);
+                self.error = None;
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn insert(&mut self, c: char) {
+        let byte_index = self.byte_index(self.cursor);
+        self.input.insert(byte_index, c);
+        self.cursor += 1;
+        self.error = None;
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.input
+            .char_indices()
+            .nth(char_index)
+            .map(|(i, _)| i)
+            .unwrap_or(self.input.len())
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let width = area.width.min(50);
+        let height = 8.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("Go to Date")
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Input
+                Constraint::Length(1), // Resulting day / error
+                Constraint::Min(1),    // Instructions
+            ])
+            .split(inner_area);
+
+        let (before, after) = self.input.split_at(self.byte_index(self.cursor));
+        let input = Paragraph::new(Line::from(vec![
+            Span::styled("Go to: ", Style::default().fg(Color::Yellow)),
+            Span::raw(before.to_string()),
+            Span::styled("█", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::raw(after.to_string()),
+        ]))
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)))
+        .style(Style::default().bg(Color::Black).fg(Color::White));
+        f.render_widget(input, chunks[0]);
+
+        // Errors after Enter; otherwise preview the day as it is typed
+        let notice = if let Some(ref error) = self.error {
+            Span::styled(error.clone(), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+        } else if let Ok(date) = self.target() {
+            let day = day_of(&date);
+            Span::styled(
+                format!("{}, {}", day.format("%A"), self.date_format.naive_date(day)),
+                Style::default().fg(Color::Cyan),
+            )
+        } else {
+            Span::styled("e.g. friday, +2w, eom, 2025-03-14", Style::default().fg(Color::DarkGray))
+        };
+        f.render_widget(
+            Paragraph::new(Line::from(notice)).style(Style::default().bg(Color::Black)).alignment(Alignment::Center),
+            chunks[1],
+        );
+
+        let instructions = Paragraph::new(Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" go  "),
+            Span::styled("Esc", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+            Span::raw(" cancel"),
+        ]))
+        .style(Style::default().bg(Color::Black).fg(Color::White))
+        .alignment(Alignment::Center);
+        f.render_widget(instructions, chunks[2]);
+    }
+}
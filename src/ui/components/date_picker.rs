@@ -0,0 +1,117 @@
+// Calendar popup for choosing a due date in the task form
+
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::handlers::input::Action;
+use crate::ui::components::calendar_view::CalendarWidget;
+use crate::utils::formatting::today;
+
+pub struct DatePicker {
+    pub selected: NaiveDate,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DatePickerResult {
+    Pick(NaiveDate),
+    Cancel,
+}
+
+impl DatePicker {
+    pub fn new(selected: NaiveDate) -> Self {
+        DatePicker { selected }
+    }
+
+    pub fn handle_input(&mut self, action: Action) -> Option<DatePickerResult> {
+        let selected = self.selected;
+        self.selected = match action {
+            Action::Back => return Some(DatePickerResult::Cancel),
+            Action::Select => return Some(DatePickerResult::Pick(selected)),
+            Action::MoveLeft => selected - Duration::days(1),
+            Action::MoveRight => selected + Duration::days(1),
+            Action::MoveUp => selected - Duration::weeks(1),
+            Action::MoveDown => selected + Duration::weeks(1),
+            // Same month keys as the Reports calendar; the day is clamped to short months
+            Action::Character('<') => selected.checked_sub_months(Months::new(1)).unwrap_or(selected),
+            Action::Character('>') => selected.checked_add_months(Months::new(1)).unwrap_or(selected),
+            Action::Character('t') => today(),
+            _ => selected,
+        };
+        None
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        // 7 columns of 4 cells plus borders; weekday row, up to 6 weeks and the key hints
+        let width = 30.min(area.width);
+        let height = 10.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let today = today();
+        let (year, month) = (self.selected.year(), self.selected.month());
+
+        let mut lines = vec![Line::from(
+            ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"]
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    let color = if i >= 5 { Color::Cyan } else { Color::Yellow };
+                    Span::styled(format!("  {}", name), Style::default().fg(color))
+                })
+                .collect::<Vec<_>>(),
+        )];
+
+        for week in CalendarWidget::month_weeks(year, month) {
+            let cells: Vec<Span> = week
+                .iter()
+                .map(|day| match day {
+                    None => Span::raw("    "),
+                    Some(day) => {
+                        let style = if *day == self.selected.day() {
+                            Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD)
+                        } else if NaiveDate::from_ymd_opt(year, month, *day) == Some(today) {
+                            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                        } else {
+                            Style::default().fg(Color::White)
+                        };
+                        Span::styled(format!("  {:>2}", day), style)
+                    }
+                })
+                .collect();
+            lines.push(Line::from(cells));
+        }
+
+        lines.push(Line::from(vec![
+            Span::styled("←→↑↓", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::raw(" day "),
+            Span::styled("<>", Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
+            Span::raw(" month "),
+            Span::styled("t", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw("oday"),
+        ]));
+
+        let picker = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(format!("{} {}", CalendarWidget::month_name(month), year))
+                    .title_alignment(Alignment::Center)
+                    .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Green)),
+            )
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(picker, popup_area);
+    }
+}
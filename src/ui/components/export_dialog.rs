@@ -0,0 +1,273 @@
+// Export dialog for writing the filtered task list to a file
+
+use anyhow::Result;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use std::path::PathBuf;
+
+use crate::data::export::ExportFormat;
+use crate::handlers::input::Action;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportField {
+    Filename,
+    Format,
+}
+
+pub struct ExportDialog {
+    pub filename_input: String,
+    pub filename_cursor: usize,
+    pub format: ExportFormat,
+    pub active_field: ExportField,
+    // Set once the user has been warned that the target file already exists
+    pub confirm_overwrite: bool,
+}
+
+#[derive(Debug)]
+pub enum ExportDialogResult {
+    Export(PathBuf, ExportFormat),
+    Cancel,
+}
+
+impl Default for ExportDialog {
+    fn default() -> Self {
+        let filename_input = "lazytask-export.json".to_string();
+        ExportDialog {
+            filename_cursor: filename_input.chars().count(),
+            filename_input,
+            format: ExportFormat::Json,
+            active_field: ExportField::Filename,
+            confirm_overwrite: false,
+        }
+    }
+}
+
+impl ExportDialog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn handle_input(&mut self, action: Action) -> Result<Option<ExportDialogResult>> {
+        match action {
+            Action::Back => return Ok(Some(ExportDialogResult::Cancel)),
+            Action::Select => {
+                let filename = self.filename_input.trim();
+                if filename.is_empty() {
+                    self.active_field = ExportField::Filename;
+                    return Ok(None);
+                }
+
+                let path = PathBuf::from(filename);
+                if path.exists() && !self.confirm_overwrite {
+                    // Require a second Enter before replacing an existing file
+                    self.confirm_overwrite = true;
+                    return Ok(None);
+                }
+                return Ok(Some(ExportDialogResult::Export(path, self.format)));
+            }
+            Action::MoveUp | Action::MoveDown | Action::Tab => {
+                self.active_field = match self.active_field {
+                    ExportField::Filename => ExportField::Format,
+                    ExportField::Format => ExportField::Filename,
+                };
+            }
+            Action::MoveLeft => match self.active_field {
+                ExportField::Filename => {
+                    self.filename_cursor = self.filename_cursor.saturating_sub(1);
+                }
+                ExportField::Format => self.toggle_format(),
+            },
+            Action::MoveRight => match self.active_field {
+                ExportField::Filename => {
+                    if self.filename_cursor < self.filename_input.chars().count() {
+                        self.filename_cursor += 1;
+                    }
+                }
+                ExportField::Format => self.toggle_format(),
+            },
+            Action::Space => match self.active_field {
+                ExportField::Filename => self.insert_char(' '),
+                ExportField::Format => self.toggle_format(),
+            },
+            Action::Character(c) => match self.active_field {
+                ExportField::Filename => self.insert_char(c),
+                ExportField::Format => match c.to_ascii_lowercase() {
+                    'j' => self.set_format(ExportFormat::Json),
+                    't' => self.set_format(ExportFormat::Taskwarrior),
+                    'c' => self.set_format(ExportFormat::Csv),
+                    'm' => self.set_format(ExportFormat::Markdown),
+                    _ => {}
+                },
+            },
+            Action::Backspace if self.active_field == ExportField::Filename && self.filename_cursor > 0 => {
+                self.filename_cursor -= 1;
+                let byte_index = self.byte_index(self.filename_cursor);
+                self.filename_input.remove(byte_index);
+                self.confirm_overwrite = false;
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn insert_char(&mut self, c: char) {
+        let byte_index = self.byte_index(self.filename_cursor);
+        self.filename_input.insert(byte_index, c);
+        self.filename_cursor += 1;
+        self.confirm_overwrite = false;
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.filename_input
+            .char_indices()
+            .nth(char_index)
+            .map(|(i, _)| i)
+            .unwrap_or(self.filename_input.len())
+    }
+
+    fn toggle_format(&mut self) {
+        let next = match self.format {
+            ExportFormat::Json => ExportFormat::Taskwarrior,
+            ExportFormat::Taskwarrior => ExportFormat::Csv,
+            ExportFormat::Csv => ExportFormat::Markdown,
+            ExportFormat::Markdown => ExportFormat::Json,
+        };
+        self.set_format(next);
+    }
+
+    fn set_format(&mut self, format: ExportFormat) {
+        self.format = format;
+
+        // Keep the file extension in sync with the chosen format
+        let extension = Self::extension(format);
+        for known in [".json", ".csv", ".md"] {
+            if let Some(stem) = self.filename_input.strip_suffix(known) {
+                self.filename_input = format!("{}{}", stem, extension);
+                self.filename_cursor = self.filename_input.chars().count();
+                break;
+            }
+        }
+        self.confirm_overwrite = false;
+    }
+
+    fn extension(format: ExportFormat) -> &'static str {
+        match format {
+            ExportFormat::Json | ExportFormat::Taskwarrior => ".json",
+            ExportFormat::Csv => ".csv",
+            ExportFormat::Markdown => ".md",
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let width = area.width.min(60);
+        let height = 11.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("Export Filtered Tasks")
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Filename
+                Constraint::Length(3), // Format
+                Constraint::Min(1),    // Instructions / warning
+            ])
+            .split(inner_area);
+
+        // Filename field with cursor
+        let filename_active = self.active_field == ExportField::Filename;
+        let (before, after) = self.filename_input.split_at(self.byte_index(self.filename_cursor));
+        let mut filename_spans = vec![
+            Span::styled("File: ", Style::default().fg(Color::Yellow)),
+            Span::raw(before.to_string()),
+        ];
+        if filename_active {
+            filename_spans.push(Span::styled("█", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)));
+        }
+        filename_spans.push(Span::raw(after.to_string()));
+        let filename = Paragraph::new(Line::from(filename_spans))
+            .block(Block::default().borders(Borders::ALL).border_style(Self::field_border(filename_active)))
+            .style(Style::default().bg(Color::Black).fg(Color::White));
+        f.render_widget(filename, chunks[0]);
+
+        // Format field
+        let format_active = self.active_field == ExportField::Format;
+        let format_span = |label: &'static str, selected: bool| {
+            if selected {
+                Span::styled(format!("[{}]", label), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+            } else {
+                Span::styled(format!(" {} ", label), Style::default().fg(Color::Gray))
+            }
+        };
+        let format_line = Line::from(vec![
+            Span::styled("Format: ", Style::default().fg(Color::Yellow)),
+            format_span("JSON", self.format == ExportFormat::Json),
+            Span::raw(" "),
+            format_span("Taskwarrior", self.format == ExportFormat::Taskwarrior),
+            Span::raw(" "),
+            format_span("CSV", self.format == ExportFormat::Csv),
+            Span::raw(" "),
+            format_span("Markdown", self.format == ExportFormat::Markdown),
+        ]);
+        let format = Paragraph::new(format_line)
+            .block(Block::default().borders(Borders::ALL).border_style(Self::field_border(format_active)))
+            .style(Style::default().bg(Color::Black).fg(Color::White));
+        f.render_widget(format, chunks[1]);
+
+        let footer = if self.confirm_overwrite {
+            Line::from(vec![
+                Span::styled("File exists! ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::styled("Enter", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::raw(" overwrite  "),
+                Span::styled("Esc", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::raw(" cancel"),
+            ])
+        } else {
+            Line::from(vec![
+                Span::styled("↑↓", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::raw(" field  "),
+                Span::styled("Space", Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
+                Span::raw(" format  "),
+                Span::styled("Enter", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::raw(" export  "),
+                Span::styled("Esc", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::raw(" cancel"),
+            ])
+        };
+        let instructions = Paragraph::new(footer)
+            .style(Style::default().bg(Color::Black).fg(Color::White))
+            .alignment(Alignment::Center);
+        f.render_widget(instructions, chunks[2]);
+    }
+
+    fn field_border(is_active: bool) -> Style {
+        if is_active {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::Gray)
+        }
+    }
+}
@@ -0,0 +1,139 @@
+// Scrollable keybinding reference, built from the input handler's bindings
+
+use ratatui::{
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+
+use crate::handlers::input::{Binding, KeyContext};
+
+pub struct HelpView {
+    // (context, keys, description); keys sharing a description are merged
+    entries: Vec<(KeyContext, String, &'static str)>,
+    scroll: usize,
+    visible_rows: usize,
+}
+
+// (context, keys, description) per binding, with keys that share a context and description
+// merged into one entry ("↑, k"). The cheat sheet reads the same entries.
+pub fn merged_entries(bindings: &[Binding]) -> Vec<(KeyContext, String, &'static str)> {
+    let mut entries: Vec<(KeyContext, String, &'static str)> = Vec::new();
+    for binding in bindings {
+        let existing = entries.iter_mut()
+            .find(|(context, _, description)| *context == binding.context && *description == binding.description);
+        match existing {
+            Some((_, keys, _)) => {
+                keys.push_str(", ");
+                keys.push_str(&binding.key_label());
+            }
+            None => entries.push((binding.context, binding.key_label(), binding.description)),
+        }
+    }
+    entries
+}
+
+impl HelpView {
+    pub fn new(bindings: &[Binding]) -> Self {
+        HelpView {
+            entries: merged_entries(bindings),
+            scroll: 0,
+            visible_rows: 0,
+        }
+    }
+
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        let key_width = self.entries.iter().map(|(_, keys, _)| keys.chars().count()).max().unwrap_or(0);
+        let mut lines = Vec::new();
+
+        for context in KeyContext::ALL {
+            let entries: Vec<_> = self.entries.iter().filter(|(c, _, _)| *c == context).collect();
+            if entries.is_empty() {
+                continue;
+            }
+            if !lines.is_empty() {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(Span::styled(
+                context.label(),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            )));
+            for (_, keys, description) in entries {
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(format!("{:<width$}", keys, width = key_width), Style::default().fg(Color::Yellow)),
+                    Span::raw("  "),
+                    Span::raw(*description),
+                ]));
+            }
+        }
+
+        lines
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = (self.scroll + 1).min(self.max_scroll());
+    }
+
+    pub fn page_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(self.visible_rows.max(1));
+    }
+
+    pub fn page_down(&mut self) {
+        self.scroll = (self.scroll + self.visible_rows.max(1)).min(self.max_scroll());
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.scroll = 0;
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = self.max_scroll();
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.lines().len().saturating_sub(self.visible_rows.max(1))
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let lines = self.lines();
+        let line_count = lines.len();
+        // Borders take two rows; remember the height so paging matches what is on screen
+        self.visible_rows = area.height.saturating_sub(2) as usize;
+        self.scroll = self.scroll.min(self.max_scroll());
+
+        let title = if line_count > self.visible_rows {
+            format!(" Help · ↑↓ scroll ({}–{} of {}) ", self.scroll + 1, (self.scroll + self.visible_rows).min(line_count), line_count)
+        } else {
+            " Help ".to_string()
+        };
+
+        let help = Paragraph::new(lines)
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .scroll((self.scroll as u16, 0));
+        f.render_widget(help, area);
+
+        if line_count > self.visible_rows {
+            let mut scrollbar_state = ScrollbarState::new(self.max_scroll() + 1)
+                .viewport_content_length(self.visible_rows)
+                .position(self.scroll);
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .track_symbol(Some("│"))
+                .thumb_style(Style::default().fg(Color::Cyan))
+                .track_style(Style::default().fg(Color::DarkGray));
+            f.render_stateful_widget(
+                scrollbar,
+                area.inner(Margin { vertical: 1, horizontal: 0 }),
+                &mut scrollbar_state,
+            );
+        }
+    }
+}
@@ -0,0 +1,116 @@
+// Picker for a task's URLs when it has more than one to open
+
+use anyhow::Result;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::handlers::input::Action;
+
+pub struct LinkPicker {
+    pub urls: Vec<String>,
+    selected: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LinkPickerResult {
+    Open(String),
+    Cancel,
+}
+
+impl LinkPicker {
+    pub fn new(urls: Vec<String>) -> Self {
+        LinkPicker { urls, selected: 0 }
+    }
+
+    pub fn handle_input(&mut self, action: Action) -> Result<Option<LinkPickerResult>> {
+        Ok(match action {
+            Action::Back => Some(LinkPickerResult::Cancel),
+            Action::MoveUp => {
+                self.selected = self.selected.saturating_sub(1);
+                None
+            }
+            Action::MoveDown => {
+                self.selected = (self.selected + 1).min(self.urls.len().saturating_sub(1));
+                None
+            }
+            // 1-9 open a URL directly
+            Action::Character(c) => c
+                .to_digit(10)
+                .and_then(|n| self.urls.get((n as usize).checked_sub(1)?))
+                .map(|url| LinkPickerResult::Open(url.clone())),
+            Action::Select => self.urls.get(self.selected).map(|url| LinkPickerResult::Open(url.clone())),
+            _ => None,
+        })
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let longest = self.urls.iter().map(|url| url.chars().count()).max().unwrap_or(0) as u16;
+        let width = (longest + 12).max(40).min(area.width);
+        let height = (self.urls.len() as u16 + 6).min(20).min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("Open Link")
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(3),    // URLs
+                Constraint::Length(1), // Instructions
+            ])
+            .split(inner_area);
+
+        let items: Vec<ListItem> = self
+            .urls
+            .iter()
+            .enumerate()
+            .map(|(i, url)| {
+                let number = if i < 9 { format!("{} ", i + 1) } else { "  ".to_string() };
+                ListItem::new(Line::from(vec![
+                    Span::styled(number, Style::default().fg(Color::Green)),
+                    Span::styled(url.clone(), Style::default().fg(Color::Cyan)),
+                ]))
+            })
+            .collect();
+        let mut state = ListState::default();
+        state.select(Some(self.selected));
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)))
+            .style(Style::default().bg(Color::Black).fg(Color::White))
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+        f.render_stateful_widget(list, chunks[0], &mut state);
+
+        let key = |k: &'static str| Span::styled(k, Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
+        let instructions = Paragraph::new(Line::from(vec![
+            key("Enter"),
+            Span::raw(" open  "),
+            key("Esc"),
+            Span::raw(" close"),
+        ]))
+        .style(Style::default().bg(Color::Black).fg(Color::White))
+        .alignment(Alignment::Center);
+        f.render_widget(instructions, chunks[1]);
+    }
+}
@@ -1,8 +1,21 @@
 pub mod task_list;
 pub mod task_detail;
 pub mod task_form;
-pub mod filter_bar;
 pub mod status_bar;
 pub mod calendar_view;
 pub mod report_panel;
 pub mod modal_dialog;
+pub mod export_dialog;
+pub mod rename_dialog;
+pub mod help_view;
+pub mod quick_add_dialog;
+pub mod date_picker;
+pub mod annotation_dialog;
+pub mod snooze_dialog;
+pub mod report_menu;
+pub mod link_picker;
+pub mod save_conflict_dialog;
+pub mod date_jump_dialog;
+pub mod cheat_sheet;
+pub mod batch_tag_dialog;
+pub mod startup_briefing;
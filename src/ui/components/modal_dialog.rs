@@ -1,22 +1,98 @@
-// Modal dialog component
+// Yes/no confirmation dialog
 
+use anyhow::Result;
 use ratatui::{
-    layout::Rect,
-    widgets::{Block, Borders, Clear, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 
-pub struct ModalDialogWidget;
+use crate::handlers::input::Action;
 
-impl ModalDialogWidget {
-    pub fn new() -> Self {
-        ModalDialogWidget
+pub struct ConfirmDialog {
+    pub title: String,
+    pub message: String,
+    // Footer labels for the two answers, e.g. "quit" / "keep editing"
+    pub confirm_label: String,
+    pub cancel_label: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ConfirmResult {
+    Confirm,
+    Cancel,
+}
+
+impl ConfirmDialog {
+    pub fn new(title: &str, message: &str, confirm_label: &str, cancel_label: &str) -> Self {
+        ConfirmDialog {
+            title: title.to_string(),
+            message: message.to_string(),
+            confirm_label: confirm_label.to_string(),
+            cancel_label: cancel_label.to_string(),
+        }
+    }
+
+    // Only an explicit `y` confirms; Enter and Esc take the safe answer
+    pub fn handle_input(&mut self, action: Action) -> Result<Option<ConfirmResult>> {
+        Ok(match action {
+            Action::Character('y') | Action::Character('Y') => Some(ConfirmResult::Confirm),
+            Action::Character('n') | Action::Character('N') | Action::Back | Action::Select => {
+                Some(ConfirmResult::Cancel)
+            }
+            _ => None,
+        })
     }
 
     pub fn render(&self, f: &mut Frame, area: Rect) {
-        let dialog = Paragraph::new("Modal Dialog - Coming Soon")
-            .block(Block::default().title("Dialog").borders(Borders::ALL));
-        f.render_widget(Clear, area);
-        f.render_widget(dialog, area);
+        let width = area.width.min(56);
+        let height = 7.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(self.title.as_str())
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),    // Message
+                Constraint::Length(1), // Answers
+            ])
+            .split(inner_area);
+
+        let message = Paragraph::new(self.message.as_str())
+            .style(Style::default().bg(Color::Black).fg(Color::White))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        f.render_widget(message, chunks[0]);
+
+        let answers = Paragraph::new(Line::from(vec![
+            Span::styled("y", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" {}  ", self.confirm_label)),
+            Span::styled("n/Esc", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" {}", self.cancel_label)),
+        ]))
+        .style(Style::default().bg(Color::Black).fg(Color::White))
+        .alignment(Alignment::Center);
+        f.render_widget(answers, chunks[1]);
     }
 }
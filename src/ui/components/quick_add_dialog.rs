@@ -0,0 +1,139 @@
+// One-line "task add" prompt for users who know Taskwarrior's syntax
+
+use anyhow::Result;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::data::quick_add::QuickAdd;
+use crate::handlers::input::Action;
+
+#[derive(Default)]
+pub struct QuickAddDialog {
+    pub input: String,
+    pub cursor: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum QuickAddResult {
+    Add(QuickAdd),
+    Cancel,
+}
+
+impl QuickAddDialog {
+    pub fn handle_input(&mut self, action: Action) -> Result<Option<QuickAddResult>> {
+        match action {
+            Action::Back => return Ok(Some(QuickAddResult::Cancel)),
+            Action::Select => match QuickAdd::parse(&self.input) {
+                Ok(quick_add) => return Ok(Some(QuickAddResult::Add(quick_add))),
+                Err(e) => self.error = Some(e.to_string()),
+            },
+            Action::MoveLeft => {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            Action::MoveRight if self.cursor < self.input.chars().count() => {
+                self.cursor += 1;
+            }
+            Action::Character(c) => self.insert(c),
+            Action::Space => self.insert(' '),
+            Action::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                let byte_index = self.byte_index(self.cursor);
+                self.input.remove(byte_index);
+                self.error = None;
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn insert(&mut self, c: char) {
+        let byte_index = self.byte_index(self.cursor);
+        self.input.insert(byte_index, c);
+        self.cursor += 1;
+        self.error = None;
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.input
+            .char_indices()
+            .nth(char_index)
+            .map(|(i, _)| i)
+            .unwrap_or(self.input.len())
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let width = area.width.min(80);
+        let height = 9.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("Quick Add")
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // Example
+                Constraint::Length(3), // Input
+                Constraint::Min(1),    // Instructions / error
+            ])
+            .split(inner_area);
+
+        let example = Paragraph::new(Line::from(Span::styled(
+            "Buy milk project:home +errand due:tomorrow priority:H",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .style(Style::default().bg(Color::Black));
+        f.render_widget(example, chunks[0]);
+
+        // Input line with cursor
+        let (before, after) = self.input.split_at(self.byte_index(self.cursor));
+        let input_line = Line::from(vec![
+            Span::styled("task add ", Style::default().fg(Color::Yellow)),
+            Span::raw(before.to_string()),
+            Span::styled("█", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::raw(after.to_string()),
+        ]);
+        let input = Paragraph::new(input_line)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)))
+            .style(Style::default().bg(Color::Black).fg(Color::White));
+        f.render_widget(input, chunks[1]);
+
+        let footer = if let Some(ref error) = self.error {
+            Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)))
+        } else {
+            Line::from(vec![
+                Span::styled("Enter", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::raw(" add  "),
+                Span::styled("Esc", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::raw(" cancel"),
+            ])
+        };
+        let instructions = Paragraph::new(footer)
+            .style(Style::default().bg(Color::Black).fg(Color::White))
+            .alignment(Alignment::Center);
+        f.render_widget(instructions, chunks[2]);
+    }
+}
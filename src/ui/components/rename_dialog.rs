@@ -0,0 +1,158 @@
+// Prompt for renaming a project across all of its tasks
+
+use anyhow::Result;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::handlers::input::Action;
+
+pub struct RenameProjectDialog {
+    pub project: String,
+    pub task_count: usize,
+    pub name_input: String,
+    pub name_cursor: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum RenameDialogResult {
+    Rename { from: String, to: String },
+    Cancel,
+}
+
+impl RenameProjectDialog {
+    pub fn new(project: String, task_count: usize) -> Self {
+        RenameProjectDialog {
+            name_cursor: project.chars().count(),
+            name_input: project.clone(),
+            project,
+            task_count,
+            error: None,
+        }
+    }
+
+    pub fn handle_input(&mut self, action: Action) -> Result<Option<RenameDialogResult>> {
+        match action {
+            Action::Back => return Ok(Some(RenameDialogResult::Cancel)),
+            Action::Select => {
+                let name = self.name_input.trim();
+                if name.is_empty() {
+                    self.error = Some("Project name can't be empty".to_string());
+                } else if name.chars().any(char::is_whitespace) {
+                    self.error = Some("Project names can't contain spaces".to_string());
+                } else if name == self.project {
+                    return Ok(Some(RenameDialogResult::Cancel));
+                } else {
+                    return Ok(Some(RenameDialogResult::Rename {
+                        from: self.project.clone(),
+                        to: name.to_string(),
+                    }));
+                }
+            }
+            Action::MoveLeft => {
+                self.name_cursor = self.name_cursor.saturating_sub(1);
+            }
+            Action::MoveRight if self.name_cursor < self.name_input.chars().count() => {
+                self.name_cursor += 1;
+            }
+            Action::Character(c) => {
+                let byte_index = self.byte_index(self.name_cursor);
+                self.name_input.insert(byte_index, c);
+                self.name_cursor += 1;
+                self.error = None;
+            }
+            Action::Backspace if self.name_cursor > 0 => {
+                self.name_cursor -= 1;
+                let byte_index = self.byte_index(self.name_cursor);
+                self.name_input.remove(byte_index);
+                self.error = None;
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.name_input
+            .char_indices()
+            .nth(char_index)
+            .map(|(i, _)| i)
+            .unwrap_or(self.name_input.len())
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let width = area.width.min(60);
+        let height = 10.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("Rename Project")
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // Summary
+                Constraint::Length(3), // New name
+                Constraint::Min(1),    // Instructions / error
+            ])
+            .split(inner_area);
+
+        let task_word = if self.task_count == 1 { "task" } else { "tasks" };
+        let summary = Paragraph::new(Line::from(vec![
+            Span::styled(self.project.clone(), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" ({} {})", self.task_count, task_word)),
+        ]))
+        .style(Style::default().bg(Color::Black).fg(Color::White));
+        f.render_widget(summary, chunks[0]);
+
+        // Name field with cursor
+        let (before, after) = self.name_input.split_at(self.byte_index(self.name_cursor));
+        let name_line = Line::from(vec![
+            Span::styled("New name: ", Style::default().fg(Color::Yellow)),
+            Span::raw(before.to_string()),
+            Span::styled("█", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::raw(after.to_string()),
+        ]);
+        let name = Paragraph::new(name_line)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)))
+            .style(Style::default().bg(Color::Black).fg(Color::White));
+        f.render_widget(name, chunks[1]);
+
+        let footer = if let Some(ref error) = self.error {
+            Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)))
+        } else {
+            Line::from(vec![
+                Span::styled("Enter", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::raw(" rename  "),
+                Span::styled("Esc", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::raw(" cancel"),
+            ])
+        };
+        let instructions = Paragraph::new(footer)
+            .style(Style::default().bg(Color::Black).fg(Color::White))
+            .alignment(Alignment::Center);
+        f.render_widget(instructions, chunks[2]);
+    }
+}
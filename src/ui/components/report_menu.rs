@@ -0,0 +1,127 @@
+// Menu of Taskwarrior reports to show the task list as
+
+use anyhow::Result;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::handlers::input::Action;
+
+// First row of the menu; picking it goes back to LazyTask's own columns and filters
+const NO_REPORT: &str = "(none) — LazyTask's own columns";
+
+pub struct ReportMenu {
+    pub reports: Vec<String>,
+    // Report the list is showing now, marked in the menu
+    pub active: Option<String>,
+    selected: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReportMenuResult {
+    Apply(String),
+    Clear,
+    Cancel,
+}
+
+impl ReportMenu {
+    pub fn new(reports: Vec<String>, active: Option<&str>) -> Self {
+        // Start on the active report, so Enter keeps things as they are
+        let selected = active
+            .and_then(|name| reports.iter().position(|report| report == name))
+            .map_or(0, |index| index + 1);
+        ReportMenu {
+            reports,
+            active: active.map(str::to_string),
+            selected,
+        }
+    }
+
+    pub fn handle_input(&mut self, action: Action) -> Result<Option<ReportMenuResult>> {
+        Ok(match action {
+            Action::Back => Some(ReportMenuResult::Cancel),
+            Action::MoveUp => {
+                self.selected = self.selected.saturating_sub(1);
+                None
+            }
+            Action::MoveDown => {
+                self.selected = (self.selected + 1).min(self.reports.len());
+                None
+            }
+            Action::Select => Some(match self.selected {
+                0 => ReportMenuResult::Clear,
+                index => ReportMenuResult::Apply(self.reports[index - 1].clone()),
+            }),
+            _ => None,
+        })
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let width = area.width.min(50);
+        let height = (self.reports.len() as u16 + 7).min(20).min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("Taskwarrior Reports")
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(3),    // Reports
+                Constraint::Length(1), // Instructions
+            ])
+            .split(inner_area);
+
+        let items: Vec<ListItem> = std::iter::once(ListItem::new(Span::styled(
+            NO_REPORT,
+            Style::default().fg(Color::Gray),
+        )))
+        .chain(self.reports.iter().map(|report| {
+            let marker = if self.active.as_ref() == Some(report) { "● " } else { "  " };
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, Style::default().fg(Color::Green)),
+                Span::raw(report.clone()),
+            ]))
+        }))
+        .collect();
+        let mut state = ListState::default();
+        state.select(Some(self.selected));
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)))
+            .style(Style::default().bg(Color::Black).fg(Color::White))
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+        f.render_stateful_widget(list, chunks[0], &mut state);
+
+        let key = |k: &'static str| Span::styled(k, Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
+        let instructions = Paragraph::new(Line::from(vec![
+            key("Enter"),
+            Span::raw(" show  "),
+            key("Esc"),
+            Span::raw(" close"),
+        ]))
+        .style(Style::default().bg(Color::Black).fg(Color::White))
+        .alignment(Alignment::Center);
+        f.render_widget(instructions, chunks[1]);
+    }
+}
@@ -4,32 +4,22 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Row, Table, Cell},
+    widgets::{Block, Borders, Paragraph, Row, Sparkline, Table, TableState, Cell},
     Frame,
 };
-use std::collections::HashMap;
-use chrono::Utc;
+use chrono::{Duration, Utc};
 
-use crate::data::models::{Priority, Task, TaskStatus};
-
-#[derive(Debug, Clone)]
-pub struct ProjectStats {
-    pub pending: usize,
-    pub completed: usize,
-    pub deleted: usize,
-    pub total: usize,
-}
+use crate::data::models::{Priority, Project, Tag, Task, TaskStatus, NO_PROJECT};
+use crate::utils::formatting::{
+    day_of, format_hours_minutes, format_relative_duration, start_of_day, today, truncate_text,
+};
 
-impl ProjectStats {
-    pub fn completion_rate(&self) -> f32 {
-        let active_total = self.pending + self.completed; // Don't count deleted in completion
-        if active_total > 0 {
-            self.completed as f32 / active_total as f32 * 100.0
-        } else {
-            0.0
-        }
-    }
-}
+// Days of history shown in the dashboard velocity sparkline
+const VELOCITY_DAYS: usize = 14;
+// Days of start/end time in the dashboard's time panel
+const TRACKED_DAYS: usize = 7;
+// The tag table in the wide layout: a tag name plus its two counts
+const TAG_TABLE_WIDTH: u16 = 32;
 
 #[derive(Debug, Clone)]
 pub struct TaskSummaryCache {
@@ -47,25 +37,123 @@ pub struct TaskSummaryCache {
     pub avg_urgency: f64,
     pub recent_tasks: usize,
     pub completed_this_week: usize,
+    // Completions per day, oldest first; the last entry is today
+    pub daily_completions: Vec<u64>,
+    // Time worked per day from start/end timestamps (see Task::tracked_interval), oldest first
+    pub daily_time: Vec<Duration>,
     pub version: u64,
 }
 
+impl TaskSummaryCache {
+    // Counts behind the dashboard, also printed by `lazytask stats`
+    pub fn from_tasks(tasks: &[Task], version: u64) -> Self {
+        let total = tasks.len();
+        let pending = tasks.iter().filter(|t| t.status == TaskStatus::Pending).count();
+        let completed = tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+        let deleted = tasks.iter().filter(|t| t.status == TaskStatus::Deleted).count();
+        let waiting = tasks.iter().filter(|t| t.status == TaskStatus::Waiting).count();
+        let active = tasks.iter().filter(|t| t.is_active()).count();
+        let overdue = tasks.iter().filter(|t| t.is_overdue()).count();
+
+        let high_priority = tasks.iter().filter(|t| t.priority == Some(Priority::High)).count();
+        let medium_priority = tasks.iter().filter(|t| t.priority == Some(Priority::Medium)).count();
+        let low_priority = tasks.iter().filter(|t| t.priority == Some(Priority::Low)).count();
+        let no_priority = tasks.iter().filter(|t| t.priority.is_none()).count();
+
+        let avg_urgency = if !tasks.is_empty() {
+            tasks.iter().map(|t| t.urgency).sum::<f64>() / tasks.len() as f64
+        } else {
+            0.0
+        };
+
+        // Calculate recent activity
+        let now = Utc::now();
+        let week_ago = now - Duration::days(7);
+
+        let recent_tasks = tasks.iter()
+            .filter(|t| t.entry > week_ago)
+            .count();
+
+        let completed_this_week = tasks.iter()
+            .filter(|t| t.completed_since(week_ago))
+            .count();
+
+        // Completions per calendar day for the velocity sparkline, oldest first
+        let today = day_of(&now);
+        let mut daily_completions = vec![0u64; VELOCITY_DAYS];
+        for task in tasks.iter().filter(|t| t.status == TaskStatus::Completed) {
+            if let Some(end) = task.end {
+                let days_ago = (today - day_of(&end)).num_days();
+                if days_ago >= 0 && (days_ago as usize) < VELOCITY_DAYS {
+                    daily_completions[VELOCITY_DAYS - 1 - days_ago as usize] += 1;
+                }
+            }
+        }
+
+        // A stretch that runs past midnight counts towards both days
+        let mut daily_time = vec![Duration::zero(); TRACKED_DAYS];
+        for (start, end) in tasks.iter().filter_map(|t| t.tracked_interval(now)) {
+            for (index, time) in daily_time.iter_mut().enumerate() {
+                let day = today - Duration::days((TRACKED_DAYS - 1 - index) as i64);
+                let from = start.max(start_of_day(day));
+                let to = end.min(start_of_day(day + Duration::days(1)));
+                if to > from {
+                    *time += to - from;
+                }
+            }
+        }
+
+        TaskSummaryCache {
+            total,
+            pending,
+            completed,
+            deleted,
+            waiting,
+            active,
+            overdue,
+            high_priority,
+            medium_priority,
+            low_priority,
+            no_priority,
+            avg_urgency,
+            recent_tasks,
+            completed_this_week,
+            daily_completions,
+            daily_time,
+            version,
+        }
+    }
+}
+
 pub struct DashboardWidget {
     tasks: Vec<Task>,
-    project_stats: HashMap<String, ProjectStats>,
+    project_stats: Vec<Project>,
     task_summary_cache: Option<TaskSummaryCache>,
+    tag_stats: Vec<Tag>,
+    // Whether Up/Down move the tag table rather than the project table
+    tags_focused: bool,
 }
 
 impl DashboardWidget {
-    pub fn new(tasks: Vec<Task>, project_stats: HashMap<String, ProjectStats>, task_summary_cache: Option<TaskSummaryCache>) -> Self {
+    pub fn new(tasks: Vec<Task>, project_stats: Vec<Project>, task_summary_cache: Option<TaskSummaryCache>) -> Self {
         DashboardWidget {
             tasks,
             project_stats,
             task_summary_cache,
+            tag_stats: Vec::new(),
+            tags_focused: false,
         }
     }
 
-    pub fn render(&self, f: &mut Frame, area: Rect) {
+    pub fn with_tags(mut self, tag_stats: Vec<Tag>, focused: bool) -> Self {
+        self.tag_stats = tag_stats;
+        self.tags_focused = focused;
+        self
+    }
+
+    // `projects` and `tags` select and scroll the project and tag tables, whose rows are
+    // in `project_stats` / `tag_stats` order. Returns how many rows of each fit.
+    pub fn render(&self, f: &mut Frame, area: Rect, projects: &mut TableState, tags: &mut TableState) -> (usize, usize) {
         // Responsive reports layout based on terminal size
         let terminal_width = area.width;
         
@@ -74,15 +162,22 @@ impl DashboardWidget {
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
-                    Constraint::Percentage(25),   // Summary - 25%
-                    Constraint::Percentage(40),   // Project table - 40%
-                    Constraint::Percentage(35),   // Activity - 35%
+                    Constraint::Percentage(20),   // Summary - 20%
+                    Constraint::Percentage(12),   // Velocity - 12%
+                    Constraint::Percentage(12),   // Time tracked - 12%
+                    Constraint::Percentage(26),   // Project table - 26%
+                    Constraint::Percentage(14),   // Tag table - 14%
+                    Constraint::Percentage(16),   // Activity - 16%
                 ])
                 .split(area);
             
             self.render_enhanced_summary_panel(f, chunks[0]);
-            self.render_enhanced_project_table(f, chunks[1]);
-            self.render_recent_activity_panel(f, chunks[2]);
+            self.render_velocity_panel(f, chunks[1]);
+            self.render_time_panel(f, chunks[2]);
+            self.render_enhanced_project_table(f, chunks[3], projects);
+            self.render_tag_table(f, chunks[4], tags);
+            self.render_recent_activity_panel(f, chunks[5]);
+            (Self::project_rows(chunks[3]), Self::project_rows(chunks[4]))
         } else {
             // Wide screen - full layout
             let chunks = Layout::default()
@@ -93,31 +188,55 @@ impl DashboardWidget {
                 ])
                 .split(area);
 
-            // Top row: Summary (left) + Burndown (right)
+            // Top row: Summary (left) + Velocity (middle) + Burndown (right)
             let top_chunks = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([
-                    Constraint::Percentage(50),
-                    Constraint::Percentage(50),
+                    Constraint::Percentage(30),
+                    Constraint::Percentage(30),
+                    Constraint::Percentage(40),
                 ])
                 .split(chunks[0]);
 
-            // Bottom row: By Project (left) + Recent Activity (right)
+            // Bottom row: By Project (left) + By Tag (middle) + Time tracked over Recent Activity (right)
             let bottom_chunks = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([
-                    Constraint::Percentage(50),
-                    Constraint::Percentage(50),
+                    Constraint::Percentage(45),
+                    Constraint::Length(TAG_TABLE_WIDTH),
+                    Constraint::Min(0),
                 ])
                 .split(chunks[1]);
 
             self.render_enhanced_summary_panel(f, top_chunks[0]);
-            self.render_burndown_panel(f, top_chunks[1]);
-            self.render_enhanced_project_table(f, bottom_chunks[0]);
-            self.render_recent_activity_panel(f, bottom_chunks[1]);
+            self.render_velocity_panel(f, top_chunks[1]);
+            self.render_burndown_panel(f, top_chunks[2]);
+            self.render_enhanced_project_table(f, bottom_chunks[0], projects);
+            self.render_tag_table(f, bottom_chunks[1], tags);
+            let right_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(50), Constraint::Min(0)])
+                .split(bottom_chunks[2]);
+            self.render_time_panel(f, right_chunks[0]);
+            self.render_recent_activity_panel(f, right_chunks[1]);
+            (Self::project_rows(bottom_chunks[0]), Self::project_rows(bottom_chunks[1]))
         }
     }
 
+    // Only the table Up/Down move shows its selection
+    fn highlight(focused: bool) -> Style {
+        if focused {
+            Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        }
+    }
+
+    // Borders and the header row, for the project and tag tables alike
+    fn project_rows(area: Rect) -> usize {
+        area.height.saturating_sub(3) as usize
+    }
+
     fn render_enhanced_summary_panel(&self, f: &mut Frame, area: Rect) {
         let cache = match &self.task_summary_cache {
             Some(cache) => cache,
@@ -178,59 +297,219 @@ impl DashboardWidget {
         f.render_widget(summary, area);
     }
 
-    fn render_burndown_panel(&self, f: &mut Frame, area: Rect) {
+    fn render_velocity_panel(&self, f: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title("Velocity (7 days)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let cache = match &self.task_summary_cache {
+            Some(cache) => cache,
+            None => {
+                f.render_widget(Paragraph::new("Loading...").block(block), area);
+                return;
+            }
+        };
+
+        // Net change in the backlog: positive means it is growing
+        let net = cache.recent_tasks as i64 - cache.completed_this_week as i64;
+        let (trend, trend_color) = if net > 0 {
+            ("growing", Color::Red)
+        } else if net < 0 {
+            ("shrinking", Color::Green)
+        } else {
+            ("steady", Color::Yellow)
+        };
+
+        let inner_area = block.inner(area);
+        f.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(4), // Counts and trend
+                Constraint::Min(1),    // Sparkline
+            ])
+            .split(inner_area);
+
+        let velocity_text = vec![
+            Line::from(vec![
+                Span::styled("Completed: ", Style::default().fg(Color::Green)),
+                Span::raw(format!("{}", cache.completed_this_week)),
+            ]),
+            Line::from(vec![
+                Span::styled("Added:     ", Style::default().fg(Color::Yellow)),
+                Span::raw(format!("{}", cache.recent_tasks)),
+            ]),
+            Line::from(vec![
+                Span::styled("Net:       ", Style::default().fg(Color::Cyan)),
+                Span::styled(format!("{:+} ({})", net, trend), Style::default().fg(trend_color).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(Span::styled(
+                format!("Completions, last {} days:", cache.daily_completions.len()),
+                Style::default().fg(Color::Gray),
+            )),
+        ];
+        f.render_widget(Paragraph::new(velocity_text), chunks[0]);
+
+        let sparkline = Sparkline::default()
+            .data(&cache.daily_completions)
+            .style(Style::default().fg(Color::Green));
+        f.render_widget(sparkline, chunks[1]);
+    }
+
+    // Time worked today and this week, then the running tasks and a bar per day
+    fn render_time_panel(&self, f: &mut Frame, area: Rect) {
+        const BAR_WIDTH: usize = 12;
+
+        let block = Block::default()
+            .title("Time Tracked (from start/end)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let cache = match &self.task_summary_cache {
+            Some(cache) => cache,
+            None => {
+                f.render_widget(Paragraph::new("Loading...").block(block), area);
+                return;
+            }
+        };
+
+        let today_time = cache.daily_time.last().copied().unwrap_or_default();
+        let week_time = cache.daily_time.iter().fold(Duration::zero(), |total, time| total + *time);
+        let mut lines = vec![Line::from(vec![
+            Span::styled("Today: ", Style::default().fg(Color::Cyan)),
+            Span::styled(format_hours_minutes(today_time), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("   Last {} days: ", cache.daily_time.len()), Style::default().fg(Color::Cyan)),
+            Span::styled(format_hours_minutes(week_time), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+        ])];
+
+        // Longest running first
         let now = Utc::now();
-        let mut daily_counts = vec![0; 30];
-        
+        let mut active: Vec<&Task> = self.tasks.iter().filter(|t| t.is_active()).collect();
+        active.sort_by_key(|t| t.start);
+        for task in active {
+            let running = task.start.map(|start| now - start).unwrap_or_default();
+            lines.push(Line::from(vec![
+                Span::styled(format!("▶ {:>7}  ", format_hours_minutes(running)), Style::default().fg(Color::Green)),
+                Span::raw(truncate_text(&task.description, 40)),
+            ]));
+        }
+
+        if week_time.is_zero() {
+            lines.push(Line::from(Span::styled(
+                "Nothing started in this time; `task <id> start` begins the clock",
+                Style::default().fg(Color::Gray),
+            )));
+        } else {
+            let minutes = |time: &Duration| time.num_minutes().max(0) as usize;
+            let longest = cache.daily_time.iter().map(minutes).max().unwrap_or(0).max(1);
+            let today = today();
+            for (days_ago, time) in cache.daily_time.iter().rev().enumerate() {
+                let day = today - Duration::days(days_ago as i64);
+                let filled = (minutes(time) * BAR_WIDTH).div_ceil(longest);
+                lines.push(Line::from(vec![
+                    Span::styled(day.format("%a %m-%d").to_string(), Style::default().fg(Color::Gray)),
+                    Span::raw(format!(" {:>7} ", format_hours_minutes(*time))),
+                    Span::styled("█".repeat(filled), Style::default().fg(Color::Blue)),
+                ]));
+            }
+        }
+
+        f.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn render_burndown_panel(&self, f: &mut Frame, area: Rect) {
+        const WINDOW_DAYS: usize = 30;
+        const LABEL_WIDTH: usize = 6; // "  12 ┤"
+
+        // Show as much of the 30-day window as fits, always ending today
+        let inner_width = area.width.saturating_sub(2) as usize;
+        let days = inner_width.saturating_sub(LABEL_WIDTH + 1).clamp(1, WINDOW_DAYS);
+        // Rows left after the x-axis, date labels and legend
+        let levels = (area.height.saturating_sub(2) as usize).saturating_sub(3).clamp(1, 8);
+
+        // Completions per calendar day, oldest first; the last entry is today
+        let today = today();
+        let mut daily_counts = vec![0usize; days];
         for task in &self.tasks {
-            if task.status == TaskStatus::Completed {
-                if let Some(end_time) = task.end {
-                    let days_ago = (now - end_time).num_days();
-                    if days_ago >= 0 && days_ago < 30 {
-                        let index = (29 - days_ago) as usize;
-                        if index < daily_counts.len() {
-                            daily_counts[index] += 1;
-                        }
-                    }
+            if task.status != TaskStatus::Completed {
+                continue;
+            }
+            if let Some(end_time) = task.end {
+                let days_ago = (today - day_of(&end_time)).num_days();
+                if days_ago >= 0 && (days_ago as usize) < days {
+                    daily_counts[days - 1 - days_ago as usize] += 1;
                 }
             }
         }
-        
-        let max_count = *daily_counts.iter().max().unwrap_or(&1).max(&1) as f32;
-        
-        let mut burndown_lines = vec![Line::from("     │")];
-        
-        for level in (1..=8).rev() {
-            let threshold = (max_count * level as f32 / 8.0) as i32;
-            let mut line = format!("{:4} ┤ ", threshold);
-            
-            for &count in &daily_counts[15..30] {
-                if count >= threshold {
-                    line.push('●');
+
+        let max_count = daily_counts.iter().copied().max().unwrap_or(0).max(1);
+        // Bar height per day in rows; any completion shows at least one row
+        let heights: Vec<usize> = daily_counts
+            .iter()
+            .map(|&count| (count * levels).div_ceil(max_count))
+            .collect();
+
+        let filled = Style::default().fg(Color::Green);
+        let filled_today = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+        let empty = Style::default().fg(Color::DarkGray);
+
+        let mut burndown_lines = Vec::new();
+        for level in (1..=levels).rev() {
+            // Label the top and bottom rows with the counts they stand for
+            let label = if level == levels {
+                format!("{:4} ┤", max_count)
+            } else if level == 1 {
+                format!("{:4} ┤", max_count.div_ceil(levels))
+            } else {
+                "     │".to_string()
+            };
+
+            let mut spans = vec![Span::raw(label), Span::raw(" ")];
+            for (i, &height) in heights.iter().enumerate() {
+                spans.push(if height >= level {
+                    Span::styled("●", if i == days - 1 { filled_today } else { filled })
                 } else {
-                    line.push('○');
-                }
-            }
-            
-            burndown_lines.push(Line::from(line));
-            
-            if level > 1 {
-                burndown_lines.push(Line::from("     │"));
+                    Span::styled("○", empty)
+                });
             }
+            burndown_lines.push(Line::from(spans));
         }
-        
-        burndown_lines.push(Line::from("     └─────────────────────────"));
+
+        // X-axis with a tick under today, then the dates at both ends
+        burndown_lines.push(Line::from(vec![
+            Span::raw(format!("     └{}", "─".repeat(days))),
+            Span::styled("┴", filled_today),
+        ]));
+
+        let start_date = today - chrono::Duration::days(days as i64 - 1);
+        let start_label = start_date.format("%m-%d").to_string();
+        let gap = days.saturating_sub(start_label.len() + "today".len());
+        burndown_lines.push(Line::from(vec![
+            Span::raw(" ".repeat(LABEL_WIDTH + 1)),
+            Span::styled(start_label, Style::default().fg(Color::Gray)),
+            Span::raw(" ".repeat(gap.max(1))),
+            Span::styled("today", filled_today),
+        ]));
+
+        burndown_lines.push(Line::from(vec![
+            Span::styled("● ", filled),
+            Span::raw("completed  "),
+            Span::styled("● ", filled_today),
+            Span::raw(format!("today ({})", daily_counts[days - 1])),
+        ]));
 
         let burndown_panel = Paragraph::new(burndown_lines)
             .block(Block::default()
-                .title("Burndown (Last 30 days)")
+                .title(format!("Completed per day (last {} days)", days))
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(Color::Cyan)));
         
         f.render_widget(burndown_panel, area);
     }
 
-    fn render_enhanced_project_table(&self, f: &mut Frame, area: Rect) {
+    fn render_enhanced_project_table(&self, f: &mut Frame, area: Rect, state: &mut TableState) {
         let header = Row::new(vec![
             Cell::from("Project").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
             Cell::from("Pending").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
@@ -243,22 +522,18 @@ impl DashboardWidget {
         let mut rows = Vec::new();
         
         if !self.project_stats.is_empty() {
-            let mut projects: Vec<_> = self.project_stats.iter().collect();
-            projects.sort_by(|a, b| (b.1.pending + b.1.completed).cmp(&(a.1.pending + a.1.completed)));
-
-            let max_projects = (area.height.saturating_sub(3) as usize).max(8);
-
-            for (project_name, stats) in projects.into_iter().take(max_projects) {
+            for stats in &self.project_stats {
+                let project_name = &stats.name;
                 let completion_rate = stats.completion_rate();
                 
                 let project_urgency: f64 = self.tasks.iter()
-                    .filter(|t| t.project.as_ref().map(|p| p == project_name).unwrap_or(project_name == "(no project)"))
+                    .filter(|t| t.project.as_ref().map(|p| p == project_name).unwrap_or(project_name == NO_PROJECT))
                     .filter(|t| t.status == TaskStatus::Pending)
                     .map(|t| t.urgency)
-                    .sum::<f64>() / stats.pending.max(1) as f64;
+                    .sum::<f64>() / stats.pending_count.max(1) as f64;
 
                 let next_due = self.tasks.iter()
-                    .filter(|t| t.project.as_ref().map(|p| p == project_name).unwrap_or(project_name == "(no project)"))
+                    .filter(|t| t.project.as_ref().map(|p| p == project_name).unwrap_or(project_name == NO_PROJECT))
                     .filter(|t| t.status == TaskStatus::Pending && t.due.is_some())
                     .min_by_key(|t| t.due)
                     .and_then(|t| t.due)
@@ -278,8 +553,8 @@ impl DashboardWidget {
 
                 let row = Row::new(vec![
                     Cell::from(format!("{}", project_name)).style(Style::default().fg(Color::Green)),
-                    Cell::from(format!("{}", stats.pending)).style(Style::default().fg(Color::Yellow)),
-                    Cell::from(format!("{}", stats.completed)).style(Style::default().fg(Color::Green)),
+                    Cell::from(format!("{}", stats.pending_count)).style(Style::default().fg(Color::Yellow)),
+                    Cell::from(format!("{}", stats.completed_count)).style(Style::default().fg(Color::Green)),
                     Cell::from(format!("{:.0}%", completion_rate)).style(
                         if completion_rate >= 80.0 { Style::default().fg(Color::Green) }
                         else if completion_rate >= 50.0 { Style::default().fg(Color::Yellow) }
@@ -300,6 +575,14 @@ impl DashboardWidget {
             }
         }
 
+        // Position in the title, since rows past the bottom are only reached by scrolling
+        let title = match state.selected() {
+            Some(index) if self.project_stats.len() > Self::project_rows(area) => {
+                format!("By Project ({}/{})", index + 1, self.project_stats.len())
+            }
+            _ => "By Project".to_string(),
+        };
+
         let table = Table::new(rows, &[
                 Constraint::Length(14),
                 Constraint::Length(9),
@@ -310,12 +593,60 @@ impl DashboardWidget {
             ])
             .header(header)
             .block(Block::default()
-                .title("By Project")
+                .title(title)
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(Color::Cyan)))
-            .column_spacing(1);
+            .column_spacing(1)
+            .row_highlight_style(Self::highlight(!self.tags_focused));
         
-        f.render_widget(table, area);
+        f.render_stateful_widget(table, area, state);
+    }
+
+    fn render_tag_table(&self, f: &mut Frame, area: Rect, state: &mut TableState) {
+        let title = match state.selected() {
+            Some(index) if self.tag_stats.len() > Self::project_rows(area) => {
+                format!("By Tag ({}/{})", index + 1, self.tag_stats.len())
+            }
+            _ => "By Tag".to_string(),
+        };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        if self.tag_stats.is_empty() {
+            let empty = Paragraph::new(Line::from(Span::styled("No tagged tasks", Style::default().fg(Color::DarkGray))))
+                .block(block);
+            f.render_widget(empty, area);
+            return;
+        }
+
+        let header = Row::new(vec![
+            Cell::from("Tag").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Cell::from("Pending").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Cell::from("All").style(Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD)),
+        ]);
+        // Long tag names are cut to the column rather than pushing the counts out of view
+        let name_width = area.width.saturating_sub(2 + 7 + 5 + 2) as usize;
+        let rows: Vec<Row> = self.tag_stats.iter().map(|tag| {
+            Row::new(vec![
+                Cell::from(truncate_text(&format!("+{}", tag.name), name_width)).style(Style::default().fg(Color::Magenta)),
+                Cell::from(format!("{:>7}", tag.pending_count)).style(Style::default().fg(Color::Yellow)),
+                Cell::from(format!("{:>5}", tag.task_count)).style(Style::default().fg(Color::Gray)),
+            ])
+        }).collect();
+
+        let table = Table::new(rows, &[
+                Constraint::Min(4),
+                Constraint::Length(7),
+                Constraint::Length(5),
+            ])
+            .header(header)
+            .block(block)
+            .column_spacing(1)
+            .row_highlight_style(Self::highlight(self.tags_focused));
+
+        f.render_stateful_widget(table, area, state);
     }
 
     fn render_recent_activity_panel(&self, f: &mut Frame, area: Rect) {
@@ -327,13 +658,7 @@ impl DashboardWidget {
                 if let Some(end_time) = task.end {
                     if end_time > now - chrono::Duration::days(7) {
                         let time_ago = now - end_time;
-                        let time_str = if time_ago.num_minutes() < 60 {
-                            format!("{}min ago", time_ago.num_minutes())
-                        } else if time_ago.num_hours() < 24 {
-                            format!("{}h ago", time_ago.num_hours())
-                        } else {
-                            format!("{}d ago", time_ago.num_days())
-                        };
+                        let time_str = format!("{} ago", format_relative_duration(time_ago));
                         
                         let activity_type = match &task.project {
                             Some(project) => format!("Completed in [{}]", project),
@@ -346,13 +671,7 @@ impl DashboardWidget {
             
             if task.entry > now - chrono::Duration::days(3) {
                 let time_ago = now - task.entry;
-                let time_str = if time_ago.num_minutes() < 60 {
-                    format!("{}min ago", time_ago.num_minutes())
-                } else if time_ago.num_hours() < 24 {
-                    format!("{}h ago", time_ago.num_hours())
-                } else {
-                    format!("{}d ago", time_ago.num_days())
-                };
+                let time_str = format!("{} ago", format_relative_duration(time_ago));
                 
                 let priority_suffix = match &task.priority {
                     Some(Priority::High) => " [H]",
@@ -408,11 +727,7 @@ impl DashboardWidget {
             activity_text.push(Line::from("No recent activity"));
         } else {
             for (_, action, description, _project, time_str) in recent_activities {
-                let short_desc = if description.len() > 45 {
-                    format!("{}...", &description[..42])
-                } else {
-                    description
-                };
+                let short_desc = truncate_text(&description, 45);
                 
                 let action_color = if action.contains("Completed") {
                     Color::Green
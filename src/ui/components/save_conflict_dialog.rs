@@ -0,0 +1,96 @@
+// Warning shown when a task being edited was changed elsewhere before saving
+
+use anyhow::Result;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::handlers::input::Action;
+
+pub struct SaveConflictDialog {
+    pub message: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SaveConflictResult {
+    // Save the form anyway, replacing the other change to the fields it covers
+    Overwrite,
+    // Drop the edits and reopen the form on the latest version
+    Reload,
+    // Back to the form with the edits as they were
+    Cancel,
+}
+
+impl SaveConflictDialog {
+    pub fn new(message: String) -> Self {
+        SaveConflictDialog { message }
+    }
+
+    // Like the yes/no dialog, Enter and Esc take the answer that loses nothing
+    pub fn handle_input(&mut self, action: Action) -> Result<Option<SaveConflictResult>> {
+        Ok(match action {
+            Action::Character('o') | Action::Character('O') => Some(SaveConflictResult::Overwrite),
+            Action::Character('r') | Action::Character('R') => Some(SaveConflictResult::Reload),
+            Action::Character('c') | Action::Character('C') | Action::Back | Action::Select => {
+                Some(SaveConflictResult::Cancel)
+            }
+            _ => None,
+        })
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let width = area.width.min(64);
+        let height = 8.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("Task Changed Elsewhere")
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),    // Message
+                Constraint::Length(1), // Answers
+            ])
+            .split(inner_area);
+
+        let message = Paragraph::new(self.message.as_str())
+            .style(Style::default().bg(Color::Black).fg(Color::White))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        f.render_widget(message, chunks[0]);
+
+        let answers = Paragraph::new(Line::from(vec![
+            Span::styled("o", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+            Span::raw(" overwrite  "),
+            Span::styled("r", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" reload  "),
+            Span::styled("c/Esc", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" keep editing"),
+        ]))
+        .style(Style::default().bg(Color::Black).fg(Color::White))
+        .alignment(Alignment::Center);
+        f.render_widget(answers, chunks[1]);
+    }
+}
@@ -0,0 +1,179 @@
+// Prompt for how long to hide a task: sets its wait date
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::data::models::Task;
+use crate::handlers::input::Action;
+use crate::ui::components::task_form::TaskForm;
+use crate::utils::formatting::{truncate_text, DateFormat};
+
+pub struct SnoozeDialog {
+    pub task_uuid: String,
+    pub task_description: String,
+    pub input: String,
+    pub cursor: usize,
+    pub error: Option<String>,
+    date_format: DateFormat,
+}
+
+#[derive(Debug)]
+pub enum SnoozeResult {
+    Snooze(DateTime<Utc>),
+    Cancel,
+}
+
+impl SnoozeDialog {
+    pub fn new(task: &Task, date_format: DateFormat) -> Self {
+        SnoozeDialog {
+            task_uuid: task.uuid.clone(),
+            task_description: task.description.clone(),
+            input: String::new(),
+            cursor: 0,
+            error: None,
+            date_format,
+        }
+    }
+
+    // Same date syntax as the task form: 3d, 1w, tomorrow, eow, 2025-11-01, ...
+    // Only future dates make sense, anything earlier would not hide the task.
+    pub fn wait_until(&self) -> Result<DateTime<Utc>, String> {
+        let input = self.input.trim();
+        if input.is_empty() {
+            return Err("Enter how long to snooze, e.g. 3d".to_string());
+        }
+        match TaskForm::parse_taskwarrior_date(input) {
+            Some(until) if until > Utc::now() => Ok(until),
+            Some(_) => Err(format!("\"{}\" is not in the future", input)),
+            None => Err(format!("Unknown date \"{}\"", input)),
+        }
+    }
+
+    pub fn handle_input(&mut self, action: Action) -> Result<Option<SnoozeResult>> {
+        match action {
+            Action::Back => return Ok(Some(SnoozeResult::Cancel)),
+            Action::Select => match self.wait_until() {
+                Ok(until) => return Ok(Some(SnoozeResult::Snooze(until))),
+                Err(e) => self.error = Some(e),
+            },
+            Action::MoveLeft => {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            Action::MoveRight if self.cursor < self.input.chars().count() => {
+                self.cursor += 1;
+            }
+            Action::Character(c) => self.insert(c),
+            Action::Space => self.insert(' '),
+            Action::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                let byte_index = self.byte_index(self.cursor);
+                self.input.remove(byte_index);
+                self.error = None;
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn insert(&mut self, c: char) {
+        let byte_index = self.byte_index(self.cursor);
+        self.input.insert(byte_index, c);
+        self.cursor += 1;
+        self.error = None;
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.input
+            .char_indices()
+            .nth(char_index)
+            .map(|(i, _)| i)
+            .unwrap_or(self.input.len())
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let width = area.width.min(60);
+        let height = 10.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("Snooze")
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // Task
+                Constraint::Length(3), // Input
+                Constraint::Length(1), // Resulting wait date / error
+                Constraint::Min(1),    // Instructions
+            ])
+            .split(inner_area);
+
+        let task = Paragraph::new(Span::styled(
+            truncate_text(&self.task_description, chunks[0].width as usize),
+            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+        ))
+        .style(Style::default().bg(Color::Black));
+        f.render_widget(task, chunks[0]);
+
+        let (before, after) = self.input.split_at(self.byte_index(self.cursor));
+        let input = Paragraph::new(Line::from(vec![
+            Span::styled("Hide for: ", Style::default().fg(Color::Yellow)),
+            Span::raw(before.to_string()),
+            Span::styled("█", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::raw(after.to_string()),
+        ]))
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)))
+        .style(Style::default().bg(Color::Black).fg(Color::White));
+        f.render_widget(input, chunks[1]);
+
+        // Errors after Enter; otherwise preview the date as it is typed
+        let notice = if let Some(ref error) = self.error {
+            Span::styled(error.clone(), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+        } else if let Ok(until) = self.wait_until() {
+            Span::styled(
+                format!("Hidden until {}", self.date_format.datetime(&until)),
+                Style::default().fg(Color::Cyan),
+            )
+        } else {
+            Span::styled("e.g. 3d, 1w, tomorrow, eow, 2025-11-01", Style::default().fg(Color::DarkGray))
+        };
+        f.render_widget(
+            Paragraph::new(Line::from(notice)).style(Style::default().bg(Color::Black)).alignment(Alignment::Center),
+            chunks[2],
+        );
+
+        let instructions = Paragraph::new(Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" snooze  "),
+            Span::styled("Esc", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+            Span::raw(" cancel"),
+        ]))
+        .style(Style::default().bg(Color::Black).fg(Color::White))
+        .alignment(Alignment::Center);
+        f.render_widget(instructions, chunks[3]);
+    }
+}
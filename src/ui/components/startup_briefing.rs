@@ -0,0 +1,132 @@
+// Startup briefing: once per launch, a popup of the pending tasks that are overdue or
+// due today, over the first task list. Any key closes it; `ui.startup_briefing = false`
+// turns it off.
+
+use chrono::Utc;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::data::models::{Task, TaskStatus};
+use crate::data::sort::DueGroup;
+use crate::utils::formatting::{display_time, format_relative_duration, truncate_text};
+
+const MAX_WIDTH: u16 = 76;
+
+pub struct StartupBriefing {
+    overdue: Vec<Task>,
+    due_today: Vec<Task>,
+}
+
+impl StartupBriefing {
+    // None when nothing is overdue or due today, so there is nothing to show
+    pub fn from_tasks(tasks: &[Task]) -> Option<Self> {
+        let now = Utc::now();
+        let mut overdue = Vec::new();
+        let mut due_today = Vec::new();
+        for task in tasks.iter().filter(|task| task.status == TaskStatus::Pending) {
+            match DueGroup::of(task, now) {
+                DueGroup::Overdue => overdue.push(task.clone()),
+                DueGroup::Today => due_today.push(task.clone()),
+                _ => {}
+            }
+        }
+        if overdue.is_empty() && due_today.is_empty() {
+            return None;
+        }
+        // Longest overdue first, then the rest of today in order
+        overdue.sort_by_key(|task| task.due);
+        due_today.sort_by_key(|task| task.due);
+        Some(StartupBriefing { overdue, due_today })
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let now = Utc::now();
+        let mut sections = Vec::new();
+        if !self.overdue.is_empty() {
+            let entries = self.overdue.iter().map(|task| {
+                let late = task.due.map(|due| format!("{} late", format_relative_duration(now - due)));
+                (task, late.unwrap_or_default())
+            });
+            sections.push((format!("Overdue ({})", self.overdue.len()), Color::Red, entries.collect::<Vec<_>>()));
+        }
+        if !self.due_today.is_empty() {
+            let entries = self.due_today.iter().map(|task| {
+                let at = task.due.map(|due| format!("at {}", display_time(&due).format("%H:%M")));
+                (task, at.unwrap_or_default())
+            });
+            sections.push((format!("Due Today ({})", self.due_today.len()), Color::Yellow, entries.collect::<Vec<_>>()));
+        }
+
+        let rows = sections.iter().map(|(_, _, entries)| entries.len() + 1).sum::<usize>() + sections.len() - 1;
+        let width = area.width.saturating_sub(4).min(MAX_WIDTH);
+        // Borders and the footer take four rows
+        let height = (rows as u16 + 4).min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+        let block = Block::default()
+            .title(" Needs Attention ")
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(block, popup_area);
+
+        let inner_area = popup_area.inner(Margin { vertical: 1, horizontal: 2 });
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1), Constraint::Length(1)])
+            .split(inner_area);
+
+        let inner_width = chunks[0].width as usize;
+        let mut lines = Vec::new();
+        for (title, color, entries) in &sections {
+            if !lines.is_empty() {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(Span::styled(title.clone(), Style::default().fg(*color).add_modifier(Modifier::BOLD))));
+            for (task, when) in entries {
+                let id = task.id.map(|id| id.to_string()).unwrap_or_default();
+                let project = task.project.as_deref().map(|project| format!(" · {}", project)).unwrap_or_default();
+                // ID column, then the description and project, with the time on the right
+                let room = inner_width.saturating_sub(5 + when.chars().count() + 1);
+                let text = truncate_text(&format!("{}{}", task.description, project), room);
+                let padding = room.saturating_sub(text.chars().count());
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{:<5}", id), Style::default().fg(Color::Gray)),
+                    Span::raw(text),
+                    Span::raw(" ".repeat(padding + 1)),
+                    Span::styled(when.clone(), Style::default().fg(*color)),
+                ]));
+            }
+        }
+
+        let visible = chunks[0].height as usize;
+        if lines.len() > visible && visible > 0 {
+            lines.truncate(visible - 1);
+            lines.push(Line::from(Span::styled("… more in the task list", Style::default().fg(Color::DarkGray))));
+        }
+        let list = Paragraph::new(lines).style(Style::default().bg(Color::Black).fg(Color::White));
+        f.render_widget(list, chunks[0]);
+
+        let footer = Paragraph::new(Line::from(vec![
+            Span::styled("Any key", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" closes  "),
+            Span::styled("ui.startup_briefing = false", Style::default().fg(Color::Gray)),
+            Span::raw(" turns this off"),
+        ]))
+        .style(Style::default().bg(Color::Black).fg(Color::White))
+        .alignment(Alignment::Center);
+        f.render_widget(footer, chunks[2]);
+    }
+}
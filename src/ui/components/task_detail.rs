@@ -9,13 +9,43 @@ use ratatui::{
     Frame,
 };
 
-use crate::data::models::{Task, TaskStatus, Priority};
+use crate::data::models::{HistoryEntry, Task, TaskStatus, Priority};
+use crate::ui::themes::TagColors;
+use crate::utils::formatting::{format_relative_duration, wrap_text, DateFormat};
 
-pub struct TaskDetailWidget;
+// Urgency that fills the whole bar; anything above is shown full
+const URGENCY_BAR_MAX: f64 = 20.0;
+// Widest the bar gets, in cells
+const URGENCY_BAR_WIDTH: usize = 20;
+
+pub struct TaskDetailWidget {
+    dates: DateFormat,
+    // Change history from `task info`, for the task with this UUID
+    history: Option<(String, Vec<HistoryEntry>)>,
+    tag_colors: TagColors,
+}
 
 impl TaskDetailWidget {
     pub fn new() -> Self {
-        TaskDetailWidget
+        TaskDetailWidget {
+            dates: DateFormat::default(),
+            history: None,
+            tag_colors: TagColors::default(),
+        }
+    }
+
+    pub fn set_history(&mut self, uuid: &str, entries: Vec<HistoryEntry>) {
+        self.history = Some((uuid.to_string(), entries));
+    }
+
+    pub fn with_date_format(mut self, dates: DateFormat) -> Self {
+        self.dates = dates;
+        self
+    }
+
+    pub fn with_tag_colors(mut self, tag_colors: TagColors) -> Self {
+        self.tag_colors = tag_colors;
+        self
     }
 
     pub fn render(&self, f: &mut Frame, area: Rect, task: Option<&Task>) {
@@ -63,11 +93,15 @@ impl TaskDetailWidget {
             Span::styled(task.id.map(|i| i.to_string()).unwrap_or_else(|| "".to_string()), Style::default().fg(Color::White)),
         ]));
         
-        // Description
-        lines.push(Line::from(vec![
-            Span::styled("Description   ", Style::default().fg(Color::Cyan)),
-            Span::styled(&task.description, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
-        ]));
+        // Description gets its own wrapped block under the label so long text is never cut
+        lines.push(Line::from(Span::styled("Description", Style::default().fg(Color::Cyan))));
+        let description_width = (area.width as usize).saturating_sub(4); // borders + indent
+        for description_line in wrap_text(&task.description, description_width) {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(description_line, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+            ]));
+        }
         
         // Status
         let (status_str, status_color) = match task.status {
@@ -113,7 +147,7 @@ impl TaskDetailWidget {
             lines.push(Line::from(vec![
                 Span::styled("Due           ", Style::default().fg(Color::Cyan)),
                 Span::styled(
-                    due.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    self.dates.datetime(&due),
                     Style::default().fg(due_color).add_modifier(Modifier::BOLD)
                 ),
             ]));
@@ -121,15 +155,26 @@ impl TaskDetailWidget {
         
         // Get current time for relative calculations
         let now = Utc::now();
+
+        // Wait date, counting down while the task is hidden (W brings it back)
+        if let Some(wait) = task.wait.filter(|wait| *wait > now) {
+            lines.push(Line::from(vec![
+                Span::styled("Waiting until ", Style::default().fg(Color::Cyan)),
+                Span::styled(format!("{} (in {})",
+                    self.dates.datetime(&wait),
+                    format_relative_duration(wait - now)
+                ), Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
+            ]));
+        }
         
         // Start date (when task is started)
         if let Some(start) = task.start {
             let start_duration = now - start;
-            let start_relative = self.format_relative_time(start_duration);
+            let start_relative = format_relative_duration(start_duration);
             lines.push(Line::from(vec![
                 Span::styled("Start         ", Style::default().fg(Color::Cyan)),
                 Span::styled(format!("{} ({})", 
-                    start.format("%Y-%m-%d %H:%M:%S"), 
+                    self.dates.datetime(&start), 
                     start_relative
                 ), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
             ]));
@@ -137,11 +182,11 @@ impl TaskDetailWidget {
         
         // Created (formerly Entered)
         let entry_duration = now - task.entry;
-        let entry_relative = self.format_relative_time(entry_duration);
+        let entry_relative = format_relative_duration(entry_duration);
         lines.push(Line::from(vec![
             Span::styled("Created       ", Style::default().fg(Color::Cyan)),
             Span::styled(format!("{} ({})", 
-                task.entry.format("%Y-%m-%d %H:%M:%S"), 
+                self.dates.datetime(&task.entry), 
                 entry_relative
             ), Style::default().fg(Color::Gray)),
         ]));
@@ -149,11 +194,11 @@ impl TaskDetailWidget {
         // Last modified
         if let Some(modified) = task.modified {
             let mod_duration = now - modified;
-            let mod_relative = self.format_relative_time(mod_duration);
+            let mod_relative = format_relative_duration(mod_duration);
             lines.push(Line::from(vec![
                 Span::styled("Last modified ", Style::default().fg(Color::Cyan)),
                 Span::styled(format!("{} ({})", 
-                    modified.format("%Y-%m-%d %H:%M:%S"), 
+                    self.dates.datetime(&modified), 
                     mod_relative
                 ), Style::default().fg(Color::Gray)),
             ]));
@@ -161,11 +206,15 @@ impl TaskDetailWidget {
         
         // Tags
         if !task.tags.is_empty() {
-            let tags_str = task.tags.join(" ");
-            lines.push(Line::from(vec![
-                Span::styled("Tags          ", Style::default().fg(Color::Cyan)),
-                Span::styled(tags_str, Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
-            ]));
+            let mut spans = vec![Span::styled("Tags          ", Style::default().fg(Color::Cyan))];
+            for (i, tag) in task.tags.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::raw(" "));
+                }
+                let style = Style::default().fg(self.tag_colors.color(tag)).add_modifier(Modifier::BOLD);
+                spans.push(Span::styled(tag.as_str(), style));
+            }
+            lines.push(Line::from(spans));
         }
         
         // UUID
@@ -174,17 +223,17 @@ impl TaskDetailWidget {
             Span::styled(&task.uuid, Style::default().fg(Color::DarkGray)),
         ]));
         
-        // Urgency
-        let urgency_color = if task.urgency >= 10.0 {
-            Color::Red
-        } else if task.urgency >= 5.0 {
-            Color::Yellow
-        } else {
-            Color::Green
-        };
+        // Urgency, with a bar after the number when the panel has room for one
+        let urgency_color = Self::urgency_color(task.urgency);
+        let urgency = format!("{:.1}", task.urgency);
+        let bar_width = (area.width as usize)
+            .saturating_sub(2 + 14 + urgency.chars().count() + 1) // borders, label, number, gap
+            .min(URGENCY_BAR_WIDTH);
         lines.push(Line::from(vec![
             Span::styled("Urgency       ", Style::default().fg(Color::Cyan)),
-            Span::styled(format!("{:.1}", task.urgency), Style::default().fg(urgency_color).add_modifier(Modifier::BOLD)),
+            Span::styled(urgency, Style::default().fg(urgency_color).add_modifier(Modifier::BOLD)),
+            Span::raw(" "),
+            Span::styled(Self::urgency_bar(task.urgency, bar_width), Style::default().fg(urgency_color)),
         ]));
 
         let detail = Paragraph::new(lines)
@@ -194,21 +243,79 @@ impl TaskDetailWidget {
         f.render_widget(detail, area);
     }
 
+    fn urgency_color(urgency: f64) -> Color {
+        if urgency >= 10.0 {
+            Color::Red
+        } else if urgency >= 5.0 {
+            Color::Yellow
+        } else {
+            Color::Green
+        }
+    }
+
+    // Filled/empty blocks in proportion to urgency out of URGENCY_BAR_MAX; zero and
+    // negative urgency give an empty bar, anything past the max a full one
+    pub fn urgency_bar(urgency: f64, width: usize) -> String {
+        let fraction = (urgency / URGENCY_BAR_MAX).clamp(0.0, 1.0);
+        let filled = ((fraction * width as f64).round() as usize).min(width);
+        format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+    }
+
+    fn history_header(&self, task: &Task) -> Line<'static> {
+        // Date column is as wide as the configured datetime format
+        let date_width = self.dates.datetime(&task.entry).chars().count();
+        Line::from(vec![
+            Span::styled(format!("{:<width$}", "Date", width = date_width), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled(" Modification", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        ])
+    }
+
     fn render_modification_history(&self, f: &mut Frame, area: Rect, task: &Task) {
-        let mut header = Vec::new();
-        header.push(Line::from(vec![
-            Span::styled("Date", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-            Span::styled("                Modification", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-        ]));
+        match self.history {
+            Some((ref uuid, ref entries)) if *uuid == task.uuid => self.render_recorded_history(f, area, task, entries),
+            _ => self.render_summary_history(f, area, task),
+        }
+    }
+
+    // The history Taskwarrior recorded, latest change first. Changes made together
+    // keep their order and share one date, as in `task info`.
+    fn render_recorded_history(&self, f: &mut Frame, area: Rect, task: &Task, entries: &[HistoryEntry]) {
+        let date_width = self.dates.datetime(&task.entry).chars().count();
+        let mut lines = vec![self.history_header(task)];
+        for group in entries.chunk_by(|a, b| a.date == b.date).rev() {
+            for (i, entry) in group.iter().enumerate() {
+                let date = if i == 0 {
+                    self.dates.datetime(&entry.date)
+                } else {
+                    " ".repeat(date_width)
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(date, Style::default().fg(Color::Gray)),
+                    Span::styled(format!(" {}", entry.change), Style::default().fg(Color::White)),
+                ]));
+            }
+        }
+
+        let history_block = Paragraph::new(lines)
+            .block(Block::default().title("History").borders(Borders::ALL))
+            .wrap(ratatui::widgets::Wrap { trim: false });
+        f.render_widget(history_block, area);
+    }
+
+    // Until `task info` answers (or if it can't be read), an outline built from the
+    // task's current fields. It isn't the real history, and the title says so.
+    fn render_summary_history(&self, f: &mut Frame, area: Rect, task: &Task) {
+        let date_width = self.dates.datetime(&task.entry).chars().count();
+        let header = vec![self.history_header(task)];
         
         // Collect modifications with latest first
         let mut modifications = Vec::new();
         
         // Use modified date if available, otherwise use entry date
         let display_date = if let Some(modified) = task.modified {
-            modified.format("%Y-%m-%d %H:%M:%S").to_string()
+            self.dates.datetime(&modified)
         } else {
-            task.entry.format("%Y-%m-%d %H:%M:%S").to_string()
+            self.dates.datetime(&task.entry)
         };
         
         // Show latest modifications first (most recent changes)
@@ -216,14 +323,14 @@ impl TaskDetailWidget {
         // Due date changes (show with modified date if changed, or entry date if set on creation)
         if let Some(due) = task.due {
             let due_display_date = if let Some(modified) = task.modified {
-                modified.format("%Y-%m-%d %H:%M:%S").to_string()
+                self.dates.datetime(&modified)
             } else {
-                task.entry.format("%Y-%m-%d %H:%M:%S").to_string()
+                self.dates.datetime(&task.entry)
             };
             modifications.push(Line::from(vec![
                 Span::styled(due_display_date, Style::default().fg(Color::Gray)),
                 Span::styled(" Due set to '", Style::default().fg(Color::Gray)),
-                Span::styled(due.format("%Y-%m-%d %H:%M:%S").to_string(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(self.dates.datetime(&due), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                 Span::styled("'.", Style::default().fg(Color::Gray)),
             ]));
         }
@@ -231,9 +338,9 @@ impl TaskDetailWidget {
         // Start date (when task is started - IMPORTANT!)
         if let Some(start) = task.start {
             modifications.push(Line::from(vec![
-                Span::styled(start.format("%Y-%m-%d %H:%M:%S").to_string(), Style::default().fg(Color::Gray)),
+                Span::styled(self.dates.datetime(&start), Style::default().fg(Color::Gray)),
                 Span::styled(" Start set to '", Style::default().fg(Color::Gray)),
-                Span::styled(start.format("%Y-%m-%d %H:%M:%S").to_string(), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled(self.dates.datetime(&start), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
                 Span::styled("'.", Style::default().fg(Color::Gray)),
             ]));
         }
@@ -243,7 +350,7 @@ impl TaskDetailWidget {
             modifications.push(Line::from(vec![
                 Span::styled(display_date.clone(), Style::default().fg(Color::Gray)),
                 Span::styled(" Tag '", Style::default().fg(Color::Gray)),
-                Span::styled(tag, Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
+                Span::styled(tag, Style::default().fg(self.tag_colors.color(tag)).add_modifier(Modifier::BOLD)),
                 Span::styled("' added.", Style::default().fg(Color::Gray)),
             ]));
         }
@@ -292,14 +399,17 @@ impl TaskDetailWidget {
         
         // Description and entry (oldest - shown last)
         modifications.push(Line::from(vec![
-            Span::styled(task.entry.format("%Y-%m-%d %H:%M:%S").to_string(), Style::default().fg(Color::Gray)),
+            Span::styled(self.dates.datetime(&task.entry), Style::default().fg(Color::Gray)),
             Span::styled(" Description set to '", Style::default().fg(Color::Gray)),
             Span::styled(&task.description, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
             Span::styled("'.", Style::default().fg(Color::Gray)),
         ]));
+        // Line up with the dates in the rows above
+        let entry_date = self.dates.datetime(&task.entry);
+        let indent = " ".repeat(date_width + 1);
         modifications.push(Line::from(vec![
-            Span::styled("                    Entry set to '", Style::default().fg(Color::Gray)),
-            Span::styled(task.entry.format("%Y-%m-%d %H:%M:%S").to_string(), Style::default().fg(Color::White)),
+            Span::styled(format!("{}Entry set to '", indent), Style::default().fg(Color::Gray)),
+            Span::styled(entry_date, Style::default().fg(Color::White)),
             Span::styled("'.", Style::default().fg(Color::Gray)),
         ]));
         
@@ -308,28 +418,9 @@ impl TaskDetailWidget {
         lines.extend(modifications);
 
         let history_block = Paragraph::new(lines)
-            .block(Block::default().borders(Borders::ALL))
+            .block(Block::default().title("History (from current fields)").borders(Borders::ALL))
             .wrap(ratatui::widgets::Wrap { trim: true });
         
         f.render_widget(history_block, area);
     }
-
-    fn format_relative_time(&self, duration: chrono::Duration) -> String {
-        if duration.num_minutes() < 60 {
-            format!("{}min", duration.num_minutes().max(1))
-        } else if duration.num_hours() < 24 {
-            format!("{}h", duration.num_hours())
-        } else if duration.num_days() < 30 {
-            format!("{}d", duration.num_days())
-        } else if duration.num_days() < 365 {
-            let weeks = duration.num_days() / 7;
-            if weeks < 10 {
-                format!("{}w", weeks)
-            } else {
-                format!("{}mo", duration.num_days() / 30)
-            }
-        } else {
-            format!("{}y", duration.num_days() / 365)
-        }
-    }
 }
@@ -1,7 +1,7 @@
 // Task form dialog for adding/editing tasks
 
 use anyhow::Result;
-use chrono::{Datelike, Duration, NaiveDate, TimeZone, Utc};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
@@ -12,6 +12,9 @@ use ratatui::{
 
 use crate::data::models::{Priority, Task};
 use crate::handlers::input::Action;
+use crate::ui::components::date_picker::{DatePicker, DatePickerResult};
+use crate::utils::formatting::{display_time, from_display_time, today};
+use crate::utils::validation::{parse_date, parse_tags, validate_project_name};
 
 #[derive(Debug, Clone)]
 pub enum FormField {
@@ -20,6 +23,18 @@ pub enum FormField {
     Priority,
     Due,
     Tags,
+    Depends,
+}
+
+// Field values as typed, used to tell whether the form has been edited
+#[derive(Debug, Clone, Default, PartialEq)]
+struct FormInputs {
+    description: String,
+    project: String,
+    priority_index: usize,
+    due: String,
+    tags: String,
+    depends: String,
 }
 
 pub struct TaskForm {
@@ -30,17 +45,32 @@ pub struct TaskForm {
     pub project_input: String,
     pub tags_input: String,
     pub due_input: String,
+    // Dependencies as task IDs (UUID prefixes for tasks without an ID)
+    pub depends_input: String,
+    // (id, uuid) of tasks that can be referenced by ID in depends_input
+    dependency_ids: Vec<(u32, String)>,
+    // Inputs the form opened with. An untouched Due keeps the original due as-is.
+    original_inputs: FormInputs,
     pub priority_index: usize,
     // Cursor positions for each text field
     pub description_cursor: usize,
     pub project_cursor: usize,
     pub tags_cursor: usize,
     pub due_cursor: usize,
+    pub depends_cursor: usize,
+    // Calendar popup for the Due field (Ctrl+D)
+    pub date_picker: Option<DatePicker>,
+    // Why the last save attempt was refused, shown above the instructions
+    pub error: Option<String>,
+    // Problems with the Project and Due inputs, shown in red under each field.
+    // Checked when leaving the field and on save; editing the field clears them.
+    pub project_error: Option<String>,
+    pub due_error: Option<String>,
 }
 
 impl TaskForm {
     pub fn new_task() -> Self {
-        TaskForm {
+        let mut form = TaskForm {
             task: Task::new("".to_string()),
             active_field: FormField::Description,
             is_editing: true, // Start editing immediately
@@ -48,13 +78,23 @@ impl TaskForm {
             project_input: String::new(),
             tags_input: String::new(),
             due_input: String::new(),
+            depends_input: String::new(),
+            dependency_ids: Vec::new(),
+            original_inputs: FormInputs::default(),
             priority_index: 0, // None, H, M, L
             // Initialize cursors at end of text
             description_cursor: 0,
             project_cursor: 0,
             tags_cursor: 0,
             due_cursor: 0,
-        }
+            depends_cursor: 0,
+            date_picker: None,
+            error: None,
+            project_error: None,
+            due_error: None,
+        };
+        form.original_inputs = form.inputs();
+        form
     }
 
     pub fn edit_task(task: Task) -> Self {
@@ -66,18 +106,29 @@ impl TaskForm {
         };
 
         let tags_str = task.tags.join(", ");
+        // Only show the time when the task is due at a specific time of day
         let due_str = task.due
-            .map(|d| d.format("%Y-%m-%d").to_string())
+            .map(|d| display_time(&d).naive_local())
+            .map(|d| {
+                if d.time() == NaiveTime::MIN {
+                    d.format("%Y-%m-%d").to_string()
+                } else {
+                    d.format("%Y-%m-%d %H:%M").to_string()
+                }
+            })
             .unwrap_or_default();
 
         let description_text = task.description.clone();
         let project_text = task.project.clone().unwrap_or_default();
         
-        TaskForm {
+        let mut form = TaskForm {
             description_input: description_text.clone(),
             project_input: project_text.clone(),
             tags_input: tags_str.clone(),
             due_input: due_str.clone(),
+            depends_input: String::new(),
+            dependency_ids: Vec::new(),
+            original_inputs: FormInputs::default(),
             task,
             active_field: FormField::Description,
             is_editing: true, // Start editing immediately
@@ -87,11 +138,116 @@ impl TaskForm {
             project_cursor: project_text.len(),
             tags_cursor: tags_str.len(),
             due_cursor: due_str.len(),
+            depends_cursor: 0,
+            date_picker: None,
+            error: None,
+            project_error: None,
+            due_error: None,
+        };
+        form.original_inputs = form.inputs();
+        form
+    }
+
+    fn inputs(&self) -> FormInputs {
+        FormInputs {
+            description: self.description_input.clone(),
+            project: self.project_input.clone(),
+            priority_index: self.priority_index,
+            due: self.due_input.clone(),
+            tags: self.tags_input.clone(),
+            depends: self.depends_input.clone(),
+        }
+    }
+
+    // Whether any field differs from what the form opened with
+    pub fn is_dirty(&self) -> bool {
+        self.inputs() != self.original_inputs
+    }
+
+    // Let the Depends field show and accept task IDs instead of UUIDs
+    pub fn with_dependency_ids(mut self, tasks: &[Task]) -> Self {
+        self.dependency_ids = tasks.iter()
+            .filter_map(|task| task.id.map(|id| (id, task.uuid.clone())))
+            .collect();
+        self.depends_input = self.task.depends.iter()
+            .map(|uuid| {
+                self.dependency_ids.iter()
+                    .find(|(_, known)| known == uuid)
+                    .map(|(id, _)| id.to_string())
+                    .unwrap_or_else(|| uuid.chars().take(8).collect())
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.depends_cursor = self.depends_input.len();
+        self.original_inputs.depends = self.depends_input.clone();
+        self
+    }
+
+    // Resolve the Depends field to UUIDs; unknown entries are passed through for Taskwarrior to check
+    fn parse_depends(&self) -> Vec<String> {
+        let mut depends: Vec<String> = Vec::new();
+        for token in self.depends_input.split(|c: char| c == ',' || c.is_whitespace()).filter(|s| !s.is_empty()) {
+            let by_id = token.parse::<u32>().ok()
+                .and_then(|id| self.dependency_ids.iter().find(|(known, _)| *known == id))
+                .map(|(_, uuid)| uuid.clone());
+            let by_prefix = || self.task.depends.iter().find(|uuid| uuid.starts_with(token)).cloned();
+            let uuid = by_id.or_else(by_prefix).unwrap_or_else(|| token.to_string());
+            if uuid != self.task.uuid && !depends.contains(&uuid) {
+                depends.push(uuid);
+            }
         }
+        depends
+    }
+
+    // Write a picked date into the Due field, keeping any time of day already typed
+    fn set_due_date(&mut self, date: NaiveDate) {
+        let time = Self::parse_display_time(&self.due_input)
+            .map(|due| due.time())
+            .filter(|time| *time != NaiveTime::MIN);
+        self.due_input = match time {
+            Some(time) => format!("{} {}", date.format("%Y-%m-%d"), time.format("%H:%M")),
+            None => date.format("%Y-%m-%d").to_string(),
+        };
+        self.due_cursor = self.due_input.len();
+        self.is_editing = true;
+    }
+
+    // Replace the description, e.g. with text edited in $EDITOR
+    pub fn set_description(&mut self, description: String) {
+        self.description_cursor = description.len();
+        self.description_input = description;
+        self.active_field = FormField::Description;
     }
 
     pub fn handle_input(&mut self, action: Action) -> Result<Option<TaskFormResult>> {
+        if let Some(picker) = self.date_picker.as_mut() {
+            if let Some(result) = picker.handle_input(action) {
+                if let DatePickerResult::Pick(date) = result {
+                    self.set_due_date(date);
+                    self.due_error = None;
+                }
+                self.date_picker = None;
+            }
+            return Ok(None);
+        }
+
+        if matches!(action, Action::Character(_) | Action::Backspace) {
+            self.error = None;
+            match self.active_field {
+                FormField::Project => self.project_error = None,
+                FormField::Due => self.due_error = None,
+                _ => {}
+            }
+        }
+
         match action {
+            Action::DatePicker if matches!(self.active_field, FormField::Due) => {
+                // Start from the current due date, or today if the field is empty or unparsable
+                let start = Self::parse_display_time(&self.due_input)
+                    .map(|due| due.date())
+                    .unwrap_or_else(today);
+                self.date_picker = Some(DatePicker::new(start));
+            }
             Action::Back => {
                 return Ok(Some(TaskFormResult::Cancel));
             }
@@ -105,8 +261,21 @@ impl TaskForm {
                         // For now, switch to editing description field
                         self.active_field = FormField::Description;
                         self.is_editing = true;
+                    } else if !self.check_field(FormField::Project) {
+                        self.edit_field(FormField::Project);
+                    } else if !self.check_field(FormField::Due) {
+                        self.edit_field(FormField::Due);
                     } else {
-                        return Ok(Some(TaskFormResult::Save(self.build_task())));
+                        match parse_tags(&self.tags_input) {
+                            Ok(tags) => return Ok(Some(TaskFormResult::Save(self.build_task(tags)))),
+                            Err(e) => {
+                                // Keep the form open on the offending field
+                                self.error = Some(e.to_string());
+                                self.active_field = FormField::Tags;
+                                self.tags_cursor = self.tags_input.len();
+                                self.is_editing = true;
+                            }
+                        }
                     }
                 }
             }
@@ -138,6 +307,10 @@ impl TaskForm {
                         self.due_input.insert(self.due_cursor, c);
                         self.due_cursor += 1;
                     }
+                    FormField::Depends => {
+                        self.depends_input.insert(self.depends_cursor, c);
+                        self.depends_cursor += 1;
+                    }
                     FormField::Priority => {
                         // Priority field uses index, handle separately
                         match c.to_ascii_uppercase() {
@@ -178,6 +351,12 @@ impl TaskForm {
                             self.due_input.remove(self.due_cursor);
                         }
                     }
+                    FormField::Depends => {
+                        if self.depends_cursor > 0 {
+                            self.depends_cursor -= 1;
+                            self.depends_input.remove(self.depends_cursor);
+                        }
+                    }
                     FormField::Priority => {
                         // Reset priority to None
                         self.priority_index = 0;
@@ -207,6 +386,11 @@ impl TaskForm {
                                 self.due_cursor -= 1;
                             }
                         }
+                        FormField::Depends => {
+                            if self.depends_cursor > 0 {
+                                self.depends_cursor -= 1;
+                            }
+                        }
                         FormField::Priority => {
                             // Priority doesn't use cursor
                         }
@@ -236,6 +420,11 @@ impl TaskForm {
                                 self.due_cursor += 1;
                             }
                         }
+                        FormField::Depends => {
+                            if self.depends_cursor < self.depends_input.len() {
+                                self.depends_cursor += 1;
+                            }
+                        }
                         FormField::Priority => {
                             // Priority doesn't use cursor
                         }
@@ -265,6 +454,10 @@ impl TaskForm {
                             self.due_input.insert(self.due_cursor, ' ');
                             self.due_cursor += 1;
                         }
+                        FormField::Depends => {
+                            self.depends_input.insert(self.depends_cursor, ' ');
+                            self.depends_cursor += 1;
+                        }
                         FormField::Priority => {
                             // Priority doesn't use text input
                         }
@@ -276,25 +469,71 @@ impl TaskForm {
         Ok(None)
     }
 
+    // Validate a field's input, recording the problem to show under it.
+    // Inputs left as the form opened with aren't checked, so editing a task
+    // whose project came from elsewhere doesn't force renaming it.
+    fn check_field(&mut self, field: FormField) -> bool {
+        match field {
+            FormField::Project => {
+                let project = self.project_input.trim();
+                self.project_error = (project != self.original_inputs.project.trim() && !project.is_empty())
+                    .then(|| validate_project_name(project).err())
+                    .flatten()
+                    .map(|e| e.to_string());
+                self.project_error.is_none()
+            }
+            FormField::Due => {
+                self.due_error = self.parse_due().err().map(|e| e.to_string());
+                self.due_error.is_none()
+            }
+            _ => true,
+        }
+    }
+
+    fn edit_field(&mut self, field: FormField) {
+        self.active_field = field;
+        self.set_cursor_to_end();
+        self.is_editing = true;
+    }
+
+    /// The due date to save: `None` when the field is empty or untouched.
+    /// Taskwarrior-style dates first, then full timestamps such as RFC 3339.
+    fn parse_due(&self) -> Result<Option<chrono::DateTime<Utc>>> {
+        let input = self.due_input.trim();
+        if self.due_input == self.original_inputs.due || input.is_empty() {
+            return Ok(None);
+        }
+        match Self::parse_taskwarrior_date(input) {
+            Some(due) => Ok(Some(due)),
+            None => parse_date(input).map(Some).map_err(|_| {
+                anyhow::anyhow!("Invalid due date \"{}\": try tomorrow, eow, 2w or YYYY-MM-DD", input)
+            }),
+        }
+    }
+
     fn next_field(&mut self) {
+        self.check_field(self.active_field.clone());
         self.active_field = match self.active_field {
             FormField::Description => FormField::Project,
             FormField::Project => FormField::Priority,
             FormField::Priority => FormField::Due,
             FormField::Due => FormField::Tags,
-            FormField::Tags => FormField::Description,
+            FormField::Tags => FormField::Depends,
+            FormField::Depends => FormField::Description,
         };
         // Set cursor to end of text for the new field
         self.set_cursor_to_end();
     }
 
     fn previous_field(&mut self) {
+        self.check_field(self.active_field.clone());
         self.active_field = match self.active_field {
-            FormField::Description => FormField::Tags,
+            FormField::Description => FormField::Depends,
             FormField::Project => FormField::Description,
             FormField::Priority => FormField::Project,
             FormField::Due => FormField::Priority,
             FormField::Tags => FormField::Due,
+            FormField::Depends => FormField::Tags,
         };
         // Set cursor to end of text for the new field
         self.set_cursor_to_end();
@@ -314,13 +553,16 @@ impl TaskForm {
             FormField::Due => {
                 self.due_cursor = self.due_input.len();
             }
+            FormField::Depends => {
+                self.depends_cursor = self.depends_input.len();
+            }
             FormField::Priority => {
                 // Priority doesn't use cursor
             }
         }
     }
 
-    fn build_task(&self) -> Task {
+    fn build_task(&self, tags: Vec<String>) -> Task {
         let mut task = self.task.clone();
         task.description = self.description_input.clone();
         task.project = if self.project_input.is_empty() {
@@ -336,54 +578,73 @@ impl TaskForm {
             _ => None,
         };
 
-        task.tags = if self.tags_input.trim().is_empty() {
-            Vec::new()
-        } else {
-            // Handle both space-separated and comma-separated tags
-            // Split on both whitespace and commas, then filter out empty strings
-            self.tags_input
-                .split(|c: char| c == ',' || c.is_whitespace())
-                .map(|s| s.trim())
-                .filter(|s| !s.is_empty())
-                .map(|s| s.to_string())
-                .collect()
-        };
+        task.tags = tags;
 
-        // Parse due date from due_input string using Taskwarrior date formats
-        if !self.due_input.trim().is_empty() {
-            if let Some(parsed_date) = Self::parse_taskwarrior_date(&self.due_input) {
-                task.due = Some(parsed_date);
-            }
-            // If parsing fails, due remains None (could add error handling here)
+        task.depends = self.parse_depends();
+
+        // An untouched field keeps the original due, including seconds the form doesn't show.
+        // The input was checked before saving, so a parse failure can't happen here.
+        if let Ok(Some(due)) = self.parse_due() {
+            task.due = Some(due);
         }
 
         task
     }
     
     /// Parse Taskwarrior date formats
-    /// Supports: today, tomorrow, eow, eom, eoy, sow, som, soy, 1d, 2w, 3mo, 1y, YYYY-MM-DD, MM/DD/YYYY, etc.
-    fn parse_taskwarrior_date(input: &str) -> Option<chrono::DateTime<Utc>> {
+    /// Supports: today, tomorrow, eow, eom, eoy, sow, som, soy, monday, fri, 1d, +2w, 3mo, 1y, YYYY-MM-DD, MM/DD/YYYY, etc.
+    /// Any of these may be followed by a time of day, e.g. "2025-10-15 17:00" or "tomorrow 9:30".
+    /// Dates and times are read on the clock they're shown with (local time, or UTC with `ui.use_utc`).
+    pub fn parse_taskwarrior_date(input: &str) -> Option<chrono::DateTime<Utc>> {
+        Self::parse_display_time(input).map(from_display_time)
+    }
+
+    fn parse_display_time(input: &str) -> Option<NaiveDateTime> {
+        let input = input.trim();
+        if let Some((date_part, time_part)) = input.rsplit_once(char::is_whitespace) {
+            if let Some(time) = Self::parse_time_of_day(time_part) {
+                let date = Self::parse_display_time(date_part)?;
+                return Some(date.date().and_time(time));
+            }
+        }
+        // ISO-style "2025-10-15T17:00"
+        if let Some((date_part, time_part)) = input.split_once('T') {
+            if let Some(time) = Self::parse_time_of_day(time_part) {
+                let date = NaiveDate::parse_from_str(date_part, "%Y-%m-%d").ok()?;
+                return Some(date.and_time(time));
+            }
+        }
+
+        Self::parse_date_only(input)
+    }
+
+    fn parse_time_of_day(input: &str) -> Option<NaiveTime> {
+        NaiveTime::parse_from_str(input, "%H:%M")
+            .or_else(|_| NaiveTime::parse_from_str(input, "%H:%M:%S"))
+            .ok()
+    }
+
+    fn parse_date_only(input: &str) -> Option<NaiveDateTime> {
         let input = input.trim().to_lowercase();
-        let now = Utc::now();
-        let today = now.date_naive();
+        let today = today();
         
         match input.as_str() {
             // Relative dates
             "today" => {
-                return Some(Utc.from_utc_datetime(&today.and_hms_opt(0, 0, 0)?));
+                today.and_hms_opt(0, 0, 0)
             }
             "tomorrow" | "tmr" => {
                 let tomorrow = today + Duration::days(1);
-                return Some(Utc.from_utc_datetime(&tomorrow.and_hms_opt(0, 0, 0)?));
+                tomorrow.and_hms_opt(0, 0, 0)
             }
             "yesterday" => {
                 let yesterday = today - Duration::days(1);
-                return Some(Utc.from_utc_datetime(&yesterday.and_hms_opt(0, 0, 0)?));
+                yesterday.and_hms_opt(0, 0, 0)
             }
             
             // End of periods
             "eod" => { // End of day (23:59:59 today)
-                return Some(Utc.from_utc_datetime(&today.and_hms_opt(23, 59, 59)?));
+                today.and_hms_opt(23, 59, 59)
             }
             "eow" => { // End of week (Sunday)
                 let days_until_sunday = (7 - today.weekday().number_from_monday()) % 7;
@@ -392,7 +653,7 @@ impl TaskForm {
                 } else {
                     today + Duration::days(days_until_sunday as i64)
                 };
-                return Some(Utc.from_utc_datetime(&eow.and_hms_opt(23, 59, 59)?));
+                eow.and_hms_opt(23, 59, 59)
             }
             "eom" => { // End of month
                 let year = today.year();
@@ -400,7 +661,7 @@ impl TaskForm {
                 let last_day = NaiveDate::from_ymd_opt(year, month + 1, 1)
                     .unwrap_or_else(|| NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap())
                     .pred_opt()?;
-                return Some(Utc.from_utc_datetime(&last_day.and_hms_opt(23, 59, 59)?));
+                last_day.and_hms_opt(23, 59, 59)
             }
             "eoq" => { // End of quarter
                 let year = today.year();
@@ -409,50 +670,56 @@ impl TaskForm {
                 let last_day = NaiveDate::from_ymd_opt(year, last_month_of_quarter + 1, 1)
                     .unwrap_or_else(|| NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap())
                     .pred_opt()?;
-                return Some(Utc.from_utc_datetime(&last_day.and_hms_opt(23, 59, 59)?));
+                last_day.and_hms_opt(23, 59, 59)
             }
             "eoy" => { // End of year
                 let eoy = NaiveDate::from_ymd_opt(today.year(), 12, 31)?;
-                return Some(Utc.from_utc_datetime(&eoy.and_hms_opt(23, 59, 59)?));
+                eoy.and_hms_opt(23, 59, 59)
             }
             
             // Start of periods
             "sow" | "bow" => { // Start/beginning of week (Monday)
                 let days_since_monday = today.weekday().number_from_monday() - 1;
                 let sow = today - Duration::days(days_since_monday as i64);
-                return Some(Utc.from_utc_datetime(&sow.and_hms_opt(0, 0, 0)?));
+                sow.and_hms_opt(0, 0, 0)
             }
             "som" | "bom" => { // Start/beginning of month
                 let som = NaiveDate::from_ymd_opt(today.year(), today.month(), 1)?;
-                return Some(Utc.from_utc_datetime(&som.and_hms_opt(0, 0, 0)?));
+                som.and_hms_opt(0, 0, 0)
             }
             "soq" | "boq" => { // Start/beginning of quarter
                 let quarter = (today.month() - 1) / 3;
                 let first_month_of_quarter = quarter * 3 + 1;
                 let soq = NaiveDate::from_ymd_opt(today.year(), first_month_of_quarter, 1)?;
-                return Some(Utc.from_utc_datetime(&soq.and_hms_opt(0, 0, 0)?));
+                soq.and_hms_opt(0, 0, 0)
             }
             "soy" | "boy" => { // Start/beginning of year
                 let soy = NaiveDate::from_ymd_opt(today.year(), 1, 1)?;
-                return Some(Utc.from_utc_datetime(&soy.and_hms_opt(0, 0, 0)?));
+                soy.and_hms_opt(0, 0, 0)
             }
             
             _ => {
                 // Try relative offsets like "1d", "2w", "3mo", "1y"
                 if let Some(duration) = Self::parse_duration(&input) {
                     let future = today + duration;
-                    return Some(Utc.from_utc_datetime(&future.and_hms_opt(0, 0, 0)?));
+                    return future.and_hms_opt(0, 0, 0);
+                }
+
+                // Weekdays ("monday", "fri") mean the next one after today, as in Taskwarrior
+                if let Ok(weekday) = input.parse::<Weekday>() {
+                    let day = Self::next_weekday(today, weekday);
+                    return day.and_hms_opt(0, 0, 0);
                 }
                 
                 // Try standard date formats
                 if let Ok(parsed_date) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
-                    return Some(Utc.from_utc_datetime(&parsed_date.and_hms_opt(0, 0, 0)?));
+                    return parsed_date.and_hms_opt(0, 0, 0);
                 }
                 if let Ok(parsed_date) = NaiveDate::parse_from_str(&input, "%m/%d/%Y") {
-                    return Some(Utc.from_utc_datetime(&parsed_date.and_hms_opt(0, 0, 0)?));
+                    return parsed_date.and_hms_opt(0, 0, 0);
                 }
                 if let Ok(parsed_date) = NaiveDate::parse_from_str(&input, "%d-%m-%Y") {
-                    return Some(Utc.from_utc_datetime(&parsed_date.and_hms_opt(0, 0, 0)?));
+                    return parsed_date.and_hms_opt(0, 0, 0);
                 }
                 
                 None
@@ -460,12 +727,18 @@ impl TaskForm {
         }
     }
     
+    /// The first `weekday` after `date`, a week on if `date` is one
+    pub fn next_weekday(date: NaiveDate, weekday: Weekday) -> NaiveDate {
+        let days = (weekday.num_days_from_monday() + 7 - date.weekday().num_days_from_monday()) % 7;
+        date + Duration::days(if days == 0 { 7 } else { days as i64 })
+    }
+
     /// Parse duration strings like "1d", "2w", "3mo", "1y"
     fn parse_duration(input: &str) -> Option<Duration> {
         let input = input.trim();
         
-        // Match patterns like "1d", "2w", "3mo", "1y"
-        if input.len() < 2 {
+        // Match patterns like "1d", "2w", "3mo", "1y" (ASCII only, so slicing is safe)
+        if input.len() < 2 || !input.is_ascii() {
             return None;
         }
         
@@ -539,6 +812,7 @@ impl TaskForm {
                 Constraint::Length(field_height), // Priority
                 Constraint::Length(field_height), // Due
                 Constraint::Length(field_height), // Tags
+                Constraint::Length(field_height), // Depends
                 instruction_space,                 // Instructions (responsive)
             ])
             .split(inner_area);
@@ -560,6 +834,7 @@ impl TaskForm {
             &self.project_input,
             matches!(self.active_field, FormField::Project),
         );
+        Self::render_field_error(f, chunks[1], self.project_error.as_deref());
 
         // Priority field
         let priority_text = match self.priority_index {
@@ -578,6 +853,7 @@ impl TaskForm {
 
         // Due field with hint
         self.render_due_field(f, chunks[3]);
+        Self::render_field_error(f, chunks[3], self.due_error.as_deref());
 
         // Tags field
         self.render_field(
@@ -588,9 +864,25 @@ impl TaskForm {
             matches!(self.active_field, FormField::Tags),
         );
 
-        // Instructions with enhanced cursor movement capabilities
+        // Depends field (task IDs)
+        self.render_field(
+            f,
+            chunks[5],
+            "Depends on:",
+            &self.depends_input,
+            matches!(self.active_field, FormField::Depends),
+        );
+
+        // Instructions with enhanced cursor movement capabilities; a refused save shows why
+        let first_line = match self.error {
+            Some(ref error) => Line::from(Span::styled(
+                error.clone(),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            None => Line::from(""),
+        };
         let instructions = Paragraph::new(vec![
-            Line::from(""),
+            first_line,
             Line::from(vec![
                 Span::styled("↑↓", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                 Span::styled(" Navigate fields  ", Style::default().fg(Color::White)),
@@ -610,7 +902,11 @@ impl TaskForm {
         ])
         .style(Style::default().bg(Color::Black))
         .alignment(Alignment::Center);
-        f.render_widget(instructions, chunks[5]);
+        f.render_widget(instructions, chunks[6]);
+
+        if let Some(ref picker) = self.date_picker {
+            picker.render(f, popup_area);
+        }
     }
 
     fn render_due_field(&self, f: &mut Frame, area: Rect) {
@@ -638,7 +934,7 @@ impl TaskForm {
         
         if is_active && self.is_editing {
             content_lines.push(String::new()); // Empty line
-            content_lines.push("  Examples: today, tomorrow, eow, eom, 1d, 2w, 3mo, YYYY-MM-DD".to_string());
+            content_lines.push("  Examples: today, tomorrow 9:30, eow, eom, 1d, 2w, 3mo, YYYY-MM-DD HH:MM · Ctrl+D calendar".to_string());
         }
         
         let content = content_lines.join("\n");
@@ -664,6 +960,25 @@ impl TaskForm {
         }
     }
 
+    // Over the field's bottom border, right under the input
+    fn render_field_error(f: &mut Frame, area: Rect, error: Option<&str>) {
+        let Some(error) = error else { return };
+        if area.height < 2 {
+            return;
+        }
+        let line = Rect {
+            x: area.x + 1,
+            y: area.y + area.height - 1,
+            width: area.width.saturating_sub(2),
+            height: 1,
+        };
+        f.render_widget(
+            Paragraph::new(format!(" {} ", error))
+                .style(Style::default().bg(Color::Black).fg(Color::Red).add_modifier(Modifier::BOLD)),
+            line,
+        );
+    }
+
     fn render_field(&self, f: &mut Frame, area: Rect, label: &str, value: &str, is_active: bool) {
         let (style, border_color) = if is_active && self.is_editing {
             (
@@ -708,6 +1023,7 @@ impl TaskForm {
             FormField::Project => self.project_cursor,
             FormField::Tags => self.tags_cursor,
             FormField::Due => self.due_cursor,
+            FormField::Depends => self.depends_cursor,
             FormField::Priority => 0, // Priority doesn't use cursor
         }
     }
@@ -1,37 +1,189 @@
 // Task display widget with clean, template-like table configuration and intelligent color coding
 
 use chrono::Utc;
+use std::collections::HashSet;
 use ratatui::{
-    layout::{Constraint, Rect},
+    layout::{Alignment, Constraint, Flex, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table, TableState, Wrap,
+    },
     Frame,
 };
 
-use crate::data::models::Task;
+use crate::config::RowColorConfig;
+use crate::data::models::{Annotation, Task};
+use crate::data::sort::{DueGroup, SortKey};
+use crate::ui::themes::TagColors;
+use crate::utils::formatting::{days_until, format_relative_duration, truncate_text, DateFormat};
 
 pub struct TaskListWidget {
+    // Selection over tasks; header rows never count
     pub state: TableState,
+    // Selection and scroll offset over rendered rows, including section headers
+    row_state: TableState,
     tasks: Vec<Task>,
+    formatter: TaskTableFormatter,
+    // Number of task rows that fit in the last rendered area (used for paging)
+    visible_rows: usize,
+    // Active search query, highlighted in matching cells
+    search_term: String,
+    // Insert due-date section headers; AppUI orders the tasks to match
+    grouped: bool,
+    // Shown in place of the rows when there are no tasks (None while nothing has loaded)
+    empty_message: Option<String>,
+
+    // The rest only show in the title; AppUI does the sorting and filtering they describe
+    sort_key: SortKey,
+    next_view: bool,
+    // Days covered by the recently completed view, when it's showing
+    recently_completed: Option<i64>,
+    // Blocked tasks are filtered out
+    unblocked_only: bool,
+    // Fuzzy search results are ranked by match score instead of the sort key
+    ranked_by_match: bool,
+    // Taskwarrior report the list is showing
+    report_name: Option<String>,
+    // Type-ahead find query, and whether it is still being typed
+    find: Option<(String, bool)>,
 }
 
 impl TaskListWidget {
-    pub fn new() -> Self {
+    pub fn new(columns: &[String]) -> Self {
         TaskListWidget {
             state: TableState::default(),
+            row_state: TableState::default(),
             tasks: Vec::new(),
+            formatter: TaskTableFormatter::new(columns),
+            visible_rows: 1,
+            sort_key: SortKey::Age,
+            search_term: String::new(),
+            next_view: false,
+            recently_completed: None,
+            unblocked_only: false,
+            ranked_by_match: false,
+            grouped: false,
+            empty_message: None,
+            report_name: None,
+            find: None,
         }
     }
 
+    pub fn set_find(&mut self, find: Option<(&str, bool)>) {
+        self.find = find.map(|(query, typing)| (query.to_string(), typing));
+    }
+
+    // Indices of the tasks whose description contains `query`, ignoring case
+    fn find_matches(&self, query: &str) -> Vec<usize> {
+        let query = query.to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        self.tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, task)| task.description.to_lowercase().contains(&query))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Select the next match after the selection (or before it, going backwards),
+    /// wrapping around the list. `include_current` lets the selected task count,
+    /// so typing another character stays put while it still matches. Without any
+    /// match the selection doesn't move; returns whether something matched.
+    pub fn select_match(&mut self, query: &str, forward: bool, include_current: bool) -> bool {
+        let matches = self.find_matches(query);
+        if matches.is_empty() {
+            return false;
+        }
+        let current = self.state.selected().unwrap_or(0);
+        let target = if forward {
+            matches
+                .iter()
+                .find(|&&i| i > current || (include_current && i == current))
+                .or(matches.first())
+        } else {
+            matches
+                .iter()
+                .rev()
+                .find(|&&i| i < current || (include_current && i == current))
+                .or(matches.last())
+        };
+        self.state.select(target.copied());
+        true
+    }
+
+    // Same names as `ui.task_list_columns`; unknown ones are skipped
+    pub fn set_columns(&mut self, columns: &[String]) {
+        self.formatter.columns = TaskTableFormatter::parse_columns(columns);
+    }
+
+    pub fn set_report_name(&mut self, name: Option<&str>) {
+        self.report_name = name.map(str::to_string);
+    }
+
+    pub fn set_grouped(&mut self, grouped: bool) {
+        self.grouped = grouped;
+    }
+
+    pub fn set_empty_message(&mut self, message: Option<String>) {
+        self.empty_message = message;
+    }
+
+    pub fn set_date_format(&mut self, dates: DateFormat) {
+        self.formatter.dates = dates;
+    }
+
+    pub fn set_tag_colors(&mut self, tag_colors: TagColors) {
+        self.formatter.tag_colors = tag_colors;
+    }
+
+    pub fn set_row_colors(&mut self, colors: RowColors) {
+        self.formatter.colors = colors;
+    }
+
+    pub fn set_search_term(&mut self, search_term: &str) {
+        self.search_term = search_term.trim().to_string();
+    }
+
+    pub fn set_sort_key(&mut self, sort_key: SortKey) {
+        self.sort_key = sort_key;
+    }
+
+    pub fn set_ranked_by_match(&mut self, ranked: bool) {
+        self.ranked_by_match = ranked;
+    }
+
+    pub fn set_next_view(&mut self, next_view: bool) {
+        self.next_view = next_view;
+    }
+
+    pub fn set_recently_completed(&mut self, days: Option<i64>) {
+        self.recently_completed = days;
+    }
+
+    pub fn set_unblocked_only(&mut self, unblocked_only: bool) {
+        self.unblocked_only = unblocked_only;
+    }
+
+    // Open tasks from the full task set, so rows can tell which dependencies still block
+    pub fn set_open_uuids(&mut self, open_uuids: HashSet<String>) {
+        self.formatter.open_uuids = open_uuids;
+    }
+
     pub fn set_tasks(&mut self, tasks: Vec<Task>) {
         self.tasks = tasks;
-        if !self.tasks.is_empty() {
+        if self.tasks.is_empty() {
+            self.state.select(None);
+        } else {
             self.state.select(Some(0));
         }
     }
 
     pub fn set_tasks_with_preserved_selection(&mut self, tasks: Vec<Task>, preserve_uuid: Option<&str>) {
-        self.tasks = tasks;
+        let previous_index = self.state.selected();
+        let previous_tasks = std::mem::replace(&mut self.tasks, tasks);
         
         if self.tasks.is_empty() {
             self.state.select(None);
@@ -40,16 +192,33 @@ impl TaskListWidget {
 
         // If we have a UUID to preserve, try to find and select that task
         if let Some(uuid) = preserve_uuid {
-            for (index, task) in self.tasks.iter().enumerate() {
-                if task.uuid == uuid {
+            if let Some(index) = self.position_of(uuid) {
+                self.state.select(Some(index));
+                return;
+            }
+
+            // The task is gone (completed elsewhere, deleted, filtered out): take its
+            // closest neighbour in the old order that is still listed
+            if let Some(old_index) = previous_tasks.iter().position(|task| task.uuid == uuid) {
+                let neighbour = (1..previous_tasks.len())
+                    .flat_map(|distance| [old_index.checked_add(distance), old_index.checked_sub(distance)])
+                    .flatten()
+                    .filter_map(|i| previous_tasks.get(i))
+                    .find_map(|task| self.position_of(&task.uuid));
+                if let Some(index) = neighbour {
                     self.state.select(Some(index));
                     return;
                 }
             }
         }
         
-        // Fallback to first task if UUID not found or not provided
-        self.state.select(Some(0));
+        // Nothing to anchor on: stay at the same position instead of jumping to the top
+        let index = previous_index.unwrap_or(0).min(self.tasks.len() - 1);
+        self.state.select(Some(index));
+    }
+
+    fn position_of(&self, uuid: &str) -> Option<usize> {
+        self.tasks.iter().position(|task| task.uuid == uuid)
     }
 
     pub fn selected_task_uuid(&self) -> Option<String> {
@@ -57,6 +226,11 @@ impl TaskListWidget {
     }
 
     pub fn next(&mut self) {
+        // Nothing to select, e.g. when filters exclude every task
+        if self.tasks.is_empty() {
+            self.state.select(None);
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i >= self.tasks.len() - 1 {
@@ -71,6 +245,10 @@ impl TaskListWidget {
     }
 
     pub fn previous(&mut self) {
+        if self.tasks.is_empty() {
+            self.state.select(None);
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
@@ -84,6 +262,35 @@ impl TaskListWidget {
         self.state.select(Some(i));
     }
 
+    pub fn select_first(&mut self) {
+        if !self.tasks.is_empty() {
+            self.state.select(Some(0));
+        }
+    }
+
+    pub fn select_last(&mut self) {
+        if !self.tasks.is_empty() {
+            self.state.select(Some(self.tasks.len() - 1));
+        }
+    }
+
+    pub fn page_up(&mut self) {
+        if self.tasks.is_empty() {
+            return;
+        }
+        let current = self.state.selected().unwrap_or(0);
+        self.state.select(Some(current.saturating_sub(self.visible_rows)));
+    }
+
+    pub fn page_down(&mut self) {
+        if self.tasks.is_empty() {
+            return;
+        }
+        let current = self.state.selected().unwrap_or(0);
+        let last = self.tasks.len() - 1;
+        self.state.select(Some((current + self.visible_rows).min(last)));
+    }
+
     pub fn selected_task(&self) -> Option<&Task> {
         if let Some(index) = self.state.selected() {
             self.tasks.get(index)
@@ -93,28 +300,100 @@ impl TaskListWidget {
     }
 
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
-        let formatter = TaskTableFormatter::new();
-        
+        // Remember how many rows fit (minus borders and header) for page navigation
+        self.visible_rows = (area.height.saturating_sub(3) as usize).max(1);
+
+        // Use responsive column widths based on terminal size, and fit descriptions
+        // to the width the Description column actually gets
+        let column_widths = self.formatter.responsive_column_widths(area.width);
+        self.formatter.description_width = TaskTableFormatter::description_width(&column_widths, &self.formatter.columns, area.width);
+        let formatter = &self.formatter;
+
         // Create clean, minimal headers
         let header_cells = formatter.headers()
-            .iter()
-            .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)))
+            .into_iter()
+            .map(|h| Cell::from(h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)))
             .collect::<Vec<_>>();
 
         let header = Row::new(header_cells)
             .style(Style::default().bg(Color::DarkGray))
             .height(1);
 
-        // Create data rows with intelligent color coding
-        let rows: Vec<Row> = self.tasks
-            .iter()
-            .map(|task| formatter.format_task_row(task))
-            .collect();
+        // Create data rows with intelligent color coding, plus section headers when grouped
+        let mut rows: Vec<Row> = Vec::with_capacity(self.tasks.len());
+        let mut task_rows: Vec<usize> = Vec::with_capacity(self.tasks.len());
+        let now = Utc::now();
+        let mut current_group = None;
+        for task in &self.tasks {
+            if self.grouped {
+                let group = DueGroup::of(task, now);
+                if current_group != Some(group) {
+                    current_group = Some(group);
+                    rows.push(formatter.format_group_row(group, &self.tasks, now));
+                }
+            }
+            task_rows.push(rows.len());
+            rows.push(formatter.format_task_row(task, &self.search_term));
+        }
 
-        // Use responsive column widths based on terminal size
-        let column_widths = formatter.responsive_column_widths(area.width);
         let task_count = self.tasks.len();
-        let title = format!(" Tasks ({}) ", task_count);
+        let row_count = rows.len();
+        let visible_rows = self.visible_rows;
+        let overflows = row_count > visible_rows;
+        let selected_row = self.state.selected().and_then(|i| task_rows.get(i).copied());
+
+        // Scroll the same way the table will (just enough to keep the selection visible),
+        // so the title can show the range before the table is rendered
+        let mut offset = self.row_state.offset();
+        if let Some(selected) = selected_row {
+            // Keep a section header in view together with its first task
+            let top = if self.grouped && selected > 0 && !task_rows.contains(&(selected - 1)) {
+                selected - 1
+            } else {
+                selected
+            };
+            if top < offset {
+                offset = top;
+            } else if selected >= offset + visible_rows {
+                offset = selected + 1 - visible_rows;
+            }
+        }
+        offset = offset.min(row_count.saturating_sub(visible_rows));
+        self.row_state.select(selected_row);
+        *self.row_state.offset_mut() = offset;
+
+        let count = if overflows {
+            let first = task_rows.iter().filter(|&&row| row < offset).count() + 1;
+            let last = task_rows.iter().filter(|&&row| row < offset + visible_rows).count();
+            format!("{}–{} of {}", first, last, task_count)
+        } else {
+            task_count.to_string()
+        };
+        let title = if self.next_view {
+            format!(" Next ({}) · {} ", count, SortKey::Urgency.label())
+        } else if let Some(days) = self.recently_completed {
+            format!(" Completed in the last {}d ({}) · Newest first ", days, count)
+        } else {
+            let order = if self.ranked_by_match { "Best match" } else { self.sort_key.label() };
+            let unblocked = if self.unblocked_only { " · Unblocked" } else { "" };
+            let name = self.report_name.as_deref().unwrap_or("Tasks");
+            format!(" {} ({}) · {}{} ", name, count, order, unblocked)
+        };
+        let title = match self.find {
+            Some((ref query, typing)) => {
+                let matches = self.find_matches(query);
+                let position = self.state.selected().and_then(|i| matches.iter().position(|&m| m == i));
+                let found = match (matches.len(), position) {
+                    (0, _) if query.is_empty() => String::new(),
+                    (0, _) => " (no match)".to_string(),
+                    (total, Some(position)) => format!(" {}/{}", position + 1, total),
+                    (total, None) => format!(" {} matches", total),
+                };
+                let cursor = if typing { "█" } else { "" };
+                format!("{}· Find: {}{}{} ", title, query, cursor, found)
+            }
+            None => title,
+        };
         
         let table = Table::new(rows, &column_widths)
             .header(header)
@@ -123,7 +402,7 @@ impl TaskListWidget {
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(Color::Cyan))
             )
-            .column_spacing(2)  // Clean spacing between columns
+            .column_spacing(COLUMN_SPACING)  // Clean spacing between columns
             .style(Style::default().fg(Color::White))
             .row_highlight_style(
                 Style::default()
@@ -133,128 +412,441 @@ impl TaskListWidget {
                     .add_modifier(Modifier::REVERSED),
             );
 
-        f.render_stateful_widget(table, area, &mut self.state);
+        f.render_stateful_widget(table, area, &mut self.row_state);
+
+        // Explain an empty list below the header instead of leaving a blank table
+        if let (true, Some(message)) = (self.tasks.is_empty(), &self.empty_message) {
+            let inner = area.inner(Margin { vertical: 1, horizontal: 1 });
+            let message_area = Rect {
+                y: inner.y + 2,
+                height: inner.height.saturating_sub(2),
+                ..inner
+            };
+            let placeholder = Paragraph::new(message.as_str())
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true });
+            f.render_widget(placeholder, message_area);
+        }
+
+        // Scrollbar on the right border, only when some tasks are out of view
+        if overflows {
+            let mut scrollbar_state = ScrollbarState::new(row_count)
+                .viewport_content_length(visible_rows)
+                .position(selected_row.unwrap_or(offset));
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .track_symbol(Some("│"))
+                .thumb_style(Style::default().fg(Color::Cyan))
+                .track_style(Style::default().fg(Color::DarkGray));
+            f.render_stateful_widget(
+                scrollbar,
+                area.inner(Margin { vertical: 1, horizontal: 0 }),
+                &mut scrollbar_state,
+            );
+        }
+    }
+}
+
+// Columns that can be listed in `ui.task_list_columns`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TaskColumn {
+    Id,
+    Project,
+    Priority,
+    Due,
+    Description,
+    Urgency,
+    Tags,
+    Status,
+    Age,
+    Modified,
+    End,
+    Annotations,
+}
+
+impl TaskColumn {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "id" => Some(TaskColumn::Id),
+            "project" => Some(TaskColumn::Project),
+            "priority" => Some(TaskColumn::Priority),
+            "due" => Some(TaskColumn::Due),
+            "description" => Some(TaskColumn::Description),
+            "urgency" => Some(TaskColumn::Urgency),
+            "tags" => Some(TaskColumn::Tags),
+            "status" => Some(TaskColumn::Status),
+            "age" => Some(TaskColumn::Age),
+            "modified" => Some(TaskColumn::Modified),
+            "end" | "completed" => Some(TaskColumn::End),
+            "annotations" | "notes" => Some(TaskColumn::Annotations),
+            _ => None,
+        }
+    }
+
+    fn header(&self) -> &'static str {
+        match self {
+            TaskColumn::Id => "ID",
+            TaskColumn::Project => "Project",
+            TaskColumn::Priority => "Priority",
+            TaskColumn::Due => "Due",
+            TaskColumn::Description => "Description",
+            TaskColumn::Urgency => "Urg",
+            TaskColumn::Tags => "Tags",
+            TaskColumn::Status => "St",
+            TaskColumn::Age => "Age",
+            TaskColumn::Modified => "Mod",
+            TaskColumn::End => "Completed",
+            TaskColumn::Annotations => "Ann",
+        }
+    }
+}
+
+// Row coloring rules from `ui.row_colors`, with the colors parsed once.
+// None means the rule is turned off.
+#[derive(Debug, Clone)]
+pub struct RowColors {
+    enabled: bool,
+    completed: Option<Color>,
+    overdue: Option<Color>,
+    due_today: Option<Color>,
+    high_priority_due_soon: Option<Color>,
+    high_priority_due_days: i64,
+    due_tomorrow: Option<Color>,
+    blocked: Option<Color>,
+    high_priority: Option<Color>,
+    medium_priority: Option<Color>,
+    low_priority: Option<Color>,
+    high_urgency: Option<Color>,
+    urgency_threshold: f64,
+    default: Color,
+}
+
+impl RowColors {
+    // Colors should already have been checked by RowColorConfig::check_colors;
+    // anything unparsable is treated as a disabled rule
+    pub fn new(config: &RowColorConfig) -> Self {
+        let color = |value: &str| RowColorConfig::parse_color(value).ok().flatten();
+        RowColors {
+            enabled: config.enabled,
+            completed: color(&config.completed),
+            overdue: color(&config.overdue),
+            due_today: color(&config.due_today),
+            high_priority_due_soon: color(&config.high_priority_due_soon),
+            high_priority_due_days: config.high_priority_due_days,
+            due_tomorrow: color(&config.due_tomorrow),
+            blocked: color(&config.blocked),
+            high_priority: color(&config.high_priority),
+            medium_priority: color(&config.medium_priority),
+            low_priority: color(&config.low_priority),
+            high_urgency: color(&config.high_urgency),
+            urgency_threshold: config.urgency_threshold,
+            default: color(&config.default).unwrap_or(Color::White),
+        }
+    }
+}
+
+impl Default for RowColors {
+    fn default() -> Self {
+        RowColors::new(&RowColorConfig::default())
+    }
+}
+
+// A piece of the tags cell; the color doesn't replace a search highlight's
+fn tag_span(text: String, style: Style, color: Option<Color>) -> Span<'static> {
+    match color {
+        Some(color) if style.fg.is_none() => Span::styled(text, style.fg(color)),
+        _ => Span::styled(text, style),
     }
 }
 
+// Split text into spans, styling case-insensitive occurrences of `query`
+fn highlight_matches(text: String, query: &str) -> Line<'static> {
+    let fold = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let query: Vec<char> = query.chars().map(fold).collect();
+    if query.is_empty() {
+        return Line::from(text);
+    }
+
+    let highlight = Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let chars: Vec<char> = text.chars().collect();
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let is_match = i + query.len() <= chars.len()
+            && chars[i..i + query.len()].iter().zip(&query).all(|(&c, &q)| fold(c) == q);
+        if is_match {
+            if !plain.is_empty() {
+                spans.push(Span::raw(std::mem::take(&mut plain)));
+            }
+            spans.push(Span::styled(chars[i..i + query.len()].iter().collect::<String>(), highlight));
+            i += query.len();
+        } else {
+            plain.push(chars[i]);
+            i += 1;
+        }
+    }
+    if !plain.is_empty() {
+        spans.push(Span::raw(plain));
+    }
+
+    Line::from(spans)
+}
+
+// Gap between table columns
+const COLUMN_SPACING: u16 = 2;
+
 // Clean, template-like table configuration with intelligent color coding
-struct TaskTableFormatter;
+struct TaskTableFormatter {
+    columns: Vec<TaskColumn>,
+    // Characters of description that fit, set from the column width on each render
+    description_width: usize,
+    dates: DateFormat,
+    colors: RowColors,
+    tag_colors: TagColors,
+    // UUIDs of pending and waiting tasks; a dependency on any of them blocks a row
+    open_uuids: HashSet<String>,
+}
 
 impl TaskTableFormatter {
-    fn new() -> Self {
-        TaskTableFormatter
+    fn new(column_names: &[String]) -> Self {
+        TaskTableFormatter {
+            columns: Self::parse_columns(column_names),
+            description_width: 45,
+            dates: DateFormat::default(),
+            colors: RowColors::default(),
+            tag_colors: TagColors::default(),
+            open_uuids: HashSet::new(),
+        }
+    }
+
+    fn parse_columns(column_names: &[String]) -> Vec<TaskColumn> {
+        let mut columns: Vec<TaskColumn> = column_names
+            .iter()
+            .filter_map(|name| TaskColumn::from_name(name))
+            .collect();
+
+        // Fall back to the default layout if nothing usable was configured
+        if columns.is_empty() {
+            columns = vec![
+                TaskColumn::Id,
+                TaskColumn::Project,
+                TaskColumn::Priority,
+                TaskColumn::Due,
+                TaskColumn::Description,
+            ];
+        }
+        columns
     }
     
-    // Define column headers - simplified, clean layout
-    fn headers(&self) -> [&'static str; 5] {
-        ["ID", "Project", "Priority", "Due", "Description"]
+    // Define column headers from the configured column list
+    fn headers(&self) -> Vec<&'static str> {
+        self.columns.iter().map(|c| c.header()).collect()
     }
     
     // Define responsive column widths that adapt to terminal size
     fn responsive_column_widths(&self, terminal_width: u16) -> Vec<Constraint> {
-        if terminal_width < 80 {
-            // Very narrow terminal - minimize columns, focus on description
-            vec![
-                Constraint::Length(3),   // ID - minimal
-                Constraint::Length(8),   // Project - abbreviated
-                Constraint::Length(4),   // Priority - single char (H/M/L)
-                Constraint::Length(8),   // Due - short date
-                Constraint::Min(20),     // Description - rest of space
-            ]
+        // Breakpoints: very narrow (<80), narrow (<120), medium (<160), wide
+        let size = if terminal_width < 80 {
+            0
         } else if terminal_width < 120 {
-            // Narrow terminal - compact but readable
-            vec![
-                Constraint::Length(4),   // ID
-                Constraint::Length(12),  // Project
-                Constraint::Length(8),   // Priority
-                Constraint::Length(10),  // Due
-                Constraint::Min(30),     // Description - grows with available space
-            ]
+            1
         } else if terminal_width < 160 {
-            // Medium terminal - balanced layout
-            vec![
-                Constraint::Length(4),   // ID
-                Constraint::Length(15),  // Project
-                Constraint::Length(10),  // Priority
-                Constraint::Length(12),  // Due
-                Constraint::Min(40),     // Description
-            ]
+            2
         } else {
-            // Wide terminal - generous spacing
-            vec![
-                Constraint::Length(5),   // ID
-                Constraint::Length(20),  // Project - more space
-                Constraint::Length(10),  // Priority
-                Constraint::Length(14),  // Due - full datetime if needed
-                Constraint::Min(50),     // Description - maximum space
-            ]
-        }
+            3
+        };
+
+        self.columns
+            .iter()
+            .map(|column| match column {
+                TaskColumn::Id => Constraint::Length([3, 4, 4, 5][size]),
+                TaskColumn::Project => Constraint::Length([8, 12, 15, 20][size]),
+                TaskColumn::Priority => Constraint::Length([4, 8, 10, 10][size]),
+                TaskColumn::Due => Constraint::Length([8, 10, 12, 14][size]),
+                TaskColumn::Description => Constraint::Min([20, 30, 40, 50][size]),
+                TaskColumn::Urgency => Constraint::Length([4, 5, 5, 6][size]),
+                TaskColumn::Tags => Constraint::Length([7, 8, 10, 12][size]),
+                TaskColumn::Status => Constraint::Length(2),
+                TaskColumn::Age | TaskColumn::Modified => Constraint::Length([4, 4, 5, 5][size]),
+                TaskColumn::End => Constraint::Length([8, 10, 12, 14][size]),
+                TaskColumn::Annotations => Constraint::Length(4),
+            })
+            .collect()
     }
     
+    // Width the table will give the Description column inside a bordered `area_width`,
+    // resolved the way ratatui's Table lays out its columns
+    fn description_width(column_widths: &[Constraint], columns: &[TaskColumn], area_width: u16) -> usize {
+        let Some(index) = columns.iter().position(|column| *column == TaskColumn::Description) else {
+            return 0;
+        };
+        let rects = Layout::horizontal(column_widths.iter().copied())
+            .flex(Flex::Start)
+            .spacing(COLUMN_SPACING)
+            .split(Rect::new(0, 0, area_width.saturating_sub(2), 1));
+        rects[index].width as usize
+    }
+
+    // Section header: the label sits in the Description column (or the first one)
+    fn format_group_row(&self, group: DueGroup, tasks: &[Task], now: chrono::DateTime<Utc>) -> Row<'static> {
+        let count = tasks.iter().filter(|task| DueGroup::of(task, now) == group).count();
+        let label_column = self.columns.iter()
+            .position(|column| *column == TaskColumn::Description)
+            .unwrap_or(0);
+        let cells = (0..self.columns.len()).map(|i| {
+            if i == label_column {
+                Cell::from(format!("▸ {} ({})", group.label(), count))
+            } else {
+                Cell::from("")
+            }
+        });
+
+        let color = match group {
+            DueGroup::Overdue => Color::Red,
+            DueGroup::Today => Color::Yellow,
+            _ => Color::Cyan,
+        };
+        Row::new(cells).style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+    }
+
     // Format a complete task row with intelligent row-level color coding
-    fn format_task_row(&self, task: &Task) -> Row {
+    fn format_task_row(&self, task: &Task, search_term: &str) -> Row<'static> {
         // Determine the most important styling factor for the entire row
         let row_style = self.get_row_style(task);
         
-        let cells = vec![
-            Cell::from(self.format_id(task.id)),
-            Cell::from(self.format_project(&task.project)),
-            Cell::from(self.format_priority_full(&task.priority)),
-            Cell::from(self.format_due(task.due)),
-            Cell::from(self.format_description(&task.description)),
-        ];
+        let cells: Vec<Cell> = self.columns
+            .iter()
+            .map(|column| {
+                let text = self.format_cell(*column, task);
+                // Highlight the search match in the columns search looks at
+                let query = match column {
+                    TaskColumn::Description | TaskColumn::Project => search_term,
+                    TaskColumn::Tags => search_term.strip_prefix('+').unwrap_or(search_term),
+                    _ => "",
+                };
+                let line = highlight_matches(text, query);
+                if *column == TaskColumn::Tags {
+                    Cell::from(self.color_tags(line, &task.tags))
+                } else {
+                    Cell::from(line)
+                }
+            })
+            .collect();
         Row::new(cells).height(1).style(row_style)
     }
+
+    // Give tags with a configured color that color in the (possibly truncated) "a,b,c"
+    // text. Other tags and the separators keep the row color, and search highlights win.
+    fn color_tags(&self, line: Line<'static>, tags: &[String]) -> Line<'static> {
+        if tags.iter().all(|tag| self.tag_colors.get(tag).is_none()) {
+            return line;
+        }
+
+        let mut char_colors: Vec<Option<Color>> = Vec::new();
+        for (i, tag) in tags.iter().enumerate() {
+            if i > 0 {
+                char_colors.push(None);
+            }
+            char_colors.extend(std::iter::repeat_n(self.tag_colors.get(tag), tag.chars().count()));
+        }
+        // Truncation keeps the start and ends in "..."
+        let shown = line.width();
+        if shown < char_colors.len() {
+            char_colors.truncate(shown.saturating_sub(3));
+        }
+
+        let mut index = 0;
+        let mut spans = Vec::new();
+        for span in line.spans {
+            let mut run = String::new();
+            let mut run_color = None;
+            for c in span.content.chars() {
+                let color = char_colors.get(index).copied().flatten();
+                index += 1;
+                if color != run_color && !run.is_empty() {
+                    spans.push(tag_span(std::mem::take(&mut run), span.style, run_color));
+                }
+                run_color = color;
+                run.push(c);
+            }
+            if !run.is_empty() {
+                spans.push(tag_span(run, span.style, run_color));
+            }
+        }
+        Line::from(spans)
+    }
+
+    fn format_cell(&self, column: TaskColumn, task: &Task) -> String {
+        match column {
+            TaskColumn::Id => self.format_id(task.id),
+            TaskColumn::Project => self.format_project(&task.project),
+            TaskColumn::Priority => self.format_priority_full(&task.priority),
+            TaskColumn::Due => self.format_due(task.due),
+            TaskColumn::Description => self.format_description(&task.description),
+            TaskColumn::Urgency => self.format_urgency(task.urgency),
+            TaskColumn::Tags => self.format_tags(&task.tags),
+            TaskColumn::Status => self.format_status(&task.status),
+            TaskColumn::Age => self.format_since(Some(task.entry)),
+            TaskColumn::Modified => self.format_since(task.modified),
+            TaskColumn::End => self.format_end(task.end),
+            TaskColumn::Annotations => self.format_annotations(&task.annotations),
+        }
+    }
     
     // ===== INTELLIGENT ROW-LEVEL COLOR CODING SYSTEM =====
     
     // Get overall row style based on intelligent task priority hierarchy  
     fn get_row_style(&self, task: &Task) -> Style {
-        // Intelligent priority hierarchy combining multiple factors:
-        // 1. High priority + overdue/due soon = CRITICAL RED BOLD
-        // 2. Any overdue tasks = URGENT RED BOLD  
-        // 3. High priority + due within 2 days = URGENT RED BOLD
-        // 4. Due today/tomorrow = URGENT YELLOW BOLD
-        // 5. High priority tasks = RED
-        // 6. Medium priority tasks = YELLOW
-        // 7. Completed tasks = DIMMED GRAY
-        // 8. Low priority tasks = GREEN
-        // 9. Default/no priority tasks = WHITE
-        
-        let is_high_priority = task.priority == Some(crate::data::models::Priority::High);
-        let is_overdue = self.is_overdue(task.due);
-        let is_due_today = self.is_due_today(task.due);
-        let is_due_within_2_days = self.is_due_within_days(task.due, 2);
-        let is_due_tomorrow = self.is_due_tomorrow(task.due);
-        
-        if is_overdue || is_due_today || (is_high_priority && is_due_within_2_days) {
-            // CRITICAL RED: 
-            // - All overdue tasks (regardless of priority)
-            // - All tasks due today (regardless of priority) 
-            // - High priority tasks due within 2 days
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
-        } else if is_due_tomorrow {
-            // URGENT YELLOW: Due tomorrow = high urgency  
-            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-        } else if is_high_priority {
-            // HIGH PRIORITY - Important but not time-critical
-            Style::default().fg(Color::Red)
-        } else if task.priority == Some(crate::data::models::Priority::Medium) {
-            // MEDIUM PRIORITY - Moderate importance
-            Style::default().fg(Color::Yellow)
-        } else if task.status == crate::data::models::TaskStatus::Completed {
-            // COMPLETED - Dimmed
-            Style::default().fg(Color::DarkGray)
-        } else if task.priority == Some(crate::data::models::Priority::Low) {
-            // LOW PRIORITY - Less urgent
-            Style::default().fg(Color::Green)
-        } else if task.urgency >= 10.0 {
-            // HIGH URGENCY (calculated, without explicit priority) 
-            Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
-        } else {
-            // DEFAULT - Normal tasks
-            Style::default().fg(Color::White)
+        // Intelligent priority hierarchy combining multiple factors, in `ui.row_colors` order:
+        // 1. Completed tasks = DIMMED GRAY (finished work isn't urgent, however overdue)
+        // 2. Any overdue tasks = URGENT RED BOLD
+        // 3. Due today = URGENT RED BOLD
+        // 4. High priority + due within 2 days = URGENT RED BOLD
+        // 5. Due tomorrow = URGENT YELLOW BOLD
+        // 6. Blocked by an open dependency = DIMMED GRAY
+        // 7. High priority tasks = RED
+        // 8. Medium priority tasks = YELLOW
+        // 9. Low priority tasks = GREEN
+        // 10. Urgency >= 10 without a matching rule = WHITE BOLD
+        // 11. Default/no priority tasks = WHITE
+        // Colors and thresholds come from config; a rule without a color is skipped.
+        let colors = &self.colors;
+        let default = Style::default().fg(colors.default);
+        if !colors.enabled {
+            return default;
         }
+
+        use crate::data::models::{Priority, TaskStatus};
+        let is_high_priority = task.priority == Some(Priority::High);
+        let rules = [
+            (task.status == TaskStatus::Completed, colors.completed, false),
+            (self.is_overdue(task.due), colors.overdue, true),
+            (self.is_due_today(task.due), colors.due_today, true),
+            (
+                is_high_priority && self.is_due_within_days(task.due, colors.high_priority_due_days),
+                colors.high_priority_due_soon,
+                true,
+            ),
+            (self.is_due_tomorrow(task.due), colors.due_tomorrow, true),
+            (task.is_blocked_by(&self.open_uuids), colors.blocked, false),
+            (is_high_priority, colors.high_priority, false),
+            (task.priority == Some(Priority::Medium), colors.medium_priority, false),
+            (task.priority == Some(Priority::Low), colors.low_priority, false),
+            (task.urgency >= colors.urgency_threshold, colors.high_urgency, true),
+        ];
+
+        rules
+            .into_iter()
+            .find_map(|(applies, color, bold)| {
+                let color = color.filter(|_| applies)?;
+                let style = Style::default().fg(color);
+                Some(if bold { style.add_modifier(Modifier::BOLD) } else { style })
+            })
+            .unwrap_or(default)
     }
     
     // Helper method to check if task is due soon (today/tomorrow)
@@ -265,8 +857,7 @@ impl TaskTableFormatter {
     // Helper method to check if task is due within N days
     fn is_due_within_days(&self, due: Option<chrono::DateTime<Utc>>, days: i64) -> bool {
         if let Some(due_date) = due {
-            let now = Utc::now();
-            let days_until_due = (due_date.date_naive() - now.date_naive()).num_days();
+            let days_until_due = days_until(&due_date);
             days_until_due >= 0 && days_until_due <= days
         } else {
             false
@@ -276,8 +867,7 @@ impl TaskTableFormatter {
     // Helper method to check if task is due today specifically
     fn is_due_today(&self, due: Option<chrono::DateTime<Utc>>) -> bool {
         if let Some(due_date) = due {
-            let now = Utc::now();
-            let days_until_due = (due_date.date_naive() - now.date_naive()).num_days();
+            let days_until_due = days_until(&due_date);
             days_until_due == 0 // Exactly today
         } else {
             false
@@ -287,8 +877,7 @@ impl TaskTableFormatter {
     // Helper method to check if task is due tomorrow specifically
     fn is_due_tomorrow(&self, due: Option<chrono::DateTime<Utc>>) -> bool {
         if let Some(due_date) = due {
-            let now = Utc::now();
-            let days_until_due = (due_date.date_naive() - now.date_naive()).num_days();
+            let days_until_due = days_until(&due_date);
             days_until_due == 1 // Exactly tomorrow
         } else {
             false
@@ -298,8 +887,7 @@ impl TaskTableFormatter {
     // Helper method to check if task is overdue
     fn is_overdue(&self, due: Option<chrono::DateTime<Utc>>) -> bool {
         if let Some(due_date) = due {
-            let now = Utc::now();
-            let days_until_due = (due_date.date_naive() - now.date_naive()).num_days();
+            let days_until_due = days_until(&due_date);
             days_until_due < 0 // Past due date
         } else {
             false
@@ -312,26 +900,9 @@ impl TaskTableFormatter {
         id.map(|i| i.to_string()).unwrap_or_else(|| "".to_string())
     }
     
-    fn format_age(&self, entry: chrono::DateTime<Utc>) -> String {
-        let now = Utc::now();
-        let duration = now - entry;
-        
-        if duration.num_minutes() < 60 {
-            format!("{}m", duration.num_minutes().max(1))
-        } else if duration.num_hours() < 24 {
-            format!("{}h", duration.num_hours())
-        } else if duration.num_days() < 30 {
-            format!("{}d", duration.num_days())
-        } else if duration.num_days() < 365 {
-            let weeks = duration.num_days() / 7;
-            if weeks < 10 {
-                format!("{}w", weeks)
-            } else {
-                format!("{}mo", duration.num_days() / 30)
-            }
-        } else {
-            format!("{}y", duration.num_days() / 365)
-        }
+    // Time since `time`, blank when the task doesn't have one
+    fn format_since(&self, time: Option<chrono::DateTime<Utc>>) -> String {
+        time.map(|time| format_relative_duration(Utc::now() - time)).unwrap_or_default()
     }
     
     fn format_status(&self, status: &crate::data::models::TaskStatus) -> String {
@@ -361,7 +932,7 @@ impl TaskTableFormatter {
     
     fn format_project(&self, project: &Option<String>) -> String {
         project.as_deref()
-            .map(|p| if p.len() > 14 { format!("{}...", &p[..11]) } else { p.to_string() })
+            .map(|p| truncate_text(p, 14))
             .unwrap_or_else(|| "".to_string())
     }
     
@@ -369,41 +940,42 @@ impl TaskTableFormatter {
         if tags.is_empty() {
             "".to_string()
         } else {
-            let joined = tags.join(",");
-            if joined.len() > 7 { 
-                format!("{}...", &joined[..4])
-            } else { 
-                joined 
-            }
+            truncate_text(&tags.join(","), 7)
         }
     }
     
     fn format_due(&self, due: Option<chrono::DateTime<Utc>>) -> String {
         if let Some(due) = due {
-            let now = Utc::now();
-            let days_until_due = (due.date_naive() - now.date_naive()).num_days();
+            let days_until_due = days_until(&due);
             
             if days_until_due < 0 {
                 format!("{}d", days_until_due)
             } else if days_until_due <= 7 {
                 format!("{}d", days_until_due)  
             } else {
-                due.format("%m/%d").to_string()
+                self.dates.short_date(&due)
             }
         } else {
             "".to_string()
         }
     }
     
+    fn format_end(&self, end: Option<chrono::DateTime<Utc>>) -> String {
+        end.map(|end| self.dates.short_date(&end)).unwrap_or_default()
+    }
+    
     fn format_description(&self, description: &str) -> String {
-        // Maximum space for description in simplified layout - up to 45+ characters!
-        if description.len() > 45 {
-            format!("{}...", &description[..42])
-        } else {
-            description.to_string()
-        }
+        truncate_text(description, self.description_width)
     }
     
+    // "(2)" for a task with two annotations, blank when there are none
+    fn format_annotations(&self, annotations: &[Annotation]) -> String {
+        match annotations.len() {
+            0 => String::new(),
+            count => format!("({})", count),
+        }
+    }
+
     fn format_urgency(&self, urgency: f64) -> String {
         format!("{:.1}", urgency)
     }
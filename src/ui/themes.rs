@@ -1,30 +1,225 @@
 // Color schemes and styling
 
-use ratatui::style::{Color, Style};
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Style},
+};
 use std::collections::HashMap;
 
+use crate::config::{RowColorConfig, ThemeConfig};
+
+// Tags without a color in `theme.tag_colors`
+pub const DEFAULT_TAG_COLOR: Color = Color::Magenta;
+
+// `theme.tag_colors` with the colors parsed once
+#[derive(Debug, Clone, Default)]
+pub struct TagColors {
+    colors: HashMap<String, Color>,
+}
+
+impl TagColors {
+    // Colors should already have been checked by ThemeConfig::check_tag_colors;
+    // anything unparsable or empty gets the default color
+    pub fn new(config: &ThemeConfig) -> Self {
+        let colors = config
+            .tag_colors
+            .iter()
+            .filter_map(|(tag, color)| Some((tag.clone(), RowColorConfig::parse_color(color).ok().flatten()?)))
+            .collect();
+        TagColors { colors }
+    }
+
+    // Only a configured color, for places that otherwise keep their own
+    pub fn get(&self, tag: &str) -> Option<Color> {
+        self.colors.get(tag).copied()
+    }
+
+    pub fn color(&self, tag: &str) -> Color {
+        self.get(tag).unwrap_or(DEFAULT_TAG_COLOR)
+    }
+}
+
+type ThemeConstructor = fn() -> Theme;
+
+// Built-in themes by their `theme.name`, in the order the theme key cycles through them
+const THEMES: [(&str, ThemeConstructor); 6] = [
+    ("terminal", Theme::terminal),
+    ("catppuccin-mocha", Theme::catppuccin_mocha),
+    ("catppuccin-latte", Theme::catppuccin_latte),
+    ("gruvbox", Theme::gruvbox),
+    ("dracula", Theme::dracula),
+    ("high-contrast", Theme::high_contrast),
+];
+
+// Color roles a theme (or `theme.colors`) can set. Widgets draw with the terminal's
+// named colors, and each one stands for a role: Cyan is "primary", Yellow "warning" and so on.
+pub const COLOR_ROLES: [&str; 10] = [
+    "background", "foreground", "primary", "secondary", "success", "warning", "error", "info", "muted", "subtle",
+];
+
 pub struct Theme {
+    // Config name, e.g. "gruvbox"
+    pub id: &'static str,
     pub name: String,
     pub colors: HashMap<String, Color>,
 }
 
 impl Theme {
-    pub fn catppuccin_mocha() -> Self {
-        let mut colors = HashMap::new();
-        colors.insert("background".to_string(), Color::Rgb(30, 30, 46));
-        colors.insert("foreground".to_string(), Color::Rgb(205, 214, 244));
-        colors.insert("primary".to_string(), Color::Rgb(137, 180, 250));
-        colors.insert("secondary".to_string(), Color::Rgb(243, 139, 168));
-        colors.insert("success".to_string(), Color::Rgb(166, 227, 161));
-        colors.insert("warning".to_string(), Color::Rgb(249, 226, 175));
-        colors.insert("error".to_string(), Color::Rgb(243, 139, 168));
+    fn new(id: &'static str, name: &str, colors: [(&str, Color); 10]) -> Self {
+        Theme {
+            id,
+            name: name.to_string(),
+            colors: colors.into_iter().map(|(role, color)| (role.to_string(), color)).collect(),
+        }
+    }
+
+    pub fn names() -> Vec<&'static str> {
+        THEMES.iter().map(|(id, _)| *id).collect()
+    }
+
+    pub fn by_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        THEMES.iter().find(|(id, _)| *id == name).map(|(_, theme)| theme())
+    }
+
+    // The configured theme with `theme.colors` on top. Unknown names fall back to the
+    // terminal's own colors; ThemeConfig::check_theme has already warned about them.
+    pub fn from_config(config: &ThemeConfig) -> Self {
+        Self::by_name(&config.name).unwrap_or_else(Self::terminal).with_overrides(config)
+    }
+
+    // The theme after this one, wrapping around, still with the `theme.colors` overrides
+    pub fn next(&self, config: &ThemeConfig) -> Self {
+        let index = THEMES.iter().position(|(id, _)| *id == self.id).unwrap_or(0);
+        (THEMES[(index + 1) % THEMES.len()].1)().with_overrides(config)
+    }
+
+    fn with_overrides(mut self, config: &ThemeConfig) -> Self {
+        for (role, color) in &config.colors {
+            if let Ok(Some(color)) = RowColorConfig::parse_color(color) {
+                self.colors.insert(role.clone(), color);
+            }
+        }
+        self
+    }
 
+    // Whatever colors the terminal is set up with; the look LazyTask always had
+    pub fn terminal() -> Self {
         Theme {
-            name: "Catppuccin Mocha".to_string(),
-            colors,
+            id: "terminal",
+            name: "Terminal".to_string(),
+            colors: HashMap::new(),
         }
     }
 
+    pub fn catppuccin_mocha() -> Self {
+        Self::new("catppuccin-mocha", "Catppuccin Mocha", [
+            ("background", Color::Rgb(30, 30, 46)),
+            ("foreground", Color::Rgb(205, 214, 244)),
+            ("primary", Color::Rgb(137, 180, 250)),
+            ("secondary", Color::Rgb(203, 166, 247)),
+            ("success", Color::Rgb(166, 227, 161)),
+            ("warning", Color::Rgb(249, 226, 175)),
+            ("error", Color::Rgb(243, 139, 168)),
+            ("info", Color::Rgb(180, 190, 254)),
+            ("muted", Color::Rgb(166, 173, 200)),
+            ("subtle", Color::Rgb(88, 91, 112)),
+        ])
+    }
+
+    // Light background with dark text
+    pub fn catppuccin_latte() -> Self {
+        Self::new("catppuccin-latte", "Catppuccin Latte", [
+            ("background", Color::Rgb(239, 241, 245)),
+            ("foreground", Color::Rgb(76, 79, 105)),
+            ("primary", Color::Rgb(30, 102, 245)),
+            ("secondary", Color::Rgb(136, 57, 239)),
+            ("success", Color::Rgb(64, 160, 43)),
+            ("warning", Color::Rgb(223, 142, 29)),
+            ("error", Color::Rgb(210, 15, 57)),
+            ("info", Color::Rgb(114, 135, 253)),
+            ("muted", Color::Rgb(108, 111, 133)),
+            ("subtle", Color::Rgb(188, 192, 204)),
+        ])
+    }
+
+    pub fn gruvbox() -> Self {
+        Self::new("gruvbox", "Gruvbox", [
+            ("background", Color::Rgb(40, 40, 40)),
+            ("foreground", Color::Rgb(235, 219, 178)),
+            ("primary", Color::Rgb(142, 192, 124)),
+            ("secondary", Color::Rgb(211, 134, 155)),
+            ("success", Color::Rgb(184, 187, 38)),
+            ("warning", Color::Rgb(250, 189, 47)),
+            ("error", Color::Rgb(251, 73, 52)),
+            ("info", Color::Rgb(131, 165, 152)),
+            ("muted", Color::Rgb(168, 153, 132)),
+            ("subtle", Color::Rgb(80, 73, 69)),
+        ])
+    }
+
+    pub fn dracula() -> Self {
+        Self::new("dracula", "Dracula", [
+            ("background", Color::Rgb(40, 42, 54)),
+            ("foreground", Color::Rgb(248, 248, 242)),
+            ("primary", Color::Rgb(139, 233, 253)),
+            ("secondary", Color::Rgb(255, 121, 198)),
+            ("success", Color::Rgb(80, 250, 123)),
+            ("warning", Color::Rgb(241, 250, 140)),
+            ("error", Color::Rgb(255, 85, 85)),
+            ("info", Color::Rgb(189, 147, 249)),
+            ("muted", Color::Rgb(191, 191, 191)),
+            ("subtle", Color::Rgb(68, 71, 90)),
+        ])
+    }
+
+    // Black background and the bright variants of every color; dim text stays readable
+    pub fn high_contrast() -> Self {
+        Self::new("high-contrast", "High Contrast", [
+            ("background", Color::Black),
+            ("foreground", Color::White),
+            ("primary", Color::LightCyan),
+            ("secondary", Color::LightMagenta),
+            ("success", Color::LightGreen),
+            ("warning", Color::LightYellow),
+            ("error", Color::LightRed),
+            ("info", Color::LightBlue),
+            ("muted", Color::White),
+            ("subtle", Color::Gray),
+        ])
+    }
+
+    // Recolor a drawn frame: each named color becomes its role's color, and the
+    // terminal's default colors become the theme's foreground and background
+    pub fn apply(&self, buffer: &mut Buffer) {
+        if self.colors.is_empty() {
+            return;
+        }
+        for cell in buffer.content.iter_mut() {
+            let fg = self.role_color(cell.fg, "foreground");
+            let bg = self.role_color(cell.bg, "background");
+            cell.set_fg(fg).set_bg(bg);
+        }
+    }
+
+    fn role_color(&self, color: Color, default_role: &str) -> Color {
+        let role = match color {
+            Color::Reset => default_role,
+            Color::Black => "background",
+            Color::White => "foreground",
+            Color::Cyan => "primary",
+            Color::Magenta => "secondary",
+            Color::Green => "success",
+            Color::Yellow => "warning",
+            Color::Red => "error",
+            Color::Blue => "info",
+            Color::Gray => "muted",
+            Color::DarkGray => "subtle",
+            _ => return color,
+        };
+        self.colors.get(role).copied().unwrap_or(color)
+    }
+
     pub fn get_color(&self, name: &str) -> Color {
         self.colors.get(name)
             .copied()
@@ -1,63 +1,294 @@
 // Primary task list view with detail panel and filters
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
-use crate::data::models::{Task, TaskStatus};
-use crate::ui::components::filter_bar::FilterBarWidget;
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, Utc};
+use std::collections::{HashMap, HashSet};
+
+use crate::config::Config;
+use crate::data::filters::TaskFilter;
+use crate::data::models::{HistoryEntry, Tag, Task, TaskStatus};
+use crate::data::search::{SearchMode, TaskSearch};
+use crate::data::sort::SortKey;
 use crate::ui::components::task_detail::TaskDetailWidget;
-use crate::ui::components::task_list::TaskListWidget;
+use crate::ui::components::task_form::TaskForm;
+use crate::ui::components::task_list::{RowColors, TaskListWidget};
+use crate::ui::themes::TagColors;
+use crate::utils::formatting::{start_of_day, today, truncate_text, DateFormat};
+
+// Below this many columns the list gets the full width and details open on demand
+pub const COMPACT_WIDTH: u16 = 80;
+
+// How the task list and detail panel share the screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PanelLayout {
+    // Compact below COMPACT_WIDTH, split otherwise
+    Auto,
+    Split,
+    Compact,
+}
+
+impl PanelLayout {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PanelLayout::Auto => "auto",
+            PanelLayout::Split => "split",
+            PanelLayout::Compact => "compact",
+        }
+    }
+
+    fn next(&self) -> PanelLayout {
+        match self {
+            PanelLayout::Auto => PanelLayout::Split,
+            PanelLayout::Split => PanelLayout::Compact,
+            PanelLayout::Compact => PanelLayout::Auto,
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum FilterSection {
     Status,
     Project,
     Tags,
+    Due,
     Search,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DuePreset {
+    Any,
+    Overdue,
+    Today,
+    ThisWeek,
+    ThisMonth,
+    NoDueDate,
+    Custom,
+}
+
+impl DuePreset {
+    // Presets in panel order; Custom is selected through the Before/After rows
+    const LIST: [DuePreset; 6] = [
+        DuePreset::Any,
+        DuePreset::Overdue,
+        DuePreset::Today,
+        DuePreset::ThisWeek,
+        DuePreset::ThisMonth,
+        DuePreset::NoDueDate,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DuePreset::Any => "Any",
+            DuePreset::Overdue => "Overdue",
+            DuePreset::Today => "Today",
+            DuePreset::ThisWeek => "This Week",
+            DuePreset::ThisMonth => "This Month",
+            DuePreset::NoDueDate => "No Due Date",
+            DuePreset::Custom => "Custom",
+        }
+    }
+}
+
+// Rows of the Status panel. Ready, Active, Overdue, Waiting and the Completed
+// Today/This Week rows are computed from the task rather than read from its
+// status field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatusFilter {
+    Pending,
+    Ready,
+    Active,
+    Overdue,
+    Completed,
+    CompletedToday,
+    CompletedThisWeek,
+    Deleted,
+    Waiting,
+    Recurring,
+}
+
+impl StatusFilter {
+    pub const LIST: [StatusFilter; 10] = [
+        StatusFilter::Pending,
+        StatusFilter::Ready,
+        StatusFilter::Active,
+        StatusFilter::Overdue,
+        StatusFilter::Completed,
+        StatusFilter::CompletedToday,
+        StatusFilter::CompletedThisWeek,
+        StatusFilter::Deleted,
+        StatusFilter::Waiting,
+        StatusFilter::Recurring,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatusFilter::Pending => "Pending",
+            StatusFilter::Ready => "Ready",
+            StatusFilter::Active => "Active",
+            StatusFilter::Overdue => "Overdue",
+            StatusFilter::Completed => "Completed",
+            StatusFilter::CompletedToday => "Completed Today",
+            StatusFilter::CompletedThisWeek => "Completed This Week",
+            StatusFilter::Deleted => "Deleted",
+            StatusFilter::Waiting => "Waiting",
+            StatusFilter::Recurring => "Recurring",
+        }
+    }
+
+    // `open_uuids` are the pending and waiting tasks that can still block others
+    pub fn matches(&self, task: &Task, open_uuids: &HashSet<String>) -> bool {
+        match self {
+            // Like Taskwarrior's reports, a future wait date hides a pending task
+            StatusFilter::Pending => task.status == TaskStatus::Pending && !task.is_waiting(),
+            StatusFilter::Ready => task.is_ready(open_uuids),
+            StatusFilter::Active => task.is_active(),
+            StatusFilter::Overdue => task.is_overdue(),
+            StatusFilter::Completed => task.status == TaskStatus::Completed,
+            StatusFilter::CompletedToday => {
+                task.completed_since(start_of_day(today()))
+            }
+            // Same seven-day window as the Reports view's "completed this week"
+            StatusFilter::CompletedThisWeek => task.completed_since(Utc::now() - Duration::days(7)),
+            StatusFilter::Deleted => task.status == TaskStatus::Deleted,
+            StatusFilter::Waiting => task.is_waiting(),
+            StatusFilter::Recurring => task.status == TaskStatus::Recurring,
+        }
+    }
+
+    // Equivalent Taskwarrior filter term
+    fn taskwarrior_term(&self) -> &'static str {
+        match self {
+            StatusFilter::Pending => "status:pending",
+            StatusFilter::Ready => "+READY",
+            StatusFilter::Active => "+ACTIVE",
+            StatusFilter::Overdue => "+OVERDUE",
+            // The end-date window is applied locally, exports only need the completed tasks
+            StatusFilter::Completed
+            | StatusFilter::CompletedToday
+            | StatusFilter::CompletedThisWeek => "status:completed",
+            StatusFilter::Deleted => "status:deleted",
+            // Taskwarrior 2.6+ keeps waiting tasks as pending with a +WAITING virtual tag
+            StatusFilter::Waiting => "+WAITING",
+            StatusFilter::Recurring => "status:recurring",
+        }
+    }
+}
+
+// Rows after the presets in the Due panel
+const DUE_BEFORE_ROW: usize = DuePreset::LIST.len();
+const DUE_AFTER_ROW: usize = DUE_BEFORE_ROW + 1;
+const URGENCY_ROW: usize = DUE_AFTER_ROW + 1;
+
 pub struct MainView {
     task_list_widget: TaskListWidget,
     task_detail_widget: TaskDetailWidget,
-    filter_bar_widget: FilterBarWidget,
     filter_focused: bool,
     active_filter_section: FilterSection,
     status_selection_index: usize,
     project_selection_index: usize,
     tag_selection_index: usize,
+    due_selection_index: usize,
     search_text: String,
+    search: TaskSearch,
     available_projects: Vec<String>,
     available_tags: Vec<String>,
-    selected_statuses: Vec<TaskStatus>,
+    selected_statuses: Vec<StatusFilter>,
     selected_projects: Vec<String>,
+    // Selected projects match only themselves, not their subprojects (work.clientA under work)
+    project_exact: bool,
     selected_tags: Vec<String>,
-    filter_active: bool,
-    filter_overdue: bool,
+    excluded_tags: Vec<String>,
+    due_preset: DuePreset,
+    due_before_input: String,
+    due_after_input: String,
+    // Urgency threshold as typed; a number sets range_filter.urgency_min
+    urgency_input: String,
+    // Bounds for the current due preset (rebuilt by refresh_due_range) and the urgency threshold
+    range_filter: TaskFilter,
+    // "Next actionable" view; overrides the manual filters without clearing them
+    next_view: bool,
+    // Tasks completed in the last `recent_days` days, newest first; also overrides the filters
+    recently_completed: bool,
+    recent_days: i64,
+    // Hide tasks that still wait on an open dependency
+    unblocked_only: bool,
+    // Pending and waiting task UUIDs from the last load, to tell which dependencies still block
+    open_uuids: HashSet<String>,
+    // Due-date sections in the task list
+    grouped: bool,
+    layout: PanelLayout,
+    // Whether the last render used the compact layout
+    compact: bool,
+    // Detail panel drawn over the list in the compact layout
+    detail_overlay: bool,
+    // Type-ahead find: jumps between matching tasks without filtering any out
+    find_query: Option<String>,
+    // Characters go to the find query until Enter or Esc
+    find_typing: bool,
+    // Every loaded task, unfiltered, for the counts next to each filter option
+    all_tasks: Vec<Task>,
+    // How many tasks each option would match with the other sections as they are;
+    // worked out on each filter change rather than each frame
+    status_counts: [usize; StatusFilter::LIST.len()],
+    project_counts: HashMap<String, usize>,
+    tag_counts: HashMap<String, usize>,
+    tag_colors: TagColors,
 }
 
 impl MainView {
-    pub fn new() -> Self {
+    pub fn new(config: &Config) -> Self {
+        let mut task_list_widget = TaskListWidget::new(&config.ui.task_list_columns);
+        task_list_widget.set_date_format(DateFormat::new(&config.ui));
+        task_list_widget.set_row_colors(RowColors::new(&config.ui.row_colors));
+        task_list_widget.set_tag_colors(TagColors::new(&config.theme));
+
         MainView {
-            task_list_widget: TaskListWidget::new(),
-            task_detail_widget: TaskDetailWidget::new(),
-            filter_bar_widget: FilterBarWidget::new(),
+            task_list_widget,
+            task_detail_widget: TaskDetailWidget::new()
+                .with_date_format(DateFormat::new(&config.ui))
+                .with_tag_colors(TagColors::new(&config.theme)),
             filter_focused: false,
             active_filter_section: FilterSection::Status,
             status_selection_index: 0,
             project_selection_index: 0,
             tag_selection_index: 0,
+            due_selection_index: 0,
             search_text: String::new(),
+            search: TaskSearch::default(),
             available_projects: Vec::new(),
             available_tags: Vec::new(),
-            selected_statuses: vec![TaskStatus::Pending],
+            selected_statuses: vec![StatusFilter::Pending],
             selected_projects: Vec::new(),
+            project_exact: false,
             selected_tags: Vec::new(),
-            filter_active: false,
-            filter_overdue: false,
+            excluded_tags: Vec::new(),
+            due_preset: DuePreset::Any,
+            due_before_input: String::new(),
+            due_after_input: String::new(),
+            urgency_input: String::new(),
+            range_filter: TaskFilter { status: None, ..TaskFilter::default() },
+            next_view: false,
+            recently_completed: false,
+            recent_days: config.ui.recently_completed_days.max(1),
+            unblocked_only: false,
+            open_uuids: HashSet::new(),
+            grouped: false,
+            layout: PanelLayout::Auto,
+            compact: false,
+            detail_overlay: false,
+            find_query: None,
+            find_typing: false,
+            all_tasks: Vec::new(),
+            status_counts: [0; StatusFilter::LIST.len()],
+            project_counts: HashMap::new(),
+            tag_counts: HashMap::new(),
+            tag_colors: TagColors::new(&config.theme),
         }
     }
 
@@ -79,34 +310,84 @@ impl MainView {
             ])
             .split(area);
 
-        // Responsive horizontal split based on terminal width
-        let (left_pct, right_pct) = if terminal_width < 100 {
-            (50, 50)  // Equal split for narrow terminals
-        } else if terminal_width < 150 {
-            (50, 50)  // Slightly favor detail panel for medium terminals  
+        // While finding, the find query is what gets highlighted
+        match self.find_query {
+            Some(ref query) if !query.is_empty() => self.task_list_widget.set_search_term(query),
+            _ => self.task_list_widget.set_search_term(&self.search_text),
+        }
+        self.task_list_widget.set_find(self.find_query.as_deref().map(|query| (query, self.find_typing)));
+
+        self.compact = self.is_compact(terminal_width);
+        if self.compact {
+            // Full-width list; the detail panel only appears as an overlay
+            self.task_list_widget.render(f, main_content_chunks[0]);
+            if self.detail_overlay {
+                let overlay_area = main_content_chunks[0].inner(Margin { vertical: 1, horizontal: 2 });
+                f.render_widget(Clear, overlay_area);
+                let selected_task = self.task_list_widget.selected_task();
+                self.task_detail_widget.render(f, overlay_area, selected_task);
+            }
         } else {
-            (50, 50)  // More space for detail panel on wide terminals
-        };
+            let top_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(50), // Task list
+                    Constraint::Percentage(50), // Task detail
+                ])
+                .split(main_content_chunks[0]);
 
-        let top_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(left_pct),   // Responsive task list
-                Constraint::Percentage(right_pct),  // Responsive task detail
-            ])
-            .split(main_content_chunks[0]);
+            // Draw task list on the left
+            self.task_list_widget.render(f, top_chunks[0]);
 
-        // Draw task list on the left
-        self.task_list_widget.render(f, top_chunks[0]);
-        
-        // Draw task detail on the right
-        let selected_task = self.task_list_widget.selected_task();
-        self.task_detail_widget.render(f, top_chunks[1], selected_task);
+            // Draw task detail on the right
+            let selected_task = self.task_list_widget.selected_task();
+            self.task_detail_widget.render(f, top_chunks[1], selected_task);
+        }
         
         // Draw filters at the bottom spanning full width
         self.draw_filters_panel(f, main_content_chunks[1], terminal_width);
     }
 
+    // The selected task's details over the whole area, for the full-screen detail view
+    pub fn render_selected_detail(&self, f: &mut Frame, area: Rect) {
+        self.task_detail_widget.render(f, area, self.task_list_widget.selected_task());
+    }
+
+    pub fn layout(&self) -> PanelLayout {
+        self.layout
+    }
+
+    // Auto → split → compact → auto
+    pub fn cycle_layout(&mut self) {
+        self.layout = self.layout.next();
+        self.detail_overlay = false;
+    }
+
+    pub fn is_compact(&self, terminal_width: u16) -> bool {
+        match self.layout {
+            PanelLayout::Auto => terminal_width < COMPACT_WIDTH,
+            PanelLayout::Split => false,
+            PanelLayout::Compact => true,
+        }
+    }
+
+    pub fn is_detail_overlay_open(&self) -> bool {
+        self.detail_overlay
+    }
+
+    // Only the compact layout has an overlay; returns false when there's nothing to toggle
+    pub fn toggle_detail_overlay(&mut self) -> bool {
+        if !self.compact {
+            return false;
+        }
+        self.detail_overlay = !self.detail_overlay;
+        true
+    }
+
+    pub fn close_detail_overlay(&mut self) {
+        self.detail_overlay = false;
+    }
+
     pub fn update_available_filters(&mut self, tasks: &[Task]) {
         // Extract unique projects from pending/active tasks only
         let mut projects: Vec<String> = tasks
@@ -119,31 +400,69 @@ impl MainView {
                 )
             })
             .filter_map(|task| task.project.as_ref())
-            .cloned()
+            .flat_map(|project| Self::project_and_parents(project))
             .collect();
-        projects.sort();
+        // Segment by segment, so each subproject follows its parent (work, work.clientA, work-home)
+        projects.sort_by(|a, b| a.split('.').cmp(b.split('.')));
         projects.dedup();
-        self.available_projects = projects.clone();
+        self.available_projects = projects;
 
-        // Extract unique tags from pending/active tasks only
-        let mut tags: Vec<String> = tasks
-            .iter()
-            .filter(|task| {
-                matches!(task.status, 
-                    TaskStatus::Pending | 
-                    TaskStatus::Waiting |
-                    TaskStatus::Recurring
-                )
-            })
-            .flat_map(|task| task.tags.iter())
-            .cloned()
+        // Tags of pending/active tasks only, alphabetically; the dashboard lists the same tags by count
+        let mut tags: Vec<String> = Tag::from_tasks(tasks)
+            .into_iter()
+            .filter(|tag| tag.pending_count > 0)
+            .map(|tag| tag.name)
             .collect();
         tags.sort();
-        tags.dedup();
-        self.available_tags = tags.clone();
+        self.available_tags = tags;
+
+        self.open_uuids = Task::open_uuids(tasks);
+        self.task_list_widget.set_open_uuids(self.open_uuids.clone());
+        self.all_tasks = tasks.to_vec();
+    }
+
+    // Counts for the Status, Project and Tags options. Each leaves its own section
+    // out of the filter, so `work (12)` is what ticking work would show alongside
+    // everything else that is selected. Call after any filter change.
+    pub fn refresh_option_counts(&mut self) {
+        let mut status_counts = [0; StatusFilter::LIST.len()];
+        let mut project_counts = HashMap::new();
+        let mut tag_counts = HashMap::new();
+
+        for task in &self.all_tasks {
+            if self.matches_filters_except(task, Some(FilterSection::Status)) {
+                for (count, status) in status_counts.iter_mut().zip(StatusFilter::LIST.iter()) {
+                    if status.matches(task, &self.open_uuids) {
+                        *count += 1;
+                    }
+                }
+            }
+            if let Some(ref project) = task.project {
+                if self.matches_filters_except(task, Some(FilterSection::Project)) {
+                    let covered_by = if self.project_exact {
+                        vec![project.clone()]
+                    } else {
+                        Self::project_and_parents(project)
+                    };
+                    for name in covered_by {
+                        *project_counts.entry(name).or_insert(0) += 1;
+                    }
+                }
+            }
+            if !task.tags.is_empty() && self.matches_filters_except(task, Some(FilterSection::Tags)) {
+                for tag in &task.tags {
+                    *tag_counts.entry(tag.clone()).or_insert(0) += 1;
+                }
+            }
+        }
+
+        self.status_counts = status_counts;
+        self.project_counts = project_counts;
+        self.tag_counts = tag_counts;
+    }
 
-        // Update filter bar widget with current projects and tags
-        self.filter_bar_widget.update_available_options(projects, tags);
+    pub fn set_empty_message(&mut self, message: Option<String>) {
+        self.task_list_widget.set_empty_message(message);
     }
 
     pub fn set_tasks_with_preserved_selection(&mut self, tasks: Vec<Task>, preserve_uuid: Option<&str>) {
@@ -151,34 +470,35 @@ impl MainView {
     }
 
     pub fn matches_filters(&self, task: &Task) -> bool {
-        // Status filter (including computed states)
-        if !self.selected_statuses.is_empty() || self.filter_active || self.filter_overdue {
-            let mut status_matches = false;
-            
-            // Check basic status matches
-            if !self.selected_statuses.is_empty() {
-                status_matches = self.selected_statuses.contains(&task.status);
-            }
-            
-            // Check computed state filters
-            if self.filter_active && task.is_active() {
-                status_matches = true;
-            }
-            
-            if self.filter_overdue && task.is_overdue() {
-                status_matches = true;
-            }
-            
-            if !status_matches {
-                return false;
-            }
+        self.matches_filters_except(task, None)
+    }
+
+    // All filters, optionally ignoring the Status, Project or Tags section
+    fn matches_filters_except(&self, task: &Task, skipped: Option<FilterSection>) -> bool {
+        if self.next_view {
+            return self.is_next_actionable(task);
+        }
+        if self.recently_completed {
+            return task.completed_since(Utc::now() - Duration::days(self.recent_days));
+        }
+
+        if self.unblocked_only && self.is_blocked(task) {
+            return false;
+        }
+
+        // Status filter: a task matching any selected option is shown
+        if skipped != Some(FilterSection::Status)
+            && !self.selected_statuses.is_empty()
+            && !self.selected_statuses.iter().any(|status| status.matches(task, &self.open_uuids))
+        {
+            return false;
         }
 
         // Project filter
-        if !self.selected_projects.is_empty() {
+        if skipped != Some(FilterSection::Project) && !self.selected_projects.is_empty() {
             match &task.project {
                 Some(project) => {
-                    if !self.selected_projects.contains(project) {
+                    if !self.selected_projects.iter().any(|selected| self.project_matches(project, selected)) {
                         return false;
                     }
                 }
@@ -186,8 +506,9 @@ impl MainView {
             }
         }
 
-        // Tags filter
-        if !self.selected_tags.is_empty() {
+        // Tags filter; skipping the section skips the excluded tags too
+        let skip_tags = skipped == Some(FilterSection::Tags);
+        if !skip_tags && !self.selected_tags.is_empty() {
             let has_selected_tag = self.selected_tags
                 .iter()
                 .any(|selected_tag| task.tags.contains(selected_tag));
@@ -196,24 +517,65 @@ impl MainView {
             }
         }
 
-        // Search filter
-        if !self.search_text.is_empty() {
-            let search_text = self.search_text.to_lowercase();
-            let matches_description = task.description.to_lowercase().contains(&search_text);
-            let matches_project = task.project.as_ref()
-                .map(|p| p.to_lowercase().contains(&search_text))
-                .unwrap_or(false);
-            let matches_tags = task.tags.iter()
-                .any(|tag| tag.to_lowercase().contains(&search_text));
-            
-            if !matches_description && !matches_project && !matches_tags {
-                return false;
-            }
+        // Excluded tags remove any task carrying them
+        if !skip_tags && self.excluded_tags.iter().any(|excluded_tag| task.tags.contains(excluded_tag)) {
+            return false;
+        }
+
+        // Due range and urgency threshold
+        if self.due_preset == DuePreset::NoDueDate && task.due.is_some() {
+            return false;
+        }
+        if !self.range_filter.matches(task) {
+            return false;
+        }
+
+        // Search filter (an empty or whitespace-only query matches everything)
+        if !self.search.matches(task, &self.search_text) {
+            return false;
         }
 
         true
     }
 
+    // Recompute due bounds so presets like "Today" follow the clock
+    pub fn refresh_due_range(&mut self) {
+        let now = Utc::now();
+        let today = today();
+        let start_of = |date: NaiveDate| -> Option<DateTime<Utc>> { Some(start_of_day(date)) };
+        // TaskFilter bounds are exclusive, so step back a second to include midnight
+        let from = |date: NaiveDate| start_of(date).map(|dt| dt - Duration::seconds(1));
+
+        let (due_after, due_before) = match self.due_preset {
+            DuePreset::Any | DuePreset::NoDueDate => (None, None),
+            DuePreset::Overdue => (None, Some(now)),
+            DuePreset::Today => (from(today), start_of(today + Duration::days(1))),
+            DuePreset::ThisWeek => {
+                let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+                (from(monday), start_of(monday + Duration::days(7)))
+            }
+            DuePreset::ThisMonth => {
+                let first = today.with_day(1).unwrap_or(today);
+                (from(first), first.checked_add_months(Months::new(1)).and_then(start_of))
+            }
+            DuePreset::Custom => (
+                Self::parse_due_input(&self.due_after_input),
+                Self::parse_due_input(&self.due_before_input),
+            ),
+        };
+
+        self.range_filter.due_after = due_after;
+        self.range_filter.due_before = due_before;
+    }
+
+    fn parse_due_input(input: &str) -> Option<DateTime<Utc>> {
+        if input.trim().is_empty() {
+            None
+        } else {
+            TaskForm::parse_taskwarrior_date(input)
+        }
+    }
+
     // Navigation methods
     pub fn next_task(&mut self) {
         self.task_list_widget.next();
@@ -223,6 +585,96 @@ impl MainView {
         self.task_list_widget.previous();
     }
 
+    pub fn set_sort_key(&mut self, sort_key: SortKey) {
+        self.task_list_widget.set_sort_key(sort_key);
+    }
+
+    pub fn search_mode(&self) -> SearchMode {
+        self.search.mode
+    }
+
+    pub fn toggle_search_mode(&mut self) {
+        self.search.mode = self.search.mode.toggled();
+    }
+
+    // Fuzzy search with a query ranks tasks by how well they match
+    pub fn ranks_by_match(&self) -> bool {
+        self.search.ranks(&self.search_text)
+    }
+
+    pub fn sort_by_match(&self, tasks: &mut [Task]) {
+        self.search.sort_by_score(tasks, &self.search_text);
+    }
+
+    pub fn set_ranked_by_match(&mut self, ranked: bool) {
+        self.task_list_widget.set_ranked_by_match(ranked);
+    }
+
+    pub fn first_task(&mut self) {
+        self.task_list_widget.select_first();
+    }
+
+    pub fn last_task(&mut self) {
+        self.task_list_widget.select_last();
+    }
+
+    pub fn page_up(&mut self) {
+        self.task_list_widget.page_up();
+    }
+
+    pub fn page_down(&mut self) {
+        self.task_list_widget.page_down();
+    }
+
+    pub fn start_find(&mut self) {
+        self.find_query = Some(String::new());
+        self.find_typing = true;
+    }
+
+    pub fn is_finding(&self) -> bool {
+        self.find_query.is_some()
+    }
+
+    pub fn is_find_typing(&self) -> bool {
+        self.find_typing
+    }
+
+    pub fn clear_find(&mut self) {
+        self.find_query = None;
+        self.find_typing = false;
+    }
+
+    // Each change to the query jumps to the first match from the selection on
+    pub fn find_push(&mut self, c: char) {
+        if let Some(ref mut query) = self.find_query {
+            query.push(c);
+            let query = query.clone();
+            self.task_list_widget.select_match(&query, true, true);
+        }
+    }
+
+    pub fn find_pop(&mut self) {
+        if let Some(ref mut query) = self.find_query {
+            query.pop();
+            let query = query.clone();
+            self.task_list_widget.select_match(&query, true, true);
+        }
+    }
+
+    // Enter: stop typing but keep the query for n/N; an empty query just ends the find
+    pub fn finish_find_typing(&mut self) {
+        self.find_typing = false;
+        if self.find_query.as_deref() == Some("") {
+            self.find_query = None;
+        }
+    }
+
+    pub fn find_next(&mut self, forward: bool) {
+        if let Some(query) = self.find_query.clone() {
+            self.task_list_widget.select_match(&query, forward, false);
+        }
+    }
+
     pub fn selected_task(&self) -> Option<&Task> {
         self.task_list_widget.selected_task()
     }
@@ -231,32 +683,286 @@ impl MainView {
         self.task_list_widget.selected_task_uuid()
     }
 
+    // Shown in the detail panel while that task is selected
+    pub fn set_task_history(&mut self, uuid: &str, entries: Vec<HistoryEntry>) {
+        self.task_detail_widget.set_history(uuid, entries);
+    }
+
     pub fn selected_index(&self) -> Option<usize> {
         self.task_list_widget.state.selected()
     }
 
     // Filter management
+    // Same idea as Taskwarrior's `next` report: work that can be started right now
+    pub fn is_next_actionable(&self, task: &Task) -> bool {
+        task.status == TaskStatus::Pending && !task.is_waiting() && !self.is_blocked(task)
+    }
+
+    // Waiting on a dependency that is still pending or waiting, judged against the last load
+    pub fn is_blocked(&self, task: &Task) -> bool {
+        task.is_blocked_by(&self.open_uuids)
+    }
+
+    // Status filters as a Taskwarrior filter expression, so exports can skip unwanted tasks.
+    // None means every task is needed (no status filter selected).
+    pub fn taskwarrior_filter(&self) -> Option<String> {
+        if self.next_view {
+            return Some("status:pending".to_string());
+        }
+        if self.recently_completed {
+            return Some("status:completed".to_string());
+        }
+
+        // Several rows can share a term (the Completed variants), list each once
+        let mut terms: Vec<&str> = Vec::new();
+        for term in self.selected_statuses.iter().map(StatusFilter::taskwarrior_term) {
+            if !terms.contains(&term) {
+                terms.push(term);
+            }
+        }
+
+        match terms.len() {
+            0 => None,
+            1 => Some(terms[0].to_string()),
+            _ => Some(format!("( {} )", terms.join(" or "))),
+        }
+    }
+
+    pub fn is_next_view(&self) -> bool {
+        self.next_view
+    }
+
+    pub fn set_next_view(&mut self, enabled: bool) {
+        if enabled {
+            self.set_recently_completed(false);
+        }
+        self.next_view = enabled;
+        self.task_list_widget.set_next_view(enabled);
+    }
+
+    pub fn is_recently_completed(&self) -> bool {
+        self.recently_completed
+    }
+
+    pub fn recent_days(&self) -> i64 {
+        self.recent_days
+    }
+
+    // Date formats, colors and the recently completed window from a reloaded config.
+    // Filters, selection and layout stay as they are; columns are AppUI's (a report may own them).
+    pub fn apply_config(&mut self, config: &Config) {
+        self.task_list_widget.set_date_format(DateFormat::new(&config.ui));
+        self.task_list_widget.set_row_colors(RowColors::new(&config.ui.row_colors));
+        self.task_list_widget.set_tag_colors(TagColors::new(&config.theme));
+        let detail = std::mem::replace(&mut self.task_detail_widget, TaskDetailWidget::new());
+        self.task_detail_widget = detail
+            .with_date_format(DateFormat::new(&config.ui))
+            .with_tag_colors(TagColors::new(&config.theme));
+        self.tag_colors = TagColors::new(&config.theme);
+        self.recent_days = config.ui.recently_completed_days.max(1);
+        self.task_list_widget.set_recently_completed(self.recently_completed.then_some(self.recent_days));
+    }
+
+    pub fn set_recently_completed(&mut self, enabled: bool) {
+        if enabled {
+            self.set_next_view(false);
+        }
+        self.recently_completed = enabled;
+        self.task_list_widget.set_recently_completed(enabled.then_some(self.recent_days));
+    }
+
+    // Leave the next or recently completed view, whose own filter would hide a filter change
+    pub fn show_filtered_list(&mut self) {
+        self.set_next_view(false);
+        self.set_recently_completed(false);
+    }
+
+    pub fn is_unblocked_only(&self) -> bool {
+        self.unblocked_only
+    }
+
+    pub fn set_unblocked_only(&mut self, enabled: bool) {
+        self.unblocked_only = enabled;
+        self.task_list_widget.set_unblocked_only(enabled);
+    }
+
+    pub fn is_grouped(&self) -> bool {
+        self.grouped
+    }
+
+    pub fn set_grouped(&mut self, grouped: bool) {
+        self.grouped = grouped;
+        self.task_list_widget.set_grouped(grouped);
+    }
+
     pub fn is_filter_focused(&self) -> bool {
         self.filter_focused
     }
 
     pub fn toggle_filter_focus(&mut self) {
         self.filter_focused = !self.filter_focused;
-        if self.filter_focused {
-            self.filter_bar_widget.is_visible = true;
+    }
+
+    // Columns and title for a Taskwarrior report; None goes back to the configured columns
+    pub fn set_report(&mut self, name: Option<&str>, columns: &[String]) {
+        self.task_list_widget.set_columns(columns);
+        self.task_list_widget.set_report_name(name);
+    }
+
+    // A report brings its own filter, so start from nothing selected, not even Pending
+    pub fn clear_filters_for_report(&mut self) {
+        self.clear_all_filters();
+        self.selected_statuses.clear();
+    }
+
+    // Back to the startup state: pending tasks only, nothing else selected
+    pub fn clear_all_filters(&mut self) {
+        self.selected_statuses = vec![StatusFilter::Pending];
+        self.selected_projects.clear();
+        self.selected_tags.clear();
+        self.excluded_tags.clear();
+        self.due_preset = DuePreset::Any;
+        self.due_before_input.clear();
+        self.due_after_input.clear();
+        self.set_urgency_input(String::new());
+        self.search_text.clear();
+        self.set_unblocked_only(false);
+        self.show_filtered_list();
+    }
+
+    // Completed and deleted tasks are in view: no status filter at all, or both selected
+    pub fn shows_closed_tasks(&self) -> bool {
+        self.selected_statuses.is_empty()
+            || (self.selected_statuses.contains(&StatusFilter::Completed)
+                && self.selected_statuses.contains(&StatusFilter::Deleted))
+    }
+
+    // Flip between "active work" and "everything" by adding or removing Completed and
+    // Deleted; the other selected statuses and filters stay as they are
+    pub fn toggle_closed_tasks(&mut self) {
+        if self.shows_closed_tasks() {
+            if self.selected_statuses.is_empty() {
+                // No status filter means all of them, so spell out the open ones
+                self.selected_statuses = vec![StatusFilter::Pending, StatusFilter::Waiting, StatusFilter::Recurring];
+            } else {
+                self.selected_statuses.retain(|status| !matches!(
+                    status,
+                    StatusFilter::Completed
+                        | StatusFilter::CompletedToday
+                        | StatusFilter::CompletedThisWeek
+                        | StatusFilter::Deleted
+                ));
+                // An empty selection would show everything again
+                if self.selected_statuses.is_empty() {
+                    self.selected_statuses.push(StatusFilter::Pending);
+                }
+            }
+        } else {
+            for status in [StatusFilter::Completed, StatusFilter::Deleted] {
+                if !self.selected_statuses.contains(&status) {
+                    self.selected_statuses.push(status);
+                }
+            }
+        }
+        // The next view ignores the status filter, so leave it or the change would be invisible
+        self.show_filtered_list();
+    }
+
+    // `work.clientA.web` gives work, work.clientA and work.clientA.web, so parents that
+    // have no tasks of their own can still be picked in the filter
+    fn project_and_parents(project: &str) -> Vec<String> {
+        project
+            .match_indices('.')
+            .map(|(i, _)| project[..i].to_string())
+            .chain(std::iter::once(project.to_string()))
+            .collect()
+    }
+
+    // Like Taskwarrior's `project:work`, a parent covers its subprojects unless exact matching is on
+    fn project_matches(&self, project: &str, selected: &str) -> bool {
+        project == selected
+            || (!self.project_exact
+                && project.strip_prefix(selected).is_some_and(|rest| rest.starts_with('.')))
+    }
+
+    pub fn toggle_project_exact(&mut self) {
+        self.project_exact = !self.project_exact;
+    }
+
+    // Narrow the list to one project, e.g. when picked in the Projects view
+    pub fn show_only_project(&mut self, project: &str) {
+        self.selected_projects = vec![project.to_string()];
+        self.show_filtered_list();
+    }
+
+    // Show only the selected task's project, or drop the project filter when that is
+    // already all it holds. Returns whether the list is now narrowed to the project.
+    pub fn toggle_project_focus(&mut self, project: &str) -> bool {
+        if self.selected_projects == [project] {
+            self.selected_projects.clear();
+            false
+        } else {
+            self.show_only_project(project);
+            true
+        }
+    }
+
+    // Same for a tag
+    pub fn toggle_tag_focus(&mut self, tag: &str) -> bool {
+        if self.selected_tags == [tag] {
+            self.selected_tags.clear();
+            false
+        } else {
+            self.show_only_tag(tag);
+            true
+        }
+    }
+
+    // An exclusion of the tag would hide everything, so it goes too
+    pub fn show_only_tag(&mut self, tag: &str) {
+        self.selected_tags = vec![tag.to_string()];
+        self.excluded_tags.retain(|excluded| excluded != tag);
+        self.show_filtered_list();
+    }
+
+    // Whether plain characters are being typed into a text input (search, a custom due date
+    // or the urgency threshold)
+    pub fn is_typing_filter_text(&self) -> bool {
+        match self.active_filter_section {
+            FilterSection::Search => true,
+            FilterSection::Due => matches!(self.due_selection_index, DUE_BEFORE_ROW | DUE_AFTER_ROW | URGENCY_ROW),
+            _ => false,
+        }
+    }
+
+    // Project under the cursor while the Project filter section is active
+    pub fn highlighted_project(&self) -> Option<&str> {
+        if self.active_filter_section == FilterSection::Project {
+            self.available_projects.get(self.project_selection_index).map(|p| p.as_str())
+        } else {
+            None
+        }
+    }
+
+    // Keep a renamed project selected in the filter
+    pub fn rename_project_filter(&mut self, from: &str, to: &str) {
+        for project in self.selected_projects.iter_mut() {
+            if project == from {
+                *project = to.to_string();
+            }
         }
     }
 
     pub fn exit_filter_mode(&mut self) {
         self.filter_focused = false;
-        self.filter_bar_widget.is_visible = false;
     }
 
     pub fn next_filter_section(&mut self) {
         self.active_filter_section = match self.active_filter_section {
             FilterSection::Status => FilterSection::Project,
             FilterSection::Project => FilterSection::Tags,
-            FilterSection::Tags => FilterSection::Search,
+            FilterSection::Tags => FilterSection::Due,
+            FilterSection::Due => FilterSection::Search,
             FilterSection::Search => FilterSection::Status,
         };
     }
@@ -278,6 +984,11 @@ impl MainView {
                     self.tag_selection_index -= 1;
                 }
             }
+            FilterSection::Due => {
+                if self.due_selection_index > 0 {
+                    self.due_selection_index -= 1;
+                }
+            }
             FilterSection::Search => {
                 // No navigation in search
             }
@@ -287,8 +998,7 @@ impl MainView {
     pub fn handle_filter_navigation_down(&mut self) {
         match self.active_filter_section {
             FilterSection::Status => {
-                let max_status = 4; // Pending, Active, Overdue, Completed, Deleted (0-4)
-                if self.status_selection_index < max_status {
+                if self.status_selection_index < StatusFilter::LIST.len() - 1 {
                     self.status_selection_index += 1;
                 }
             }
@@ -302,6 +1012,11 @@ impl MainView {
                     self.tag_selection_index += 1;
                 }
             }
+            FilterSection::Due => {
+                if self.due_selection_index < URGENCY_ROW {
+                    self.due_selection_index += 1;
+                }
+            }
             FilterSection::Search => {
                 // No navigation in search
             }
@@ -311,43 +1026,12 @@ impl MainView {
     pub fn toggle_current_selection(&mut self) {
         match self.active_filter_section {
             FilterSection::Status => {
-                match self.status_selection_index {
-                    0 => {
-                        // Pending status
-                        let status = TaskStatus::Pending;
-                        if self.selected_statuses.contains(&status) {
-                            self.selected_statuses.retain(|s| s != &status);
-                        } else {
-                            self.selected_statuses.push(status);
-                        }
-                    }
-                    1 => {
-                        // Active (computed filter)
-                        self.filter_active = !self.filter_active;
-                    }
-                    2 => {
-                        // Overdue (computed filter)  
-                        self.filter_overdue = !self.filter_overdue;
-                    }
-                    3 => {
-                        // Completed status
-                        let status = TaskStatus::Completed;
-                        if self.selected_statuses.contains(&status) {
-                            self.selected_statuses.retain(|s| s != &status);
-                        } else {
-                            self.selected_statuses.push(status);
-                        }
-                    }
-                    4 => {
-                        // Deleted status
-                        let status = TaskStatus::Deleted;
-                        if self.selected_statuses.contains(&status) {
-                            self.selected_statuses.retain(|s| s != &status);
-                        } else {
-                            self.selected_statuses.push(status);
-                        }
+                if let Some(status) = StatusFilter::LIST.get(self.status_selection_index) {
+                    if self.selected_statuses.contains(status) {
+                        self.selected_statuses.retain(|s| s != status);
+                    } else {
+                        self.selected_statuses.push(*status);
                     }
-                    _ => {}
                 }
             }
             FilterSection::Project => {
@@ -360,29 +1044,103 @@ impl MainView {
                 }
             }
             FilterSection::Tags => {
+                // Cycle ignore -> include -> exclude -> ignore
                 if let Some(tag) = self.available_tags.get(self.tag_selection_index) {
                     if self.selected_tags.contains(tag) {
                         self.selected_tags.retain(|t| t != tag);
+                        self.excluded_tags.push(tag.clone());
+                    } else if self.excluded_tags.contains(tag) {
+                        self.excluded_tags.retain(|t| t != tag);
                     } else {
                         self.selected_tags.push(tag.clone());
                     }
                 }
             }
+            FilterSection::Due if self.due_selection_index == URGENCY_ROW => {
+                // Space on the urgency row drops the threshold
+                self.set_urgency_input(String::new());
+            }
+            FilterSection::Due => {
+                self.due_preset = match DuePreset::LIST.get(self.due_selection_index) {
+                    // Toggling the active preset again clears it
+                    Some(&preset) if preset == self.due_preset => DuePreset::Any,
+                    Some(&preset) => preset,
+                    None => DuePreset::Custom,
+                };
+            }
             FilterSection::Search => {
                 // No toggle in search
             }
         }
     }
 
-    pub fn handle_search_character(&mut self, c: char) {
-        if self.active_filter_section == FilterSection::Search {
-            self.search_text.push(c);
+    pub fn handle_filter_character(&mut self, c: char) {
+        match self.active_filter_section {
+            FilterSection::Search => self.search_text.push(c),
+            FilterSection::Project if c == 'e' => self.toggle_project_exact(),
+            FilterSection::Due if self.due_selection_index == URGENCY_ROW => match c {
+                '+' => self.step_urgency(1.0),
+                '-' => self.step_urgency(-1.0),
+                '0'..='9' | '.' => {
+                    let mut input = self.urgency_input.clone();
+                    input.push(c);
+                    self.set_urgency_input(input);
+                }
+                _ => {}
+            },
+            FilterSection::Due => {
+                if let Some(input) = self.due_input_mut() {
+                    input.push(c);
+                    self.due_preset = DuePreset::Custom;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn handle_filter_backspace(&mut self) {
+        match self.active_filter_section {
+            FilterSection::Search => {
+                self.search_text.pop();
+            }
+            FilterSection::Due if self.due_selection_index == URGENCY_ROW => {
+                let mut input = self.urgency_input.clone();
+                input.pop();
+                self.set_urgency_input(input);
+            }
+            FilterSection::Due => {
+                if let Some(input) = self.due_input_mut() {
+                    input.pop();
+                    self.due_preset = DuePreset::Custom;
+                }
+            }
+            _ => {}
         }
     }
 
-    pub fn handle_search_backspace(&mut self) {
-        if self.active_filter_section == FilterSection::Search {
-            self.search_text.pop();
+    pub fn urgency_min(&self) -> Option<f64> {
+        self.range_filter.urgency_min
+    }
+
+    // Anything that doesn't parse as a number leaves the threshold off
+    fn set_urgency_input(&mut self, input: String) {
+        self.range_filter.urgency_min = input.trim().parse::<f64>().ok().filter(|value| value.is_finite());
+        self.urgency_input = input;
+    }
+
+    // + and - move the threshold by whole points, from 0 when none is set
+    fn step_urgency(&mut self, delta: f64) {
+        let value = self.range_filter.urgency_min.unwrap_or(0.0) + delta;
+        // Keep one decimal so repeated steps don't collect float noise
+        self.set_urgency_input(format!("{}", (value * 10.0).round() / 10.0));
+    }
+
+    // The custom date input under the cursor, if a Before/After row is highlighted
+    fn due_input_mut(&mut self) -> Option<&mut String> {
+        match self.due_selection_index {
+            DUE_BEFORE_ROW => Some(&mut self.due_before_input),
+            DUE_AFTER_ROW => Some(&mut self.due_after_input),
+            _ => None,
         }
     }
 
@@ -401,9 +1159,9 @@ impl MainView {
         } else {
             // Horizontal layout for wider screens with responsive widths
             let widths = if terminal_width < 160 {
-                [20, 30, 25, 25] // Compact layout
+                [16, 22, 20, 20, 22] // Compact layout
             } else {
-                [25, 25, 25, 25] // Full layout
+                [20, 20, 20, 20, 20] // Full layout
             };
             
             Layout::default()
@@ -412,7 +1170,8 @@ impl MainView {
                     Constraint::Percentage(widths[0]), // Status filters
                     Constraint::Percentage(widths[1]), // Project filters 
                     Constraint::Percentage(widths[2]), // Tag filters
-                    Constraint::Percentage(widths[3]), // Search filters
+                    Constraint::Percentage(widths[3]), // Due filters
+                    Constraint::Percentage(widths[4]), // Search filters
                 ])
                 .split(area)
         };
@@ -421,7 +1180,11 @@ impl MainView {
             // Narrow screen: combine filters in vertical layout
             let top_row = Layout::default()
                 .direction(Direction::Horizontal)
-                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .constraints([
+                    Constraint::Percentage(34),
+                    Constraint::Percentage(33),
+                    Constraint::Percentage(33),
+                ])
                 .split(filter_chunks[0]);
             let bottom_row = Layout::default()
                 .direction(Direction::Horizontal)
@@ -430,6 +1193,7 @@ impl MainView {
                 
             self.draw_status_filters(f, top_row[0]);
             self.draw_project_filters(f, top_row[1]);
+            self.draw_due_filters(f, top_row[2]);
             self.draw_tag_filters(f, bottom_row[0]);
             self.draw_search_filter(f, bottom_row[1]);
         } else {
@@ -437,31 +1201,22 @@ impl MainView {
             self.draw_status_filters(f, filter_chunks[0]);
             self.draw_project_filters(f, filter_chunks[1]);
             self.draw_tag_filters(f, filter_chunks[2]);
-            self.draw_search_filter(f, filter_chunks[3]);
+            self.draw_due_filters(f, filter_chunks[3]);
+            self.draw_search_filter(f, filter_chunks[4]);
         }
     }
 
     fn draw_status_filters(&self, f: &mut Frame, area: Rect) {
-        let statuses = [
-            ("Pending", TaskStatus::Pending),
-            ("Active", TaskStatus::Pending),
-            ("Overdue", TaskStatus::Pending),
-            ("Completed", TaskStatus::Completed),
-            ("Deleted", TaskStatus::Deleted),
-        ];
-        
-        let status_text: Vec<Line> = statuses
+        // Keep the highlighted row in view when the panel is shorter than the list
+        let visible_rows = (area.height as usize).saturating_sub(2).max(1);
+        let first_row = self.status_selection_index.saturating_sub(visible_rows - 1);
+
+        let status_text: Vec<Line> = StatusFilter::LIST
             .iter()
             .enumerate()
-            .map(|(i, (name, _status))| {
-                let is_selected = match i {
-                    0 => self.selected_statuses.contains(&TaskStatus::Pending),
-                    1 => self.filter_active,
-                    2 => self.filter_overdue,
-                    3 => self.selected_statuses.contains(&TaskStatus::Completed),
-                    4 => self.selected_statuses.contains(&TaskStatus::Deleted),
-                    _ => false,
-                };
+            .skip(first_row)
+            .map(|(i, status)| {
+                let is_selected = self.selected_statuses.contains(status);
                 
                 let is_highlighted = self.active_filter_section == FilterSection::Status 
                     && self.status_selection_index == i;
@@ -480,7 +1235,8 @@ impl MainView {
                 
                 Line::from(vec![
                     checkbox,
-                    Span::styled(*name, text_style),
+                    Span::styled(status.label(), text_style),
+                    Span::styled(format!(" ({})", self.status_counts[i]), Style::default().fg(Color::DarkGray)),
                 ])
             })
             .collect();
@@ -512,12 +1268,7 @@ impl MainView {
                     if self.selected_projects.is_empty() {
                         "None".to_string()
                     } else {
-                        let selection = self.selected_projects.join(", ");
-                        if selection.len() > 20 {
-                            format!("{}...", &selection[..17])
-                        } else {
-                            selection
-                        }
+                        truncate_text(&self.selected_projects.join(", "), 20)
                     },
                     Style::default().fg(Color::Green)
                 ),
@@ -579,16 +1330,17 @@ impl MainView {
                 Style::default().fg(Color::White)
             };
             
-            let max_chars = (area.width as usize).saturating_sub(6).max(8);
-            let display_name = if project.len() > max_chars {
-                format!("{}...", &project[..max_chars.saturating_sub(3)])
-            } else {
-                project.to_string()
-            };
+            // Subprojects are indented under their parent and show only their own segment
+            let depth = project.matches('.').count();
+            let name = project.rsplit('.').next().unwrap_or(project);
+            let count = format!(" ({})", self.project_counts.get(*project).copied().unwrap_or(0));
+            let max_chars = (area.width as usize).saturating_sub(6 + depth * 2 + count.chars().count()).max(8);
+            let display_name = format!("{}{}", "  ".repeat(depth), truncate_text(name, max_chars));
             
             project_text.push(Line::from(vec![
                 checkbox,
                 Span::styled(display_name, text_style),
+                Span::styled(count, Style::default().fg(Color::DarkGray)),
             ]));
         }
 
@@ -612,7 +1364,7 @@ impl MainView {
 
         let project_panel = Paragraph::new(project_text)
             .block(Block::default()
-                .title("Project")
+                .title(if self.project_exact { "Project (exact)" } else { "Project" })
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(border_color))
             )
@@ -626,15 +1378,14 @@ impl MainView {
             Line::from(vec![
                 Span::styled("Selected: ", Style::default().fg(Color::Yellow)),
                 Span::styled(
-                    if self.selected_tags.is_empty() {
+                    if self.selected_tags.is_empty() && self.excluded_tags.is_empty() {
                         "None".to_string()
                     } else {
-                        let selection = format!("+{}", self.selected_tags.join(" +"));
-                        if selection.len() > 20 {
-                            format!("{}...", &selection[..17])
-                        } else {
-                            selection
-                        }
+                        let summary: Vec<String> = self.selected_tags.iter()
+                            .map(|t| format!("+{}", t))
+                            .chain(self.excluded_tags.iter().map(|t| format!("-{}", t)))
+                            .collect();
+                        truncate_text(&summary.join(" "), 20)
                     },
                     Style::default().fg(Color::Green)
                 ),
@@ -680,12 +1431,13 @@ impl MainView {
         }
 
         for (original_i, tag) in visible_tags.iter() {
-            let is_selected = self.selected_tags.contains(tag);
             let is_highlighted = self.active_filter_section == FilterSection::Tags 
                 && self.tag_selection_index == *original_i;
             
-            let checkbox = if is_selected {
-                Span::styled("[✓] ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+            let checkbox = if self.selected_tags.contains(tag) {
+                Span::styled("[+] ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+            } else if self.excluded_tags.contains(tag) {
+                Span::styled("[-] ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
             } else {
                 Span::styled("[ ] ", Style::default().fg(Color::Gray))
             };
@@ -693,19 +1445,17 @@ impl MainView {
             let text_style = if is_highlighted {
                 Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(self.tag_colors.get(tag).unwrap_or(Color::White))
             };
             
-            let max_chars = (area.width as usize).saturating_sub(6).max(6);
-            let display_name = if tag.len() > max_chars {
-                format!("{}...", &tag[..max_chars.saturating_sub(3)])
-            } else {
-                tag.to_string()
-            };
+            let count = format!(" ({})", self.tag_counts.get(*tag).copied().unwrap_or(0));
+            let max_chars = (area.width as usize).saturating_sub(6 + count.chars().count()).max(6);
+            let display_name = truncate_text(tag, max_chars);
             
             tag_text.push(Line::from(vec![
                 checkbox,
                 Span::styled(display_name, text_style),
+                Span::styled(count, Style::default().fg(Color::DarkGray)),
             ]));
         }
 
@@ -738,6 +1488,122 @@ impl MainView {
         f.render_widget(tag_panel, area);
     }
 
+    fn draw_due_filters(&self, f: &mut Frame, area: Rect) {
+        let is_active = self.active_filter_section == FilterSection::Due;
+
+        let mut rows: Vec<Line> = DuePreset::LIST
+            .iter()
+            .enumerate()
+            .map(|(i, preset)| {
+                let radio = if *preset == self.due_preset {
+                    Span::styled("(•) ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+                } else {
+                    Span::styled("( ) ", Style::default().fg(Color::Gray))
+                };
+
+                let text_style = if is_active && self.due_selection_index == i {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+
+                Line::from(vec![radio, Span::styled(preset.label(), text_style)])
+            })
+            .collect();
+
+        // Custom before/after inputs accept the same dates as the task form
+        let max_chars = (area.width as usize).saturating_sub(12).max(4);
+        for (row, label, input) in [
+            (DUE_BEFORE_ROW, "Before: ", &self.due_before_input),
+            (DUE_AFTER_ROW, "After:  ", &self.due_after_input),
+        ] {
+            let is_highlighted = is_active && self.due_selection_index == row;
+            let label_style = if is_highlighted {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let value_color = if !input.trim().is_empty() && Self::parse_due_input(input).is_none() {
+                Color::Red
+            } else {
+                Color::Green
+            };
+            let value = if input.is_empty() && is_highlighted {
+                "_".to_string()
+            } else {
+                truncate_text(input, max_chars)
+            };
+
+            rows.push(Line::from(vec![
+                Span::styled(label, label_style),
+                Span::styled(value, Style::default().fg(value_color)),
+            ]));
+        }
+
+        // Urgency threshold: typed, or stepped with +/-
+        let is_highlighted = is_active && self.due_selection_index == URGENCY_ROW;
+        let label_style = if is_highlighted {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let value_color = if !self.urgency_input.trim().is_empty() && self.urgency_min().is_none() {
+            Color::Red
+        } else {
+            Color::Green
+        };
+        let mut urgency_row = vec![
+            Span::styled("Urgency ≥ ", label_style),
+            Span::styled(
+                if self.urgency_input.is_empty() && is_highlighted { "_".to_string() } else { truncate_text(&self.urgency_input, max_chars) },
+                Style::default().fg(value_color),
+            ),
+        ];
+        if is_highlighted {
+            urgency_row.push(Span::styled("  +/- step", Style::default().fg(Color::Gray)));
+        }
+        rows.push(Line::from(urgency_row));
+
+        // Keep the highlighted row visible on short panels
+        let visible_rows = (area.height as usize).saturating_sub(4).max(1);
+        let scroll_offset = if is_active {
+            (self.due_selection_index + 1).saturating_sub(visible_rows)
+        } else {
+            0
+        };
+
+        let mut selected = vec![
+            Span::styled("Selected: ", Style::default().fg(Color::Yellow)),
+            Span::styled(self.due_preset.label(), Style::default().fg(Color::Green)),
+        ];
+        if let Some(urgency_min) = self.urgency_min() {
+            selected.push(Span::styled(format!(" · ≥ {}", urgency_min), Style::default().fg(Color::Green)));
+        }
+        let mut due_text = vec![
+            Line::from(selected),
+            Line::from(""),
+        ];
+        due_text.extend(rows.into_iter().skip(scroll_offset));
+
+        let border_color = if self.filter_focused && is_active {
+            Color::Yellow
+        } else if self.filter_focused {
+            Color::DarkGray
+        } else {
+            Color::Cyan
+        };
+
+        let due_panel = Paragraph::new(due_text)
+            .block(Block::default()
+                .title("Due / Urgency")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(border_color))
+            )
+            .style(Style::default().fg(Color::White));
+
+        f.render_widget(due_panel, area);
+    }
+
     fn draw_search_filter(&self, f: &mut Frame, area: Rect) {
         let is_active = self.active_filter_section == FilterSection::Search;
         
@@ -764,12 +1630,17 @@ impl MainView {
             search_text.push(Line::from(vec![
                 Span::styled("Type to search", Style::default().fg(Color::Gray).add_modifier(Modifier::ITALIC)),
             ]));
+            search_text.push(Line::from(vec![
+                Span::styled("Ctrl+f", Style::default().fg(Color::Yellow)),
+                Span::styled(" exact / fuzzy", Style::default().fg(Color::Gray)),
+            ]));
         } else {
             search_text.extend(vec![
                 Line::from("Searches in:"),
                 Line::from("• Description"),
                 Line::from("• Project"),
-                Line::from("• Tags"),
+                Line::from("• Tags (+tag)"),
+                Line::from("• Annotations"),
             ]);
         }
 
@@ -783,7 +1654,7 @@ impl MainView {
 
         let search_panel = Paragraph::new(search_text)
             .block(Block::default()
-                .title("Search")
+                .title(format!("Search ({})", self.search.mode.label()))
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(border_color))
             )
@@ -1,4 +1,5 @@
 pub mod main_view;
+pub mod projects_view;
 pub mod reports_view;
 pub mod settings_view;
 
@@ -0,0 +1,150 @@
+// Projects view - every project with its task counts; Enter filters the task list to one
+
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    Frame,
+};
+
+use crate::data::models::{Project, Task, NO_PROJECT};
+
+// Width of the completion bar, in cells
+const BAR_WIDTH: usize = 20;
+
+#[derive(Default)]
+pub struct ProjectsView {
+    projects: Vec<Project>,
+    state: TableState,
+    visible_rows: usize,
+}
+
+impl ProjectsView {
+    // Rebuild the counts, keeping the cursor on the same project when it still exists
+    pub fn update_tasks(&mut self, tasks: &[Task]) {
+        let selected = self.selected_project().map(|project| project.name.clone());
+
+        // Tasks without a project can't be selected in the project filter, so they aren't listed
+        self.projects = Project::from_tasks(tasks)
+            .into_iter()
+            .filter(|project| project.name != NO_PROJECT)
+            .collect();
+
+        let index = selected
+            .and_then(|name| self.projects.iter().position(|project| project.name == name))
+            .or_else(|| self.state.selected().map(|i| i.min(self.projects.len().saturating_sub(1))))
+            .unwrap_or(0);
+        self.state.select(if self.projects.is_empty() { None } else { Some(index) });
+    }
+
+    pub fn projects(&self) -> &[Project] {
+        &self.projects
+    }
+
+    pub fn selected_project(&self) -> Option<&Project> {
+        self.state.selected().and_then(|i| self.projects.get(i))
+    }
+
+    fn select(&mut self, index: usize) {
+        if !self.projects.is_empty() {
+            self.state.select(Some(index.min(self.projects.len() - 1)));
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.select(self.state.selected().map_or(0, |i| i + 1));
+    }
+
+    pub fn previous(&mut self) {
+        self.select(self.state.selected().map_or(0, |i| i.saturating_sub(1)));
+    }
+
+    pub fn select_first(&mut self) {
+        self.select(0);
+    }
+
+    pub fn select_last(&mut self) {
+        self.select(self.projects.len().saturating_sub(1));
+    }
+
+    pub fn page_up(&mut self) {
+        self.select(self.state.selected().unwrap_or(0).saturating_sub(self.visible_rows.max(1)));
+    }
+
+    pub fn page_down(&mut self) {
+        self.select(self.state.selected().unwrap_or(0) + self.visible_rows.max(1));
+    }
+
+    // Filled/empty blocks for a completion percentage, followed by the percentage
+    pub fn completion_bar(rate: f32, width: usize) -> String {
+        let filled = ((rate / 100.0) * width as f32).round() as usize;
+        let filled = filled.min(width);
+        format!("{}{} {:>3.0}%", "█".repeat(filled), "░".repeat(width - filled), rate)
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        // Borders and the header row
+        self.visible_rows = (area.height.saturating_sub(3) as usize).max(1);
+
+        let header = Row::new(vec![
+            Cell::from("Project").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Cell::from("Pending").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Cell::from("Done").style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Cell::from("Total").style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+            Cell::from("Progress").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        ])
+        .style(Style::default().bg(Color::DarkGray));
+
+        let rows: Vec<Row> = self.projects
+            .iter()
+            .map(|project| {
+                let rate = project.completion_rate();
+                let bar_color = if rate >= 80.0 {
+                    Color::Green
+                } else if rate >= 50.0 {
+                    Color::Yellow
+                } else {
+                    Color::Red
+                };
+                Row::new(vec![
+                    Cell::from(project.name.clone()).style(Style::default().fg(Color::Green)),
+                    Cell::from(project.pending_count.to_string()).style(Style::default().fg(Color::Yellow)),
+                    Cell::from(project.completed_count.to_string()).style(Style::default().fg(Color::Green)),
+                    Cell::from(project.task_count.to_string()),
+                    Cell::from(Self::completion_bar(rate, BAR_WIDTH)).style(Style::default().fg(bar_color)),
+                ])
+            })
+            .collect();
+
+        let title = Line::from(vec![
+            Span::raw(format!(" Projects ({}) · ", self.projects.len())),
+            Span::styled("Enter", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" show tasks "),
+        ]);
+
+        let table = Table::new(rows, [
+                Constraint::Min(16),
+                Constraint::Length(8),
+                Constraint::Length(6),
+                Constraint::Length(6),
+                Constraint::Length(BAR_WIDTH as u16 + 5),
+            ])
+            .header(header)
+            .block(Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+            )
+            .column_spacing(2)
+            .style(Style::default().fg(Color::White))
+            .row_highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD)
+                    .add_modifier(Modifier::REVERSED),
+            );
+
+        f.render_stateful_widget(table, area, &mut self.state);
+    }
+}
@@ -2,12 +2,15 @@
 
 use ratatui::Frame;
 use ratatui::layout::Rect;
-use std::collections::HashMap;
-use chrono::{DateTime, Datelike, Duration, Utc};
+use ratatui::widgets::TableState;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
 
-use crate::data::models::{Priority, Task, TaskStatus};
+use crate::config::Config;
+use crate::data::models::{Project, Tag, Task};
 use crate::ui::components::calendar_view::CalendarWidget;
-use crate::ui::components::report_panel::{DashboardWidget, ProjectStats, TaskSummaryCache};
+use crate::ui::components::report_panel::{DashboardWidget, TaskSummaryCache};
+use crate::ui::components::task_form::TaskForm;
+use crate::utils::formatting::{day_of, start_of_day, today, DateFormat};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ReportMode {
@@ -15,6 +18,13 @@ pub enum ReportMode {
     Calendar,   // Calendar view
 }
 
+// The dashboard table that Up/Down/Enter work on; Tab switches
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DashboardTable {
+    Projects,
+    Tags,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DateNavigation {
     NextDay,
@@ -29,27 +39,50 @@ pub enum DateNavigation {
 
 pub struct ReportsView {
     tasks: Vec<Task>,
-    // Cache expensive calculations
-    project_stats: HashMap<String, ProjectStats>,
+    // Cache expensive calculations; projects are sorted busiest first, as listed
+    project_stats: Vec<Project>,
+    // Selected row of the dashboard's project table, which scrolls to keep it in view
+    project_state: TableState,
+    // Project rows that fit in the last render, for paging
+    project_rows: usize,
+    // The same for the tag table, busiest first
+    tag_stats: Vec<Tag>,
+    tag_state: TableState,
+    tag_rows: usize,
+    focus: DashboardTable,
     task_summary_cache: Option<TaskSummaryCache>,
     data_version: u64, // Track when data changes
     // Calendar mode state
     mode: ReportMode,
     selected_date: DateTime<Utc>,
+    dates: DateFormat,
 }
 
 impl ReportsView {
-    pub fn new() -> Self {
+    pub fn new(config: &Config) -> Self {
         ReportsView {
             tasks: Vec::new(),
-            project_stats: HashMap::new(),
+            project_stats: Vec::new(),
+            project_state: TableState::default(),
+            project_rows: 0,
+            tag_stats: Vec::new(),
+            tag_state: TableState::default(),
+            tag_rows: 0,
+            focus: DashboardTable::Projects,
             task_summary_cache: None,
             data_version: 0,
             mode: ReportMode::Dashboard,
             selected_date: Utc::now(),
+            dates: DateFormat::new(&config.ui),
         }
     }
 
+    // A reloaded config may change date formats or ui.use_utc, which moves the day boundaries
+    pub fn apply_config(&mut self, config: &Config) {
+        self.dates = DateFormat::new(&config.ui);
+        self.calculate_summary_cache();
+    }
+
     pub fn update_tasks(&mut self, tasks: Vec<Task>) {
         self.tasks = tasks;
         self.data_version += 1; // Increment version to invalidate cache
@@ -57,83 +90,87 @@ impl ReportsView {
     }
 
     fn recalculate_stats(&mut self) {
-        // Recalculate project statistics
-        self.project_stats.clear();
-        
-        for task in &self.tasks {
-            let project_name = task.project.clone().unwrap_or_else(|| "(no project)".to_string());
-            let stats = self.project_stats.entry(project_name).or_insert(ProjectStats {
-                pending: 0,
-                completed: 0,
-                deleted: 0,
-                total: 0,
-            });
-            
-            match task.status {
-                TaskStatus::Pending => stats.pending += 1,
-                TaskStatus::Completed => stats.completed += 1,
-                TaskStatus::Deleted => stats.deleted += 1,
-                TaskStatus::Waiting => stats.pending += 1, // Count waiting as pending for stats
-                TaskStatus::Recurring => stats.pending += 1, // Count recurring as pending for stats
-            }
-            stats.total += 1;
-        }
+        // Recalculate project statistics, keeping the same project selected
+        let selected = self.selected_project().map(str::to_string);
+        self.project_stats = Project::from_tasks(&self.tasks);
+        self.project_stats
+            .sort_by_key(|project| std::cmp::Reverse(project.pending_count + project.completed_count));
+        let index = selected
+            .and_then(|name| self.project_stats.iter().position(|project| project.name == name))
+            .unwrap_or(0);
+        self.project_state.select((!self.project_stats.is_empty()).then_some(index));
+
+        // Same for tags
+        let selected = self.selected_tag().map(str::to_string);
+        self.tag_stats = Tag::from_tasks(&self.tasks);
+        let index = selected
+            .and_then(|name| self.tag_stats.iter().position(|tag| tag.name == name))
+            .unwrap_or(0);
+        self.tag_state.select((!self.tag_stats.is_empty()).then_some(index));
 
         // Recalculate summary cache
         self.calculate_summary_cache();
     }
 
     fn calculate_summary_cache(&mut self) {
-        let total = self.tasks.len();
-        let pending = self.tasks.iter().filter(|t| t.status == TaskStatus::Pending).count();
-        let completed = self.tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
-        let deleted = self.tasks.iter().filter(|t| t.status == TaskStatus::Deleted).count();
-        let waiting = self.tasks.iter().filter(|t| t.status == TaskStatus::Waiting).count();
-        let active = self.tasks.iter().filter(|t| t.is_active()).count();
-        let overdue = self.tasks.iter().filter(|t| t.is_overdue()).count();
-
-        let high_priority = self.tasks.iter().filter(|t| t.priority == Some(Priority::High)).count();
-        let medium_priority = self.tasks.iter().filter(|t| t.priority == Some(Priority::Medium)).count();
-        let low_priority = self.tasks.iter().filter(|t| t.priority == Some(Priority::Low)).count();
-        let no_priority = self.tasks.iter().filter(|t| t.priority.is_none()).count();
-        
-        let avg_urgency = if !self.tasks.is_empty() {
-            self.tasks.iter().map(|t| t.urgency).sum::<f64>() / self.tasks.len() as f64
-        } else {
-            0.0
+        self.task_summary_cache = Some(TaskSummaryCache::from_tasks(&self.tasks, self.data_version));
+    }
+
+    // Dashboard tables; every row is reachable however few fit
+
+    pub fn selected_project(&self) -> Option<&str> {
+        self.project_state.selected().and_then(|i| self.project_stats.get(i)).map(|project| project.name.as_str())
+    }
+
+    pub fn selected_tag(&self) -> Option<&str> {
+        self.tag_state.selected().and_then(|i| self.tag_stats.get(i)).map(|tag| tag.name.as_str())
+    }
+
+    pub fn focused_table(&self) -> DashboardTable {
+        self.focus
+    }
+
+    pub fn switch_table(&mut self) {
+        self.focus = match self.focus {
+            DashboardTable::Projects => DashboardTable::Tags,
+            DashboardTable::Tags => DashboardTable::Projects,
+        };
+    }
+
+    // Move the focused table's selection to `to(selected, rows, visible rows)`, kept in range
+    fn select_row(&mut self, to: impl Fn(Option<usize>, usize, usize) -> usize) {
+        let (state, len, visible) = match self.focus {
+            DashboardTable::Projects => (&mut self.project_state, self.project_stats.len(), self.project_rows),
+            DashboardTable::Tags => (&mut self.tag_state, self.tag_stats.len(), self.tag_rows),
         };
+        if len > 0 {
+            let index = to(state.selected(), len, visible.max(1));
+            state.select(Some(index.min(len - 1)));
+        }
+    }
+
+    pub fn next_row(&mut self) {
+        self.select_row(|selected, _, _| selected.map_or(0, |i| i + 1));
+    }
+
+    pub fn previous_row(&mut self) {
+        self.select_row(|selected, _, _| selected.map_or(0, |i| i.saturating_sub(1)));
+    }
 
-        // Calculate recent activity
-        use chrono::{Duration, Utc};
-        let now = Utc::now();
-        let week_ago = now - Duration::days(7);
-        
-        let recent_tasks = self.tasks.iter()
-            .filter(|t| t.entry > week_ago)
-            .count();
-        
-        let completed_this_week = self.tasks.iter()
-            .filter(|t| t.status == TaskStatus::Completed && 
-                        t.end.map_or(false, |end| end > week_ago))
-            .count();
-
-        self.task_summary_cache = Some(TaskSummaryCache {
-            total,
-            pending,
-            completed,
-            deleted,
-            waiting,
-            active,
-            overdue,
-            high_priority,
-            medium_priority,
-            low_priority,
-            no_priority,
-            avg_urgency,
-            recent_tasks,
-            completed_this_week,
-            version: self.data_version,
-        });
+    pub fn first_row(&mut self) {
+        self.select_row(|_, _, _| 0);
+    }
+
+    pub fn last_row(&mut self) {
+        self.select_row(|_, len, _| len - 1);
+    }
+
+    pub fn page_up(&mut self) {
+        self.select_row(|selected, _, page| selected.unwrap_or(0).saturating_sub(page));
+    }
+
+    pub fn page_down(&mut self) {
+        self.select_row(|selected, _, page| selected.unwrap_or(0) + page);
     }
 
     // Calendar mode methods
@@ -148,60 +185,59 @@ impl ReportsView {
         self.mode == ReportMode::Calendar
     }
 
+    // Switch to the calendar on the given day (e.g. a task's due date), or today without one
+    pub fn show_in_calendar(&mut self, date: Option<DateTime<Utc>>) {
+        self.mode = ReportMode::Calendar;
+        self.select_day(date.map_or_else(today, |date| day_of(&date)));
+    }
+
+    // Select a day, e.g. from the go-to-date prompt; the three months re-center on it
+    pub fn jump_to(&mut self, date: DateTime<Utc>) {
+        self.select_day(day_of(&date));
+    }
+
+    // The next Monday..Sunday after the selected day, so pressing the key again steps a week
+    pub fn jump_to_weekday(&mut self, weekday: Weekday) {
+        self.select_day(TaskForm::next_weekday(self.selected_day(), weekday));
+    }
+
+    // Steps are in calendar days, so a DST change can't skip or repeat a day
     pub fn navigate_date(&mut self, direction: DateNavigation) {
+        let day = self.selected_day();
         match direction {
-            DateNavigation::NextDay => {
-                self.selected_date = self.selected_date + Duration::days(1);
-            }
-            DateNavigation::PrevDay => {
-                self.selected_date = self.selected_date - Duration::days(1);
-            }
-            DateNavigation::NextWeek => {
-                self.selected_date = self.selected_date + Duration::weeks(1);
-            }
-            DateNavigation::PrevWeek => {
-                self.selected_date = self.selected_date - Duration::weeks(1);
-            }
-            DateNavigation::NextMonth => {
-                // Proper month arithmetic: go to same day next month
-                let current = self.selected_date;
-                let next_month = if current.month() == 12 {
-                    chrono::NaiveDate::from_ymd_opt(current.year() + 1, 1, current.day().min(31))
-                } else {
-                    chrono::NaiveDate::from_ymd_opt(current.year(), current.month() + 1, current.day().min(31))
-                };
-                
-                if let Some(date) = next_month {
-                    self.selected_date = date.and_hms_opt(0, 0, 0).unwrap().and_utc();
-                } else {
-                    // Fallback to adding days if date calculation fails
-                    self.selected_date = self.selected_date + Duration::days(30);
-                }
-            }
-            DateNavigation::PrevMonth => {
-                // Proper month arithmetic: go to same day previous month
-                let current = self.selected_date;
-                let prev_month = if current.month() == 1 {
-                    chrono::NaiveDate::from_ymd_opt(current.year() - 1, 12, current.day().min(31))
-                } else {
-                    chrono::NaiveDate::from_ymd_opt(current.year(), current.month() - 1, current.day().min(31))
-                };
-                
-                if let Some(date) = prev_month {
-                    self.selected_date = date.and_hms_opt(0, 0, 0).unwrap().and_utc();
-                } else {
-                    // Fallback to subtracting days if date calculation fails
-                    self.selected_date = self.selected_date - Duration::days(30);
-                }
-            }
-            DateNavigation::Today => {
-                self.selected_date = Utc::now();
-            }
+            DateNavigation::NextDay => self.select_day(day + Duration::days(1)),
+            DateNavigation::PrevDay => self.select_day(day - Duration::days(1)),
+            DateNavigation::NextWeek => self.select_day(day + Duration::weeks(1)),
+            DateNavigation::PrevWeek => self.select_day(day - Duration::weeks(1)),
+            DateNavigation::NextMonth => self.shift_month(1),
+            DateNavigation::PrevMonth => self.shift_month(-1),
+            DateNavigation::Today => self.select_day(today()),
+        }
+    }
+
+    fn selected_day(&self) -> NaiveDate {
+        day_of(&self.selected_date)
+    }
+
+    fn select_day(&mut self, day: NaiveDate) {
+        self.selected_date = start_of_day(day);
+    }
+
+    fn shift_month(&mut self, offset: i32) {
+        // Same day in the target month, clamped to its length (Jan 31 -> Feb 28)
+        let current = self.selected_day();
+        let (year, month) = CalendarWidget::offset_month(current.year(), current.month(), offset);
+        let date = (1..=current.day())
+            .rev()
+            .find_map(|day| NaiveDate::from_ymd_opt(year, month, day));
+
+        if let Some(date) = date {
+            self.select_day(date);
         }
     }
 
 
-    pub fn render(&self, f: &mut Frame, area: Rect) {
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
         match self.mode {
             ReportMode::Dashboard => {
                 // Delegate dashboard rendering to DashboardWidget
@@ -209,8 +245,10 @@ impl ReportsView {
                     self.tasks.clone(),
                     self.project_stats.clone(),
                     self.task_summary_cache.clone()
-                );
-                dashboard.render(f, area);
+                )
+                .with_tags(self.tag_stats.clone(), self.focus == DashboardTable::Tags);
+                (self.project_rows, self.tag_rows) =
+                    dashboard.render(f, area, &mut self.project_state, &mut self.tag_state);
             }
             ReportMode::Calendar => self.render_calendar(f, area),
         }
@@ -219,7 +257,7 @@ impl ReportsView {
 
     fn render_calendar(&self, f: &mut Frame, area: Rect) {
         // Use CalendarWidget component for clean separation
-        let calendar_widget = CalendarWidget::new(self.selected_date, self.tasks.clone());
+        let calendar_widget = CalendarWidget::new(self.selected_date, self.tasks.clone(), self.dates.clone());
         calendar_widget.render(f, area);
     }
 }
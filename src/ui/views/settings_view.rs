@@ -1,15 +1,142 @@
-// Configuration UI view
+// Settings view: the active configuration, read-only, as it would be written to config.toml
 
-use ratatui::{layout::Rect, Frame};
+use ratatui::{
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
 
-pub struct SettingsView;
+use crate::config::Config;
+
+pub struct SettingsView {
+    lines: Vec<Line<'static>>,
+    scroll: usize,
+    visible_rows: usize,
+}
 
 impl SettingsView {
-    pub fn new() -> Self {
-        SettingsView
+    pub fn new(config: &Config) -> Self {
+        SettingsView {
+            lines: Self::config_lines(config),
+            scroll: 0,
+            visible_rows: 0,
+        }
+    }
+
+    // Show a (re)loaded config, keeping the scroll position where it still fits
+    pub fn set_config(&mut self, config: &Config) {
+        self.lines = Self::config_lines(config);
+        self.scroll = self.scroll.min(self.max_scroll());
     }
 
-    pub fn render(&self, _f: &mut Frame, _area: Rect) {
-        // TODO: Implement settings view
+    fn config_lines(config: &Config) -> Vec<Line<'static>> {
+        let label = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+        let file = match &config.path {
+            Some(path) => path.display().to_string(),
+            None => "none, built-in defaults".to_string(),
+        };
+        let mut lines = vec![
+            Line::from(vec![Span::styled("Config file  ", label), Span::raw(file)]),
+            Line::from(Span::styled(
+                "R reloads it: theme, keys, columns, dates and colors change right away; [taskwarrior] on the next start",
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+
+        if !config.warnings.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Problems", label)));
+            lines.extend(config.warnings.iter().map(|warning| {
+                Line::from(Span::styled(format!("  {}", warning), Style::default().fg(Color::Red)))
+            }));
+        }
+
+        let toml = match toml::to_string_pretty(config) {
+            Ok(toml) => toml,
+            Err(e) => format!("# can't show the config: {}", e),
+        };
+        for text in toml.lines() {
+            let line = if text.starts_with('[') {
+                // A blank line ahead of each table, like the file
+                lines.push(Line::from(""));
+                Line::from(Span::styled(text.to_string(), label))
+            } else if let Some((key, value)) = text.split_once(" = ") {
+                Line::from(vec![
+                    Span::styled(key.to_string(), Style::default().fg(Color::Yellow)),
+                    Span::raw(format!(" = {}", value)),
+                ])
+            } else if text.is_empty() {
+                continue;
+            } else {
+                Line::from(text.to_string())
+            };
+            lines.push(line);
+        }
+        lines
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = (self.scroll + 1).min(self.max_scroll());
+    }
+
+    pub fn page_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(self.visible_rows.max(1));
+    }
+
+    pub fn page_down(&mut self) {
+        self.scroll = (self.scroll + self.visible_rows.max(1)).min(self.max_scroll());
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.scroll = 0;
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = self.max_scroll();
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(self.visible_rows.max(1))
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let line_count = self.lines.len();
+        // Borders take two rows; remember the height so paging matches what is on screen
+        self.visible_rows = area.height.saturating_sub(2) as usize;
+        self.scroll = self.scroll.min(self.max_scroll());
+
+        let title = if line_count > self.visible_rows {
+            format!(" Settings · ↑↓ scroll ({}–{} of {}) ", self.scroll + 1, (self.scroll + self.visible_rows).min(line_count), line_count)
+        } else {
+            " Settings ".to_string()
+        };
+
+        let settings = Paragraph::new(self.lines.clone())
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .scroll((self.scroll as u16, 0));
+        f.render_widget(settings, area);
+
+        if line_count > self.visible_rows {
+            let mut scrollbar_state = ScrollbarState::new(self.max_scroll() + 1)
+                .viewport_content_length(self.visible_rows)
+                .position(self.scroll);
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .track_symbol(Some("│"))
+                .thumb_style(Style::default().fg(Color::Cyan))
+                .track_style(Style::default().fg(Color::DarkGray));
+            f.render_stateful_widget(
+                scrollbar,
+                area.inner(Margin { vertical: 1, horizontal: 0 }),
+                &mut scrollbar_state,
+            );
+        }
     }
 }
@@ -0,0 +1,23 @@
+// Copy text to the system clipboard
+
+use anyhow::{Context, Result};
+use arboard::Clipboard;
+
+// Opened on first use and then kept: on X11 and Wayland the copied text is served
+// by this process, so dropping the handle would take it off the clipboard again
+#[derive(Default)]
+pub struct SystemClipboard {
+    clipboard: Option<Clipboard>,
+}
+
+impl SystemClipboard {
+    /// Put `text` on the clipboard. Fails where there is none, e.g. over SSH or
+    /// without a display server; callers should show the text some other way.
+    pub fn copy(&mut self, text: &str) -> Result<()> {
+        let clipboard = match self.clipboard {
+            Some(ref mut clipboard) => clipboard,
+            None => self.clipboard.insert(Clipboard::new().context("No system clipboard available")?),
+        };
+        clipboard.set_text(text).context("Failed to copy to the clipboard")
+    }
+}
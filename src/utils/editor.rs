@@ -0,0 +1,71 @@
+// Edit text in the user's $VISUAL/$EDITOR via a temp file
+
+use anyhow::{Context, Result};
+use std::env;
+use std::fs;
+use std::io;
+use std::process::{Command, ExitStatus};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Tried in order when neither $VISUAL nor $EDITOR is set
+const FALLBACK_EDITORS: [&str; 2] = ["vi", "nano"];
+
+/// Open `initial` in an external editor and return the edited text joined onto one line,
+/// or `None` if it came back unchanged. The caller must have released the terminal.
+pub fn edit_text(initial: &str) -> Result<Option<String>> {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let path = env::temp_dir().join(format!("lazytask-{}-{}.txt", std::process::id(), nanos));
+    fs::write(&path, format!("{}\n", initial))
+        .with_context(|| format!("Failed to create temp file: {:?}", path))?;
+
+    let result = run_editor(&path.to_string_lossy()).and_then(|status| {
+        if !status.success() {
+            anyhow::bail!("Editor exited with {}; description not changed", status);
+        }
+        fs::read_to_string(&path).with_context(|| format!("Failed to read temp file: {:?}", path))
+    });
+    let _ = fs::remove_file(&path);
+
+    // Taskwarrior descriptions are a single line
+    let edited = result?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if edited.is_empty() {
+        anyhow::bail!("Description can't be empty; description not changed");
+    }
+    Ok((edited != initial.trim()).then_some(edited))
+}
+
+fn run_editor(path: &str) -> Result<ExitStatus> {
+    // The variable may carry arguments, e.g. EDITOR="code --wait"
+    let configured = env::var("VISUAL")
+        .ok()
+        .or_else(|| env::var("EDITOR").ok())
+        .filter(|editor| !editor.trim().is_empty());
+
+    if let Some(editor) = configured {
+        let mut parts = editor.split_whitespace();
+        let program = parts.next().unwrap_or_default();
+        return Command::new(program)
+            .args(parts)
+            .arg(path)
+            .status()
+            .with_context(|| format!("Failed to run editor `{}`", editor));
+    }
+
+    for editor in FALLBACK_EDITORS {
+        match Command::new(editor).arg(path).status() {
+            Ok(status) => return Ok(status),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(anyhow::Error::new(e).context(format!("Failed to run editor `{}`", editor))),
+        }
+    }
+    anyhow::bail!("No editor found; set $EDITOR")
+}
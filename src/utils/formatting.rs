@@ -1,15 +1,104 @@
 // Date/text formatting utilities
 
-use chrono::{DateTime, Local, Utc};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use std::sync::atomic::{AtomicBool, Ordering};
 
-pub fn format_date(date: &DateTime<Utc>) -> String {
-    let local_date = date.with_timezone(&Local);
-    local_date.format("%Y-%m-%d").to_string()
+use crate::config::UIConfig;
+
+// `ui.use_utc`: show times and count days in UTC instead of the local time zone.
+// Set from the config at startup, by AppUI::new (or main for the subcommands).
+static USE_UTC: AtomicBool = AtomicBool::new(false);
+
+pub fn set_use_utc(use_utc: bool) {
+    USE_UTC.store(use_utc, Ordering::Relaxed);
+}
+
+/// `time` on the clock it's shown with: local time, or UTC with `ui.use_utc`.
+/// Taskwarrior stores UTC; this is only for showing times and telling days apart.
+pub fn display_time(time: &DateTime<Utc>) -> DateTime<FixedOffset> {
+    if USE_UTC.load(Ordering::Relaxed) {
+        time.fixed_offset()
+    } else {
+        time.with_timezone(&Local).fixed_offset()
+    }
+}
+
+/// The calendar day `time` falls on, so "due today" follows the user's midnight
+pub fn day_of(time: &DateTime<Utc>) -> NaiveDate {
+    display_time(time).date_naive()
+}
+
+pub fn today() -> NaiveDate {
+    day_of(&Utc::now())
+}
+
+/// Midnight at the start of `day` on the same clock as `day_of`
+pub fn start_of_day(day: NaiveDate) -> DateTime<Utc> {
+    from_display_time(day.and_time(NaiveTime::MIN))
+}
+
+/// The instant a time read off the display clock stands for, e.g. one the user typed
+pub fn from_display_time(time: NaiveDateTime) -> DateTime<Utc> {
+    if USE_UTC.load(Ordering::Relaxed) {
+        return time.and_utc();
+    }
+    // Where a DST change skips the time, it counts from when the clock jumps forward
+    (0..=2)
+        .find_map(|hour| Local.from_local_datetime(&(time + Duration::hours(hour))).earliest())
+        .map_or_else(|| time.and_utc(), |start| start.with_timezone(&Utc))
+}
+
+/// Whole days from today to the day of `time`; negative once that day has passed
+pub fn days_until(time: &DateTime<Utc>) -> i64 {
+    (day_of(time) - today()).num_days()
 }
 
-pub fn format_datetime(datetime: &DateTime<Utc>) -> String {
-    let local_datetime = datetime.with_timezone(&Local);
-    local_datetime.format("%Y-%m-%d %H:%M").to_string()
+/// Date patterns from `[ui]` in the config; timestamps are shown in local time
+/// (or UTC, see `ui.use_utc`).
+#[derive(Debug, Clone)]
+pub struct DateFormat {
+    pub date: String,
+    pub datetime: String,
+    pub short_date: String,
+}
+
+impl DateFormat {
+    pub fn new(ui: &UIConfig) -> Self {
+        DateFormat {
+            date: ui.date_format.clone(),
+            datetime: ui.datetime_format.clone(),
+            short_date: ui.short_date_format.clone(),
+        }
+    }
+
+    /// Whether chrono can format with `pattern` (unknown specifiers make it panic).
+    pub fn is_valid_pattern(pattern: &str) -> bool {
+        !StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error))
+    }
+
+    pub fn date(&self, date: &DateTime<Utc>) -> String {
+        display_time(date).format(&self.date).to_string()
+    }
+
+    pub fn datetime(&self, datetime: &DateTime<Utc>) -> String {
+        display_time(datetime).format(&self.datetime).to_string()
+    }
+
+    pub fn short_date(&self, date: &DateTime<Utc>) -> String {
+        display_time(date).format(&self.short_date).to_string()
+    }
+
+    /// Calendar days have no time zone, so they are formatted as-is.
+    pub fn naive_date(&self, date: NaiveDate) -> String {
+        date.format(&self.date).to_string()
+    }
+}
+
+impl Default for DateFormat {
+    fn default() -> Self {
+        DateFormat::new(&crate::config::Config::default().ui)
+    }
 }
 
 pub fn format_relative_date(date: &DateTime<Utc>) -> String {
@@ -27,11 +116,98 @@ pub fn format_relative_date(date: &DateTime<Utc>) -> String {
     }
 }
 
+/// Compact relative duration such as "5m", "3h", "12d", "6w", "4mo" or "2y", used for
+/// ages, last-modified times and countdowns alike. Under a minute (or slightly negative
+/// from clock skew) shows as "1m". Days run to 13, weeks to 7, then months, rounded to
+/// the nearest so 8 weeks reads "2mo" and capped at 11 so a year never reads "12mo".
+pub fn format_relative_duration(duration: Duration) -> String {
+    let days = duration.num_days();
+
+    if duration.num_minutes() < 60 {
+        format!("{}m", duration.num_minutes().max(1))
+    } else if duration.num_hours() < 24 {
+        format!("{}h", duration.num_hours())
+    } else if days < 14 {
+        format!("{}d", days)
+    } else if days < 56 {
+        format!("{}w", days / 7)
+    } else if days < 365 {
+        format!("{}mo", ((days + 15) / 30).min(11))
+    } else {
+        format!("{}y", days / 365)
+    }
+}
+
+/// Time worked, such as "45m" or "2h 05m"
+pub fn format_hours_minutes(duration: Duration) -> String {
+    let minutes = duration.num_minutes().max(0);
+    if minutes < 60 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    }
+}
+
+/// Quote `text` as one word for a POSIX shell. Plain words like `project:work` or `+home`
+/// are left alone; anything else goes in single quotes.
+pub fn shell_quote(text: &str) -> String {
+    let plain = !text.is_empty()
+        && text.chars().all(|c| c.is_ascii_alphanumeric() || "-_.:+/@%=,".contains(c));
+    if plain {
+        text.to_string()
+    } else {
+        format!("'{}'", text.replace('\'', "'\\''"))
+    }
+}
+
+/// Word-wrap text into lines of at most `width` characters.
+/// Words longer than a line are split so nothing is ever cut off.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+
+        // Hard-break words that can't fit on a line of their own
+        while word.len() > width {
+            if current_len > 0 {
+                lines.push(std::mem::take(&mut current));
+                current_len = 0;
+            }
+            let rest = word.split_off(width);
+            lines.push(word.into_iter().collect());
+            word = rest;
+        }
+
+        if current_len > 0 && current_len + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        if current_len > 0 {
+            current.push(' ');
+            current_len += 1;
+        }
+        current_len += word.len();
+        current.extend(word);
+    }
+
+    if current_len > 0 || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Truncate text to at most `max_length` characters, ending with "..." when cut.
+/// Counts characters rather than bytes so multibyte text never splits mid-character.
 pub fn truncate_text(text: &str, max_length: usize) -> String {
-    if text.len() <= max_length {
+    if text.chars().count() <= max_length {
         text.to_string()
     } else {
-        format!("{}...", &text[..max_length.saturating_sub(3)])
+        let kept: String = text.chars().take(max_length.saturating_sub(3)).collect();
+        format!("{}...", kept)
     }
 }
 
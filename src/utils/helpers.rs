@@ -37,51 +37,107 @@ pub fn get_taskrc_path() -> Option<PathBuf> {
     None
 }
 
-pub fn calculate_urgency(task: &crate::data::models::Task) -> f64 {
+/// Urgency coefficients as configured in the user's taskrc
+#[derive(Debug, Clone)]
+pub struct UrgencyCoefficients {
+    pub priority_high: f64,
+    pub priority_medium: f64,
+    pub priority_low: f64,
+    pub project: f64,
+    pub active: f64,
+    pub tags: f64,
+    pub due: f64,
+}
+
+impl Default for UrgencyCoefficients {
+    fn default() -> Self {
+        UrgencyCoefficients {
+            priority_high: 6.0,
+            priority_medium: 3.9,
+            priority_low: 1.8,
+            project: 1.0,
+            active: 4.0,
+            tags: 1.0,
+            due: 12.0,
+        }
+    }
+}
+
+impl UrgencyCoefficients {
+    /// Taskwarrior config keys for each coefficient
+    pub const KEYS: [&'static str; 7] = [
+        "urgency.uda.priority.H.coefficient",
+        "urgency.uda.priority.M.coefficient",
+        "urgency.uda.priority.L.coefficient",
+        "urgency.project.coefficient",
+        "urgency.active.coefficient",
+        "urgency.tags.coefficient",
+        "urgency.due.coefficient",
+    ];
+
+    /// Build coefficients from a config lookup, keeping the default for any
+    /// key that is missing or not a number
+    pub fn from_lookup<F>(lookup: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let defaults = Self::default();
+        let read = |key: &str, default: f64| {
+            lookup(key)
+                .and_then(|value| value.trim().parse::<f64>().ok())
+                .unwrap_or(default)
+        };
+
+        UrgencyCoefficients {
+            priority_high: read(Self::KEYS[0], defaults.priority_high),
+            priority_medium: read(Self::KEYS[1], defaults.priority_medium),
+            priority_low: read(Self::KEYS[2], defaults.priority_low),
+            project: read(Self::KEYS[3], defaults.project),
+            active: read(Self::KEYS[4], defaults.active),
+            tags: read(Self::KEYS[5], defaults.tags),
+            due: read(Self::KEYS[6], defaults.due),
+        }
+    }
+}
+
+pub fn calculate_urgency(task: &crate::data::models::Task, coefficients: &UrgencyCoefficients) -> f64 {
     let mut urgency = 0.0;
     
-    // Base urgency
-    urgency += 1.0;
-    
     // Priority urgency
     if let Some(priority) = &task.priority {
         match priority {
-            crate::data::models::Priority::High => urgency += 6.0,
-            crate::data::models::Priority::Medium => urgency += 3.9,
-            crate::data::models::Priority::Low => urgency += 1.8,
+            crate::data::models::Priority::High => urgency += coefficients.priority_high,
+            crate::data::models::Priority::Medium => urgency += coefficients.priority_medium,
+            crate::data::models::Priority::Low => urgency += coefficients.priority_low,
         }
     }
     
     // Project urgency
     if task.project.is_some() {
-        urgency += 1.0;
+        urgency += coefficients.project;
     }
     
     // Active task urgency
     if task.is_active() {
-        urgency += 4.0;
+        urgency += coefficients.active;
     }
     
-    // Tags urgency
-    urgency += task.tags.len() as f64 * 1.0;
+    // Tags urgency: Taskwarrior counts 0.8 for one tag, 0.9 for two, the whole
+    // coefficient from three on
+    let tag_factor = match task.tags.len() {
+        0 => 0.0,
+        1 => 0.8,
+        2 => 0.9,
+        _ => 1.0,
+    };
+    urgency += tag_factor * coefficients.tags;
     
-    // Due date urgency
+    // Due date urgency: Taskwarrior's curve over 21 days, from 0.2 at two weeks
+    // ahead up to 1.0 at a week overdue
     if let Some(due) = task.due {
-        let now = chrono::Utc::now();
-        let days_until_due = (due - now).num_days();
-        
-        if days_until_due < 0 {
-            // Overdue
-            urgency += 12.0;
-        } else if days_until_due < 7 {
-            // Due this week
-            urgency += 5.0;
-        } else if days_until_due < 30 {
-            // Due this month
-            urgency += 2.0;
-        }
+        let days_overdue = (chrono::Utc::now() - due).num_seconds() as f64 / 86400.0;
+        urgency += (((days_overdue + 14.0) * 0.8 / 21.0) + 0.2).clamp(0.2, 1.0) * coefficients.due;
     }
     
     urgency
 }
-
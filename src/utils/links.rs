@@ -0,0 +1,108 @@
+// Find URLs in a task and open them in the default browser
+
+use anyhow::Result;
+use std::env;
+use std::io;
+use std::process::{Command, Stdio};
+
+use crate::data::models::Task;
+
+const SCHEMES: [&str; 2] = ["https://", "http://"];
+
+// Sentence punctuation right after a URL, e.g. "see https://example.com."
+const TRAILING: &[char] = &['.', ',', ';', ':', '!', '?', '\'', '"'];
+
+/// URLs in `text`, in order. A URL runs to the next whitespace, less trailing
+/// punctuation and a closing bracket it didn't open, as in "(https://example.com)".
+pub fn find_urls(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .filter_map(|word| {
+            let start = SCHEMES.iter().filter_map(|scheme| word.find(scheme)).min()?;
+            let mut url = &word[start..];
+            loop {
+                let trimmed = url.trim_end_matches(TRAILING);
+                let trimmed = match trimmed.chars().last() {
+                    Some(close @ (')' | ']' | '>')) => {
+                        let open = match close {
+                            ')' => '(',
+                            ']' => '[',
+                            _ => '<',
+                        };
+                        if trimmed.matches(open).count() < trimmed.matches(close).count() {
+                            &trimmed[..trimmed.len() - 1]
+                        } else {
+                            trimmed
+                        }
+                    }
+                    _ => trimmed,
+                };
+                if trimmed == url {
+                    break;
+                }
+                url = trimmed;
+            }
+            // A bare scheme isn't worth opening
+            (!SCHEMES.contains(&url)).then(|| url.to_string())
+        })
+        .collect()
+}
+
+/// URLs in the task's description, then its annotations, each once
+pub fn task_urls(task: &Task) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    let texts = std::iter::once(task.description.as_str()).chain(task.annotations.iter().map(|a| a.description.as_str()));
+    for url in texts.flat_map(find_urls) {
+        if !urls.contains(&url) {
+            urls.push(url);
+        }
+    }
+    urls
+}
+
+/// Open `url` in the default browser without waiting for it. Fails where no
+/// browser can be shown, e.g. over SSH without a display, rather than doing nothing.
+pub fn open_url(url: &str) -> Result<()> {
+    let (program, args) = opener(url)?;
+    let mut child = Command::new(program)
+        .args(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => anyhow::anyhow!("`{}` not found; can't open a browser", program),
+            _ => anyhow::Error::new(e).context(format!("Failed to run `{}`", program)),
+        })?;
+
+    // Reap the opener so it doesn't linger; a browser it starts carries on regardless
+    let program = program.to_string();
+    std::thread::spawn(move || match child.wait() {
+        Ok(status) if !status.success() => log::warn!("`{}` exited with {}", program, status),
+        Err(e) => log::warn!("Failed to wait for `{}`: {}", program, e),
+        _ => {}
+    });
+    Ok(())
+}
+
+// The platform's "open with the default application" command
+fn opener(url: &str) -> Result<(&'static str, Vec<String>)> {
+    if cfg!(target_os = "macos") {
+        return Ok(("open", vec![url.to_string()]));
+    }
+    if cfg!(windows) {
+        // Not `cmd /C start`, which would read the `&`s in a query string as commands
+        return Ok(("rundll32", vec!["url.dll,FileProtocolHandler".into(), url.to_string()]));
+    }
+
+    let has_display = ["DISPLAY", "WAYLAND_DISPLAY"]
+        .iter()
+        .any(|var| env::var_os(var).is_some_and(|value| !value.is_empty()));
+    if !has_display {
+        let over_ssh = env::var_os("SSH_CONNECTION").is_some() || env::var_os("SSH_TTY").is_some();
+        anyhow::bail!(
+            "No display to open a browser on{}",
+            if over_ssh { " (running over SSH)" } else { "" }
+        );
+    }
+    Ok(("xdg-open", vec![url.to_string()]))
+}
@@ -0,0 +1,37 @@
+// File logging for --verbose; stdout is taken by the alternate screen
+
+use anyhow::{Context, Result};
+use log::LevelFilter;
+use simplelog::{ConfigBuilder, WriteLogger};
+use std::fs::{self, OpenOptions};
+use std::path::PathBuf;
+
+pub fn log_file_path() -> Result<PathBuf> {
+    let config_dir = dirs::config_dir()
+        .ok_or_else(|| anyhow::anyhow!("Could not find config directory"))?;
+
+    Ok(config_dir.join("lazytask").join("lazytask.log"))
+}
+
+// Append debug logs to the log file and return its path
+pub fn init_file_logger() -> Result<PathBuf> {
+    let path = log_file_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create log directory: {:?}", parent))?;
+    }
+
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open log file: {:?}", path))?;
+
+    let config = ConfigBuilder::new()
+        .set_time_format_rfc3339()
+        .build();
+    WriteLogger::init(LevelFilter::Debug, config, file)
+        .with_context(|| "Failed to initialize logger")?;
+
+    Ok(path)
+}
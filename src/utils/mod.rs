@@ -2,4 +2,8 @@ pub mod keybindings;
 pub mod formatting;
 pub mod validation;
 pub mod helpers;
+pub mod logging;
+pub mod editor;
 
+pub mod clipboard;
+pub mod links;
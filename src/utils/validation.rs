@@ -47,6 +47,21 @@ pub fn validate_tag_name(tag: &str) -> Result<()> {
     Ok(())
 }
 
+/// Split a tag list typed by the user. Tags may be separated by commas and/or
+/// whitespace and written with a leading `+` or `-` as on the Taskwarrior
+/// command line; the sign is dropped. Fails on the first invalid tag.
+pub fn parse_tags(input: &str) -> Result<Vec<String>> {
+    let mut tags: Vec<String> = Vec::new();
+    for token in input.split(|c: char| c == ',' || c.is_whitespace()).filter(|s| !s.is_empty()) {
+        let tag = token.strip_prefix(['+', '-']).unwrap_or(token);
+        validate_tag_name(tag).map_err(|e| anyhow!("Invalid tag \"{}\": {}", token, e))?;
+        if !tags.iter().any(|known| known == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    Ok(tags)
+}
+
 pub fn parse_date(date_str: &str) -> Result<DateTime<Utc>> {
     // Try parsing different date formats
     if let Ok(date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {